@@ -0,0 +1,5 @@
+// Links the Node.js module the way Node.js loads it; nothing to do without the `node` feature.
+fn main() {
+    #[cfg(feature = "node")]
+    napi_build::setup();
+}
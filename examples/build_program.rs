@@ -0,0 +1,20 @@
+// Builds a countdown program with the library API instead of the DSL and prints the Brainfuck.
+//
+//     cargo run --example build_program > countdown.bf
+
+use brainfucktool::{CompileError, Program, Statements};
+
+fn main() -> Result<(), CompileError> {
+    let code = Program::new()
+        .let_var("n")
+        .push(5)
+        .set("n")
+        .while_do(
+            |c| c.get("n"),
+            |b| b.get("n").print_num().print(" ").get("n").dec().set("n"),
+        )
+        .print("liftoff\n")
+        .compile()?;
+    println!("{}", code);
+    Ok(())
+}
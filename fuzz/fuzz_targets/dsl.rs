@@ -0,0 +1,13 @@
+// Tokenizes and parses random DSL text, which must fail with an error rather than a panic.
+
+#![no_main]
+
+use brainfucktool::parser::{self, SourceMap};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|src: &str| {
+    let mut sources = SourceMap::default();
+    let file = sources.add("fuzz.bfs", src.to_string());
+    // No search path, so `import` cannot read files.
+    let _ = parser::parse(&mut sources, file, &[]);
+});
@@ -0,0 +1,21 @@
+// Parses random text with random syntax options; what parses must have its brackets linked.
+
+#![no_main]
+
+use brainfucktool::ir::{self, Op, Syntax};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|case: (Syntax<'_>, &str)| {
+    let (syntax, src) = case;
+    let Ok(program) = ir::parse(src, syntax) else {
+        return;
+    };
+    for (i, inst) in program.iter().enumerate() {
+        assert!(inst.pos < src.len());
+        match inst.op {
+            Op::Open(close) => assert_eq!(program[close].op, Op::Close(i)),
+            Op::Close(open) => assert_eq!(program[open].op, Op::Open(i)),
+            _ => {}
+        }
+    }
+});
@@ -0,0 +1,32 @@
+// Runs random programs on the reference interpreter and the interpreter of the folded ops, within
+// a small budget, and checks that they agree whenever both finish.
+
+#![no_main]
+
+use brainfucktool::ir::{self, Syntax};
+use brainfucktool::{reference, verify, Target};
+use libfuzzer_sys::fuzz_target;
+
+// Small enough that a case runs in well under a millisecond.
+const MAX_STEPS: u64 = 10_000;
+const CELLS: usize = 30000;
+
+fuzz_target!(|case: (&str, &[u8], Target)| {
+    let (src, input, target) = case;
+    let Ok(program) = ir::parse(src, Syntax::default()) else {
+        return;
+    };
+    // Any cell size must run without panicking.
+    let _ = verify::execute_within(&program, input, target, MAX_STEPS);
+    let (Ok(expected), Ok(actual)) = (
+        reference::run_within(src, input, CELLS, MAX_STEPS),
+        verify::execute_within(&program, input, Target::default(), MAX_STEPS),
+    ) else {
+        return;
+    };
+    let tape = actual.tape.iter().map(|&cell| cell as u8).collect();
+    let actual = reference::Run { output: actual.output, tape, ptr: actual.ptr };
+    if let Err(e) = reference::compare(&expected, &actual) {
+        panic!("{:?}: {}", src, e);
+    }
+});
@@ -0,0 +1,16 @@
+// `npm run build`: builds the library with the `node` feature and copies it to where
+// node/index.js loads it from. Pass `--debug` for an unoptimized build.
+
+'use strict'
+
+const { execFileSync } = require('child_process')
+const fs = require('fs')
+const path = require('path')
+
+const release = !process.argv.includes('--debug')
+const root = path.join(__dirname, '..')
+const args = ['build', '--lib', '--features', 'node'].concat(release ? ['--release'] : [])
+execFileSync('cargo', args, { cwd: root, stdio: 'inherit' })
+
+const file = { darwin: 'libbrainfucktool.dylib', win32: 'brainfucktool.dll' }[process.platform] || 'libbrainfucktool.so'
+fs.copyFileSync(path.join(root, 'target', release ? 'release' : 'debug', file), path.join(__dirname, 'brainfucktool.node'))
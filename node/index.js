@@ -0,0 +1,73 @@
+// The Node.js module: `run` and a `Machine` that emits events as it runs.
+//
+// The addon is built from the `node` feature by `npm run build` (node/build.js). `Machine` emits
+//   'step'   { pc, position, pointer } of the op it is about to run
+//   'output' a Buffer of the bytes an op printed
+//   'end'    the Buffer of everything the program printed, once it has ended
+//   'error'  the Error a failed op threw (a pointer off the tape, or too many steps in `run`)
+// and `run` steps it until the end, so listeners see every op without a loop of their own.
+
+'use strict'
+
+const { EventEmitter } = require('events')
+const path = require('path')
+const native = require(path.join(__dirname, 'brainfucktool.node'))
+
+class Machine extends EventEmitter {
+  constructor(source, options = {}) {
+    super()
+    this.native = new native.Machine(source, options)
+    this.maxSteps = options.maxSteps
+  }
+
+  // Runs the next op. Returns false, doing nothing, once the program has ended or an op failed.
+  step() {
+    if (this.native.finished || this.failed) {
+      return false
+    }
+    if (this.listenerCount('step') > 0) {
+      this.emit('step', { pc: this.native.pc, position: this.native.position, pointer: this.native.pointer })
+    }
+    try {
+      this.native.step()
+    } catch (error) {
+      return this.fail(error)
+    }
+    const output = this.native.takeOutput()
+    if (output.length > 0) {
+      this.emit('output', output)
+    }
+    if (this.native.finished) {
+      this.emit('end', this.native.output)
+    }
+    return true
+  }
+
+  // Steps until the program ends, failing after `maxSteps` more ops (by default the one given
+  // to the constructor). Returns whether the program ended.
+  run(maxSteps = this.maxSteps) {
+    for (let left = maxSteps; !this.native.finished && !this.failed; left--) {
+      if (maxSteps !== undefined && left <= 0) {
+        return this.fail(new Error(`no result after ${maxSteps} steps`))
+      }
+      this.step()
+    }
+    return this.native.finished
+  }
+
+  fail(error) {
+    this.failed = true
+    this.emit('error', error)
+    return false
+  }
+
+  get finished() { return this.native.finished }
+  get position() { return this.native.position }
+  get tape() { return this.native.tape }
+  get pointer() { return this.native.pointer }
+  get pc() { return this.native.pc }
+  get steps() { return this.native.steps }
+  get output() { return this.native.output }
+}
+
+module.exports = { run: native.run, Machine }
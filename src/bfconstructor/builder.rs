@@ -0,0 +1,375 @@
+// Building `Cmd` programs from Rust, as an alternative to the DSL:
+//
+//     let code = Program::new()
+//         .let_var("a")
+//         .push(5)
+//         .set("a")
+//         .if_then(|c| c.get("a"), |t| t.print("five\n"))
+//         .compile()?;
+//
+// The statement methods come from `Statements`, which `Program` implements for the main program
+// and `Block` for the bodies of block statements. Each method appends the `Cmd` of the DSL
+// statement with the same name (`break` and `continue` are `break_loop` and `continue_loop`).
+
+use crate::ir::{self, Inst, Syntax};
+use crate::procedure::{CallConv, Proc};
+use crate::{scope, Cmd, CompileError, Config, Let, Program};
+
+// Appends statements to a command list.
+pub trait Statements: Sized {
+    fn cmd(self, cmd: Cmd) -> Self;
+
+    fn clear(self) -> Self {
+        self.cmd(Cmd::Clear)
+    }
+    fn copy(self) -> Self {
+        self.cmd(Cmd::Copy)
+    }
+    fn swap(self) -> Self {
+        self.cmd(Cmd::Swap)
+    }
+    fn drop(self) -> Self {
+        self.cmd(Cmd::Drop)
+    }
+    fn over(self) -> Self {
+        self.cmd(Cmd::Over)
+    }
+    fn pick(self, n: usize) -> Self {
+        self.cmd(Cmd::Pick(n))
+    }
+    fn poke(self, n: usize) -> Self {
+        self.cmd(Cmd::Poke(n))
+    }
+    fn get(self, var: &str) -> Self {
+        self.cmd(Cmd::Get(var.to_string()))
+    }
+    fn set(self, var: &str) -> Self {
+        self.cmd(Cmd::Set(var.to_string()))
+    }
+    fn get_idx(self, array: &str, index: usize) -> Self {
+        self.cmd(Cmd::GetIdx(array.to_string(), index))
+    }
+    fn set_idx(self, array: &str, index: usize) -> Self {
+        self.cmd(Cmd::SetIdx(array.to_string(), index))
+    }
+    fn read(self) -> Self {
+        self.cmd(Cmd::Read)
+    }
+    fn write(self) -> Self {
+        self.cmd(Cmd::Write)
+    }
+    fn push(self, n: usize) -> Self {
+        self.cmd(Cmd::Push(n))
+    }
+    fn inc(self) -> Self {
+        self.cmd(Cmd::Inc)
+    }
+    fn dec(self) -> Self {
+        self.cmd(Cmd::Dec)
+    }
+    fn add(self) -> Self {
+        self.cmd(Cmd::Add)
+    }
+    fn sub(self) -> Self {
+        self.cmd(Cmd::Sub)
+    }
+    fn mul(self) -> Self {
+        self.cmd(Cmd::Mul)
+    }
+    fn addc(self, n: usize) -> Self {
+        self.cmd(Cmd::Addc(n))
+    }
+    fn subc(self, n: usize) -> Self {
+        self.cmd(Cmd::Subc(n))
+    }
+    fn bool(self) -> Self {
+        self.cmd(Cmd::Bool)
+    }
+    fn eq(self) -> Self {
+        self.cmd(Cmd::Eq)
+    }
+    fn neq(self) -> Self {
+        self.cmd(Cmd::Neq)
+    }
+    fn lt(self) -> Self {
+        self.cmd(Cmd::Lt)
+    }
+    fn le(self) -> Self {
+        self.cmd(Cmd::Le)
+    }
+    fn gt(self) -> Self {
+        self.cmd(Cmd::Gt)
+    }
+    fn ge(self) -> Self {
+        self.cmd(Cmd::Ge)
+    }
+    fn slt(self) -> Self {
+        self.cmd(Cmd::Slt)
+    }
+    fn sle(self) -> Self {
+        self.cmd(Cmd::Sle)
+    }
+    fn sgt(self) -> Self {
+        self.cmd(Cmd::Sgt)
+    }
+    fn sge(self) -> Self {
+        self.cmd(Cmd::Sge)
+    }
+    fn neg(self) -> Self {
+        self.cmd(Cmd::Neg)
+    }
+    fn and(self) -> Self {
+        self.cmd(Cmd::And)
+    }
+    fn or(self) -> Self {
+        self.cmd(Cmd::Or)
+    }
+    fn not(self) -> Self {
+        self.cmd(Cmd::Not)
+    }
+    fn read_num(self) -> Self {
+        self.cmd(Cmd::ReadNum)
+    }
+    fn read_line(self, buf: &str) -> Self {
+        self.cmd(Cmd::ReadLine(buf.to_string()))
+    }
+    fn lookup(self, array: &str) -> Self {
+        self.cmd(Cmd::Lookup(array.to_string()))
+    }
+    fn store(self, array: &str) -> Self {
+        self.cmd(Cmd::Store(array.to_string()))
+    }
+    fn alloc(self, name: &str, len: usize) -> Self {
+        self.cmd(Cmd::Alloc(name.to_string(), len))
+    }
+    fn free(self, name: &str) -> Self {
+        self.cmd(Cmd::Free(name.to_string()))
+    }
+    fn addr(self, name: &str) -> Self {
+        self.cmd(Cmd::Addr(name.to_string()))
+    }
+    fn print_num(self) -> Self {
+        self.cmd(Cmd::PrintNum)
+    }
+    fn print_signed(self) -> Self {
+        self.cmd(Cmd::PrintSigned)
+    }
+    fn print(self, text: &str) -> Self {
+        self.cmd(Cmd::PrintStr(text.to_string()))
+    }
+    fn print_var(self, array: &str) -> Self {
+        self.cmd(Cmd::PrintVar(array.to_string()))
+    }
+    // Pushes an 8.8 fixed-point number (`pushfx`), rounded to the nearest 1/256 and clamped to 0..=255.996.
+    fn push_fixed(self, value: f64) -> Self {
+        self.cmd(Cmd::PushWide(2, (value * 256.0).round() as u16 as u64))
+    }
+    fn add_fixed(self) -> Self {
+        self.cmd(Cmd::AddWide(2))
+    }
+    fn sub_fixed(self) -> Self {
+        self.cmd(Cmd::SubWide(2))
+    }
+    fn mul_fixed(self) -> Self {
+        self.cmd(Cmd::MulFixed)
+    }
+    fn print_fixed(self, digits: usize) -> Self {
+        self.cmd(Cmd::PrintFixed(digits))
+    }
+    fn push16(self, value: u16) -> Self {
+        self.cmd(Cmd::PushWide(2, value as u64))
+    }
+    fn push32(self, value: u32) -> Self {
+        self.cmd(Cmd::PushWide(4, value as u64))
+    }
+    fn add16(self) -> Self {
+        self.cmd(Cmd::AddWide(2))
+    }
+    fn add32(self) -> Self {
+        self.cmd(Cmd::AddWide(4))
+    }
+    fn sub16(self) -> Self {
+        self.cmd(Cmd::SubWide(2))
+    }
+    fn sub32(self) -> Self {
+        self.cmd(Cmd::SubWide(4))
+    }
+    fn cmp16(self) -> Self {
+        self.cmd(Cmd::CmpWide(2))
+    }
+    fn cmp32(self) -> Self {
+        self.cmd(Cmd::CmpWide(4))
+    }
+    fn print16(self) -> Self {
+        self.cmd(Cmd::PrintWide(2))
+    }
+    fn print32(self) -> Self {
+        self.cmd(Cmd::PrintWide(4))
+    }
+    fn break_loop(self) -> Self {
+        self.cmd(Cmd::Break)
+    }
+    fn continue_loop(self) -> Self {
+        self.cmd(Cmd::Continue)
+    }
+    fn call(self, name: &str) -> Self {
+        self.cmd(Cmd::Call(name.to_string()))
+    }
+    fn assert_stack(self, height: isize) -> Self {
+        self.cmd(Cmd::AssertStack(height))
+    }
+
+    fn stat(self, body: impl FnOnce(Block) -> Block) -> Self {
+        self.cmd(Cmd::Stat(body(Block::new()).cmds))
+    }
+    fn scope(self, lets: Vec<Let>, body: impl FnOnce(Block) -> Block) -> Self {
+        self.cmd(Cmd::Scope { lets, body: body(Block::new()).cmds })
+    }
+    fn if_then(self, cond: impl FnOnce(Block) -> Block, then_block: impl FnOnce(Block) -> Block) -> Self {
+        self.cmd(Cmd::IfThen {
+            cond: cond(Block::new()).cmds,
+            then_block: then_block(Block::new()).cmds,
+        })
+    }
+    fn if_then_else(
+        self,
+        cond: impl FnOnce(Block) -> Block,
+        then_block: impl FnOnce(Block) -> Block,
+        else_block: impl FnOnce(Block) -> Block,
+    ) -> Self {
+        self.cmd(Cmd::IfThenElse {
+            cond: cond(Block::new()).cmds,
+            then_block: then_block(Block::new()).cmds,
+            else_block: else_block(Block::new()).cmds,
+        })
+    }
+    fn while_do(self, cond: impl FnOnce(Block) -> Block, body: impl FnOnce(Block) -> Block) -> Self {
+        self.cmd(Cmd::While {
+            cond: cond(Block::new()).cmds,
+            body: body(Block::new()).cmds,
+        })
+    }
+    fn switch(self, scrutinee: impl FnOnce(Block) -> Block, cases: impl FnOnce(Cases) -> Cases) -> Self {
+        let cases = cases(Cases { arms: Vec::new(), default: Vec::new() });
+        self.cmd(Cmd::Switch {
+            scrutinee: scrutinee(Block::new()).cmds,
+            arms: cases.arms,
+            default: cases.default,
+        })
+    }
+}
+
+// The body of a block statement.
+#[derive(Debug, Clone, Default)]
+pub struct Block {
+    pub cmds: Vec<Cmd>,
+}
+
+impl Block {
+    pub fn new() -> Block {
+        Block::default()
+    }
+}
+
+impl Statements for Block {
+    fn cmd(mut self, cmd: Cmd) -> Self {
+        self.cmds.push(cmd);
+        self
+    }
+}
+
+// The arms of a `switch`.
+#[derive(Debug, Clone)]
+pub struct Cases {
+    arms: Vec<(u8, Vec<Cmd>)>,
+    default: Vec<Cmd>,
+}
+
+impl Cases {
+    pub fn case(mut self, value: u8, body: impl FnOnce(Block) -> Block) -> Cases {
+        self.arms.push((value, body(Block::new()).cmds));
+        self
+    }
+
+    pub fn default(mut self, body: impl FnOnce(Block) -> Block) -> Cases {
+        self.default = body(Block::new()).cmds;
+        self
+    }
+}
+
+impl Program {
+    pub fn new() -> Program {
+        Program::default()
+    }
+
+    // Declares a variable (`let name`).
+    pub fn let_var(mut self, name: &str) -> Program {
+        self.lets.push(Let::scalar(name));
+        self
+    }
+
+    // Declares an array (`let name[len]`).
+    pub fn let_array(mut self, name: &str, len: usize) -> Program {
+        self.lets.push(Let::array(name, len));
+        self
+    }
+
+    // Declares an initialized string (`let name = "text"`, or `counted "text"`).
+    pub fn let_string(mut self, name: &str, text: &str, counted: bool) -> Program {
+        self.lets.push(Let::string(name, text, counted));
+        self
+    }
+
+    // Declares a table (`let name = [v, ...]`).
+    pub fn let_table(mut self, name: &str, values: &[u8]) -> Program {
+        self.lets.push(Let::table(name, values.to_vec()));
+        self
+    }
+
+    // Declares the region that `alloc` hands out (`heap len`).
+    pub fn heap(mut self, len: usize) -> Program {
+        self.lets.push(Let::array(crate::heap::NAME, len));
+        self
+    }
+
+    // Declares a procedure (`proc [inline|frame] name(params) returns n { body }`).
+    pub fn procedure(
+        mut self,
+        conv: CallConv,
+        name: &str,
+        params: &[&str],
+        returns: usize,
+        body: impl FnOnce(Block) -> Block,
+    ) -> Program {
+        self.procs.push(Proc {
+            name: name.to_string(),
+            params: params.iter().map(|p| p.to_string()).collect(),
+            returns,
+            conv,
+            body: body(Block::new()).cmds,
+        });
+        self
+    }
+
+    // Generates Brainfuck for 8-bit wrapping cells.
+    pub fn compile(&self) -> Result<String, CompileError> {
+        self.compile_with(Config::default())
+    }
+
+    pub fn compile_with(&self, config: Config) -> Result<String, CompileError> {
+        scope(&self.lets, &self.procs, config, &self.body, 0, 4).map(|state| state.code)
+    }
+
+    // Generates the program as `ir` ops, ready to run or to emit with `ir::emit`.
+    pub fn compile_ir(&self) -> Result<Vec<Inst>, CompileError> {
+        let code = self.compile()?;
+        Ok(ir::parse(&code, Syntax::default()).expect("generated code has balanced brackets"))
+    }
+}
+
+impl Statements for Program {
+    fn cmd(mut self, cmd: Cmd) -> Self {
+        self.body.push(cmd);
+        self
+    }
+}
@@ -0,0 +1,137 @@
+// Diagnostics: the errors and notes the tools report about a source file.
+//
+// A diagnostic has a severity, a stable code naming the kind of problem, a message, the span it is
+// about and extra notes. `render` formats it for people, with the source line underlined;
+// `to_json` formats it as one line of JSON for `--error-format json`, so editors and CI scripts
+// can read the same information:
+//
+//     {"severity":"error","code":"undefined-variable","message":"undefined variable 'x'",
+//      "span":{"file":"a.bfs","start":10,"end":11,"line":2,"column":5},"notes":[]}
+
+use crate::lexer::Span;
+use crate::parser::{ParseError, SourceMap};
+use crate::CompileError;
+use clap::ValueEnum;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+    Note,
+}
+
+impl Severity {
+    fn label(self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Note => "note",
+        }
+    }
+}
+
+// How diagnostics are printed (`--error-format`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+    #[default]
+    Human,
+    Json,
+}
+
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: &'static str,
+    pub message: String,
+    pub span: Option<Span>,
+    pub notes: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct JsonSpan<'a> {
+    file: &'a str,
+    start: usize,
+    end: usize,
+    line: usize,
+    column: usize,
+}
+
+#[derive(Serialize)]
+struct JsonDiagnostic<'a> {
+    severity: Severity,
+    code: &'a str,
+    message: &'a str,
+    span: Option<JsonSpan<'a>>,
+    notes: &'a [String],
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, code: &'static str, message: impl Into<String>, span: Option<Span>) -> Diagnostic {
+        Diagnostic { severity, code, message: message.into(), span, notes: Vec::new() }
+    }
+
+    pub fn with_note(mut self, note: impl Into<String>) -> Diagnostic {
+        self.notes.push(note.into());
+        self
+    }
+
+    // Formats the diagnostic for people. Errors and warnings show the source line with the span
+    // underlined; notes fit on one line.
+    pub fn render(&self, sources: &SourceMap) -> String {
+        let mut out = match (self.severity, self.span) {
+            (Severity::Error | Severity::Warning, Some(span)) => {
+                sources.render_as(self.severity.label(), span, &self.message)
+            }
+            (_, Some(span)) => format!("{}: {} at {}\n", self.severity.label(), self.message, sources.location(span)),
+            (_, None) => format!("{}: {}\n", self.severity.label(), self.message),
+        };
+        for note in &self.notes {
+            out += &format!("  = {}\n", note);
+        }
+        out
+    }
+
+    pub fn to_json(&self, sources: &SourceMap) -> String {
+        let names = sources.names();
+        let span = self.span.map(|span| {
+            let (line, column) = sources.line_col(span);
+            JsonSpan {
+                file: names.get(span.file).map_or("<unknown>", String::as_str),
+                start: span.start,
+                end: span.end,
+                line,
+                column,
+            }
+        });
+        let json = JsonDiagnostic {
+            severity: self.severity,
+            code: self.code,
+            message: &self.message,
+            span,
+            notes: &self.notes,
+        };
+        serde_json::to_string(&json).expect("diagnostics serialize")
+    }
+
+    // Formats the diagnostic in `format`, ending with a newline.
+    pub fn format(&self, sources: &SourceMap, format: Format) -> String {
+        match format {
+            Format::Human => self.render(sources),
+            Format::Json => self.to_json(sources) + "\n",
+        }
+    }
+}
+
+impl From<&ParseError> for Diagnostic {
+    fn from(e: &ParseError) -> Diagnostic {
+        Diagnostic::new(Severity::Error, "syntax", e.message.clone(), Some(e.span))
+    }
+}
+
+impl From<&CompileError> for Diagnostic {
+    fn from(e: &CompileError) -> Diagnostic {
+        Diagnostic::new(Severity::Error, e.code(), e.to_string(), e.span())
+    }
+}
@@ -0,0 +1,186 @@
+// Errors reported while compiling a `Cmd` program.
+
+use crate::lexer::Span;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompileError {
+    // A `get`/`set` referred to a variable that was never declared with `let`.
+    UndefinedVariable { name: String },
+    // A command tried to pop more values than the stack holds.
+    StackUnderflow { cmd: String },
+    // A block left the stack pointer somewhere other than where its construct requires.
+    UnbalancedBlock {
+        block: String,
+        expected: isize,
+        actual: isize,
+    },
+    // Like `UnbalancedBlock`, located at the statement that left the stack at `at` (relative to the
+    // block's start), after which it never returned to the expected height.
+    Diverges {
+        block: String,
+        expected: isize,
+        actual: isize,
+        at: isize,
+    },
+    // An `assert_stack` found the stack top at a different height above the start of its block.
+    StackAssertion { expected: isize, actual: isize },
+    // An array was used as a scalar variable.
+    MissingIndex { name: String },
+    // A constant array index was past the end of the array.
+    IndexOutOfBounds { name: String, index: usize, len: usize },
+    // An array operation named a variable that is not an array.
+    NotAnArray { name: String },
+    // `break` or `continue` used outside of a loop body.
+    OutsideLoop { cmd: String },
+    // `call` named a procedure that was never declared.
+    UndefinedProcedure { name: String },
+    // A frame procedure was called inside a block rather than directly in a procedure body or the main program.
+    NestedFrameCall { name: String },
+    // An inline procedure called itself, directly or indirectly.
+    RecursiveInline { name: String },
+    // The frame call dispatcher ran out of block numbers.
+    TooManyBlocks,
+    // A command whose code relies on 8-bit wraparound was compiled for other cells.
+    NeedsByteWrap { cmd: String, target: String },
+    // Code that relies on cells wrapping around at all was compiled for cells that do not.
+    NeedsWrap { what: String },
+    // `alloc` was used in a program without a `heap` declaration.
+    NoHeap,
+    // `alloc` found no run of free heap cells long enough.
+    HeapFull { name: String, len: usize },
+    // `alloc` named a block that is already allocated.
+    AlreadyAllocated { name: String },
+    // `free` or `addr` named something that is not a block allocated in the current block of code.
+    NotAllocated { name: String },
+    // A block of code ended with a heap block it allocated still allocated.
+    NotFreed { name: String },
+    // An error located at a span of the DSL source.
+    At { span: Span, error: Box<CompileError> },
+}
+
+impl CompileError {
+    // Attaches a source span unless the error already carries a more precise one.
+    pub fn at(self, span: Span) -> CompileError {
+        match self {
+            CompileError::At { .. } => self,
+            error => CompileError::At {
+                span,
+                error: Box::new(error),
+            },
+        }
+    }
+
+    // A stable name for the kind of error, for `--error-format json`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            CompileError::UndefinedVariable { .. } => "undefined-variable",
+            CompileError::StackUnderflow { .. } => "stack-underflow",
+            CompileError::UnbalancedBlock { .. } | CompileError::Diverges { .. } => "unbalanced-block",
+            CompileError::StackAssertion { .. } => "stack-assertion",
+            CompileError::MissingIndex { .. } => "missing-index",
+            CompileError::IndexOutOfBounds { .. } => "index-out-of-bounds",
+            CompileError::NotAnArray { .. } => "not-an-array",
+            CompileError::OutsideLoop { .. } => "outside-loop",
+            CompileError::UndefinedProcedure { .. } => "undefined-procedure",
+            CompileError::NestedFrameCall { .. } => "nested-frame-call",
+            CompileError::RecursiveInline { .. } => "recursive-inline",
+            CompileError::TooManyBlocks => "too-many-blocks",
+            CompileError::NeedsByteWrap { .. } => "needs-byte-wrap",
+            CompileError::NeedsWrap { .. } => "needs-wrap",
+            CompileError::NoHeap => "no-heap",
+            CompileError::HeapFull { .. } => "heap-full",
+            CompileError::AlreadyAllocated { .. } => "already-allocated",
+            CompileError::NotAllocated { .. } => "not-allocated",
+            CompileError::NotFreed { .. } => "not-freed",
+            CompileError::At { error, .. } => error.code(),
+        }
+    }
+
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            CompileError::At { span, .. } => Some(*span),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for CompileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CompileError::UndefinedVariable { name } => {
+                write!(f, "undefined variable '{}'", name)
+            }
+            CompileError::StackUnderflow { cmd } => {
+                write!(f, "stack underflow in '{}'", cmd)
+            }
+            CompileError::UnbalancedBlock { block, expected, actual } => write!(
+                f,
+                "{} must change the stack pointer by {:+}, but it changed it by {:+}",
+                block, expected, actual
+            ),
+            CompileError::Diverges { block, expected, actual, at } => write!(
+                f,
+                "{} must change the stack pointer by {:+}, but it changed it by {:+}: this statement leaves it at {:+} and it never returns to {:+}",
+                block, expected, actual, at, expected
+            ),
+            CompileError::StackAssertion { expected, actual } => write!(
+                f,
+                "assert_stack({}) failed: the stack is at {:+} from the start of the block",
+                expected, actual
+            ),
+            CompileError::MissingIndex { name } => {
+                write!(f, "'{}' is an array; use {}[index]", name, name)
+            }
+            CompileError::IndexOutOfBounds { name, index, len } => write!(
+                f,
+                "index {} is out of bounds for '{}' of length {}",
+                index, name, len
+            ),
+            CompileError::NotAnArray { name } => {
+                write!(f, "'{}' is not an array", name)
+            }
+            CompileError::OutsideLoop { cmd } => {
+                write!(f, "'{}' used outside of a loop body", cmd)
+            }
+            CompileError::UndefinedProcedure { name } => {
+                write!(f, "undefined procedure '{}'", name)
+            }
+            CompileError::NestedFrameCall { name } => write!(
+                f,
+                "frame procedure '{}' can only be called directly in the main program or a frame procedure body",
+                name
+            ),
+            CompileError::RecursiveInline { name } => write!(
+                f,
+                "inline procedure '{}' calls itself; declare it with 'proc frame'",
+                name
+            ),
+            CompileError::TooManyBlocks => {
+                write!(f, "too many frame calls (at most 255 blocks)")
+            }
+            CompileError::NeedsByteWrap { cmd, target } => {
+                write!(f, "'{}' relies on 8-bit wraparound, which the target ({}) does not provide", cmd, target)
+            }
+            CompileError::NeedsWrap { what } => {
+                write!(f, "{} rely on cells wrapping around; remove --no-wrap to use them", what)
+            }
+            CompileError::NoHeap => {
+                write!(f, "'alloc' needs a heap; declare one with 'heap N'")
+            }
+            CompileError::HeapFull { name, len } => {
+                write!(f, "no room in the heap for the {} cells of '{}'", len, name)
+            }
+            CompileError::AlreadyAllocated { name } => {
+                write!(f, "'{}' is already allocated; free it first", name)
+            }
+            CompileError::NotAllocated { name } => {
+                write!(f, "'{}' is not a heap block allocated in this block", name)
+            }
+            CompileError::NotFreed { name } => {
+                write!(f, "heap block '{}' is still allocated at the end of the block that allocated it", name)
+            }
+            CompileError::At { error, .. } => error.fmt(f),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+// `heap N`, `alloc` and `free`: a region of cells handed out while compiling.
+//
+// `heap N` declares the region as a top-level array called `heap`, so it sits with the other
+// globals below the stack and can be read and written by runtime index with `lookup heap` and
+// `store heap`. `alloc name n` takes `n` consecutive cells of it, first fit, and binds `name` as an
+// array over them until `free name`. Handles are compile-time names, not runtime pointers: `addr
+// name` pushes the offset of the first cell within `heap`, which is what linked structures store
+// to walk from one block to the next.
+//
+// A block is freed in the block of code that allocated it, and must be freed before that block
+// ends, so that its cells are never reused while a name still refers to them.
+
+pub const NAME: &str = "heap";
+
+#[derive(Debug, Clone)]
+struct Block {
+    name: String,
+    start: usize,
+    len: usize,
+    depth: usize, // The nesting depth of the block of code that allocated it.
+}
+
+// The blocks of the heap in use, in order of their cells.
+#[derive(Debug, Clone, Default)]
+pub struct Heap {
+    blocks: Vec<Block>,
+}
+
+impl Heap {
+    // Takes `len` cells of a heap of `size` cells for `name`, returning the offset of the first.
+    pub fn alloc(&mut self, name: &str, len: usize, size: usize, depth: usize) -> Option<usize> {
+        let mut start = 0;
+        let mut at = self.blocks.len();
+        for (i, block) in self.blocks.iter().enumerate() {
+            if block.start - start >= len {
+                at = i;
+                break;
+            }
+            start = block.start + block.len;
+        }
+        if at == self.blocks.len() && size.saturating_sub(start) < len {
+            return None;
+        }
+        let block = Block { name: name.to_string(), start, len, depth };
+        self.blocks.insert(at, block);
+        Some(start)
+    }
+
+    // Releases the block of `name`, if it was allocated at `depth`.
+    pub fn free(&mut self, name: &str, depth: usize) -> bool {
+        match self.blocks.iter().position(|b| b.name == name && b.depth == depth) {
+            Some(i) => {
+                self.blocks.remove(i);
+                true
+            }
+            None => false,
+        }
+    }
+
+    // The offset of the block of `name`, if it is allocated.
+    pub fn start(&self, name: &str) -> Option<usize> {
+        self.blocks.iter().find(|b| b.name == name).map(|b| b.start)
+    }
+
+    // A block allocated at `depth` or deeper, which should have been freed by the end of a block of code at `depth`.
+    pub fn unfreed(&self, depth: usize) -> Option<&str> {
+        self.blocks.iter().find(|b| b.depth >= depth).map(|b| b.name.as_str())
+    }
+}
@@ -0,0 +1,133 @@
+// `--layout`: which tape cells are owned by which variables, arrays and temporaries.
+//
+// The compiler records every cell it hands out while it generates code: top-level and scope
+// variables, procedure parameters, and the flag cells that `if`, `switch`, `while` and
+// `readline` keep below their blocks. Cells are reused, so a cell can have several owners, each
+// live during the statement it was recorded for. Frame procedures run wherever the caller's
+// stack top is, so their cells are given relative to the start of their frame.
+
+use crate::lexer::Span;
+use crate::parser::SourceMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Variable,
+    Array,
+    Parameter,
+    Temporary,
+}
+
+impl std::fmt::Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let name = match self {
+            Kind::Variable => "variable",
+            Kind::Array => "array",
+            Kind::Parameter => "parameter",
+            Kind::Temporary => "temporary",
+        };
+        f.write_str(name)
+    }
+}
+
+// Cells `start..start + len` owned by `name`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Owner {
+    pub start: usize,
+    pub len: usize,
+    pub name: String,
+    pub kind: Kind,
+    pub frame: Option<String>, // The frame procedure whose frame the cells are relative to.
+    pub span: Option<Span>,    // The statement during which the cells are owned; `None` for the whole program.
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Layout {
+    pub owners: Vec<Owner>,
+    pub stack_top: usize, // The highest stack top reached outside of frame procedures.
+    spans: Vec<Span>,
+    frame: Option<String>,
+}
+
+impl Layout {
+    pub(crate) fn record(&mut self, start: usize, len: usize, name: &str, kind: Kind) {
+        let owner = Owner {
+            start,
+            len,
+            name: name.to_string(),
+            kind,
+            frame: self.frame.clone(),
+            span: self.spans.last().copied(),
+        };
+        if !self.owners.contains(&owner) {
+            self.owners.push(owner);
+        }
+    }
+
+    pub(crate) fn reach(&mut self, top: usize) {
+        if self.frame.is_none() {
+            self.stack_top = self.stack_top.max(top);
+        }
+    }
+
+    pub(crate) fn enter(&mut self, span: Span) {
+        self.spans.push(span);
+    }
+
+    pub(crate) fn leave(&mut self) {
+        self.spans.pop();
+    }
+
+    pub(crate) fn set_frame(&mut self, frame: Option<&str>) {
+        self.frame = frame.map(str::to_string);
+    }
+
+    // Formats the owners as a table sorted by cell, main program first and frames after it.
+    pub fn render(&self, sources: &SourceMap) -> String {
+        let mut owners: Vec<&Owner> = self.owners.iter().collect();
+        owners.sort_by(|a, b| (&a.frame, a.start).cmp(&(&b.frame, b.start)));
+        let rows: Vec<[String; 4]> = owners
+            .iter()
+            .map(|owner| {
+                let offset = if owner.frame.is_some() { "+" } else { "" };
+                let cells = match owner.len {
+                    1 => format!("{}{}", offset, owner.start),
+                    len => format!("{}{}-{}", offset, owner.start, owner.start + len - 1),
+                };
+                let live = match (&owner.frame, owner.span) {
+                    (Some(frame), Some(span)) => format!("{} (frame of '{}')", sources.location(span), frame),
+                    (None, Some(span)) => sources.location(span),
+                    (Some(frame), None) => format!("frame of '{}'", frame),
+                    (None, None) => "program".to_string(),
+                };
+                [cells, owner.name.clone(), owner.kind.to_string(), live]
+            })
+            .collect();
+        let header = ["cells", "owner", "kind", "live"].map(str::to_string);
+        let mut widths = [0; 4];
+        for row in std::iter::once(&header).chain(&rows) {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        let mut out = String::new();
+        for row in std::iter::once(&header).chain(&rows) {
+            let line = format!(
+                "{:<w0$}  {:<w1$}  {:<w2$}  {}",
+                row[0],
+                row[1],
+                row[2],
+                row[3],
+                w0 = widths[0],
+                w1 = widths[1],
+                w2 = widths[2]
+            );
+            out += line.trim_end();
+            out.push('\n');
+        }
+        out += &format!(
+            "stack top reaches cell {}; commands use scratch cells just above the top\n",
+            self.stack_top
+        );
+        out
+    }
+}
@@ -0,0 +1,258 @@
+// Lexer for the constructor DSL.
+
+use crate::parser::ParseError;
+use serde::{Deserialize, Serialize};
+
+// A byte range in a DSL source file; `file` indexes the parser's `SourceMap`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+    #[serde(default)]
+    pub file: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Span { start, end, file: 0 }
+    }
+
+    pub fn in_file(file: usize, start: usize, end: usize) -> Self {
+        Span { start, end, file }
+    }
+
+    // Returns the smallest span covering both `self` and `other`.
+    pub fn to(self, other: Span) -> Span {
+        Span::in_file(self.file, self.start.min(other.start), self.end.max(other.end))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind {
+    Ident(String),
+    Number(usize),
+    Decimal(String), // A number with a fractional part, such as `3.25`, as written.
+    Str(String),
+    LBrace,
+    RBrace,
+    LBracket,
+    RBracket,
+    Comma,
+    Semicolon,
+    Minus,
+    LParen,
+    RParen,
+    Plus,
+    Star,
+    Slash,
+    Percent,
+    Equals,
+}
+
+impl TokenKind {
+    // Describes the token for error messages.
+    pub fn describe(&self) -> String {
+        match self {
+            TokenKind::Ident(name) => format!("'{}'", name),
+            TokenKind::Number(n) => format!("number {}", n),
+            TokenKind::Decimal(text) => format!("number {}", text),
+            TokenKind::Str(_) => "string literal".to_string(),
+            TokenKind::LBrace => "'{'".to_string(),
+            TokenKind::RBrace => "'}'".to_string(),
+            TokenKind::LBracket => "'['".to_string(),
+            TokenKind::RBracket => "']'".to_string(),
+            TokenKind::Comma => "','".to_string(),
+            TokenKind::Semicolon => "';'".to_string(),
+            TokenKind::Minus => "'-'".to_string(),
+            TokenKind::LParen => "'('".to_string(),
+            TokenKind::RParen => "')'".to_string(),
+            TokenKind::Plus => "'+'".to_string(),
+            TokenKind::Star => "'*'".to_string(),
+            TokenKind::Slash => "'/'".to_string(),
+            TokenKind::Percent => "'%'".to_string(),
+            TokenKind::Equals => "'='".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub span: Span,
+}
+
+// Splits the DSL source of file `file` into tokens.
+pub fn tokenize(src: &str, file: usize) -> Result<Vec<Token>, ParseError> {
+    let mut tokens = tokenize_file(src).map_err(|mut e| {
+        e.span.file = file;
+        e
+    })?;
+    for token in &mut tokens {
+        token.span.file = file;
+    }
+    Ok(tokens)
+}
+
+// Splits DSL source into tokens, skipping whitespace and `//` / `/* */` comments.
+fn tokenize_file(src: &str) -> Result<Vec<Token>, ParseError> {
+    let bytes = src.as_bytes();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+
+    while pos < bytes.len() {
+        let c = bytes[pos];
+        let start = pos;
+        match c {
+            b' ' | b'\t' | b'\r' | b'\n' => pos += 1,
+            b'/' if bytes.get(pos + 1) == Some(&b'/') => {
+                while pos < bytes.len() && bytes[pos] != b'\n' {
+                    pos += 1;
+                }
+            }
+            b'/' if bytes.get(pos + 1) == Some(&b'*') => {
+                pos += 2;
+                loop {
+                    if pos + 1 >= bytes.len() {
+                        return Err(ParseError::new("unterminated block comment", Span::new(start, start + 2)));
+                    }
+                    if bytes[pos] == b'*' && bytes[pos + 1] == b'/' {
+                        pos += 2;
+                        break;
+                    }
+                    pos += 1;
+                }
+            }
+            b'{' | b'}' | b'[' | b']' | b',' | b';' | b'-' | b'(' | b')' | b'+' | b'*' | b'/' | b'%' | b'=' => {
+                pos += 1;
+                let kind = match c {
+                    b'{' => TokenKind::LBrace,
+                    b'}' => TokenKind::RBrace,
+                    b'[' => TokenKind::LBracket,
+                    b']' => TokenKind::RBracket,
+                    b',' => TokenKind::Comma,
+                    b'-' => TokenKind::Minus,
+                    b'(' => TokenKind::LParen,
+                    b')' => TokenKind::RParen,
+                    b'+' => TokenKind::Plus,
+                    b'*' => TokenKind::Star,
+                    b'/' => TokenKind::Slash,
+                    b'%' => TokenKind::Percent,
+                    b'=' => TokenKind::Equals,
+                    _ => TokenKind::Semicolon,
+                };
+                tokens.push(Token { kind, span: Span::new(start, pos) });
+            }
+            b'0'..=b'9' => {
+                while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+                    pos += 1;
+                }
+                if bytes.get(pos) == Some(&b'.') && bytes.get(pos + 1).is_some_and(u8::is_ascii_digit) {
+                    pos += 1;
+                    while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+                        pos += 1;
+                    }
+                    let kind = TokenKind::Decimal(src[start..pos].to_string());
+                    tokens.push(Token { kind, span: Span::new(start, pos) });
+                    continue;
+                }
+                let span = Span::new(start, pos);
+                let value = src[start..pos]
+                    .parse::<usize>()
+                    .map_err(|_| ParseError::new("number is too large", span))?;
+                tokens.push(Token { kind: TokenKind::Number(value), span });
+            }
+            b'"' => {
+                let (value, end) = string_literal(src, start)?;
+                pos = end;
+                tokens.push(Token { kind: TokenKind::Str(value), span: Span::new(start, pos) });
+            }
+            b'\'' => {
+                let (value, end) = char_literal(src, start)?;
+                pos = end;
+                tokens.push(Token { kind: TokenKind::Number(value), span: Span::new(start, pos) });
+            }
+            c if c.is_ascii_alphabetic() || c == b'_' => {
+                while pos < bytes.len() && (bytes[pos].is_ascii_alphanumeric() || bytes[pos] == b'_') {
+                    pos += 1;
+                }
+                tokens.push(Token {
+                    kind: TokenKind::Ident(src[start..pos].to_string()),
+                    span: Span::new(start, pos),
+                });
+            }
+            _ => {
+                let len = src[start..].chars().next().map_or(1, |ch| ch.len_utf8());
+                return Err(ParseError::new("unexpected character", Span::new(start, start + len)));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+// Returns the character denoted by the escape sequence `\<c>`.
+fn unescape(c: char) -> Option<char> {
+    match c {
+        'n' => Some('\n'),
+        't' => Some('\t'),
+        'r' => Some('\r'),
+        '0' => Some('\0'),
+        '\\' => Some('\\'),
+        '\'' => Some('\''),
+        '"' => Some('"'),
+        _ => None,
+    }
+}
+
+// Reads one possibly escaped character at byte offset `pos`, returning it and its length in bytes.
+fn literal_char(src: &str, pos: usize) -> Result<Option<(char, usize)>, ParseError> {
+    let mut chars = src[pos..].chars();
+    match chars.next() {
+        Some('\\') => match chars.next() {
+            Some(esc) => match unescape(esc) {
+                Some(value) => Ok(Some((value, 1 + esc.len_utf8()))),
+                None => Err(ParseError::new(
+                    "unknown escape sequence",
+                    Span::new(pos, pos + 1 + esc.len_utf8()),
+                )),
+            },
+            None => Ok(None),
+        },
+        Some(ch) => Ok(Some((ch, ch.len_utf8()))),
+        None => Ok(None),
+    }
+}
+
+// Reads a string literal such as "Hello\n" starting at `start`, returning its value and end offset.
+fn string_literal(src: &str, start: usize) -> Result<(String, usize), ParseError> {
+    let mut value = String::new();
+    let mut pos = start + 1;
+    loop {
+        if src[pos..].starts_with('"') {
+            return Ok((value, pos + 1));
+        }
+        match literal_char(src, pos)? {
+            Some(('\n', 1)) | None => {
+                return Err(ParseError::new("unterminated string literal", Span::new(start, start + 1)))
+            }
+            Some((ch, len)) => {
+                value.push(ch);
+                pos += len;
+            }
+        }
+    }
+}
+
+// Reads a character literal such as 'A' or '\n' starting at `start`, returning its code and end offset.
+fn char_literal(src: &str, start: usize) -> Result<(usize, usize), ParseError> {
+    let unterminated = || ParseError::new("unterminated character literal", Span::new(start, start + 1));
+    let (value, consumed) = match literal_char(src, start + 1)? {
+        Some(('\'', 1)) | None => return Err(unterminated()),
+        Some(literal) => literal,
+    };
+    let end = start + 1 + consumed;
+    if src[end..].starts_with('\'') {
+        Ok((value as usize, end + 1))
+    } else {
+        Err(unterminated())
+    }
+}
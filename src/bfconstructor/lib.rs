@@ -0,0 +1,1610 @@
+// Brainfuck code constructor: compiles `Cmd` programs, parsed from the DSL or built in Rust with
+// `Program` and the `Statements` methods, into Brainfuck.
+
+#[path = "../bfmap.rs"]
+pub mod bfmap;
+pub mod builder;
+pub mod diagnostic;
+pub mod error;
+mod heap;
+#[path = "../ir.rs"]
+pub mod ir;
+pub mod layout;
+pub mod lexer;
+pub mod machine;
+pub mod native;
+// The napi exports are only registered by the addon, so the lib tests would find them unused.
+#[cfg(all(feature = "node", not(test)))]
+mod node;
+pub mod optimize;
+pub mod parser;
+pub mod peephole;
+pub mod procedure;
+#[cfg(feature = "python")]
+mod python;
+pub mod reference;
+pub mod repl;
+pub mod schema;
+pub mod sizes;
+pub mod sourcemap;
+mod stdlib;
+mod temps;
+#[path = "../term.rs"]
+pub mod term;
+pub mod verify;
+mod wide;
+
+pub use builder::{Block, Cases, Statements};
+pub use error::CompileError;
+pub use lexer::Span;
+pub use parser::Program;
+pub use procedure::{CallConv, Proc};
+
+use heap::Heap;
+use layout::{Kind, Layout};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+use temps::Temps;
+
+// Replicates a string `s` for `n` times.
+fn replicate(n: usize, s: &str) -> String {
+    s.repeat(n)
+}
+
+// Encodes a string into a vector of Unicode code points.
+pub fn text_encoder(s: &str) -> Vec<u32> {
+    s.chars().map(|c| c as u32).collect()
+}
+
+// Generates code that adds `n` to the current cell, choosing the shortest of a run of `+` or `-`
+// and a loop that adds `a * b` followed by a run for the rest. The loop counts down in the cell
+// to the right, which must be zero and is left zero. On wrapping cells, going the other way
+// around the cell's range is also considered; on cells that do not, the loop never adds more than
+// `n`, since the cell may already hold a value and going past it would overflow.
+fn constant(target: Target, n: isize) -> String {
+    let deltas = match target.bits {
+        bits if target.wrap && bits < isize::BITS - 1 => {
+            let n = n.rem_euclid(1 << bits);
+            vec![n, n - (1 << bits)]
+        }
+        _ => vec![n],
+    };
+    deltas
+        .into_iter()
+        .map(|n| shortest_add(n, target.wrap))
+        .min_by_key(String::len)
+        .unwrap_or_default()
+}
+
+// The shortest code adding `n` to the current cell without wrapping around. With `overshoot`, the
+// loop may add more than `n` and take the rest back.
+fn shortest_add(n: isize, overshoot: bool) -> String {
+    let sign = if n < 0 { "-" } else { "+" };
+    let m = n.unsigned_abs();
+    let mut best = replicate(m, sign);
+    // `>` a `[<` b `>-]<` and the rest: 7 characters of overhead. Counters above the square root
+    // only repeat pairs with their factors swapped.
+    let mut a = 2;
+    while a * a <= m + a {
+        for b in [m / a, m / a + 1] {
+            let rest = m as isize - (a * b) as isize;
+            if rest < 0 && !overshoot {
+                continue;
+            }
+            if a + b + rest.unsigned_abs() + 7 < best.len() {
+                let fix = if (rest < 0) == (n < 0) { "+" } else { "-" };
+                best = format!(
+                    ">{}[<{}>-]<{}",
+                    replicate(a, "+"),
+                    replicate(b, sign),
+                    replicate(rest.unsigned_abs(), fix)
+                );
+            }
+        }
+        a += 1;
+    }
+    best
+}
+
+// Generates code that outputs the bytes of `s` from a single zeroed work cell, which is left
+// holding the last byte. Each byte is reached from the previous one by adding the difference, or
+// by clearing the cell and building it from zero when that is shorter.
+fn encode_string(target: Target, s: &str) -> String {
+    let mut code = String::new();
+    let mut current: u8 = 0;
+    for &byte in s.as_bytes() {
+        let step = constant(target, byte as isize - current as isize);
+        let rebuild = format!("[-]{}", constant(target, byte as isize));
+        code += if rebuild.len() < step.len() { &rebuild } else { &step };
+        code.push('.');
+        current = byte;
+    }
+    code
+}
+
+// Generates a Brainfuck snippet to move a value `n` cells to the left.
+fn move_left(n: usize) -> String {
+    format!(
+        "{}[-]{}[{}+{}-]",
+        replicate(n, "<"),
+        replicate(n, ">"),
+        replicate(n, "<"),
+        replicate(n, ">")
+    )
+}
+
+// Generates a Brainfuck snippet to move a value `n` cells from the left.
+pub fn move_right(n: usize) -> String {
+    format!(
+        "[-]{}[{}+{}-]{}",
+        replicate(n, "<"),
+        replicate(n, ">"),
+        replicate(n, "<"),
+        replicate(n, ">")
+    )
+}
+
+// Generates a Brainfuck snippet to clear the cell `n` cells to the left and come back.
+fn clear_left(n: usize) -> String {
+    format!("{}[-]{}", replicate(n, "<"), replicate(n, ">"))
+}
+
+// Generates a Brainfuck snippet to copy a value `n` cells to the right. The current cell and the
+// one after it are freshly allocated stack slots, which are always zero, so neither is cleared.
+// The pointer starts and ends on the current cell, the new stack top holding the copy, as every
+// command leaves it; the final `<` returns from the cell after it, which restored the source.
+fn copy_right(n: usize) -> String {
+    format!(
+        "{}[{}+>+<{}-]{}[{}+{}-]<",
+        replicate(n, "<"),
+        replicate(n, ">"),
+        replicate(n, "<"),
+        replicate(n + 1, ">"),
+        replicate(n + 1, "<"),
+        replicate(n + 1, ">")
+    )
+}
+
+// Generates a Brainfuck snippet to copy the value `n` cells below the top of the stack onto a new
+// top. Extended Brainfuck passes it through the storage cell instead of a loop.
+fn pick(target: Target, n: usize) -> String {
+    match target.extended {
+        true => format!("{}${}!", replicate(n, "<"), replicate(n + 1, ">")),
+        false => format!(">{}", copy_right(1 + n)),
+    }
+}
+
+// Generates a Brainfuck snippet to pop the top of the stack into the cell `n` cells below it.
+fn put(target: Target, n: usize) -> String {
+    match target.extended {
+        true => format!("$[-]{}!{}<", replicate(n, "<"), replicate(n, ">")),
+        false => format!("{}<", move_left(n)),
+    }
+}
+
+// Creates a string with indent*indentsize spaces.
+fn make_indent(indent: usize, indentsize: usize) -> String {
+    " ".repeat(indent * indentsize)
+}
+
+// The cells the generated code runs on, chosen with `--cell-bits` and `--no-wrap`, and whether it
+// may use the storage cell and shifts of Extended Brainfuck Type I (`--target extended1`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Target {
+    pub bits: u32,
+    pub wrap: bool,
+    pub extended: bool,
+}
+
+impl Default for Target {
+    fn default() -> Target {
+        Target { bits: 8, wrap: true, extended: false }
+    }
+}
+
+impl Target {
+    // Whether cells wrap around at 256, which two's complement and multi-cell arithmetic rely on.
+    fn byte_wrap(self) -> bool {
+        self.bits == 8 && self.wrap
+    }
+
+    // Checks that `cmd` can be compiled for this target.
+    fn require_byte_wrap(self, cmd: &str) -> Result<(), CompileError> {
+        match self.byte_wrap() {
+            true => Ok(()),
+            false => Err(CompileError::NeedsByteWrap { cmd: cmd.to_string(), target: self.to_string() }),
+        }
+    }
+}
+
+impl std::fmt::Display for Target {
+    // Avoids Brainfuck commands, since this text ends up in a comment.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let wrap = if self.wrap { "with" } else { "without" };
+        write!(f, "{} bit cells {} wraparound", self.bits, wrap)?;
+        if self.extended {
+            write!(f, ", Extended Brainfuck Type I")?;
+        }
+        Ok(())
+    }
+}
+
+// Code generation settings that stay fixed for the whole program.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Config {
+    pub target: Target,
+    pub mark_spans: bool, // Wrap the code of located commands in source map markers.
+}
+
+// Cells holding the flags of the innermost loop whose body uses break/continue.
+#[derive(Debug, Clone, Copy)]
+struct LoopFlags {
+    alive: usize,   // Cleared by `break` to stop the loop.
+    running: usize, // Cleared by `break`/`continue` to skip the rest of the iteration.
+}
+
+// A variable declared by `scope`: a single cell, or a fixed-size array of contiguous cells.
+// String variables are arrays whose leading cells are initialized from `init`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Let {
+    pub name: String,
+    #[serde(default)]
+    pub len: Option<usize>,
+    #[serde(default)]
+    pub init: Vec<u8>,
+    #[serde(default)]
+    pub counted: bool, // The first cell holds the string's length, instead of a NUL terminating it.
+}
+
+impl Let {
+    pub fn scalar(name: &str) -> Let {
+        Let { name: name.to_string(), len: None, init: Vec::new(), counted: false }
+    }
+
+    pub fn array(name: &str, len: usize) -> Let {
+        Let { name: name.to_string(), len: Some(len), init: Vec::new(), counted: false }
+    }
+
+    // A table: an array holding `values`.
+    pub fn table(name: &str, values: Vec<u8>) -> Let {
+        Let { name: name.to_string(), len: Some(values.len()), init: values, counted: false }
+    }
+
+    // A string variable: the bytes of `text` followed by a NUL, or preceded by their count.
+    pub fn string(name: &str, text: &str, counted: bool) -> Let {
+        let mut init = text.as_bytes().to_vec();
+        if counted {
+            init.insert(0, text.len() as u8);
+        } else {
+            init.push(0);
+        }
+        Let { name: name.to_string(), len: Some(init.len()), init, counted }
+    }
+}
+
+// Generates code that allocates `len` array cells, setting each to its initial value. The pointer
+// moves before filling each cell if `move_first` is set, and after filling it otherwise.
+fn array_init(target: Target, var: &Let, len: usize, move_first: bool) -> String {
+    (0..len)
+        .map(|i| {
+            let value = constant(target, var.init.get(i).copied().unwrap_or(0) as isize);
+            if move_first {
+                format!(">{}", value)
+            } else {
+                format!("{}>", value)
+            }
+        })
+        .collect()
+}
+
+// Name under which element `i` of array `name` is stored in the environment.
+fn element_name(name: &str, i: usize) -> String {
+    format!("{}[{}]", name, i)
+}
+
+// Structure representing the compiler state.
+//
+// Cells are allocated as a stack: `next_cell` is the top, and every command's temporaries live
+// just above it and are cleared before the command ends. Scratch cells are therefore reused by
+// each following command, and `scope` locals are popped on exit. Variables that are no longer
+// used leave their cells in `temps`, where later `scope` locals find them (see `temps`).
+#[derive(Debug, Clone, Default)]
+pub struct CompilerState {
+    env: HashMap<String, usize>,
+    arrays: HashMap<String, usize>, // Array name -> length; elements live in `env` under `element_name`.
+    counted: HashSet<String>,       // Arrays holding length-prefixed strings.
+    pub next_cell: usize,
+    base: usize,         // The stack top where the current block started; `assert_stack` counts from here.
+    floor: usize,        // The lowest stack top: the last cell of the variables below the stack.
+    heights: Vec<usize>, // The stack top after each statement of the current block so far.
+    pub code: String,
+    loop_flags: Option<LoopFlags>,
+    procs: Rc<HashMap<String, Proc>>,
+    inlining: Vec<String>, // Inline procedures being expanded, to reject recursion.
+    config: Config,
+    layout: Rc<RefCell<Layout>>, // Shared by all nested states, so it collects the whole program's cells.
+    heap: Rc<RefCell<Heap>>,     // The `alloc`ed blocks, shared like `layout`.
+    depth: usize,                // How deeply the current block is nested, for checking `free`.
+    temps: Temps,                // Dead variables' cells below the stack top.
+}
+
+impl CompilerState {
+    // Creates an empty-code state for a nested block starting at `next_cell`.
+    fn child(&self, next_cell: usize) -> CompilerState {
+        CompilerState {
+            env: self.env.clone(),
+            arrays: self.arrays.clone(),
+            counted: self.counted.clone(),
+            next_cell,
+            base: next_cell,
+            floor: self.floor,
+            heights: Vec::new(),
+            code: String::new(),
+            loop_flags: self.loop_flags,
+            procs: Rc::clone(&self.procs),
+            inlining: self.inlining.clone(),
+            config: self.config,
+            layout: Rc::clone(&self.layout),
+            heap: Rc::clone(&self.heap),
+            depth: self.depth + 1,
+            temps: self.temps.clone(),
+        }
+    }
+
+    // Like `child`, but for blocks that cannot break out of the enclosing loop (conditions, nested loops).
+    fn detached_child(&self, next_cell: usize) -> CompilerState {
+        CompilerState {
+            loop_flags: None,
+            ..self.child(next_cell)
+        }
+    }
+
+    // The cells owned by variables and temporaries so far.
+    pub fn layout(&self) -> Layout {
+        self.layout.borrow().clone()
+    }
+
+    // Records that cells `start..start + len` belong to `name` during the current statement.
+    fn own(&self, start: usize, len: usize, name: &str, kind: Kind) {
+        self.layout.borrow_mut().record(start, len, name, kind);
+    }
+}
+
+// Calculates the relative address of a variable.
+fn adr_local(state: &CompilerState, var: &str) -> Result<usize, CompileError> {
+    match state.env.get(var) {
+        Some(&val) => Ok(state.next_cell - val),
+        None if state.arrays.contains_key(var) => Err(CompileError::MissingIndex { name: var.to_string() }),
+        None => Err(CompileError::UndefinedVariable { name: var.to_string() }),
+    }
+}
+
+// Resolves a constant array index to the element's environment name, checking it at compile time.
+fn element(state: &CompilerState, var: &str, index: usize) -> Result<String, CompileError> {
+    match state.arrays.get(var) {
+        Some(&len) if index < len => Ok(element_name(var, index)),
+        Some(&len) => Err(CompileError::IndexOutOfBounds {
+            name: var.to_string(),
+            index,
+            len,
+        }),
+        None => Err(CompileError::NotAnArray { name: var.to_string() }),
+    }
+}
+
+// Checks that a block moved the stack pointer from `before` to `after` by exactly `expected` cells.
+fn check_delta(block: &str, before: usize, after: usize, expected: isize) -> Result<(), CompileError> {
+    let actual = after as isize - before as isize;
+    if actual != expected {
+        return Err(CompileError::UnbalancedBlock {
+            block: block.to_string(),
+            expected,
+            actual,
+        });
+    }
+    Ok(())
+}
+
+const COMMENT_WIDTH: usize = 2;
+
+// Updated append_code function with indentation.
+fn append_code(
+    mut state: CompilerState,
+    cmd: &str,
+    s: &str,
+    delta: isize,
+    indent: usize,
+    indentsize: usize,
+) -> Result<CompilerState, CompileError> {
+    let new_next = state.next_cell as isize + delta;
+    if new_next < state.floor as isize {
+        return Err(CompileError::StackUnderflow { cmd: cmd.to_string() });
+    }
+    let indent_str = make_indent(indent, indentsize);
+    let tail = make_indent(COMMENT_WIDTH.saturating_sub(indent), indentsize);
+    let code = format!("/* {}{: <12}{} */ {} #{}\n", indent_str, comment_safe(cmd),tail,s,&new_next.to_string());
+    state.code.push_str(&code);
+    state.next_cell = new_next as usize;
+    state.layout.borrow_mut().reach(state.next_cell);
+    Ok(state)
+}
+
+// Removes the `/* ... */` labels and `#n` annotations added by `append_code`, keeping the line
+// layout and dropping lines that end up empty. Source map markers are kept.
+pub fn strip_comments(code: &str) -> String {
+    let mut out = String::new();
+    let mut rest = code;
+    while let Some(start) = rest.find(['/', '#']) {
+        out += &rest[..start];
+        rest = &rest[start..];
+        if let Some(comment) = rest.strip_prefix("/*") {
+            rest = comment.find("*/").map_or("", |end| &comment[end + 2..]);
+        } else if let Some(annotation) = rest.strip_prefix('#') {
+            rest = annotation.trim_start_matches(|c: char| c.is_ascii_digit());
+        } else {
+            out.push('/');
+            rest = &rest[1..];
+        }
+    }
+    out += rest;
+    let mut stripped = String::new();
+    for line in out.lines() {
+        let content = |c: char| !c.is_whitespace() && !sourcemap::is_marker(c);
+        match (line.find(content), line.rfind(content)) {
+            (Some(first), Some(last)) => {
+                let end = last + line[last..].chars().next().map_or(0, char::len_utf8);
+                stripped.extend(line[..first].chars().filter(|&c| sourcemap::is_marker(c)));
+                stripped += &line[first..end];
+                stripped.extend(line[end..].chars().filter(|&c| sourcemap::is_marker(c)));
+                stripped.push('\n');
+            }
+            _ => stripped.extend(line.chars().filter(|&c| sourcemap::is_marker(c))),
+        }
+    }
+    stripped
+}
+
+// Keeps only the eight Brainfuck commands (and source map markers).
+pub fn minify(code: &str) -> String {
+    code.chars().filter(|&c| "+-<>.,[]".contains(c) || ir::Ext::from_char(c).is_some() || sourcemap::is_marker(c)).collect()
+}
+
+// Escapes characters that would be executed as Brainfuck (or end the comment) inside a label.
+fn comment_safe(label: &str) -> String {
+    let mut out = String::new();
+    let mut chars = label.chars().peekable();
+    while let Some(c) = chars.next() {
+        if "+-<>.,[]#\n\r".contains(c) || (c == '*' && chars.peek() == Some(&'/')) {
+            out += &format!("\\x{:02x}", c as u32);
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+// The shortest code setting a zero cell to `n`: a constant, or on Extended Brainfuck one built
+// from its high bits with `{` (shift left) and `+`.
+fn push_constant(target: Target, n: isize) -> String {
+    let best = constant(target, n);
+    if !target.extended || target.bits >= isize::BITS - 1 {
+        return best;
+    }
+    let n = n.rem_euclid(1 << target.bits);
+    if n < 2 {
+        return best;
+    }
+    let shifted = format!("{}{{{}", push_constant(target, n >> 1), replicate((n & 1) as usize, "+"));
+    if shifted.len() < best.len() {
+        shifted
+    } else {
+        best
+    }
+}
+
+// Generates code that prints `s` from a single zeroed work cell and clears it afterwards.
+fn print_string(target: Target, s: &str) -> String {
+    encode_string(target, s) + "[-]"
+}
+
+// Divides the current cell by the cell two to its right: `n 0 d` becomes `0 n d-n%d n%d n/d`.
+const DIVMOD_CODE: &str = "[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]";
+
+// Generates code that pops the top of the stack and prints it in decimal without leading zeros.
+// Two divide-by-10 steps leave the ones, tens and hundreds digits at offsets 3, 7 and 8 from the
+// top; the flag at offset 5 forces the tens digit to print once a hundreds digit was printed.
+fn print_num(target: Target) -> String {
+    format!(
+        ">>{ten}<<{divmod}>[-]>[-]>>>>{ten}<<{divmod}>[-]>[-]>>[{digit}.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>{digit}.[-]<<<<]<<{digit}.[-]<<<<",
+        ten = constant(target, 10),
+        divmod = DIVMOD_CODE,
+        digit = constant(target, 48)
+    )
+}
+
+// The stack program behind `ReadNum`: accumulates `acc * 10 + digit` while the last byte read is a digit.
+// Wrapping cells test `c - '0' < 10`; cells that do not wrap compare `c` with both ends instead,
+// since bytes below '0' would go below zero.
+fn read_num_cmds(target: Target) -> Vec<Cmd> {
+    let is_digit = match target.wrap {
+        true => vec![Cmd::Copy, Cmd::Subc(48), Cmd::Push(10), Cmd::Lt],
+        false => vec![Cmd::Copy, Cmd::Push(47), Cmd::Gt, Cmd::Over, Cmd::Push(58), Cmd::Lt, Cmd::And],
+    };
+    vec![
+        Cmd::Push(0),
+        Cmd::Read,
+        Cmd::While {
+            cond: is_digit,
+            body: vec![
+                Cmd::Subc(48),
+                Cmd::Swap,
+                Cmd::Push(10),
+                Cmd::Mul,
+                Cmd::Add,
+                Cmd::Read,
+            ],
+        },
+        Cmd::Drop,
+    ]
+}
+
+// The name of `cmd` if its code relies on 8-bit wraparound: two's complement negation and the
+// carries and borrows of multi-cell arithmetic.
+fn byte_wrap_cmd(cmd: &Cmd) -> Option<String> {
+    let name = match cmd {
+        Cmd::Neg => "neg",
+        Cmd::Slt => "slt",
+        Cmd::Sle => "sle",
+        Cmd::Sgt => "sgt",
+        Cmd::Sge => "sge",
+        Cmd::PrintSigned => "sprintnum",
+        Cmd::AddWide(width) => return Some(format!("add{}", width * 8)),
+        Cmd::SubWide(width) => return Some(format!("sub{}", width * 8)),
+        Cmd::CmpWide(width) => return Some(format!("cmp{}", width * 8)),
+        Cmd::PrintWide(width) => return Some(format!("print{}", width * 8)),
+        Cmd::MulFixed => "mulfx",
+        Cmd::PrintFixed(_) => "printfx",
+        _ => return None,
+    };
+    Some(name.to_string())
+}
+
+// The name of a command that reads values from the stack, and how many it reads; the check
+// against the stack's floor catches commands that would read a variable's cell instead.
+fn operands(cmd: &Cmd) -> Option<(String, usize)> {
+    let (name, count) = match cmd {
+        Cmd::Clear => ("clear", 1),
+        Cmd::Copy => ("copy", 1),
+        Cmd::Drop => ("drop", 1),
+        Cmd::Write => ("write", 1),
+        Cmd::Inc => ("inc", 1),
+        Cmd::Dec => ("dec", 1),
+        Cmd::Bool => ("bool", 1),
+        Cmd::Not => ("not", 1),
+        Cmd::Neg => ("neg", 1),
+        Cmd::PrintNum => ("printnum", 1),
+        Cmd::PrintSigned => ("sprintnum", 1),
+        Cmd::Swap => ("swap", 2),
+        Cmd::Over => ("over", 2),
+        Cmd::Add => ("add", 2),
+        Cmd::Sub => ("sub", 2),
+        Cmd::Mul => ("mul", 2),
+        Cmd::Eq => ("eq", 2),
+        Cmd::Neq => ("neq", 2),
+        Cmd::Lt => ("lt", 2),
+        Cmd::Le => ("le", 2),
+        Cmd::Gt => ("gt", 2),
+        Cmd::Ge => ("ge", 2),
+        Cmd::Slt => ("slt", 2),
+        Cmd::Sle => ("sle", 2),
+        Cmd::Sgt => ("sgt", 2),
+        Cmd::Sge => ("sge", 2),
+        Cmd::And => ("and", 2),
+        Cmd::Or => ("or", 2),
+        Cmd::MulFixed => ("mulfx", 4),
+        Cmd::Addc(n) => return Some((format!("addc {}", n), 1)),
+        Cmd::Subc(n) => return Some((format!("subc {}", n), 1)),
+        Cmd::Pick(n) => return Some((format!("pick {}", n), n + 1)),
+        Cmd::Set(var) => return Some((format!("set {}", var), 1)),
+        Cmd::SetIdx(var, index) => return Some((format!("set {}[{}]", var, index), 1)),
+        Cmd::Lookup(name) => return Some((format!("lookup {}", name), 1)),
+        Cmd::Store(name) => return Some((format!("store {}", name), 2)),
+        Cmd::AddWide(width) => return Some((format!("add{}", width * 8), 2 * width)),
+        Cmd::SubWide(width) => return Some((format!("sub{}", width * 8), 2 * width)),
+        Cmd::CmpWide(width) => return Some((format!("cmp{}", width * 8), 2 * width)),
+        Cmd::PrintWide(width) => return Some((format!("print{}", width * 8), *width)),
+        Cmd::PrintFixed(digits) => return Some((format!("printfx {}", digits), 2)),
+        _ => return None,
+    };
+    Some((name.to_string(), count))
+}
+
+// Pops b and a, pushes a nonzero value if they differ, without ever decrementing a zero cell:
+// the saturating comparisons `a < b` and `a > b`, or-ed together.
+fn saturating_differ_cmds() -> Vec<Cmd> {
+    vec![Cmd::Over, Cmd::Over, Cmd::Lt, Cmd::Pick(2), Cmd::Pick(2), Cmd::Gt, Cmd::Or, Cmd::Poke(2), Cmd::Drop]
+}
+
+// The stack program behind `PrintSigned`: prints a minus sign and negates negative values first.
+fn print_signed_cmds() -> Vec<Cmd> {
+    vec![
+        Cmd::IfThen {
+            cond: vec![Cmd::Copy, Cmd::Push(128), Cmd::Ge],
+            // The then block runs above the flag, so the value is reached with pick/poke.
+            then_block: vec![Cmd::PrintStr("-".to_string()), Cmd::Pick(1), Cmd::Neg, Cmd::Poke(2)],
+        },
+        Cmd::PrintNum,
+    ]
+}
+
+// The stack program behind `PrintVar`, unrolled over the array. A NUL-terminated string keeps a
+// flag that stays set until the first NUL; a counted string compares each index with its length.
+fn print_array_cmds(name: &str, len: usize, counted: bool) -> Vec<Cmd> {
+    let elem = |i| Cmd::GetIdx(name.to_string(), i);
+    let write_elem = |i| vec![elem(i), Cmd::Write];
+    if counted {
+        return (1..len)
+            .map(|i| Cmd::IfThen {
+                cond: vec![elem(0), Cmd::Push(i), Cmd::Ge],
+                then_block: write_elem(i),
+            })
+            .collect();
+    }
+    let mut cmds = vec![Cmd::Push(1)];
+    for i in 0..len {
+        cmds.push(elem(i));
+        cmds.push(Cmd::And);
+        cmds.push(Cmd::IfThen {
+            cond: vec![Cmd::Copy],
+            then_block: write_elem(i),
+        });
+    }
+    cmds.push(Cmd::Drop);
+    cmds
+}
+
+// The stack program behind `Lookup`, unrolled over the elements an index can reach. The result
+// sits under the index, and the element whose number equals the index is added to it. Wrapping
+// cells count the index down, reaching 0 at that element only; cells that do not wrap compare it
+// with each element's number instead.
+fn lookup_cmds(name: &str, len: usize, target: Target) -> Vec<Cmd> {
+    let reach = 1usize.checked_shl(target.bits).map_or(len, |cells| len.min(cells));
+    let mut cmds = vec![Cmd::Push(0), Cmd::Swap];
+    for i in 0..reach {
+        let cond = match target.wrap {
+            true => vec![Cmd::Copy, Cmd::Not],
+            false => vec![Cmd::Copy, Cmd::Push(i), Cmd::Eq],
+        };
+        // The then block runs above the flag, so the result is three cells below the element.
+        cmds.push(Cmd::IfThen { cond, then_block: vec![Cmd::GetIdx(name.to_string(), i), Cmd::Poke(3)] });
+        if target.wrap {
+            cmds.push(Cmd::Dec);
+        }
+    }
+    cmds.push(Cmd::Drop);
+    cmds
+}
+
+// The stack program behind `Store`, unrolled like `lookup_cmds`. The value sits under the index,
+// and is copied into the element whose number equals the index.
+fn store_cmds(name: &str, len: usize, target: Target) -> Vec<Cmd> {
+    let reach = 1usize.checked_shl(target.bits).map_or(len, |cells| len.min(cells));
+    let mut cmds = vec![Cmd::Swap];
+    for i in 0..reach {
+        let cond = match target.wrap {
+            true => vec![Cmd::Copy, Cmd::Not],
+            false => vec![Cmd::Copy, Cmd::Push(i), Cmd::Eq],
+        };
+        cmds.push(Cmd::IfThen { cond, then_block: vec![Cmd::Pick(2), Cmd::SetIdx(name.to_string(), i)] });
+        if target.wrap {
+            cmds.push(Cmd::Dec);
+        }
+    }
+    cmds.push(Cmd::Drop);
+    cmds.push(Cmd::Drop);
+    cmds
+}
+
+// The stack program behind `ReadLine`, unrolled over the array. The `readline:count` and
+// `readline:running` cells sit on the stack; each step stores `c * ok` so the element after the
+// line is cleared, where `ok` is whether `c` is neither newline nor NUL. The end of the input
+// ends the line too on interpreters where `,` then gives 0, as `--verify` and `bfir --eof zero`
+// do; where it leaves the cell unchanged, the cleared read cell reads as NUL as well.
+fn read_line_cmds(buf: &str, len: usize) -> Vec<Cmd> {
+    let count = || "readline:count".to_string();
+    let running = || "readline:running".to_string();
+    (0..len)
+        .map(|i| Cmd::IfThen {
+            cond: vec![Cmd::Get(running())],
+            then_block: vec![
+                Cmd::Read,
+                Cmd::Copy,
+                Cmd::Push(10),
+                Cmd::Neq,
+                Cmd::Over,
+                Cmd::And,
+                Cmd::Copy,
+                Cmd::Set(running()),
+                Cmd::Copy,
+                Cmd::Get(count()),
+                Cmd::Add,
+                Cmd::Set(count()),
+                Cmd::Mul,
+                Cmd::Set(element_name(buf, i)),
+            ],
+        })
+        .collect()
+}
+
+// Emits a command implemented as a stack program, nesting the program's lines under `label`.
+fn expand(
+    state: CompilerState,
+    label: &str,
+    cmds: &[Cmd],
+    indent: usize,
+    indentsize: usize,
+) -> Result<CompilerState, CompileError> {
+    let inner_state = process_cmd_list(state.detached_child(state.next_cell), cmds, indent + 1, indentsize)?;
+    let delta = inner_state.next_cell as isize - state.next_cell as isize;
+    let code_str = format!(
+        "\n{}{}",
+        inner_state.code,
+        block_comment(&format!("end {}", label), indent, indentsize),
+    );
+    append_code(state, label, &code_str, delta, indent, indentsize)
+}
+
+// Formats the `/* ... */` label that marks a section inside a block command.
+fn block_comment(label: &str, indent: usize, indentsize: usize) -> String {
+    format!(
+        "/* {}{: <12}{} */",
+        make_indent(indent, indentsize),
+        label,
+        make_indent(COMMENT_WIDTH.saturating_sub(indent), indentsize),
+    )
+}
+
+// Enum representing various Brainfuck commands.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Cmd {
+    Clear,
+    Copy, // Duplicates the top of the stack (dup), using one temporary cell above the new top.
+    Swap, // Exchanges the two top values, using one temporary cell above the top.
+    Drop, // Clears and pops the top of the stack.
+    Over, // Pushes a copy of the second value, using one temporary cell above the new top.
+    Pick(usize), // Pushes a copy of the value n below the top (pick 0 = copy, pick 1 = over).
+    Poke(usize), // Pops the top and stores it into the value n below it (n >= 1).
+    Get(String),
+    Set(String),
+    GetIdx(String, usize), // Pushes a copy of an array element.
+    SetIdx(String, usize), // Pops the top of the stack into an array element.
+    Read,
+    Write,
+    Push(usize),
+    Inc,
+    Dec,
+    Add,
+    Sub,
+    Mul,
+    Addc(usize),
+    Subc(usize),
+    Bool,
+    // Comparisons pop b (top) and a, then push a <op> b as 0/1. They use two temporary cells above the top.
+    Eq,
+    Neq,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    // Signed comparisons treat both values as two's complement bytes (-128..127).
+    Slt,
+    Sle,
+    Sgt,
+    Sge,
+    Neg, // Negates the top of the stack (two's complement).
+    // Logical operators treat any nonzero value as true and push 0/1. And uses one temporary cell above the top.
+    And,
+    Or,
+    Not,
+    ReadNum,          // Reads decimal digits up to the first non-digit (which is consumed) and pushes their value mod 256.
+    ReadLine(String), // Reads bytes into an array until newline, NUL, the end of input or a full array, then pushes the count.
+    Lookup(String),   // Pops an index and pushes that element of an array, or 0 past its end.
+    Store(String),    // Pops a value and an index and stores the value into that element of an array, if there is one.
+    PrintNum,         // Pops the top of the stack and prints it in decimal, using nine cells above the top.
+    PrintSigned,      // Like PrintNum, but prints values of 128 and above as negative numbers.
+    PrintStr(String), // Prints a literal using one work cell above the top; the stack is unchanged.
+    PrintVar(String), // Prints a string variable up to its NUL (or its length); the stack is unchanged.
+    // Multi-cell unsigned numbers of the given width in cells, most significant cell deepest (see `wide`).
+    PushWide(usize, u64),
+    AddWide(usize),
+    SubWide(usize),
+    CmpWide(usize), // Pops two numbers and pushes one cell: 0 if a == b, 1 if a > b, 255 if a < b.
+    PrintWide(usize),
+    MulFixed,          // Pops two 8.8 fixed-point numbers (see `wide`) and pushes their product.
+    PrintFixed(usize), // Pops an 8.8 fixed-point number and prints it with the given number of decimals.
+    // Blocks of the `heap` array handed out at compile time (see `heap`).
+    Alloc(String, usize), // Binds a name to that many cleared cells of the heap, as an array.
+    Free(String),         // Releases a block allocated in the same block of code.
+    Addr(String),         // Pushes the offset of a block within the heap.
+    Stat(Vec<Cmd>), // A block that guarantees the stack remains unchanged.
+    Scope { lets: Vec<Let>, body: Vec<Cmd> }, // A balanced block with its own variables, pushed on entry and cleared on exit.
+    IfThen { cond: Vec<Cmd>, then_block: Vec<Cmd> }, // if [condition] [block]
+    IfThenElse { cond: Vec<Cmd>, then_block: Vec<Cmd>, else_block: Vec<Cmd> }, // if [condition] [block] else [block]
+    While { cond: Vec<Cmd>, body: Vec<Cmd> }, // while [condition] [block], re-evaluating the condition each iteration
+    // Runs the first arm whose value equals the scrutinee's, or `default` if none does.
+    Switch { scrutinee: Vec<Cmd>, arms: Vec<(u8, Vec<Cmd>)>, default: Vec<Cmd> },
+    Break,    // Leaves the innermost loop.
+    Continue, // Skips to the next iteration of the innermost loop.
+    Call(String), // Calls a procedure, replacing its arguments on the stack with its results.
+    AssertStack(isize), // Checks at compile time that the stack top is n cells above the start of the block; emits no code.
+    At(Span, Box<Cmd>), // The wrapped command's location in the DSL source, used for diagnostics.
+}
+
+// Whether a command may run `break` or `continue` for the loop it appears in.
+fn may_exit_loop(cmd: &Cmd) -> bool {
+    match cmd {
+        Cmd::Break | Cmd::Continue => true,
+        Cmd::Stat(cmds) | Cmd::Scope { body: cmds, .. } => cmds.iter().any(may_exit_loop),
+        Cmd::IfThen { then_block, .. } => then_block.iter().any(may_exit_loop),
+        Cmd::IfThenElse { then_block, else_block, .. } => {
+            then_block.iter().chain(else_block).any(may_exit_loop)
+        }
+        Cmd::Switch { arms, default, .. } => {
+            arms.iter().flat_map(|(_, arm)| arm).chain(default).any(may_exit_loop)
+        }
+        Cmd::At(_, inner) => may_exit_loop(inner),
+        _ => false,
+    }
+}
+
+// Whether a command reads or writes the variable or array `name`, possibly in a nested block.
+// Procedures only see their parameters, so a call mentions nothing.
+fn mentions(cmd: &Cmd, name: &str) -> bool {
+    match cmd {
+        Cmd::Get(var)
+        | Cmd::Set(var)
+        | Cmd::GetIdx(var, _)
+        | Cmd::SetIdx(var, _)
+        | Cmd::ReadLine(var)
+        | Cmd::Lookup(var)
+        | Cmd::Store(var)
+        | Cmd::PrintVar(var)
+        | Cmd::Alloc(var, _)
+        | Cmd::Free(var)
+        | Cmd::Addr(var) => var == name,
+        Cmd::Stat(cmds) => cmds.iter().any(|cmd| mentions(cmd, name)),
+        Cmd::Scope { lets, body } => lets.iter().all(|var| var.name != name) && body.iter().any(|cmd| mentions(cmd, name)),
+        Cmd::IfThen { cond, then_block } => cond.iter().chain(then_block).any(|cmd| mentions(cmd, name)),
+        Cmd::IfThenElse { cond, then_block, else_block } => {
+            cond.iter().chain(then_block).chain(else_block).any(|cmd| mentions(cmd, name))
+        }
+        Cmd::While { cond, body } => cond.iter().chain(body).any(|cmd| mentions(cmd, name)),
+        Cmd::Switch { scrutinee, arms, default } => {
+            scrutinee.iter().chain(arms.iter().flat_map(|(_, arm)| arm)).chain(default).any(|cmd| mentions(cmd, name))
+        }
+        Cmd::At(_, inner) => mentions(inner, name),
+        _ => false,
+    }
+}
+
+// Compiles a block that declared `vars` (name, first cell, cells) at its start. After the last
+// statement that mentions a variable, its cells go to `temps` for the scopes that follow.
+fn process_block(
+    mut state: CompilerState,
+    cmds: &[Cmd],
+    vars: &[(String, usize, usize)],
+    indent: usize,
+    indentsize: usize,
+) -> Result<CompilerState, CompileError> {
+    // `break` and `continue` guard the rest of the block, which must then be compiled in one piece.
+    if cmds.iter().any(may_exit_loop) {
+        return process_cmd_list(state, cmds, indent, indentsize);
+    }
+    let mut deaths: Vec<(usize, usize, usize)> = vars
+        .iter()
+        .filter(|(name, _, _)| name != heap::NAME) // `alloc`ed blocks use the heap without naming it.
+        .map(|(name, cell, len)| (cmds.iter().rposition(|cmd| mentions(cmd, name)).map_or(0, |i| i + 1), *cell, *len))
+        .filter(|&(dead, _, _)| dead < cmds.len())
+        .collect();
+    deaths.sort_unstable();
+    let mut done = 0;
+    for (dead, cell, len) in deaths {
+        state = process_cmd_list(state, &cmds[done..dead], indent, indentsize)?;
+        state.temps.give(cell, len);
+        done = dead;
+    }
+    process_cmd_list(state, &cmds[done..], indent, indentsize)
+}
+
+// Returns the source span covered by a block's located commands, if any.
+fn block_span(cmds: &[Cmd]) -> Option<Span> {
+    let mut spans = cmds.iter().filter_map(|cmd| match cmd {
+        Cmd::At(span, _) => Some(*span),
+        _ => None,
+    });
+    let first = spans.next()?;
+    Some(spans.fold(first, Span::to))
+}
+
+// Checks a block's stack delta. An unbalanced block is reported at the statement from which its
+// stack top never returned to the expected height, or at the whole block if that is unknown.
+fn check_block(
+    block: &str,
+    cmds: &[Cmd],
+    before: usize,
+    state: &CompilerState,
+    expected: isize,
+) -> Result<(), CompileError> {
+    let error = match check_delta(block, before, state.next_cell, expected) {
+        Ok(()) => return Ok(()),
+        Err(error) => error,
+    };
+    let target = before as isize + expected;
+    let heights = &state.heights;
+    if heights.len() == cmds.len() {
+        let from = heights.iter().rposition(|&h| h as isize == target).map_or(0, |i| i + 1);
+        if let Cmd::At(span, _) = &cmds[from] {
+            return Err(CompileError::Diverges {
+                block: block.to_string(),
+                expected,
+                actual: state.next_cell as isize - before as isize,
+                at: heights[from] as isize - before as isize,
+            }
+            .at(*span));
+        }
+    }
+    Err(match block_span(cmds) {
+        Some(span) => error.at(span),
+        None => error,
+    })
+}
+
+// Checks that a block of code freed the heap blocks it allocated.
+fn check_freed(state: &CompilerState) -> Result<(), CompileError> {
+    match state.heap.borrow().unfreed(state.depth) {
+        Some(name) => Err(CompileError::NotFreed { name: name.to_string() }),
+        None => Ok(()),
+    }
+}
+
+// Duplicates the top of the stack, using one temporary cell above the new top.
+const COPY_CODE: &str = "[>+>+<<-]>>[<<+>>-]<";
+// Subtracts the top of the stack from the value below it, popping the top.
+const SUB_CODE: &str = "[<->-]<";
+// Normalizes the top of the stack to 0/1.
+const BOOL_CODE: &str = "[[-]>+<]>[<+>-]<";
+// Replaces the top of the stack with 1 if it is zero, 0 otherwise.
+const NOT_CODE: &str = ">+<[[-]>-<]>[<+>-]<";
+// a < b: subtracts a from b with saturation (decrementing b only while it is nonzero), then tests b.
+const LT_CODE: &str = "<[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]<";
+// a > b: subtracts b from a with saturation, then tests a.
+const GT_CODE: &str = "[-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]<";
+// Negates the top of the stack by counting it down into a spare cell.
+const NEG_CODE: &str = "[>-<-]>[<+>-]<";
+
+// Biases both operands by 128 so that an unsigned comparison orders them as signed bytes. The
+// bias is built in the cell above the top and added to both operands by one loop.
+fn signed_compare(target: Target, code: &str) -> String {
+    format!(">{}[-<+<+>>]<{}", constant(target, 128), code)
+}
+
+// a && b: moves bool(b) into a spare cell, then copies it back only if a is nonzero.
+const AND_CODE: &str = "[[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]<";
+// a || b: sets a to 1 if b is nonzero, then normalizes a.
+const OR_CODE: &str = "[[-]<[-]+>]<[[-]>+<]>[<+>-]<";
+
+// Processes a list of commands, updating the compiler state with indentation.
+pub fn process_cmd_list(
+    mut state: CompilerState,
+    cmds: &[Cmd],
+    indent: usize,
+    indentsize: usize,
+) -> Result<CompilerState, CompileError> {
+    for (i, cmd) in cmds.iter().enumerate() {
+        state = process_cmd(state, cmd, indent, indentsize)?;
+        state.heights.push(state.next_cell);
+        // After a possible break/continue, the rest of the block only runs if the iteration is still running.
+        let rest = &cmds[i + 1..];
+        if state.loop_flags.is_some() && may_exit_loop(cmd) && !rest.is_empty() {
+            return guard_running(state, rest, indent, indentsize);
+        }
+    }
+    Ok(state)
+}
+
+// Emits `rest` wrapped in an if that tests the running flag of the enclosing loop.
+fn guard_running(
+    mut state: CompilerState,
+    rest: &[Cmd],
+    indent: usize,
+    indentsize: usize,
+) -> Result<CompilerState, CompileError> {
+    let running = state.loop_flags.map_or(0, |flags| flags.running);
+    // The rest continues the block, so it frees what the block allocated.
+    let inner = CompilerState {
+        depth: state.depth,
+        ..state.child(state.next_cell + 1)
+    };
+    let inner_state = process_cmd_list(inner, rest, indent + 1, indentsize)?;
+    check_block("loop body", rest, state.next_cell + 1, &inner_state, 0)?;
+    // The rest runs above the running flag's copy, one cell higher than it would unguarded.
+    state.heights.extend(inner_state.heights.iter().map(|h| h - 1));
+    let code_str = format!(
+        ">{}[\n{}{}{}   [-]]<",
+        copy_right(1 + state.next_cell - running),
+        inner_state.code,
+        block_comment("end if", indent, indentsize),
+        make_indent(indent, indentsize)
+    );
+    append_code(state, "if running", &code_str, 0, indent, indentsize)
+}
+
+// Processes a single command and updates the compiler state accordingly with indentation.
+pub fn process_cmd(
+    state: CompilerState,
+    cmd: &Cmd,
+    indent: usize,
+    indentsize: usize,
+) -> Result<CompilerState, CompileError> {
+    if let Some(name) = byte_wrap_cmd(cmd) {
+        state.config.target.require_byte_wrap(&name)?;
+    }
+    if let Some((name, count)) = operands(cmd) {
+        if state.next_cell < state.floor + count {
+            return Err(CompileError::StackUnderflow { cmd: name });
+        }
+    }
+    match cmd {
+        Cmd::Clear => append_code(state, "clear", "[-]", 0, indent, indentsize),
+        Cmd::Copy if state.config.target.extended => append_code(state, "copy", "$>!", 1, indent, indentsize),
+        Cmd::Copy => append_code(state, "copy", COPY_CODE, 1, indent, indentsize),
+        Cmd::Swap if state.config.target.extended => append_code(state, "swap", "$[-]<[>+<-]!>", 0, indent, indentsize),
+        Cmd::Swap => append_code(state, "swap", "<[>>+<<-]>[<+>-]>[<+>-]<", 0, indent, indentsize),
+        Cmd::Drop => append_code(state, "drop", "[-]<", -1, indent, indentsize),
+        Cmd::Over => {
+            let code_str = pick(state.config.target, 1);
+            append_code(state, "over", &code_str, 1, indent, indentsize)
+        }
+        Cmd::Pick(n) => {
+            let code_str = pick(state.config.target, *n);
+            append_code(state, &format!("pick {}", n), &code_str, 1, indent, indentsize)
+        }
+        Cmd::Poke(n) => {
+            if *n == 0 || *n >= state.next_cell {
+                return Err(CompileError::StackUnderflow { cmd: format!("poke {}", n) });
+            }
+            let code_str = put(state.config.target, *n);
+            append_code(state, &format!("poke {}", n), &code_str, -1, indent, indentsize)
+        }
+        Cmd::Get(var) => {
+            let adr = adr_local(&state, var)?;
+            let code_str = pick(state.config.target, adr);
+            append_code(state, &format!("get {}", var), &code_str, 1, indent, indentsize)
+        }
+        Cmd::Set(var) => {
+            let adr = adr_local(&state, var)?;
+            let code_str = put(state.config.target, adr);
+            append_code(state, &format!("set {}", var), &code_str, -1, indent, indentsize)
+        }
+        Cmd::GetIdx(var, index) => {
+            let name = element(&state, var, *index)?;
+            process_cmd(state, &Cmd::Get(name), indent, indentsize)
+        }
+        Cmd::SetIdx(var, index) => {
+            let name = element(&state, var, *index)?;
+            process_cmd(state, &Cmd::Set(name), indent, indentsize)
+        }
+        Cmd::Read => append_code(state, "read", ">,", 1, indent, indentsize),
+        Cmd::Write => append_code(state, "write", ".[-]<", -1, indent, indentsize),
+        Cmd::Push(n) => {
+            let code_str = format!(">{}", push_constant(state.config.target, *n as isize));
+            append_code(state, &format!("push {}", n), &code_str, 1, indent, indentsize)
+        }
+        Cmd::Inc => append_code(state, "inc", "+", 0, indent, indentsize),
+        Cmd::Dec => append_code(state, "dec", "-", 0, indent, indentsize),
+        Cmd::Add => append_code(state, "add", "[<+>-]<", -1, indent, indentsize),
+        Cmd::Sub => append_code(state, "sub", SUB_CODE, -1, indent, indentsize),
+        Cmd::Mul => append_code(state, "mul", "<[>>+<<-]>[>[<<+>>>+<-]>[<+>-]<<-]>[-]<<", -1, indent, indentsize),
+        Cmd::Addc(n) => {
+            let code_str = constant(state.config.target, *n as isize);
+            append_code(state, &format!("addc {}", n), &code_str, 0, indent, indentsize)
+        }
+        Cmd::Subc(n) => {
+            let code_str = constant(state.config.target, -(*n as isize));
+            append_code(state, &format!("subc {}", n), &code_str, 0, indent, indentsize)
+        }
+        Cmd::Bool => append_code(state, "bool", BOOL_CODE, 0, indent, indentsize),
+        Cmd::Eq | Cmd::Neq if !state.config.target.wrap => {
+            let (label, last) = if matches!(cmd, Cmd::Eq) { ("eq", Cmd::Not) } else { ("neq", Cmd::Bool) };
+            let mut cmds = saturating_differ_cmds();
+            cmds.push(last);
+            expand(state, label, &cmds, indent, indentsize)
+        }
+        Cmd::Eq => append_code(state, "eq", &format!("{}{}", SUB_CODE, NOT_CODE), -1, indent, indentsize),
+        Cmd::Neq => append_code(state, "neq", &format!("{}{}", SUB_CODE, BOOL_CODE), -1, indent, indentsize),
+        Cmd::And => append_code(state, "and", AND_CODE, -1, indent, indentsize),
+        Cmd::Or => append_code(state, "or", OR_CODE, -1, indent, indentsize),
+        Cmd::Not => append_code(state, "not", NOT_CODE, 0, indent, indentsize),
+        Cmd::Lt => append_code(state, "lt", LT_CODE, -1, indent, indentsize),
+        Cmd::Le => append_code(state, "le", &format!("{}{}", GT_CODE, NOT_CODE), -1, indent, indentsize),
+        Cmd::Gt => append_code(state, "gt", GT_CODE, -1, indent, indentsize),
+        Cmd::Ge => append_code(state, "ge", &format!("{}{}", LT_CODE, NOT_CODE), -1, indent, indentsize),
+        Cmd::Slt => {
+            let code_str = signed_compare(state.config.target, LT_CODE);
+            append_code(state, "slt", &code_str, -1, indent, indentsize)
+        }
+        Cmd::Sle => {
+            let code_str = signed_compare(state.config.target, &format!("{}{}", GT_CODE, NOT_CODE));
+            append_code(state, "sle", &code_str, -1, indent, indentsize)
+        }
+        Cmd::Sgt => {
+            let code_str = signed_compare(state.config.target, GT_CODE);
+            append_code(state, "sgt", &code_str, -1, indent, indentsize)
+        }
+        Cmd::Sge => {
+            let code_str = signed_compare(state.config.target, &format!("{}{}", LT_CODE, NOT_CODE));
+            append_code(state, "sge", &code_str, -1, indent, indentsize)
+        }
+        Cmd::Neg => append_code(state, "neg", NEG_CODE, 0, indent, indentsize),
+        Cmd::ReadNum => {
+            let inner_state = process_cmd_list(state.detached_child(state.next_cell), &read_num_cmds(state.config.target), indent + 1, indentsize)?;
+            let code_str = format!(
+                "\n{}{}",
+                inner_state.code,
+                block_comment("end readnum", indent, indentsize),
+            );
+            append_code(state, "readnum", &code_str, 1, indent, indentsize)
+        }
+        Cmd::ReadLine(buf) => {
+            let len = *state
+                .arrays
+                .get(buf)
+                .ok_or_else(|| CompileError::NotAnArray { name: buf.clone() })?;
+            let mut inner_state = process_cmd_list(
+                state.detached_child(state.next_cell),
+                &[Cmd::Push(0), Cmd::Push(1)],
+                indent + 1,
+                indentsize,
+            )?;
+            inner_state.env.insert("readline:count".to_string(), state.next_cell + 1);
+            inner_state.env.insert("readline:running".to_string(), state.next_cell + 2);
+            state.own(state.next_cell + 1, 1, "readline count", Kind::Temporary);
+            state.own(state.next_cell + 2, 1, "readline running", Kind::Temporary);
+            inner_state = process_cmd_list(inner_state, &read_line_cmds(buf, len), indent + 1, indentsize)?;
+            inner_state = process_cmd(inner_state, &Cmd::Drop, indent + 1, indentsize)?;
+            let code_str = format!(
+                "\n{}{}",
+                inner_state.code,
+                block_comment("end readline", indent, indentsize),
+            );
+            append_code(state, &format!("readline {}", buf), &code_str, 1, indent, indentsize)
+        }
+        Cmd::Lookup(name) => {
+            let len = *state
+                .arrays
+                .get(name)
+                .ok_or_else(|| CompileError::NotAnArray { name: name.clone() })?;
+            let cmds = lookup_cmds(name, len, state.config.target);
+            expand(state, &format!("lookup {}", name), &cmds, indent, indentsize)
+        }
+        Cmd::Store(name) => {
+            let len = *state
+                .arrays
+                .get(name)
+                .ok_or_else(|| CompileError::NotAnArray { name: name.clone() })?;
+            let cmds = store_cmds(name, len, state.config.target);
+            expand(state, &format!("store {}", name), &cmds, indent, indentsize)
+        }
+        Cmd::PrintNum => {
+            let code_str = print_num(state.config.target);
+            append_code(state, "printnum", &code_str, -1, indent, indentsize)
+        }
+        Cmd::PrintSigned => expand(state, "sprintnum", &print_signed_cmds(), indent, indentsize),
+        Cmd::PrintStr(text) => {
+            let code_str = format!(">{}<", print_string(state.config.target, text));
+            append_code(state, &format!("print {:?}", text), &code_str, 0, indent, indentsize)
+        }
+        Cmd::PushWide(width, value) => {
+            let label = format!("push{} {}", width * 8, value);
+            expand(state, &label, &wide::push(*width, *value), indent, indentsize)
+        }
+        Cmd::AddWide(width) => expand(state, &format!("add{}", width * 8), &wide::add(*width), indent, indentsize),
+        Cmd::SubWide(width) => expand(state, &format!("sub{}", width * 8), &wide::sub(*width), indent, indentsize),
+        Cmd::CmpWide(width) => expand(state, &format!("cmp{}", width * 8), &wide::cmp(*width), indent, indentsize),
+        Cmd::PrintWide(width) => {
+            expand(state, &format!("print{}", width * 8), &wide::print(*width), indent, indentsize)
+        }
+        Cmd::MulFixed => expand(state, "mulfx", &wide::mul_fixed(), indent, indentsize),
+        Cmd::PrintFixed(digits) => expand(state, &format!("printfx {}", digits), &wide::print_fixed(*digits), indent, indentsize),
+        Cmd::Alloc(name, len) => {
+            let mut state = state;
+            let size = *state.arrays.get(heap::NAME).ok_or(CompileError::NoHeap)?;
+            if state.heap.borrow().start(name).is_some() {
+                return Err(CompileError::AlreadyAllocated { name: name.clone() });
+            }
+            let start = state
+                .heap
+                .borrow_mut()
+                .alloc(name, *len, size, state.depth)
+                .ok_or_else(|| CompileError::HeapFull { name: name.clone(), len: *len })?;
+            let cell = state.env[&element_name(heap::NAME, 0)] + start;
+            for i in 0..*len {
+                state.env.insert(element_name(name, i), cell + i);
+            }
+            state.env.remove(name);
+            state.arrays.insert(name.clone(), *len);
+            state.counted.remove(name);
+            state.own(cell, *len, name, Kind::Array);
+            // Blocks may get cells that an earlier block or a `store heap` left nonzero.
+            let code_str: String = (0..*len).map(|i| clear_left(state.next_cell - cell - i)).collect();
+            append_code(state, &format!("alloc {} {}", name, len), &code_str, 0, indent, indentsize)
+        }
+        Cmd::Free(name) => {
+            let mut state = state;
+            if !state.heap.borrow_mut().free(name, state.depth) {
+                return Err(CompileError::NotAllocated { name: name.clone() });
+            }
+            for i in 0..state.arrays.remove(name).unwrap_or(0) {
+                state.env.remove(&element_name(name, i));
+            }
+            Ok(state)
+        }
+        Cmd::Addr(name) => {
+            let start = state.heap.borrow().start(name);
+            let start = start.ok_or_else(|| CompileError::NotAllocated { name: name.clone() })?;
+            process_cmd(state, &Cmd::Push(start), indent, indentsize)
+        }
+        Cmd::PrintVar(name) => {
+            let len = *state
+                .arrays
+                .get(name)
+                .ok_or_else(|| CompileError::NotAnArray { name: name.clone() })?;
+            let cmds = print_array_cmds(name, len, state.counted.contains(name));
+            expand(state, &format!("print {}", name), &cmds, indent, indentsize)
+        }
+        Cmd::Stat(cmds_inner) => {
+            // Increase indent for inner stat block.
+            let inner_state = process_cmd_list(state.child(state.next_cell), cmds_inner, indent + 1, indentsize)?;
+            check_block("stat block", cmds_inner, state.next_cell, &inner_state, 0)?;
+            check_freed(&inner_state)?;
+            let code_str = format!(
+                "\n{}{}",
+                inner_state.code,
+                block_comment("end stat", indent, indentsize),
+            );
+            append_code(state, "stat", &code_str, 0, indent, indentsize)
+        }
+        Cmd::Scope { lets, body } => {
+            // Variables are allocated above the stack top, or in the cells of dead variables if they
+            // start cleared, and shadow outer names until the scope ends.
+            let mut inner_state = state.child(state.next_cell);
+            let mut cells = 0;
+            let mut vars = Vec::new();
+            for var in lets {
+                let len = var.len.unwrap_or(1);
+                let reused = match var.init.iter().all(|&byte| byte == 0) {
+                    true => inner_state.temps.take(len),
+                    false => None,
+                };
+                if let Some(idx) = reused {
+                    vars.push((var.name.clone(), idx, len));
+                    match var.len {
+                        None => {
+                            inner_state.env.insert(var.name.clone(), idx);
+                            inner_state.arrays.remove(&var.name);
+                        }
+                        Some(len) => {
+                            for i in 0..len {
+                                inner_state.env.insert(element_name(&var.name, i), idx + i);
+                            }
+                            inner_state.env.remove(&var.name);
+                            inner_state.arrays.insert(var.name.clone(), len);
+                            inner_state.counted.remove(&var.name);
+                        }
+                    }
+                    let kind = if var.len.is_some() { Kind::Array } else { Kind::Variable };
+                    inner_state.own(idx, len, &var.name, kind);
+                    let code_str: String = (0..len).map(|i| clear_left(inner_state.next_cell - idx - i)).collect();
+                    inner_state = append_code(inner_state, &format!("let {} (reused)", var.name), &code_str, 0, indent + 1, indentsize)?;
+                    continue;
+                }
+                let idx = inner_state.next_cell + 1;
+                vars.push((var.name.clone(), idx, len));
+                match var.len {
+                    None => {
+                        inner_state.env.insert(var.name.clone(), idx);
+                        inner_state.arrays.remove(&var.name);
+                        inner_state.own(idx, 1, &var.name, Kind::Variable);
+                        inner_state = append_code(inner_state, &format!("let {}", var.name), ">", 1, indent + 1, indentsize)?;
+                        cells += 1;
+                    }
+                    Some(len) => {
+                        for i in 0..len {
+                            inner_state.env.insert(element_name(&var.name, i), idx + i);
+                        }
+                        inner_state.env.remove(&var.name);
+                        inner_state.arrays.insert(var.name.clone(), len);
+                        inner_state.own(idx, len, &var.name, Kind::Array);
+                        if var.counted {
+                            inner_state.counted.insert(var.name.clone());
+                        } else {
+                            inner_state.counted.remove(&var.name);
+                        }
+                        let label = format!("let {}[{}]", var.name, len);
+                        let code_str = array_init(inner_state.config.target, var, len, true);
+                        inner_state = append_code(inner_state, &label, &code_str, len as isize, indent + 1, indentsize)?;
+                        cells += len;
+                    }
+                }
+            }
+            let start = inner_state.next_cell;
+            inner_state.base = start;
+            inner_state.floor = start;
+            inner_state = process_block(inner_state, body, &vars, indent + 1, indentsize)?;
+            check_block("scope body", body, start, &inner_state, 0)?;
+            check_freed(&inner_state)?;
+            inner_state.floor = state.floor;
+            inner_state = append_code(inner_state, "free", &replicate(cells, "[-]<"), -(cells as isize), indent + 1, indentsize)?;
+            let code_str = format!(
+                "\n{}{}",
+                inner_state.code,
+                block_comment("end scope", indent, indentsize),
+            );
+            append_code(state, "scope", &code_str, 0, indent, indentsize)
+        }
+        Cmd::IfThen { cond, then_block } => {
+            // Process condition block in a temporary state with increased indent.
+            let cond_state = process_cmd_list(state.detached_child(state.next_cell), cond, indent + 1, indentsize)?;
+            check_block("if condition", cond, state.next_cell, &cond_state, 1)?;
+            check_freed(&cond_state)?;
+            state.own(cond_state.next_cell, 1, "if flag", Kind::Temporary);
+            // Process then block with increased indent.
+            let then_state = process_cmd_list(state.child(cond_state.next_cell), then_block, indent + 1, indentsize)?;
+            check_block("then block", then_block, cond_state.next_cell, &then_state, 0)?;
+            check_freed(&then_state)?;
+            let indent_str = make_indent(indent, indentsize);
+            let code_str = format!(
+                "\n{}{}{} [\n{}{}{}   [-]]<",
+                cond_state.code,
+                block_comment("then", indent, indentsize),
+                indent_str,
+                then_state.code,
+                block_comment("end if", indent, indentsize),
+                indent_str
+            );
+            append_code(state, "if", &code_str, 0, indent, indentsize)
+        }
+        Cmd::IfThenElse { cond, then_block, else_block } => {
+            // The condition pushes a flag and an else-flag is set above it; the then branch clears
+            // the else-flag, so exactly one branch runs. Both branches run above the two flag cells.
+            let cond_state = process_cmd_list(state.detached_child(state.next_cell), cond, indent + 1, indentsize)?;
+            check_block("if condition", cond, state.next_cell, &cond_state, 1)?;
+            check_freed(&cond_state)?;
+            state.own(state.next_cell + 1, 1, "if flag", Kind::Temporary);
+            state.own(state.next_cell + 2, 1, "else flag", Kind::Temporary);
+            let then_state = process_cmd_list(state.child(state.next_cell + 2), then_block, indent + 1, indentsize)?;
+            check_block("then block", then_block, state.next_cell + 2, &then_state, 0)?;
+            check_freed(&then_state)?;
+            let else_state = process_cmd_list(state.child(state.next_cell + 2), else_block, indent + 1, indentsize)?;
+            check_block("else block", else_block, state.next_cell + 2, &else_state, 0)?;
+            check_freed(&else_state)?;
+            let indent_str = make_indent(indent, indentsize);
+            let code_str = format!(
+                "\n{}{}{} >+<[>-\n{}{}{}   <[-]]>[-\n{}{}{}   ]<<",
+                cond_state.code,
+                block_comment("then", indent, indentsize),
+                indent_str,
+                then_state.code,
+                block_comment("else", indent, indentsize),
+                indent_str,
+                else_state.code,
+                block_comment("end if", indent, indentsize),
+                indent_str
+            );
+            append_code(state, "if", &code_str, 0, indent, indentsize)
+        }
+        Cmd::Switch { scrutinee, arms, default } => {
+            // The scrutinee and a pending flag sit on the stack; each arm tests `pending && value == k`
+            // and clears the pending flag when taken. Arms run above the value, the flag and the test.
+            let base = state.next_cell;
+            let scrut_state = process_cmd_list(state.detached_child(base), scrutinee, indent + 1, indentsize)?;
+            check_block("switch scrutinee", scrutinee, base, &scrut_state, 1)?;
+            check_freed(&scrut_state)?;
+            state.own(base + 1, 1, "switch value", Kind::Temporary);
+            state.own(base + 2, 1, "switch pending", Kind::Temporary);
+            state.own(base + 3, 1, "switch test", Kind::Temporary);
+            let mut inner_state = CompilerState {
+                code: scrut_state.code,
+                ..state.child(base + 1)
+            };
+            inner_state = process_cmd(inner_state, &Cmd::Push(1), indent + 1, indentsize)?;
+            let indent_str = make_indent(indent + 1, indentsize);
+            let cases = arms
+                .iter()
+                .map(|(k, arm)| (Some(*k), arm))
+                .chain((!default.is_empty()).then_some((None, default)));
+            for (k, arm) in cases {
+                let test = match k {
+                    Some(k) => vec![Cmd::Pick(1), Cmd::Push(k as usize), Cmd::Eq, Cmd::Pick(1), Cmd::And],
+                    None => vec![Cmd::Copy],
+                };
+                let test_state = process_cmd_list(inner_state.detached_child(base + 2), &test, indent + 2, indentsize)?;
+                let arm_state = process_cmd_list(inner_state.child(base + 3), arm, indent + 2, indentsize)?;
+                let (label, block) = match k {
+                    Some(k) => (format!("case {}", k), format!("switch arm {}", k)),
+                    None => ("default".to_string(), "switch default".to_string()),
+                };
+                check_block(&block, arm, base + 3, &arm_state, 0)?;
+                check_freed(&arm_state)?;
+                let code_str = format!(
+                    "\n{}{}{} [<[-]>\n{}{}{}   [-]]<",
+                    test_state.code,
+                    block_comment("then", indent + 1, indentsize),
+                    indent_str,
+                    arm_state.code,
+                    block_comment(&format!("end {}", label), indent + 1, indentsize),
+                    indent_str
+                );
+                inner_state = append_code(inner_state, &label, &code_str, 0, indent + 1, indentsize)?;
+            }
+            inner_state = process_cmd_list(inner_state, &[Cmd::Drop, Cmd::Drop], indent + 1, indentsize)?;
+            let code_str = format!(
+                "\n{}{}",
+                inner_state.code,
+                block_comment("end switch", indent, indentsize),
+            );
+            append_code(state, "switch", &code_str, 0, indent, indentsize)
+        }
+        Cmd::While { cond, body } if body.iter().any(may_exit_loop) => {
+            // Loops using break/continue keep an alive flag and a running flag above the stack top.
+            // The test pushes `alive ? cond : 0`; the body runs above both flags.
+            let base = state.next_cell;
+            let flags = LoopFlags { alive: base + 1, running: base + 2 };
+            state.own(flags.alive, 1, "loop alive", Kind::Temporary);
+            state.own(flags.running, 1, "loop running", Kind::Temporary);
+            let cond_state = process_cmd_list(state.detached_child(base + 4), cond, indent + 1, indentsize)?;
+            check_block("while condition", cond, base + 4, &cond_state, 1)?;
+            check_freed(&cond_state)?;
+            let body_state = process_cmd_list(
+                CompilerState {
+                    loop_flags: Some(flags),
+                    ..state.child(base + 2)
+                },
+                body,
+                indent + 1,
+                indentsize,
+            )?;
+            check_block("while body", body, base + 2, &body_state, 0)?;
+            check_freed(&body_state)?;
+            let indent_str = make_indent(indent, indentsize);
+            let test_code = format!(
+                ">>{}[[-]\n{}{}{}   {}<]<",
+                copy_right(3),
+                cond_state.code,
+                block_comment("test", indent, indentsize),
+                indent_str,
+                move_left(2)
+            );
+            let code_str = format!(
+                ">+>{}[[-]<+\n{}{}{}   [-]{}]\n{}{}   <<[-]<",
+                test_code,
+                body_state.code,
+                block_comment("again", indent, indentsize),
+                indent_str,
+                test_code,
+                block_comment("end while", indent, indentsize),
+                indent_str
+            );
+            append_code(state, "while", &code_str, 0, indent, indentsize)
+        }
+        Cmd::While { cond, body } => {
+            // The condition pushes a flag; each iteration pops it, runs the body and pushes it again.
+            let cond_state = process_cmd_list(state.detached_child(state.next_cell), cond, indent + 1, indentsize)?;
+            check_block("while condition", cond, state.next_cell, &cond_state, 1)?;
+            check_freed(&cond_state)?;
+            let body_state = process_cmd_list(state.detached_child(state.next_cell), body, indent + 1, indentsize)?;
+            check_block("while body", body, state.next_cell, &body_state, 0)?;
+            check_freed(&body_state)?;
+            let indent_str = make_indent(indent, indentsize);
+            let code_str = format!(
+                "\n{}{}{} [[-]<\n{}{}\n{}{}{}   ]<",
+                cond_state.code,
+                block_comment("do", indent, indentsize),
+                indent_str,
+                body_state.code,
+                block_comment("again", indent, indentsize),
+                cond_state.code,
+                block_comment("end while", indent, indentsize),
+                indent_str
+            );
+            append_code(state, "while", &code_str, 0, indent, indentsize)
+        }
+        Cmd::Break | Cmd::Continue => {
+            let name = if matches!(cmd, Cmd::Break) { "break" } else { "continue" };
+            let flags = state
+                .loop_flags
+                .ok_or_else(|| CompileError::OutsideLoop { cmd: name.to_string() })?;
+            let mut code_str = clear_left(state.next_cell - flags.running);
+            if matches!(cmd, Cmd::Break) {
+                code_str += &clear_left(state.next_cell - flags.alive);
+            }
+            append_code(state, name, &code_str, 0, indent, indentsize)
+        }
+        Cmd::Call(name) => procedure::call(state, name, indent, indentsize),
+        Cmd::AssertStack(n) => {
+            let height = state.next_cell as isize - state.base as isize;
+            if height != *n {
+                return Err(CompileError::StackAssertion { expected: *n, actual: height });
+            }
+            Ok(state)
+        }
+        Cmd::At(span, inner) if state.config.mark_spans => {
+            let mut state = state;
+            state.code += &sourcemap::open(*span);
+            let mut state = located(state, *span, inner, indent, indentsize)?;
+            state.code.push(sourcemap::close());
+            Ok(state)
+        }
+        Cmd::At(span, inner) => located(state, *span, inner, indent, indentsize),
+    }
+}
+
+// Processes the command at `span`, attributing errors and the cells it owns to that location.
+fn located(state: CompilerState, span: Span, cmd: &Cmd, indent: usize, indentsize: usize) -> Result<CompilerState, CompileError> {
+    let layout = Rc::clone(&state.layout);
+    layout.borrow_mut().enter(span);
+    let result = process_cmd(state, cmd, indent, indentsize).map_err(|e| e.at(span));
+    layout.borrow_mut().leave();
+    result
+}
+
+// Establishes a scope by setting up let variables and processing commands.
+pub fn scope(
+    letvars: &[Let],
+    procs: &[Proc],
+    config: Config,
+    cmds: &[Cmd],
+    indent: usize,
+    indentsize: usize,
+) -> Result<CompilerState, CompileError> {
+    let mut state = CompilerState {
+        procs: Rc::new(procs.iter().map(|p| (p.name.clone(), p.clone())).collect()),
+        config,
+        ..CompilerState::default()
+    };
+    let mut vars = Vec::new();
+    for var in letvars {
+        let idx = state.next_cell;
+        vars.push((var.name.clone(), idx, var.len.unwrap_or(1)));
+        match var.len {
+            None => {
+                state.env.insert(var.name.clone(), idx);
+                state.own(idx, 1, &var.name, Kind::Variable);
+                state = append_code(state, &format!("let {}", var.name), ">", 1, indent, indentsize)?;
+            }
+            Some(len) => {
+                for i in 0..len {
+                    state.env.insert(element_name(&var.name, i), idx + i);
+                }
+                state.arrays.insert(var.name.clone(), len);
+                if var.counted {
+                    state.counted.insert(var.name.clone());
+                }
+                state.own(idx, len, &var.name, Kind::Array);
+                let label = format!("let {}[{}]", var.name, len);
+                let code_str = array_init(state.config.target, var, len, false);
+                state = append_code(state, &label, &code_str, len as isize, indent, indentsize)?;
+            }
+        }
+    }
+    state.code += "\n";
+    state.base = state.next_cell;
+    state.floor = state.next_cell;
+    if procedure::calls_frame(&state, cmds) {
+        return procedure::dispatch(state, cmds, indent, indentsize);
+    }
+    process_block(state, cmds, &vars, indent, indentsize)
+}
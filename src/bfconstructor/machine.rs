@@ -0,0 +1,169 @@
+// A program run one op at a time, for the Python and Node.js bindings.
+//
+// Runs of `+-` and `<>` are one op, as in `ir`. Cells wrap at `cell_size` bits (8, 16 or 32),
+// `,` gives 0 at the end of the input, and a pointer that leaves the tape is an error.
+//
+// `state` gives where a run is as a `MachineState`, a `schema` document, and `restore` carries on
+// from one, on a machine made from the same program and input.
+
+use crate::ir::{self, Inst, Op, Syntax};
+use crate::schema::Document;
+use serde::{Deserialize, Serialize};
+
+pub struct Machine {
+    program: Vec<Inst>,
+    input: Vec<u8>,
+    read: usize,
+    mask: u64,
+    tape: Vec<u64>,
+    pointer: usize,
+    pc: usize, // The index of the next op.
+    steps: u64,
+    output: Vec<u8>,
+}
+
+/// Where a run is.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MachineState {
+    pub tape: Vec<u64>,
+    pub pointer: usize,
+    pub pc: usize, // The index of the next op.
+    pub steps: u64,
+    pub read: usize, // Bytes of the input read so far, counting reads past its end.
+    pub output: Vec<u8>,
+}
+
+impl Document for MachineState {
+    const KIND: &'static str = "machine";
+}
+
+impl Machine {
+    /// Parses `source` to run it on `input` with `cells` cells of `cell_size` bits.
+    pub fn new(source: &str, input: Vec<u8>, cells: usize, cell_size: u32) -> Result<Machine, String> {
+        if ![8, 16, 32].contains(&cell_size) {
+            return Err(format!("cells must have 8, 16 or 32 bits, not {}", cell_size));
+        }
+        if cells == 0 {
+            return Err("cells must be at least 1".to_string());
+        }
+        let program = ir::parse(source, Syntax::default())?;
+        let mask = (1u64 << cell_size) - 1;
+        Ok(Machine { program, input, read: 0, mask, tape: vec![0; cells], pointer: 0, pc: 0, steps: 0, output: Vec::new() })
+    }
+
+    /// Runs the next op. Returns false, doing nothing, once the program has ended.
+    pub fn step(&mut self) -> Result<bool, String> {
+        let Some(inst) = self.program.get(self.pc) else {
+            return Ok(false);
+        };
+        let cell = self.tape[self.pointer];
+        match inst.op {
+            Op::Add(n) => self.tape[self.pointer] = cell.wrapping_add(n as i64 as u64) & self.mask,
+            Op::Move(n) => {
+                let moved = self.pointer.checked_add_signed(n as isize).filter(|&p| p < self.tape.len());
+                self.pointer =
+                    moved.ok_or_else(|| format!("pointer moved off the tape from cell {} by {}", self.pointer, n))?;
+            }
+            Op::Output => self.output.push(cell as u8),
+            Op::Input => {
+                self.tape[self.pointer] = self.input.get(self.read).copied().unwrap_or(0) as u64;
+                self.read += 1;
+            }
+            Op::Open(close) if cell == 0 => self.pc = close,
+            Op::Close(open) if cell != 0 => self.pc = open,
+            _ => {}
+        }
+        self.pc += 1;
+        self.steps += 1;
+        Ok(true)
+    }
+
+    /// Runs until the program ends, failing after `max_steps` more ops.
+    pub fn run(&mut self, max_steps: Option<u64>) -> Result<(), String> {
+        let mut left = max_steps;
+        while !self.finished() {
+            if left == Some(0) {
+                return Err(format!("no result after {} steps", max_steps.unwrap_or(0)));
+            }
+            left = left.map(|n| n - 1);
+            self.step()?;
+        }
+        Ok(())
+    }
+
+    pub fn state(&self) -> MachineState {
+        MachineState {
+            tape: self.tape.clone(),
+            pointer: self.pointer,
+            pc: self.pc,
+            steps: self.steps,
+            read: self.read,
+            output: self.output.clone(),
+        }
+    }
+
+    /// Carries on from `state`, checking that it fits this program and tape.
+    pub fn restore(&mut self, state: MachineState) -> Result<(), String> {
+        if state.tape.len() != self.tape.len() {
+            return Err(format!("the state has {} cells, but the tape {}", state.tape.len(), self.tape.len()));
+        }
+        if state.pointer >= state.tape.len() {
+            return Err(format!("the pointer {} is off the tape", state.pointer));
+        }
+        if state.pc > self.program.len() {
+            return Err(format!("op {} is past the end of the program", state.pc));
+        }
+        if let Some(i) = state.tape.iter().position(|&cell| cell > self.mask) {
+            return Err(format!("cell {} does not fit in {} bits", i, self.mask.count_ones()));
+        }
+        let MachineState { tape, pointer, pc, steps, read, output } = state;
+        (self.tape, self.pointer, self.pc, self.steps, self.read, self.output) = (tape, pointer, pc, steps, read, output);
+        Ok(())
+    }
+
+    pub fn finished(&self) -> bool {
+        self.pc >= self.program.len()
+    }
+
+    /// The byte offset in the source of the next op, or `None` once the program has ended.
+    pub fn position(&self) -> Option<usize> {
+        self.program.get(self.pc).map(|inst| inst.pos)
+    }
+
+    /// How many ops the program has.
+    pub fn ops(&self) -> usize {
+        self.program.len()
+    }
+
+    /// The byte offset in the source of the first op at or after `position`.
+    pub fn op_at(&self, position: usize) -> Option<usize> {
+        self.program.iter().map(|inst| inst.pos).find(|&pos| pos >= position)
+    }
+
+    /// The next op, or `None` once the program has ended.
+    pub fn next_op(&self) -> Option<&Op> {
+        self.program.get(self.pc).map(|inst| &inst.op)
+    }
+
+    pub fn tape(&self) -> &[u64] {
+        &self.tape
+    }
+
+    pub fn pointer(&self) -> usize {
+        self.pointer
+    }
+
+    /// The index of the next op.
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    /// What the program has printed so far.
+    pub fn output(&self) -> &[u8] {
+        &self.output
+    }
+}
@@ -1,283 +1,132 @@
-use std::collections::HashMap;
+use brainfucktool::diagnostic::{self, Diagnostic, Severity};
+use brainfucktool::layout::Layout;
+use brainfucktool::{
+    ir, lexer, move_right, minify, native, optimize, parser, peephole, repl, schema, scope, sizes, sourcemap, strip_comments, text_encoder, verify, Cmd,
+    CompileError, Config, Let, Target,
+};
+use clap::builder::{PossibleValuesParser, TypedValueParser};
+use clap::{ArgGroup, Parser, ValueEnum};
+use std::fs;
+use std::path::PathBuf;
 
-// Replicates a string `s` for `n` times.
-fn replicate(n: usize, s: &str) -> String {
-    s.repeat(n)
-}
+/// Brainfuck code constructor
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+#[command(group(ArgGroup::new("input").args(["filename", "from_ast"])))]
+struct Opt {
+    /// DSL source file to compile (prints the built-in example when omitted)
+    filename: Option<String>,
 
-// Encodes a string into a vector of Unicode code points.
-fn text_encoder(s: &str) -> Vec<u32> {
-    s.chars().map(|c| c as u32).collect()
-}
+    /// Compile a program read from a JSON file written by `--emit ast`, instead of DSL source
+    #[arg(long, value_name = "FILE")]
+    from_ast: Option<PathBuf>,
 
-// Returns a string consisting of `x` plus signs.
-fn number(x: usize) -> String {
-    replicate(x, "+")
-}
+    /// What to print: the generated Brainfuck, an earlier stage of the pipeline (the source's tokens, the parsed
+    /// program as JSON, or the generated code as numbered ops or as JSON), or an equivalent C or Rust program
+    #[arg(long, value_enum, default_value = "bf")]
+    emit: Emit,
 
-// Intercalates encoded numbers with ".>" and appends a final ".>".
-fn encode_string(s: &str) -> String {
-    let encoded: Vec<String> = text_encoder(s)
-        .iter()
-        .map(|&n| number(n as usize))
-        .collect();
-    format!("{}{}", encoded.join(".>"), ".>")
-}
+    /// Directory searched for imported files, after the importing file's directory (repeatable)
+    #[arg(short = 'I', long = "include", value_name = "DIR")]
+    include: Vec<PathBuf>,
 
-// Generates a Brainfuck snippet to move a value `n` cells to the left.
-fn move_left(n: usize) -> String {
-    format!(
-        "{}[-]{}[{}+{}-]",
-        replicate(n, "<"),
-        replicate(n, ">"),
-        replicate(n, "<"),
-        replicate(n, ">")
-    )
-}
+    /// Skip the optimization passes over the parsed program and the generated code
+    #[arg(long)]
+    no_opt: bool,
 
-// Generates a Brainfuck snippet to move a value `n` cells from the left.
-fn move_right(n: usize) -> String {
-    format!(
-        "[-]{}[{}+{}-]{}",
-        replicate(n, "<"),
-        replicate(n, ">"),
-        replicate(n, "<"),
-        replicate(n, ">")
-    )
-}
+    /// Report what the optimization passes changed
+    #[arg(short, long)]
+    verbose: bool,
 
-// Generates a Brainfuck snippet to copy a value `n` cells to the right.
-fn copy_right(n: usize) -> String {
-    format!(
-        "[-]{}[{}+>+<{}-]{}[{}+{}-]<",
-        replicate(n, "<"),
-        replicate(n, ">"),
-        replicate(n, "<"),
-        replicate(n + 1, ">"),
-        replicate(n + 1, "<"),
-        replicate(n + 1, ">")
-    ) + "<"
-}
+    /// Leave out the `/* ... */` labels and `#n` stack height annotations
+    #[arg(long)]
+    no_comments: bool,
 
-// Creates a string with indent*indentsize spaces.
-fn make_indent(indent: usize, indentsize: usize) -> String {
-    " ".repeat(indent * indentsize)
-}
+    /// Emit only Brainfuck commands, without comments or whitespace
+    #[arg(long, conflicts_with = "no_comments")]
+    minify: bool,
 
-// Structure representing the compiler state.
-#[derive(Debug, Clone)]
-struct CompilerState {
-    env: HashMap<String, usize>,
-    next_cell: usize,
-    code: String,
-}
+    /// Width of the target's cells; wider cells rule out commands that rely on 8-bit wraparound
+    #[arg(long, value_name = "BITS", default_value = "8", value_parser = PossibleValuesParser::new(["8", "16", "32"]).map(|s| s.parse::<u32>().unwrap()))]
+    cell_bits: u32,
 
-impl Default for CompilerState {
-    fn default() -> Self {
-        CompilerState {
-            env: HashMap::new(),
-            next_cell: 0,
-            code: String::new(),
-        }
-    }
-}
+    /// Instructions the generated code may use: plain Brainfuck, or also the storage cell and shifts of Extended
+    /// Brainfuck Type I where they make it shorter (for `bfir --dialect extended1`, 8-bit cells only)
+    #[arg(long, value_enum, value_name = "INSTRUCTIONS", default_value = "brainfuck")]
+    target: Instructions,
 
-// Calculates the relative address of a variable.
-fn adr_local(state: &CompilerState, var: &str) -> usize {
-    if let Some(&val) = state.env.get(var) {
-        state.next_cell - val
-    } else {
-        panic!("Error: Undefined Variable Name");
-    }
-}
+    /// Generate code that never wraps a cell around (for `bfir -w`)
+    #[arg(long)]
+    no_wrap: bool,
 
-const COMMENT_WIDTH: usize = 2;
+    /// Run the generated code against the input/output cases of a TOML file before printing it
+    #[arg(long, value_name = "FILE", requires = "input")]
+    verify: Option<PathBuf>,
 
-// Updated append_code function with indentation.
-fn append_code(
-    mut state: CompilerState,
-    cmd: &str,
-    s: &str,
-    delta: isize,
-    indent: usize,
-    indentsize: usize,
-) -> CompilerState {
-    let new_next = state.next_cell as isize + delta;
-    if new_next < 0 {
-        panic!("Error");
-    }
-    let indent_str = make_indent(indent, indentsize);
-    let tail = make_indent(COMMENT_WIDTH-indent, indentsize);
-    let code = format!("/* {}{: <12}{} */ {} #{}\n", indent_str, cmd,tail,s,&new_next.to_string());
-    state.code.push_str(&code);
-    state.next_cell = new_next as usize;
-    state
-}
+    /// Check the generated code against the program's `example` declarations and write them to a TOML file of
+    /// cases for `bfir --test`
+    #[arg(long, value_name = "FILE", requires = "input")]
+    with_tests: Option<PathBuf>,
+
+    /// Write a JSON map from byte ranges of the generated code to DSL source locations
+    #[arg(long, value_name = "FILE", requires = "filename")]
+    source_map: Option<PathBuf>,
 
-// Enum representing various Brainfuck commands.
-#[derive(Debug, Clone)]
-enum Cmd {
-    Clear,
-    Copy,
-    Get(String),
-    Set(String),
-    Read,
-    Write,
-    Push(usize),
-    Inc,
-    Dec,
-    Add,
-    Sub,
-    Mul,
-    Addc(usize),
-    Subc(usize),
-    Bool,
-    Stat(Vec<Cmd>), // A block that guarantees the stack remains unchanged.
-    IfThen { cond: Vec<Cmd>, then_block: Vec<Cmd> }, // if [condition] [block]
+    /// Print a table of the tape cells owned by each variable, array and temporary to stderr
+    #[arg(long, requires = "input")]
+    layout: bool,
+
+    /// Print how many Brainfuck commands each statement and procedure compiles to, before and after the peephole pass, to stderr
+    #[arg(long, requires = "input")]
+    size_report: bool,
+
+    /// How to print errors and notes: for people, or as one JSON object per line for editors and CI
+    #[arg(long, value_enum, value_name = "FORMAT", default_value = "human")]
+    error_format: diagnostic::Format,
+
+    /// Compile statements typed one at a time, showing the code and running it after each
+    #[arg(long, conflicts_with = "input")]
+    repl: bool,
 }
 
-// Processes a list of commands, updating the compiler state with indentation.
-fn process_cmd_list(
-    mut state: CompilerState,
-    cmds: &[Cmd],
-    indent: usize,
-    indentsize: usize,
-) -> CompilerState {
-    for cmd in cmds {
-        state = process_cmd(state, cmd, indent, indentsize);
-    }
-    state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Instructions {
+    Brainfuck,
+    Extended1,
 }
 
-// Processes a single command and updates the compiler state accordingly with indentation.
-fn process_cmd(
-    state: CompilerState,
-    cmd: &Cmd,
-    indent: usize,
-    indentsize: usize,
-) -> CompilerState {
-    match cmd {
-        Cmd::Clear => append_code(state, "clear", "[-]", 0, indent, indentsize),
-        Cmd::Copy => append_code(state, "copy", "[>+>+<<-]>>[<<+>>-]<", 1, indent, indentsize),
-        Cmd::Get(var) => {
-            let adr = adr_local(&state, var);
-            let code_str = format!(">{}", copy_right(1 + adr));
-            append_code(state, &format!("get {}", var), &code_str, 1, indent, indentsize)
-        }
-        Cmd::Set(var) => {
-            let adr = adr_local(&state, var);
-            let code_str = format!("{}<", move_left(adr));
-            append_code(state, &format!("set {}", var), &code_str, -1, indent, indentsize)
-        }
-        Cmd::Read => append_code(state, "read", ">,", 1, indent, indentsize),
-        Cmd::Write => append_code(state, "write", ".[-]<", -1, indent, indentsize),
-        Cmd::Push(n) => {
-            let code_str = format!(">{}", number(*n));
-            append_code(state, &format!("push {}", n), &code_str, 1, indent, indentsize)
-        }
-        Cmd::Inc => append_code(state, "inc", "+", 0, indent, indentsize),
-        Cmd::Dec => append_code(state, "dec", "-", 0, indent, indentsize),
-        Cmd::Add => append_code(state, "add", "[<+>-]<", -1, indent, indentsize),
-        Cmd::Sub => append_code(state, "sub", "[<->-]<", -1, indent, indentsize),
-        Cmd::Mul => append_code(state, "mul", "<[>>+<<-]>[>[<<+>>>+<-]>[<+>-]<<-]>[-]<<", -1, indent, indentsize),
-        Cmd::Addc(n) => {
-            let code_str = replicate(*n, "+");
-            append_code(state, &format!("addc {}", n), &code_str, 0, indent, indentsize)
-        }
-        Cmd::Subc(n) => {
-            let code_str = replicate(*n, "-");
-            append_code(state, &format!("subc {}", n), &code_str, 0, indent, indentsize)
-        }
-        Cmd::Bool => append_code(state, "bool", "[[-]>+<]>[<+>-]<", 0, indent, indentsize),
-        Cmd::Stat(cmds_inner) => {
-            // Create a temporary state with the same next_cell and env, but empty code.
-            let temp_state = CompilerState {
-                env: state.env.clone(),
-                next_cell: state.next_cell,
-                code: String::new(),
-            };
-            // Increase indent for inner stat block.
-            let inner_state = process_cmd_list(temp_state, cmds_inner, indent + 1, indentsize);
-            if inner_state.next_cell != state.next_cell {
-                panic!("Error: Stack pointer changed in stat block");
-            }
-            let inner_indent = make_indent(indent, indentsize);
-            let code_str = format!(
-                "\n{}{}",
-                inner_state.code,
-                format!("/* {}{: <12}{} */",
-                    make_indent(indent, indentsize),
-                    "end stat",
-                    make_indent(COMMENT_WIDTH-indent, indentsize),
-                ),
-            );
-            append_code(state, "stat", &code_str, 0, indent, indentsize)
-        }
-        Cmd::IfThen { cond, then_block } => {
-            // Process condition block in a temporary state with increased indent.
-            let temp_state = CompilerState {
-                env: state.env.clone(),
-                next_cell: state.next_cell,
-                code: String::new(),
-            };
-            let cond_state = process_cmd_list(temp_state, cond, indent + 1, indentsize);
-            if cond_state.next_cell != state.next_cell + 1 {
-                panic!("Error: Condition block must increase stack pointer by 1");
-            }
-            // Process then block with increased indent.
-            let then_state = process_cmd_list(
-                CompilerState {
-                    env: cond_state.env.clone(),
-                    next_cell: cond_state.next_cell,
-                    code: String::new(),
-                },
-                then_block,
-                indent + 1,
-                indentsize,
-            );
-            if then_state.next_cell != cond_state.next_cell {
-                panic!("Error: Then block must not change stack pointer");
-            }
-            let indent_str = make_indent(indent, indentsize);
-            let code_str = format!(
-                "\n{}{}{} [\n{}{}{}   [-]]<",
-                cond_state.code,
-                format!("/* {}{: <12}{} */",
-                    make_indent(indent, indentsize),
-                    "then",
-                    make_indent(COMMENT_WIDTH-indent, indentsize),
-                ),
-                indent_str,
-                then_state.code,
-                format!("/* {}{: <12}{} */",
-                    make_indent(indent, indentsize),
-                    "end if",
-                    make_indent(COMMENT_WIDTH-indent, indentsize),
-                ),
-                indent_str
-            );
-            append_code(state, "if", &code_str, 0, indent, indentsize)
-        }
-    }
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Emit {
+    Tokens,
+    Ast,
+    Ir,
+    IrJson,
+    Bf,
+    C,
+    Rust,
 }
 
-// Establishes a scope by setting up let variables and processing commands.
-fn scope(letvars: &[&str], cmds: &[Cmd], indent: usize, indentsize: usize) -> CompilerState {
-    let mut state = CompilerState::default();
-    for var in letvars {
-        let idx = state.next_cell;
-        state.env.insert(var.to_string(), idx);
-        let code_str = format!(">");
-        state = append_code(state, &format!("let {}", var), &code_str, 1, indent, indentsize);
+// Prints the tokens of a DSL file one per line with their locations, before imports and macros are expanded.
+fn print_tokens(sources: &parser::SourceMap, src: &str, file: usize, format: diagnostic::Format) {
+    let tokens = lexer::tokenize(src, file).unwrap_or_else(|e| {
+        eprint!("{}", Diagnostic::from(&e).format(sources, format));
+        std::process::exit(1);
+    });
+    for token in tokens {
+        let text = match &token.kind {
+            lexer::TokenKind::Str(text) => format!("string {:?}", text),
+            kind => kind.describe(),
+        };
+        println!("{}  {}", sources.location(token.span), text);
     }
-    state.code += "\n";
-    process_cmd_list(state, cmds, indent, indentsize)
 }
 
 // Example program that uses the defined commands.
-fn example_program() -> String {
+fn example_program() -> Result<String, CompileError> {
     scope(
-        &["a", "b"],
+        &[Let::scalar("a"), Let::scalar("b")],
+        &[],
+        Config::default(),
         &[
             Cmd::IfThen {
                 cond: vec![Cmd::Push(1)],
@@ -306,10 +155,187 @@ fn example_program() -> String {
         ],
         0,
         4,
-    ).code
+    )
+    .map(|state| state.code)
 }
 
 fn main() {
+    let opt = Opt::parse();
+    if opt.target == Instructions::Extended1 && opt.cell_bits != 8 {
+        eprintln!("Error: --target extended1 needs --cell-bits 8");
+        std::process::exit(1);
+    }
+    let target = Target { bits: opt.cell_bits, wrap: !opt.no_wrap, extended: opt.target == Instructions::Extended1 };
+
+    if opt.repl {
+        let config = Config {
+            target,
+            mark_spans: false,
+        };
+        let stdin = std::io::stdin();
+        repl::run(stdin.lock(), &mut std::io::stdout(), config, &opt.include).unwrap_or_else(|e| {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        });
+        return;
+    }
+
+    // Compile a DSL source file or a JSON program if one was given.
+    if opt.filename.is_some() || opt.from_ast.is_some() {
+        let suite = opt.verify.as_ref().map(|path| {
+            fs::read_to_string(path)
+                .map_err(|e| e.to_string())
+                .and_then(|src| verify::load(&src))
+                .unwrap_or_else(|e| {
+                    eprintln!("Error reading {}: {}", path.display(), e);
+                    std::process::exit(1);
+                })
+        });
+        let mut sources = parser::SourceMap::default();
+        let mut program = match (&opt.filename, &opt.from_ast) {
+            (Some(filename), _) => {
+                let src = fs::read_to_string(filename).unwrap_or_else(|e| {
+                    eprintln!("Error reading file: {}", e);
+                    std::process::exit(1);
+                });
+                let file = sources.add(filename, src.clone());
+                if opt.emit == Emit::Tokens {
+                    print_tokens(&sources, &src, file, opt.error_format);
+                    return;
+                }
+                parser::parse(&mut sources, file, &opt.include).unwrap_or_else(|e| {
+                    eprint!("{}", Diagnostic::from(&e).format(&sources, opt.error_format));
+                    std::process::exit(1);
+                })
+            }
+            (None, Some(_)) if opt.emit == Emit::Tokens => {
+                eprintln!("Error: --emit tokens needs DSL source, not --from-ast");
+                std::process::exit(1);
+            }
+            (None, Some(path)) => fs::read_to_string(path)
+                .map_err(|e| e.to_string())
+                .and_then(|json| schema::from_json(&json))
+                .unwrap_or_else(|e| {
+                    eprintln!("Error reading {}: {}", path.display(), e);
+                    std::process::exit(1);
+                }),
+            (None, None) => unreachable!("checked above"),
+        };
+        if opt.emit == Emit::Ast {
+            println!("{}", schema::to_json(&program));
+            return;
+        }
+        if opt.with_tests.is_some() && program.examples.is_empty() {
+            eprintln!("Error: --with-tests needs `example` declarations in the program");
+            std::process::exit(1);
+        }
+        let config = Config {
+            target,
+            mark_spans: opt.source_map.is_some() || opt.size_report,
+        };
+        if !opt.no_opt {
+            for removal in optimize::eliminate_dead_code(&mut program) {
+                if opt.verbose {
+                    let note = Diagnostic::new(Severity::Note, "dead-code", format!("removed {}", removal.what), removal.span);
+                    eprint!("{}", note.format(&sources, opt.error_format));
+                }
+            }
+        }
+        let mut layout = Layout::default();
+        let compiled = match opt.emit {
+            Emit::C => native::emit(&program, config, native::Lang::C),
+            Emit::Rust => native::emit(&program, config, native::Lang::Rust),
+            _ => scope(&program.lets, &program.procs, config, &program.body, 0, 4).map(|state| {
+                if opt.layout {
+                    eprint!("{}", state.layout().render(&sources));
+                }
+                layout = state.layout();
+                state.code
+            }),
+        };
+        match compiled {
+            Ok(code) if matches!(opt.emit, Emit::C | Emit::Rust) => print!("{}", code),
+            Ok(code) => {
+                let code = format!("/* target: {} */\n{}", config.target, code);
+                // Comments would hold commands of Extended Brainfuck, so extended code has none.
+                let mut code = match opt.no_comments || opt.minify || target.extended {
+                    true => strip_comments(&code),
+                    false => code,
+                };
+                let before = opt.size_report.then(|| sourcemap::resolve(&code));
+                if !opt.no_opt {
+                    let (removed, dead_loops);
+                    (code, removed, dead_loops) = peephole::optimize(&code);
+                    if opt.verbose {
+                        for removal in dead_loops {
+                            let note = Diagnostic::new(Severity::Note, "dead-loop", format!("removed {}", removal.what), removal.span);
+                            eprint!("{}", note.format(&sources, opt.error_format));
+                        }
+                        let message = format!("peephole pass removed {} commands", removed);
+                        let note = Diagnostic::new(Severity::Note, "peephole", message, None);
+                        eprint!("{}", note.format(&sources, opt.error_format));
+                    }
+                }
+                if opt.minify {
+                    code = minify(&code);
+                }
+                if let Some(before) = &before {
+                    eprint!("{}", sizes::report(&sources, &program, config.target, before, &sourcemap::resolve(&code)));
+                }
+                if config.mark_spans {
+                    let ranges;
+                    (code, ranges) = sourcemap::resolve(&code);
+                    if let Some(path) = &opt.source_map {
+                        let map = sourcemap::build(&sources, &ranges, &layout, code.len());
+                        let json = serde_json::to_string_pretty(&map).expect("source maps serialize");
+                        fs::write(path, json).unwrap_or_else(|e| {
+                            eprintln!("Error writing source map: {}", e);
+                            std::process::exit(1);
+                        });
+                    }
+                }
+                if let Some(suite) = &suite {
+                    if !verify::check(suite, &code, config.target) {
+                        std::process::exit(1);
+                    }
+                }
+                if let Some(path) = &opt.with_tests {
+                    let examples = verify::Suite { tests: program.examples.clone() };
+                    if !verify::check(&examples, &code, config.target) {
+                        std::process::exit(1);
+                    }
+                    let source = opt.filename.clone().or(opt.from_ast.as_ref().map(|p| p.display().to_string()));
+                    let manifest = format!(
+                        "# The examples of {}, for `bfir --test {} <program>`.\n# target: {}\n{}",
+                        source.unwrap_or_default(),
+                        path.display(),
+                        config.target,
+                        verify::save(&examples)
+                    );
+                    fs::write(path, manifest).unwrap_or_else(|e| {
+                        eprintln!("Error writing {}: {}", path.display(), e);
+                        std::process::exit(1);
+                    });
+                }
+                if matches!(opt.emit, Emit::Ir | Emit::IrJson) {
+                    let syntax = ir::Syntax { comments: true, extended: target.extended, ..Default::default() };
+                    let program = ir::parse(&code, syntax).expect("generated code has balanced brackets");
+                    match opt.emit {
+                        Emit::Ir => print!("{}", ir::render(&program)),
+                        _ => println!("{}", schema::to_json(&schema::Ir { ops: program })),
+                    }
+                } else {
+                    println!("{}", code);
+                }
+            }
+            Err(e) => {
+                eprint!("{}", Diagnostic::from(&e).format(&sources, opt.error_format));
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
     // Testing utility functions.
     println!("text_encoder(\"ABCDE\") -> {:?}", text_encoder("ABCDE"));
     println!("text_encoder(\"abcde\") -> {:?}", text_encoder("abcde"));
@@ -317,6 +343,6 @@ fn main() {
 
     // Print the generated Brainfuck code from the example program.
     println!("```bf");
-    println!("{}", example_program());
+    println!("{}", example_program().expect("the example program compiles"));
     println!("```");
 }
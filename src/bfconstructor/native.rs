@@ -0,0 +1,549 @@
+// `--emit c` and `--emit rust`: native programs equivalent to the generated Brainfuck.
+//
+// The program is first compiled as usual, so it is rejected with the same errors. The native
+// code then mirrors the Brainfuck cell layout: every stack slot and variable keeps the cell the
+// compiler assigned to it, but the snippets become direct reads and writes of a `m[]` array.
+// Commands that the compiler implements as stack programs (`readnum`, `readline`, `lookup`,
+// `print` of a string variable, the wide operations, ...) are expanded from the same programs, so both
+// outputs compute the same results. Procedures become functions that receive `m` offset so
+// that their first parameter is `m[1]`. Reading past the end of input yields 0.
+
+use crate::procedure::{CallConv, Proc};
+use crate::heap::{self, Heap};
+use crate::{element_name, lookup_cmds, print_array_cmds, print_signed_cmds, read_line_cmds, read_num_cmds, scope, store_cmds, wide};
+use crate::{Cmd, CompileError, Config, Program, Target};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lang {
+    C,
+    Rust,
+}
+
+// Compiles `program` for `config.target` and returns it as a C or Rust source file.
+pub fn emit(program: &Program, config: Config, lang: Lang) -> Result<String, CompileError> {
+    scope(&program.lets, &program.procs, Config { mark_spans: false, ..config }, &program.body, 0, 4)?;
+    let procs: HashMap<&str, &Proc> = program.procs.iter().map(|p| (p.name.as_str(), p)).collect();
+    let mut e = Emitter {
+        lang,
+        target: config.target,
+        procs: &procs,
+        out: String::new(),
+        depth: 1,
+        called: Vec::new(),
+        heap: Heap::default(),
+    };
+    let mut env = Env::default();
+    let mut top = 0;
+    for var in &program.lets {
+        top = e.declare(&mut env, var, top, top + var.len.unwrap_or(1));
+    }
+    e.block(&env, top, &program.body);
+    let main = std::mem::take(&mut e.out);
+
+    // Procedures are emitted once they are called, so unused ones (which were never checked) are left out.
+    let mut functions = Vec::new();
+    let mut done = HashSet::new();
+    while let Some(name) = e.called.pop() {
+        if done.insert(name.clone()) {
+            let proc = procs[name.as_str()];
+            functions.push((name, e.function(proc)));
+        }
+    }
+    functions.sort();
+    Ok(e.file(&functions, &main))
+}
+
+// Variables in scope, as in `CompilerState`: array elements are stored under `element_name`.
+#[derive(Debug, Clone, Default)]
+struct Env {
+    vars: HashMap<String, usize>,
+    arrays: HashMap<String, usize>,
+    counted: HashSet<String>,
+}
+
+struct Emitter<'a> {
+    lang: Lang,
+    target: Target,
+    procs: &'a HashMap<&'a str, &'a Proc>,
+    out: String,
+    depth: usize,
+    called: Vec<String>,
+    heap: Heap, // Allocates in the same order as the compiler, so blocks get the same cells.
+}
+
+impl Emitter<'_> {
+    fn line(&mut self, text: &str) {
+        self.out += &"    ".repeat(self.depth);
+        self.out += text;
+        self.out.push('\n');
+    }
+
+    fn set(&mut self, cell: usize, value: &str) {
+        self.line(&format!("m[{}] = {};", cell, value));
+    }
+
+    fn open(&mut self, head: &str) {
+        self.line(&format!("{} {{", head));
+        self.depth += 1;
+    }
+
+    fn close(&mut self) {
+        self.depth -= 1;
+        self.line("}");
+    }
+
+    fn open_if(&mut self, cond: &str) {
+        match self.lang {
+            Lang::C => self.open(&format!("if ({})", cond)),
+            Lang::Rust => self.open(&format!("if {}", cond)),
+        }
+    }
+
+    fn open_else(&mut self, cond: Option<&str>) {
+        self.depth -= 1;
+        match (cond, self.lang) {
+            (Some(cond), Lang::C) => self.line(&format!("}} else if ({}) {{", cond)),
+            (Some(cond), Lang::Rust) => self.line(&format!("}} else if {} {{", cond)),
+            (None, _) => self.line("} else {"),
+        }
+        self.depth += 1;
+    }
+
+    fn open_loop(&mut self) {
+        match self.lang {
+            Lang::C => self.open("for (;;)"),
+            Lang::Rust => self.open("loop"),
+        }
+    }
+
+    // A comparison as a 0/1 cell value.
+    fn flag(&self, cond: &str) -> String {
+        match self.lang {
+            Lang::C => cond.to_string(),
+            Lang::Rust => format!("({}) as Cell", cond),
+        }
+    }
+
+    // A cell read as a two's complement byte.
+    fn signed(&self, cell: usize) -> String {
+        match self.lang {
+            Lang::C => format!("(int8_t)m[{}]", cell),
+            Lang::Rust => format!("(m[{}] as i8)", cell),
+        }
+    }
+
+    // `n` reduced to the cell width, as `constant` does.
+    fn literal(&self, n: u64) -> u64 {
+        match self.target.bits {
+            bits if bits >= 64 => n,
+            bits => n & ((1 << bits) - 1),
+        }
+    }
+
+    fn print_str(&mut self, text: &str) {
+        let mut escaped = String::new();
+        for &b in text.as_bytes() {
+            match (b, self.lang) {
+                (b'"' | b'\\', _) => {
+                    escaped.push('\\');
+                    escaped.push(b as char);
+                }
+                (b' '..=b'~', _) => escaped.push(b as char),
+                (_, Lang::C) => escaped += &format!("\\{:03o}", b),
+                (_, Lang::Rust) => escaped += &format!("\\x{:02x}", b),
+            }
+        }
+        match self.lang {
+            Lang::C => self.line(&format!("print_str(\"{}\", {});", escaped, text.len())),
+            Lang::Rust => self.line(&format!("print_str(b\"{}\");", escaped)),
+        }
+    }
+
+    // Allocates a variable in cells `at..end` and sets its initial value.
+    fn declare(&mut self, env: &mut Env, var: &crate::Let, at: usize, end: usize) -> usize {
+        match var.len {
+            None => {
+                env.vars.insert(var.name.clone(), at);
+                env.arrays.remove(&var.name);
+                self.set(at, "0");
+            }
+            Some(len) => {
+                for i in 0..len {
+                    env.vars.insert(element_name(&var.name, i), at + i);
+                    self.set(at + i, &var.init.get(i).copied().unwrap_or(0).to_string());
+                }
+                env.vars.remove(&var.name);
+                env.arrays.insert(var.name.clone(), len);
+                if var.counted {
+                    env.counted.insert(var.name.clone());
+                } else {
+                    env.counted.remove(&var.name);
+                }
+            }
+        }
+        end
+    }
+
+    // Emits `cmds` with the stack top at `top` and returns the new top. `alloc` and `free` bind
+    // names until the end of the block, so they are handled here rather than in `cmd`.
+    fn block(&mut self, env: &Env, mut top: usize, cmds: &[Cmd]) -> usize {
+        let mut inner: Option<Env> = None;
+        for cmd in cmds {
+            let mut bare = cmd;
+            while let Cmd::At(_, located) = bare {
+                bare = located;
+            }
+            match bare {
+                Cmd::Alloc(name, len) => {
+                    let env = inner.get_or_insert_with(|| env.clone());
+                    let size = env.arrays[heap::NAME];
+                    let start = self.heap.alloc(name, *len, size, 0).expect("checked by the compiler");
+                    let cell = env.vars[&element_name(heap::NAME, 0)] + start;
+                    for i in 0..*len {
+                        env.vars.insert(element_name(name, i), cell + i);
+                        self.set(cell + i, "0");
+                    }
+                    env.vars.remove(name);
+                    env.arrays.insert(name.clone(), *len);
+                    env.counted.remove(name);
+                }
+                Cmd::Free(name) => {
+                    let env = inner.get_or_insert_with(|| env.clone());
+                    self.heap.free(name, 0);
+                    for i in 0..env.arrays.remove(name).unwrap_or(0) {
+                        env.vars.remove(&element_name(name, i));
+                    }
+                }
+                _ => top = self.cmd(inner.as_ref().unwrap_or(env), top, cmd),
+            }
+        }
+        top
+    }
+
+    fn binary(&mut self, top: usize, value: &str) -> usize {
+        self.set(top - 1, value);
+        top - 1
+    }
+
+    fn cmd(&mut self, env: &Env, t: usize, cmd: &Cmd) -> usize {
+        let var = |name: &str| env.vars[name];
+        match cmd {
+            Cmd::Clear => {
+                self.set(t, "0");
+                t
+            }
+            Cmd::Copy => self.cmd(env, t, &Cmd::Pick(0)),
+            Cmd::Swap => {
+                self.set(t + 1, &format!("m[{}]", t));
+                self.set(t, &format!("m[{}]", t - 1));
+                self.set(t - 1, &format!("m[{}]", t + 1));
+                t
+            }
+            Cmd::Drop => t - 1,
+            Cmd::Over => self.cmd(env, t, &Cmd::Pick(1)),
+            Cmd::Pick(n) => {
+                self.set(t + 1, &format!("m[{}]", t - n));
+                t + 1
+            }
+            Cmd::Poke(n) => {
+                self.set(t - n, &format!("m[{}]", t));
+                t - 1
+            }
+            Cmd::Get(name) => {
+                self.set(t + 1, &format!("m[{}]", var(name)));
+                t + 1
+            }
+            Cmd::Set(name) => {
+                self.set(var(name), &format!("m[{}]", t));
+                t - 1
+            }
+            Cmd::GetIdx(name, i) => self.cmd(env, t, &Cmd::Get(element_name(name, *i))),
+            Cmd::SetIdx(name, i) => self.cmd(env, t, &Cmd::Set(element_name(name, *i))),
+            Cmd::Read => {
+                self.set(t + 1, "read_byte()");
+                t + 1
+            }
+            Cmd::Write => {
+                self.line(&format!("write_byte(m[{}]);", t));
+                t - 1
+            }
+            Cmd::Push(n) => {
+                self.set(t + 1, &self.literal(*n as u64).to_string());
+                t + 1
+            }
+            Cmd::Inc => self.cmd(env, t, &Cmd::Addc(1)),
+            Cmd::Dec => self.cmd(env, t, &Cmd::Subc(1)),
+            Cmd::Add => self.binary(t, &format!("add(m[{}], m[{}])", t - 1, t)),
+            Cmd::Sub => self.binary(t, &format!("sub(m[{}], m[{}])", t - 1, t)),
+            Cmd::Mul => self.binary(t, &format!("mul(m[{}], m[{}])", t - 1, t)),
+            Cmd::Addc(n) => {
+                self.set(t, &format!("add(m[{}], {})", t, self.literal(*n as u64)));
+                t
+            }
+            Cmd::Subc(n) => {
+                self.set(t, &format!("sub(m[{}], {})", t, self.literal(*n as u64)));
+                t
+            }
+            Cmd::Bool => {
+                self.set(t, &self.flag(&format!("m[{}] != 0", t)));
+                t
+            }
+            Cmd::Not => {
+                self.set(t, &self.flag(&format!("m[{}] == 0", t)));
+                t
+            }
+            Cmd::Neg => {
+                self.set(t, &format!("sub(0, m[{}])", t));
+                t
+            }
+            Cmd::Eq | Cmd::Neq | Cmd::Lt | Cmd::Le | Cmd::Gt | Cmd::Ge => {
+                let op = match cmd {
+                    Cmd::Eq => "==",
+                    Cmd::Neq => "!=",
+                    Cmd::Lt => "<",
+                    Cmd::Le => "<=",
+                    Cmd::Gt => ">",
+                    _ => ">=",
+                };
+                self.binary(t, &self.flag(&format!("m[{}] {} m[{}]", t - 1, op, t)))
+            }
+            Cmd::Slt | Cmd::Sle | Cmd::Sgt | Cmd::Sge => {
+                let op = match cmd {
+                    Cmd::Slt => "<",
+                    Cmd::Sle => "<=",
+                    Cmd::Sgt => ">",
+                    _ => ">=",
+                };
+                let cond = format!("{} {} {}", self.signed(t - 1), op, self.signed(t));
+                self.binary(t, &self.flag(&cond))
+            }
+            Cmd::And => self.binary(t, &self.flag(&format!("m[{}] != 0 && m[{}] != 0", t - 1, t))),
+            Cmd::Or => self.binary(t, &self.flag(&format!("m[{}] != 0 || m[{}] != 0", t - 1, t))),
+            Cmd::ReadNum => self.block(env, t, &read_num_cmds(self.target)),
+            Cmd::ReadLine(buf) => {
+                let mut inner = env.clone();
+                inner.vars.insert("readline:count".to_string(), t + 1);
+                inner.vars.insert("readline:running".to_string(), t + 2);
+                let top = self.block(&inner, t, &[Cmd::Push(0), Cmd::Push(1)]);
+                let top = self.block(&inner, top, &read_line_cmds(buf, env.arrays[buf]));
+                self.cmd(&inner, top, &Cmd::Drop)
+            }
+            Cmd::Lookup(name) => self.block(env, t, &lookup_cmds(name, env.arrays[name], self.target)),
+            Cmd::Store(name) => self.block(env, t, &store_cmds(name, env.arrays[name], self.target)),
+            Cmd::Alloc(..) | Cmd::Free(_) => unreachable!("handled by block"),
+            Cmd::Addr(name) => {
+                let start = self.heap.start(name).expect("checked by the compiler");
+                self.cmd(env, t, &Cmd::Push(start))
+            }
+            Cmd::PrintNum => {
+                self.line(&format!("print_num(m[{}]);", t));
+                t - 1
+            }
+            Cmd::PrintSigned => self.block(env, t, &print_signed_cmds()),
+            Cmd::PrintStr(text) => {
+                self.print_str(text);
+                t
+            }
+            Cmd::PrintVar(name) => {
+                let cmds = print_array_cmds(name, env.arrays[name], env.counted.contains(name));
+                self.block(env, t, &cmds)
+            }
+            Cmd::PushWide(width, value) => self.block(env, t, &wide::push(*width, *value)),
+            Cmd::AddWide(width) => self.block(env, t, &wide::add(*width)),
+            Cmd::SubWide(width) => self.block(env, t, &wide::sub(*width)),
+            Cmd::CmpWide(width) => self.block(env, t, &wide::cmp(*width)),
+            Cmd::PrintWide(width) => self.block(env, t, &wide::print(*width)),
+            Cmd::MulFixed => self.block(env, t, &wide::mul_fixed()),
+            Cmd::PrintFixed(digits) => self.block(env, t, &wide::print_fixed(*digits)),
+            Cmd::Stat(cmds) => self.block(env, t, cmds),
+            Cmd::Scope { lets, body } => {
+                let mut inner = env.clone();
+                let mut top = t;
+                for var in lets {
+                    top = self.declare(&mut inner, var, top + 1, top + var.len.unwrap_or(1));
+                }
+                self.block(&inner, top, body);
+                t
+            }
+            Cmd::IfThen { cond, then_block } => {
+                let flag = self.block(env, t, cond);
+                self.open_if(&format!("m[{}] != 0", flag));
+                self.block(env, flag, then_block);
+                self.close();
+                t
+            }
+            Cmd::IfThenElse { cond, then_block, else_block } => {
+                let flag = self.block(env, t, cond);
+                self.open_if(&format!("m[{}] != 0", flag));
+                self.block(env, t + 2, then_block);
+                self.open_else(None);
+                self.block(env, t + 2, else_block);
+                self.close();
+                t
+            }
+            Cmd::Switch { scrutinee, arms, default } => {
+                let value = self.block(env, t, scrutinee);
+                for (i, (k, arm)) in arms.iter().enumerate() {
+                    let cond = format!("m[{}] == {}", value, self.literal(*k as u64));
+                    match i {
+                        0 => self.open_if(&cond),
+                        _ => self.open_else(Some(&cond)),
+                    }
+                    self.block(env, t + 3, arm);
+                }
+                match (arms.is_empty(), default.is_empty()) {
+                    (true, _) => {
+                        self.block(env, t + 3, default);
+                    }
+                    (false, true) => self.close(),
+                    (false, false) => {
+                        self.open_else(None);
+                        self.block(env, t + 3, default);
+                        self.close();
+                    }
+                }
+                t
+            }
+            Cmd::While { cond, body } => {
+                // Loops using break/continue keep two flag cells below their body, as in the Brainfuck.
+                let (test, body_top) = match body.iter().any(crate::may_exit_loop) {
+                    true => (t + 4, t + 2),
+                    false => (t, t),
+                };
+                self.open_loop();
+                let flag = self.block(env, test, cond);
+                self.open_if(&format!("m[{}] == 0", flag));
+                self.line("break;");
+                self.close();
+                self.block(env, body_top, body);
+                self.close();
+                t
+            }
+            Cmd::Break => {
+                self.line("break;");
+                t
+            }
+            Cmd::Continue => {
+                self.line("continue;");
+                t
+            }
+            Cmd::Call(name) => {
+                let proc = self.procs[name.as_str()];
+                let n = proc.params.len();
+                match self.lang {
+                    Lang::C => self.line(&format!("p_{}(m + {});", name, t - n)),
+                    Lang::Rust => self.line(&format!("p_{}(&mut m[{}..]);", name, t - n)),
+                }
+                self.called.push(name.clone());
+                t - n + proc.returns
+            }
+            Cmd::AssertStack(_) => t,
+            Cmd::At(_, inner) => self.cmd(env, t, inner),
+        }
+    }
+
+    // The body of a procedure's function: parameters in `m[1..=n]`, results moved down to `m[1]`.
+    fn function(&mut self, proc: &Proc) -> String {
+        let n = proc.params.len();
+        let env = Env {
+            vars: proc.params.iter().enumerate().map(|(i, name)| (name.clone(), i + 1)).collect(),
+            ..Env::default()
+        };
+        // A frame procedure's stack starts above its return cell.
+        let start = match proc.conv {
+            CallConv::Inline => n,
+            CallConv::Frame => n + 1,
+        };
+        self.depth = 1;
+        self.block(&env, start, &proc.body);
+        for i in 1..=proc.returns {
+            if start + i != i {
+                self.set(i, &format!("m[{}]", start + i));
+            }
+        }
+        std::mem::take(&mut self.out)
+    }
+
+    fn file(&self, functions: &[(String, String)], main: &str) -> String {
+        let bits = self.target.bits;
+        let mut out = String::new();
+        match self.lang {
+            Lang::C => {
+                out += &format!("/* Generated by bfconstructor for {}. */\n", self.target);
+                out += &C_PRELUDE.replace("{bits}", &bits.to_string());
+                for (name, _) in functions {
+                    out += &format!("static void p_{}(cell *m);\n", name);
+                }
+                for (name, body) in functions {
+                    out += &format!("\nstatic void p_{}(cell *m) {{\n{}}}\n", name, body);
+                }
+                out += &format!("\nint main(void) {{\n    cell *m = mem;\n{}    return 0;\n}}\n", main);
+            }
+            Lang::Rust => {
+                out += &format!("// Generated by bfconstructor for {}.\n", self.target);
+                out += &RUST_PRELUDE.replace("{bits}", &bits.to_string());
+                for (name, body) in functions {
+                    out += &format!("\nfn p_{}(m: &mut [Cell]) {{\n{}}}\n", name, body);
+                }
+                out += &format!(
+                    "\nfn main() {{\n    let mut mem = vec![0 as Cell; CELLS];\n    let m = &mut mem[..];\n{}    let _ = std::io::stdout().flush();\n}}\n",
+                    main
+                );
+            }
+        }
+        out
+    }
+}
+
+const C_PRELUDE: &str = r#"
+#include <stdint.h>
+#include <stdio.h>
+
+typedef uint{bits}_t cell;
+
+#define CELLS 30000
+
+static cell mem[CELLS];
+
+static inline cell add(cell a, cell b) { return (cell)((uint64_t)a + b); }
+static inline cell sub(cell a, cell b) { return (cell)((uint64_t)a - b); }
+static inline cell mul(cell a, cell b) { return (cell)((uint64_t)a * b); }
+
+static inline cell read_byte(void) {
+    int c = getchar();
+    return c == EOF ? 0 : (cell)c;
+}
+
+static inline void write_byte(cell c) { putchar((unsigned char)c); }
+static inline void print_num(cell c) { printf("%llu", (unsigned long long)c); }
+static inline void print_str(const char *s, size_t len) { fwrite(s, 1, len, stdout); }
+
+"#;
+
+const RUST_PRELUDE: &str = r#"
+#![allow(unused, non_snake_case)]
+
+use std::io::{Read, Write};
+
+type Cell = u{bits};
+
+const CELLS: usize = 30000;
+
+fn add(a: Cell, b: Cell) -> Cell { a.wrapping_add(b) }
+fn sub(a: Cell, b: Cell) -> Cell { a.wrapping_sub(b) }
+fn mul(a: Cell, b: Cell) -> Cell { a.wrapping_mul(b) }
+
+fn read_byte() -> Cell {
+    let _ = std::io::stdout().flush();
+    let mut b = [0u8];
+    match std::io::stdin().read(&mut b) {
+        Ok(1) => b[0] as Cell,
+        _ => 0,
+    }
+}
+
+fn write_byte(c: Cell) { let _ = std::io::stdout().write_all(&[c as u8]); }
+fn print_num(c: Cell) { print!("{}", c); }
+fn print_str(s: &[u8]) { let _ = std::io::stdout().write_all(s); }
+"#;
@@ -0,0 +1,106 @@
+// Node.js bindings, built with the `node` feature into the addon that `node/index.js` loads:
+//
+//     const bf = require('brainfucktool')
+//     bf.run('++++++++[>++++++++<-]>+.', { input: Buffer.from(''), cells: 30000, cellSize: 8 })  // <Buffer 41>
+//
+//     const m = new bf.Machine(',[.,]', { input: Buffer.from('hi') })
+//     m.on('output', bytes => process.stdout.write(bytes))
+//     m.on('end', () => console.log(m.steps))
+//     m.run()
+//
+// `run` returns what the program printed, and `Machine` runs one op at a time; `node/index.js`
+// wraps it in an `EventEmitter`. See `machine` for how programs run. A program that does not
+// parse, bad options or a pointer that leaves the tape throw an `Error`.
+
+use crate::machine;
+use napi::bindgen_prelude::Buffer;
+use napi::{Error, Result};
+use napi_derive::napi;
+
+#[napi(object)]
+#[derive(Default)]
+pub struct Options {
+    pub input: Option<Buffer>,
+    pub cells: Option<u32>,
+    pub cell_size: Option<u32>,
+    /// For `run`, the ops to run before throwing.
+    pub max_steps: Option<i64>,
+}
+
+/// Runs `source` and returns the bytes it printed.
+#[napi]
+pub fn run(source: String, options: Option<Options>) -> Result<Buffer> {
+    let max_steps = options.as_ref().and_then(|options| options.max_steps);
+    let mut machine = Machine::new(source, options)?;
+    machine.0.run(max_steps.map(|n| n.max(0) as u64)).map_err(Error::from_reason)?;
+    Ok(machine.0.output().to_vec().into())
+}
+
+/// A program being run one op at a time.
+#[napi]
+pub struct Machine(machine::Machine, usize); // The machine and how much of its output `takeOutput` has given.
+
+#[napi]
+impl Machine {
+    #[napi(constructor)]
+    pub fn new(source: String, options: Option<Options>) -> Result<Machine> {
+        let Options { input, cells, cell_size, .. } = options.unwrap_or_default();
+        let input = input.map(Vec::from).unwrap_or_default();
+        let machine = machine::Machine::new(&source, input, cells.unwrap_or(30000) as usize, cell_size.unwrap_or(8));
+        Ok(Machine(machine.map_err(Error::from_reason)?, 0))
+    }
+
+    /// Runs the next op. Returns false, doing nothing, once the program has ended.
+    #[napi]
+    pub fn step(&mut self) -> Result<bool> {
+        self.0.step().map_err(Error::from_reason)
+    }
+
+    /// What the program has printed since the last call.
+    #[napi]
+    pub fn take_output(&mut self) -> Buffer {
+        let output = self.0.output()[self.1..].to_vec();
+        self.1 += output.len();
+        output.into()
+    }
+
+    /// Whether the program has ended.
+    #[napi(getter)]
+    pub fn finished(&self) -> bool {
+        self.0.finished()
+    }
+
+    /// The byte offset in the source of the next op, or null once the program has ended.
+    #[napi(getter)]
+    pub fn position(&self) -> Option<u32> {
+        self.0.position().map(|pos| pos as u32)
+    }
+
+    /// The cells of the tape.
+    #[napi(getter)]
+    pub fn tape(&self) -> Vec<u32> {
+        self.0.tape().iter().map(|&cell| cell as u32).collect()
+    }
+
+    #[napi(getter)]
+    pub fn pointer(&self) -> u32 {
+        self.0.pointer() as u32
+    }
+
+    /// The index of the next op.
+    #[napi(getter)]
+    pub fn pc(&self) -> u32 {
+        self.0.pc() as u32
+    }
+
+    #[napi(getter)]
+    pub fn steps(&self) -> i64 {
+        self.0.steps() as i64
+    }
+
+    /// What the program has printed so far.
+    #[napi(getter)]
+    pub fn output(&self) -> Buffer {
+        self.0.output().to_vec().into()
+    }
+}
@@ -0,0 +1,339 @@
+// Optimization passes over the `Cmd` IR, run between parsing and code generation.
+//
+// Each pass records what it changed as a `Removal` so the command line can report it.
+
+use crate::lexer::Span;
+use crate::parser::Program;
+use crate::{may_exit_loop, Cmd, Let};
+use std::collections::{HashMap, HashSet};
+
+// A change made by an optimization pass, located at the source of the first command involved.
+#[derive(Debug, Clone)]
+pub struct Removal {
+    pub span: Option<Span>,
+    pub what: String,
+}
+
+// Strips the source location from a command.
+fn bare(cmd: &Cmd) -> &Cmd {
+    match cmd {
+        Cmd::At(_, inner) => bare(inner),
+        cmd => cmd,
+    }
+}
+
+fn span_of(cmd: &Cmd) -> Option<Span> {
+    match cmd {
+        Cmd::At(span, _) => Some(*span),
+        _ => None,
+    }
+}
+
+// The DSL spelling of a simple command, for reports.
+fn describe(cmd: &Cmd) -> String {
+    match bare(cmd) {
+        Cmd::Push(n) => format!("push {}", n),
+        Cmd::Get(x) => format!("get {}", x),
+        Cmd::GetIdx(x, i) => format!("get {}[{}]", x, i),
+        Cmd::Set(x) => format!("set {}", x),
+        Cmd::Pick(n) => format!("pick {}", n),
+        Cmd::Addc(n) => format!("addc {}", n),
+        Cmd::Subc(n) => format!("subc {}", n),
+        cmd => format!("{:?}", cmd).to_lowercase(),
+    }
+}
+
+// Commands that push one value without side effects.
+fn is_producer(cmd: &Cmd) -> bool {
+    matches!(
+        bare(cmd),
+        Cmd::Push(_) | Cmd::Get(_) | Cmd::GetIdx(..) | Cmd::Copy | Cmd::Over | Cmd::Pick(_)
+    )
+}
+
+// Commands that replace the top value without side effects.
+fn is_unary(cmd: &Cmd) -> bool {
+    matches!(
+        bare(cmd),
+        Cmd::Inc | Cmd::Dec | Cmd::Addc(_) | Cmd::Subc(_) | Cmd::Bool | Cmd::Not | Cmd::Neg | Cmd::Clear
+    )
+}
+
+// Commands that replace the top two values with one, without side effects.
+fn is_binary(cmd: &Cmd) -> bool {
+    matches!(
+        bare(cmd),
+        Cmd::Add
+            | Cmd::Sub
+            | Cmd::Mul
+            | Cmd::Eq
+            | Cmd::Neq
+            | Cmd::Lt
+            | Cmd::Le
+            | Cmd::Gt
+            | Cmd::Ge
+            | Cmd::Slt
+            | Cmd::Sle
+            | Cmd::Sgt
+            | Cmd::Sge
+            | Cmd::And
+            | Cmd::Or
+    )
+}
+
+// Applies `f` to every command list nested in `cmd`.
+fn for_each_block(cmd: &mut Cmd, f: &mut impl FnMut(&mut Vec<Cmd>)) {
+    match cmd {
+        Cmd::Stat(body) | Cmd::Scope { body, .. } => f(body),
+        Cmd::IfThen { cond, then_block } => {
+            f(cond);
+            f(then_block);
+        }
+        Cmd::IfThenElse { cond, then_block, else_block } => {
+            f(cond);
+            f(then_block);
+            f(else_block);
+        }
+        Cmd::While { cond, body } => {
+            f(cond);
+            f(body);
+        }
+        Cmd::Switch { scrutinee, arms, default } => {
+            f(scrutinee);
+            for (_, arm) in arms {
+                f(arm);
+            }
+            f(default);
+        }
+        Cmd::At(_, inner) => for_each_block(inner, f),
+        _ => {}
+    }
+}
+
+// The command lists nested directly in `cmd`.
+fn blocks(cmd: &Cmd) -> Vec<&Vec<Cmd>> {
+    match cmd {
+        Cmd::Stat(body) | Cmd::Scope { body, .. } => vec![body],
+        Cmd::IfThen { cond, then_block } => vec![cond, then_block],
+        Cmd::IfThenElse { cond, then_block, else_block } => vec![cond, then_block, else_block],
+        Cmd::While { cond, body } => vec![cond, body],
+        Cmd::Switch { scrutinee, arms, default } => std::iter::once(scrutinee)
+            .chain(arms.iter().map(|(_, arm)| arm))
+            .chain(std::iter::once(default))
+            .collect(),
+        Cmd::At(_, inner) => blocks(inner),
+        _ => vec![],
+    }
+}
+
+// Visits every command in `cmds`, including nested ones.
+fn walk(cmds: &[Cmd], f: &mut impl FnMut(&Cmd)) {
+    for cmd in cmds {
+        f(cmd);
+        for block in blocks(cmd) {
+            walk(block, f);
+        }
+    }
+}
+
+// Whether `cmd` (or anything nested in it) may read variable `name`.
+fn reads(cmd: &Cmd, name: &str) -> bool {
+    let mut found = false;
+    walk(std::slice::from_ref(cmd), &mut |c| {
+        if let Cmd::Get(x) = bare(c) {
+            found |= x == name;
+        }
+    });
+    found
+}
+
+// The variables in scope where a command is compiled: scalars and array lengths. Names that
+// `alloc` or `free` rebind are never in scope here, since whether they resolve depends on which of
+// those commands ran before.
+#[derive(Default, Clone)]
+struct Declared {
+    scalars: HashSet<String>,
+    arrays: HashMap<String, usize>,
+    rebound: HashSet<String>,
+}
+
+impl Declared {
+    // The variables in scope once `lets` are declared, which shadow outer ones of the same name.
+    fn with(&self, lets: &[Let]) -> Declared {
+        let mut declared = self.clone();
+        for var in lets {
+            declared.scalars.remove(&var.name);
+            declared.arrays.remove(&var.name);
+            if declared.rebound.contains(&var.name) {
+                continue;
+            }
+            match var.len {
+                None => {
+                    declared.scalars.insert(var.name.clone());
+                }
+                Some(len) => {
+                    declared.arrays.insert(var.name.clone(), len);
+                }
+            }
+        }
+        declared
+    }
+
+    // Whether removing `cmd` cannot hide a compile error: the names and indexes it uses resolve.
+    fn resolves(&self, cmd: &Cmd) -> bool {
+        match bare(cmd) {
+            Cmd::Get(x) | Cmd::Set(x) => self.scalars.contains(x),
+            Cmd::GetIdx(x, i) | Cmd::SetIdx(x, i) => self.arrays.get(x).is_some_and(|len| i < len),
+            _ => true,
+        }
+    }
+}
+
+// Applies `f` to every command list nested in `cmd`, with the variables in scope there.
+fn for_each_block_in(cmd: &mut Cmd, declared: &Declared, f: &mut impl FnMut(&mut Vec<Cmd>, &Declared)) {
+    match cmd {
+        Cmd::Scope { lets, body } => f(body, &declared.with(lets)),
+        Cmd::At(_, inner) => for_each_block_in(inner, declared, f),
+        cmd => for_each_block(cmd, &mut |block: &mut Vec<Cmd>| f(block, declared)),
+    }
+}
+
+// Removes pure values that are dropped or cleared right after being computed.
+fn drop_unused_values(cmds: &mut Vec<Cmd>, declared: &Declared, removed: &mut Vec<Removal>) {
+    for cmd in cmds.iter_mut() {
+        for_each_block_in(cmd, declared, &mut |block, declared| drop_unused_values(block, declared, removed));
+    }
+    let mut i = 0;
+    while i + 1 < cmds.len() {
+        let (first, second) = (&cmds[i], &cmds[i + 1]);
+        let what = format!("{}; {}", describe(first), describe(second));
+        let span = span_of(first);
+        let replacement = match bare(second) {
+            _ if !declared.resolves(first) => None,
+            Cmd::Drop if is_producer(first) => Some(vec![]),
+            Cmd::Drop if is_unary(first) => Some(vec![second.clone()]),
+            Cmd::Drop if is_binary(first) => Some(vec![second.clone(), second.clone()]),
+            Cmd::Clear if is_producer(first) && !matches!(bare(first), Cmd::Push(0)) => {
+                Some(vec![Cmd::At(span.unwrap_or_default(), Box::new(Cmd::Push(0)))])
+            }
+            Cmd::Clear if is_unary(first) => Some(vec![second.clone()]),
+            _ => None,
+        };
+        match replacement {
+            Some(replacement) => {
+                removed.push(Removal { span, what });
+                cmds.splice(i..i + 2, replacement);
+                // Earlier commands may now pair up with the replacement.
+                i = i.saturating_sub(1);
+            }
+            None => i += 1,
+        }
+    }
+}
+
+// Commands that push one value without reading the stack.
+fn is_fresh_producer(cmd: &Cmd) -> bool {
+    matches!(bare(cmd), Cmd::Push(_) | Cmd::Get(_) | Cmd::GetIdx(..))
+}
+
+// Removes `clear` on a top value that is already zero, and pure values that `poke 1` overwrites
+// with the next one before anything reads them.
+fn drop_redundant_writes(cmds: &mut Vec<Cmd>, declared: &Declared, removed: &mut Vec<Removal>) {
+    for cmd in cmds.iter_mut() {
+        for_each_block_in(cmd, declared, &mut |block, declared| drop_redundant_writes(block, declared, removed));
+    }
+    let mut i = 0;
+    while i + 1 < cmds.len() {
+        let (first, second) = (&cmds[i], &cmds[i + 1]);
+        let third = cmds.get(i + 2).map(bare);
+        let replacement = match bare(second) {
+            Cmd::Clear if matches!(bare(first), Cmd::Push(0) | Cmd::Clear) => {
+                Some((2, format!("clear after {} (already zero)", describe(first)), first.clone()))
+            }
+            _ if is_producer(first)
+                && declared.resolves(first)
+                && is_fresh_producer(second)
+                && matches!(third, Some(Cmd::Poke(1))) =>
+            {
+                Some((3, format!("{} (overwritten by {}; poke 1)", describe(first), describe(second)), second.clone()))
+            }
+            _ => None,
+        };
+        match replacement {
+            Some((len, what, kept)) => {
+                removed.push(Removal { span: span_of(first), what });
+                cmds.splice(i..i + len, [kept]);
+                i = i.saturating_sub(1);
+            }
+            None => i += 1,
+        }
+    }
+}
+
+// Turns `set x` into `drop` where the stored value can never be read.
+fn drop_dead_stores(cmds: &mut [Cmd], declared: &Declared, unread: &HashSet<String>, removed: &mut Vec<Removal>) {
+    for i in 0..cmds.len() {
+        let name = match bare(&cmds[i]) {
+            Cmd::Set(x) if declared.resolves(&cmds[i]) => x.clone(),
+            _ => continue,
+        };
+        // Dead if the variable is never read, or is set again in this block before any read or loop exit.
+        let overwritten = cmds[i + 1..]
+            .iter()
+            .take_while(|cmd| !reads(cmd, &name) && !may_exit_loop(cmd))
+            .any(|cmd| matches!(bare(cmd), Cmd::Set(x) if *x == name));
+        let reason = if unread.contains(&name) {
+            "never read"
+        } else if overwritten {
+            "overwritten"
+        } else {
+            continue;
+        };
+        let span = span_of(&cmds[i]);
+        removed.push(Removal {
+            span,
+            what: format!("set {} ({})", name, reason),
+        });
+        cmds[i] = Cmd::At(span.unwrap_or_default(), Box::new(Cmd::Drop));
+    }
+    for cmd in cmds.iter_mut() {
+        for_each_block_in(cmd, declared, &mut |block, declared| drop_dead_stores(block, declared, unread, removed));
+    }
+}
+
+// Runs dead-code elimination over the program and its procedures.
+pub fn eliminate_dead_code(program: &mut Program) -> Vec<Removal> {
+    let mut scalars: HashSet<String> = program.lets.iter().filter(|var| var.len.is_none()).map(|var| var.name.clone()).collect();
+    let mut read: HashSet<String> = HashSet::new();
+    let mut rebound: HashSet<String> = HashSet::new();
+    for body in std::iter::once(&program.body).chain(program.procs.iter().map(|p| &p.body)) {
+        walk(body, &mut |cmd| match bare(cmd) {
+            Cmd::Get(x) => {
+                read.insert(x.clone());
+            }
+            Cmd::Scope { lets, .. } => scalars.extend(lets.iter().filter(|var| var.len.is_none()).map(|var| var.name.clone())),
+            Cmd::Alloc(x, _) | Cmd::Free(x) => {
+                rebound.insert(x.clone());
+            }
+            _ => {}
+        });
+    }
+    // Scalars declared anywhere that no `get` (in any scope or procedure) refers to.
+    let unread: HashSet<String> = scalars.difference(&read).cloned().collect();
+
+    // The main body sees the program's variables, and a procedure only its parameters.
+    let empty = Declared { rebound, ..Declared::default() };
+    let mut bodies = vec![(&mut program.body, empty.with(&program.lets))];
+    for proc in program.procs.iter_mut() {
+        let mut declared = empty.clone();
+        declared.scalars.extend(proc.params.iter().filter(|param| !empty.rebound.contains(*param)).cloned());
+        bodies.push((&mut proc.body, declared));
+    }
+    let mut removed = Vec::new();
+    for (body, declared) in bodies {
+        drop_dead_stores(body, &declared, &unread, &mut removed);
+        drop_unused_values(body, &declared, &mut removed);
+        drop_redundant_writes(body, &declared, &mut removed);
+    }
+    removed
+}
@@ -0,0 +1,1141 @@
+// Parser for the constructor DSL.
+//
+// A program is a list of `let` declarations followed by procedures and statements:
+//
+//     let a, b, line[16], msg = "Hello\n", squares = [0, 1, 4, 9, 16]
+//     if { push 1 } then { push 5; set a }
+//     if { get a } then { push 'y'; write } else { push 'n'; write }
+//     while { get b } do { get b; dec; set b }
+//     get a
+//     get line[0]; set b
+//     push 3; lookup squares; printnum
+//     heap 16
+//     alloc node 2; push 7; set node[0]; addr node; printnum; free node
+//     push 4; push 9; store heap
+//     print "a + 'A' = "
+//     print msg
+//     push 'A'
+//     add
+//     write
+//     push16 1000; push16 234; add16; print16
+//     pushfx 3.25; pushfx 1.5; mulfx; printfx 2
+//     push32 100000; push32 1; sub32; print32
+//     push -5; push 3; slt; printnum
+//     proc frame square(x) returns 1 { get x; get x; mul }
+//     push 12; call square; printnum
+//     macro inc_by(v, n) { get v; addc n; set v }
+//     inc_by(a, 3)
+//     const WIDTH = 8 * 10
+//     push WIDTH / 2; printnum
+//     switch { get a } { case 1 { print "one" } case 'x' { print "x" } default { print "?" } }
+//     scope { let a, t; push 3; set t; get t; set a; get a; printnum }
+//     example "abc\n" "cba\n"
+//
+// Statements map one-to-one onto `Cmd`; `;` separators are optional. Procedures are declared at
+// the top level with `proc [inline|frame] name(params) [returns n] { body }` (inline by default).
+// `import "file.bfs"` reads the declarations of another file, and `import "std/mem"` those of a
+// standard library module (see `stdlib`). Numeric operands are constant
+// expressions over `const` names, folded while parsing. Macros are expanded on the token stream
+// before their statements are parsed. `example input output` declares a run of the program for
+// `--with-tests`.
+
+use crate::lexer::{tokenize, Span, Token, TokenKind};
+use crate::procedure::{CallConv, Proc};
+use crate::stdlib;
+use crate::verify::Case;
+use crate::{heap, Cmd, Let};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// A parsed DSL program, ready to be passed to `scope`. `--emit ast` and `--from-ast` write and
+// read it as JSON.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Program {
+    #[serde(default)]
+    pub lets: Vec<Let>,
+    #[serde(default)]
+    pub procs: Vec<Proc>,
+    pub body: Vec<Cmd>,
+    #[serde(default)]
+    pub examples: Vec<Case>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ParseError {
+    pub message: String,
+    pub span: Span,
+}
+
+impl ParseError {
+    pub fn new(message: &str, span: Span) -> Self {
+        ParseError {
+            message: message.to_string(),
+            span,
+        }
+    }
+
+    // Formats the error with the offending source line underlined.
+    pub fn render(&self, sources: &SourceMap) -> String {
+        sources.render(self.span, &self.message)
+    }
+}
+
+struct SourceFile {
+    name: String,
+    path: Option<PathBuf>, // Canonical path, used to import each file once.
+    src: String,
+}
+
+// The DSL files read while parsing, indexed by `Span::file`.
+#[derive(Default)]
+pub struct SourceMap {
+    files: Vec<SourceFile>,
+}
+
+impl SourceMap {
+    // Adds a file and returns its index. `name` is the path as given, used in messages.
+    pub fn add(&mut self, name: &str, src: String) -> usize {
+        self.files.push(SourceFile {
+            name: name.to_string(),
+            path: fs::canonicalize(name).ok(),
+            src,
+        });
+        self.files.len() - 1
+    }
+
+    fn contains(&self, path: &Path) -> bool {
+        self.files.iter().any(|f| f.path.as_deref() == Some(path))
+    }
+
+    // The names of the files, in index order.
+    pub fn names(&self) -> Vec<String> {
+        self.files.iter().map(|f| f.name.clone()).collect()
+    }
+
+    // The 1-based line and column of the start of `span`.
+    pub fn line_col(&self, span: Span) -> (usize, usize) {
+        match self.files.get(span.file) {
+            Some(file) => {
+                let start = floor_boundary(&file.src, span.start);
+                let line_start = file.src[..start].rfind('\n').map_or(0, |i| i + 1);
+                let line_no = file.src[..start].matches('\n').count() + 1;
+                (line_no, file.src[line_start..start].chars().count() + 1)
+            }
+            None => (0, 0),
+        }
+    }
+
+    // The source text covered by `span`.
+    pub fn text(&self, span: Span) -> &str {
+        match self.files.get(span.file) {
+            Some(file) => &file.src[floor_boundary(&file.src, span.start)..floor_boundary(&file.src, span.end)],
+            None => "",
+        }
+    }
+
+    // Formats the start of `span` as `file:line:col`.
+    pub fn location(&self, span: Span) -> String {
+        match self.files.get(span.file) {
+            Some(file) => {
+                let (line, col) = self.line_col(span);
+                format!("{}:{}:{}", file.name, line, col)
+            }
+            None => "<unknown>".to_string(),
+        }
+    }
+
+    // Formats `message` with the source line of `span` underlined.
+    pub fn render(&self, span: Span, message: &str) -> String {
+        self.render_as("error", span, message)
+    }
+
+    // Like `render`, labelled with `severity` instead of `error`.
+    pub fn render_as(&self, severity: &str, span: Span, message: &str) -> String {
+        match self.files.get(span.file) {
+            Some(file) => render_excerpt(&file.src, &file.name, span, severity, message),
+            None => format!("{}: {}\n", severity, message),
+        }
+    }
+}
+
+// Rounds `i` down to a char boundary of `src`.
+fn floor_boundary(src: &str, mut i: usize) -> usize {
+    i = i.min(src.len());
+    while !src.is_char_boundary(i) {
+        i -= 1;
+    }
+    i
+}
+
+// Formats `severity: message` followed by a `file:line:col` header and the source line with `span`
+// underlined.
+pub fn render_excerpt(src: &str, filename: &str, span: Span, severity: &str, message: &str) -> String {
+    let start = floor_boundary(src, span.start);
+    let line_start = src[..start].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = src[start..].find('\n').map_or(src.len(), |i| start + i);
+    let line_no = src[..start].matches('\n').count() + 1;
+    let col = src[line_start..start].chars().count() + 1;
+    let line = &src[line_start..line_end];
+    let width = src[start..floor_boundary(src, span.end.clamp(start, line_end))].chars().count().max(1);
+    let gutter = " ".repeat(line_no.to_string().len());
+    format!(
+        "{}: {}\n{}--> {}:{}:{}\n{} |\n{} | {}\n{} | {}{}\n",
+        severity,
+        message,
+        gutter,
+        filename,
+        line_no,
+        col,
+        gutter,
+        line_no,
+        line,
+        gutter,
+        " ".repeat(col - 1),
+        "^".repeat(width),
+    )
+}
+
+// The 8.8 fixed-point value of a decimal such as `3.25`, in 256ths rounded to the nearest, or
+// `None` if it is 256 or more.
+fn fixed_value(text: &str) -> Option<u64> {
+    let (whole, fraction) = text.split_once('.')?;
+    // Digits past the 18th cannot change the rounded value.
+    let fraction = &fraction[..fraction.len().min(18)];
+    let scale = 10u128.pow(fraction.len() as u32);
+    let fraction: u128 = fraction.parse().ok()?;
+    let value = whole.parse::<u128>().ok()? * 256 + (fraction * 512 + scale) / (2 * scale);
+    u16::try_from(value).ok().map(u64::from)
+}
+
+// Width in cells of a multi-cell keyword such as `add16` or `print32`.
+fn wide_width(word: &str) -> usize {
+    if word.ends_with("32") {
+        4
+    } else {
+        2
+    }
+}
+
+struct Parser<'a> {
+    tokens: Vec<Token>,
+    pos: usize,
+    eof: Span,
+    file: usize,
+    consts: HashMap<String, i128>, // Values of the `const` declarations seen so far.
+    macros: HashMap<String, Macro>,
+    procs: Vec<Proc>,
+    examples: Vec<Case>,
+    expansions: usize, // Macro expansions so far; also numbers the hygienic names.
+    sources: &'a mut SourceMap,
+    search_path: &'a [PathBuf], // Directories searched by `import` after the importing file's own.
+}
+
+// A `macro` definition: its body is kept as tokens and spliced in at each use. The sized macros
+// of the standard library instead generate their body from a length passed after the parameters.
+#[derive(Debug, Clone)]
+struct Macro {
+    params: Vec<String>,
+    body: Vec<Token>,
+    sized: Option<fn(usize) -> String>,
+}
+
+// Expansions allowed per program, which stops runaway recursive macros.
+const MAX_EXPANSIONS: usize = 10000;
+
+// Renames the variables declared by `let` in a macro body so they cannot capture names from the call
+// site. Only declarations and `get`/`set`/`readline`/`lookup`/`print` operands are renamed, so keywords stay intact.
+fn hygienic(body: &[Token], params: &[String], suffix: usize) -> Vec<Token> {
+    let mut locals = Vec::new();
+    let mut in_let = false;
+    let mut expect_name = false;
+    let mut brackets = 0; // Commas inside `[...]` separate the values of a table, not names.
+    for token in body {
+        match &token.kind {
+            TokenKind::Ident(word) if word == "let" => {
+                in_let = true;
+                expect_name = true;
+                brackets = 0;
+            }
+            TokenKind::Ident(word) if word == "alloc" && !expect_name => expect_name = true,
+            TokenKind::Ident(name) if expect_name => {
+                if !params.contains(name) {
+                    locals.push(name.clone());
+                }
+                expect_name = false;
+            }
+            TokenKind::LBracket if in_let => brackets += 1,
+            TokenKind::RBracket if in_let => brackets -= 1,
+            TokenKind::Comma if in_let && brackets == 0 => expect_name = true,
+            TokenKind::Semicolon | TokenKind::LBrace | TokenKind::RBrace => in_let = false,
+            TokenKind::Ident(_) if in_let => in_let = false,
+            _ => {}
+        }
+    }
+    let mut out: Vec<Token> = Vec::new();
+    for token in body {
+        let renamed = match (&token.kind, out.last().map(|t| &t.kind)) {
+            (TokenKind::Ident(name), Some(TokenKind::Ident(prev)))
+                if locals.contains(name)
+                    && ["let", "get", "set", "readline", "lookup", "print", "store", "alloc", "free", "addr"].contains(&prev.as_str()) =>
+            {
+                Some(name)
+            }
+            (TokenKind::Ident(name), Some(TokenKind::Comma)) if locals.contains(name) => Some(name),
+            _ => None,
+        };
+        out.push(match renamed {
+            Some(name) => Token {
+                kind: TokenKind::Ident(format!("{}~{}", name, suffix)),
+                span: token.span,
+            },
+            None => token.clone(),
+        });
+    }
+    out
+}
+
+// Parses file `file` of `sources` into a `Program`, reading any files it imports into `sources`.
+pub fn parse(sources: &mut SourceMap, file: usize, search_path: &[PathBuf]) -> Result<Program, ParseError> {
+    let src = &sources.files[file].src;
+    let mut parser = Parser {
+        tokens: tokenize(src, file)?,
+        pos: 0,
+        eof: Span::in_file(file, src.len(), src.len()),
+        file,
+        consts: HashMap::new(),
+        macros: HashMap::new(),
+        procs: Vec::new(),
+        examples: Vec::new(),
+        expansions: 0,
+        sources,
+        search_path,
+    };
+    parser.program()
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    // End offset of the most recently consumed token.
+    fn last_end(&self) -> usize {
+        self.tokens[..self.pos.min(self.tokens.len())]
+            .last()
+            .map_or(0, |t| t.span.end)
+    }
+
+    // Span from offset `start` of the current file to the end of the most recently consumed token.
+    fn span_from(&self, start: usize) -> Span {
+        Span::in_file(self.file, start, self.last_end().max(start))
+    }
+
+    // Span of the next token, or of the end of input.
+    fn here(&self) -> Span {
+        self.peek().map_or(self.eof, |t| t.span)
+    }
+
+    fn at_keyword(&self, word: &str) -> bool {
+        matches!(self.peek(), Some(Token { kind: TokenKind::Ident(name), .. }) if name == word)
+    }
+
+    fn expect(&mut self, kind: TokenKind, what: &str) -> Result<Span, ParseError> {
+        match self.next() {
+            Some(token) if token.kind == kind => Ok(token.span),
+            Some(token) => Err(ParseError::new(
+                &format!("expected {}, found {}", what, token.kind.describe()),
+                token.span,
+            )),
+            None => Err(ParseError::new(&format!("expected {}, found end of input", what), self.eof)),
+        }
+    }
+
+    fn ident(&mut self, what: &str) -> Result<String, ParseError> {
+        match self.next() {
+            Some(Token { kind: TokenKind::Ident(name), .. }) => Ok(name),
+            Some(token) => Err(ParseError::new(
+                &format!("expected {}, found {}", what, token.kind.describe()),
+                token.span,
+            )),
+            None => Err(ParseError::new(&format!("expected {}, found end of input", what), self.eof)),
+        }
+    }
+
+    fn string(&mut self) -> Result<String, ParseError> {
+        match self.next() {
+            Some(Token { kind: TokenKind::Str(value), .. }) => Ok(value),
+            Some(token) => Err(ParseError::new(
+                &format!("expected a string literal, found {}", token.kind.describe()),
+                token.span,
+            )),
+            None => Err(ParseError::new("expected a string literal, found end of input", self.eof)),
+        }
+    }
+
+    // Reads the values of a table, `[v, ...]`.
+    fn table(&mut self) -> Result<Vec<u8>, ParseError> {
+        self.expect(TokenKind::LBracket, "'['")?;
+        let mut values = vec![self.number()? as u8];
+        while matches!(self.peek(), Some(Token { kind: TokenKind::Comma, .. })) {
+            self.next();
+            values.push(self.number()? as u8);
+        }
+        self.expect(TokenKind::RBracket, "',' or ']'")?;
+        Ok(values)
+    }
+
+    // Reads an optional `[index]` suffix.
+    fn index(&mut self) -> Result<Option<usize>, ParseError> {
+        if !matches!(self.peek(), Some(Token { kind: TokenKind::LBracket, .. })) {
+            return Ok(None);
+        }
+        self.next();
+        let index = self.size()?;
+        self.expect(TokenKind::RBracket, "']'")?;
+        Ok(Some(index))
+    }
+
+    // Reads a non-negative constant expression, such as an array length.
+    fn size(&mut self) -> Result<usize, ParseError> {
+        let (value, span) = self.const_expr()?;
+        usize::try_from(value).map_err(|_| ParseError::new("expected a non-negative number", span))
+    }
+
+    // Reads a byte-sized constant expression. Negative values -128..-1 give the two's complement byte.
+    fn number(&mut self) -> Result<usize, ParseError> {
+        let (value, span) = self.const_expr()?;
+        match value {
+            0..=255 => Ok(value as usize),
+            -128..=-1 => Ok((value + 256) as usize),
+            _ => Err(ParseError::new("number out of range (expected -128 to 255)", span)),
+        }
+    }
+
+    // Reads an 8.8 fixed-point number: a decimal such as `3.25`, rounded to the nearest 1/256, or a
+    // constant expression for a whole number.
+    fn fixed_number(&mut self) -> Result<u64, ParseError> {
+        let out_of_range = |span| ParseError::new("number out of range (expected 0 to 255.99)", span);
+        if let Some(Token { kind: TokenKind::Decimal(text), span }) = self.peek().cloned() {
+            self.next();
+            return fixed_value(&text).ok_or_else(|| out_of_range(span));
+        }
+        let (value, span) = self.const_expr()?;
+        match value {
+            0..=255 => Ok(value as u64 * 256),
+            _ => Err(out_of_range(span)),
+        }
+    }
+
+    // Reads a constant expression that fits in `width` cells.
+    fn wide_number(&mut self, width: usize) -> Result<u64, ParseError> {
+        let (value, span) = self.const_expr()?;
+        let max = (1i128 << (8 * width)) - 1;
+        if !(0..=max).contains(&value) {
+            return Err(ParseError::new(&format!("number out of range (expected 0-{})", max), span));
+        }
+        Ok(value as u64)
+    }
+
+    // Parses `macro name(params) { body }`, keeping the body's tokens for later expansion.
+    fn macro_definition(&mut self) -> Result<(), ParseError> {
+        self.next();
+        let span = self.here();
+        let name = self.ident("a macro name")?;
+        if self.macros.contains_key(&name) {
+            return Err(ParseError::new(&format!("macro '{}' is declared twice", name), span));
+        }
+        self.expect(TokenKind::LParen, "'('")?;
+        let mut params: Vec<String> = Vec::new();
+        if !matches!(self.peek(), Some(Token { kind: TokenKind::RParen, .. })) {
+            loop {
+                let span = self.here();
+                let param = self.ident("a parameter name")?;
+                if params.contains(&param) {
+                    return Err(ParseError::new(&format!("parameter '{}' is declared twice", param), span));
+                }
+                params.push(param);
+                if matches!(self.peek(), Some(Token { kind: TokenKind::Comma, .. })) {
+                    self.next();
+                } else {
+                    break;
+                }
+            }
+        }
+        self.expect(TokenKind::RParen, "')'")?;
+        let open = self.expect(TokenKind::LBrace, "'{'")?;
+        let start = self.pos;
+        let mut depth = 1;
+        while depth > 0 {
+            match self.next().map(|t| t.kind) {
+                Some(TokenKind::LBrace) => depth += 1,
+                Some(TokenKind::RBrace) => depth -= 1,
+                Some(_) => {}
+                None => return Err(ParseError::new("unclosed macro body", open)),
+            }
+        }
+        let body = self.tokens[start..self.pos - 1].to_vec();
+        self.macros.insert(name, Macro { params, body, sized: None });
+        Ok(())
+    }
+
+    fn at_macro_call(&self) -> bool {
+        match (self.peek(), self.tokens.get(self.pos + 1)) {
+            (Some(Token { kind: TokenKind::Ident(name), .. }), Some(Token { kind: TokenKind::LParen, .. })) => {
+                self.macros.contains_key(name)
+            }
+            _ => false,
+        }
+    }
+
+    // Replaces a `name(args)` macro use with the macro body, substituting the arguments'
+    // tokens for the parameters. Arguments of more than one token are parenthesized.
+    fn expand_macro(&mut self) -> Result<(), ParseError> {
+        let start = self.pos;
+        let span = self.here();
+        let name = self.ident("a macro name")?;
+        let mac = self.macros[&name].clone();
+        self.expect(TokenKind::LParen, "'('")?;
+        let mut args: Vec<Vec<Token>> = vec![Vec::new()];
+        let mut depth = 0;
+        loop {
+            let token = self
+                .next()
+                .ok_or_else(|| ParseError::new("unclosed macro arguments", span))?;
+            match token.kind {
+                TokenKind::RParen if depth == 0 => break,
+                TokenKind::Comma if depth == 0 => {
+                    args.push(Vec::new());
+                    continue;
+                }
+                TokenKind::LParen => depth += 1,
+                TokenKind::RParen => depth -= 1,
+                _ => {}
+            }
+            args.last_mut().expect("at least one argument").push(token);
+        }
+        if args.len() == 1 && args[0].is_empty() {
+            args.clear();
+        }
+        let arity = mac.params.len() + mac.sized.is_some() as usize;
+        if args.len() != arity {
+            return Err(ParseError::new(
+                &format!("macro '{}' takes {} argument(s), but {} were given", name, arity, args.len()),
+                self.span_from(span.start),
+            ));
+        }
+        let body = match mac.sized {
+            Some(generate) => {
+                let len = self.evaluate(args.pop().expect("the length argument"), span)?;
+                self.generated(&generate(len), self.span_from(span.start))?
+            }
+            None => mac.body,
+        };
+        self.expansions += 1;
+        if self.expansions > MAX_EXPANSIONS {
+            return Err(ParseError::new("too many macro expansions (is a macro recursive?)", span));
+        }
+        let mut expansion = Vec::new();
+        for token in hygienic(&body, &mac.params, self.expansions) {
+            let arg = match &token.kind {
+                TokenKind::Ident(param) => mac.params.iter().position(|p| p == param).map(|i| &args[i]),
+                _ => None,
+            };
+            match arg {
+                Some(arg) if arg.len() == 1 => expansion.push(arg[0].clone()),
+                Some(arg) => {
+                    let paren = |kind| Token { kind, span: token.span };
+                    expansion.push(paren(TokenKind::LParen));
+                    expansion.extend(arg.iter().cloned());
+                    expansion.push(paren(TokenKind::RParen));
+                }
+                None => expansion.push(token),
+            }
+        }
+        self.tokens.splice(start..self.pos, expansion);
+        self.pos = start;
+        Ok(())
+    }
+
+    // Evaluates the tokens of a macro argument as a non-negative constant expression.
+    fn evaluate(&mut self, arg: Vec<Token>, span: Span) -> Result<usize, ParseError> {
+        let rest = std::mem::replace(&mut self.tokens, arg);
+        let pos = std::mem::replace(&mut self.pos, 0);
+        let result = self.size().and_then(|value| match self.peek() {
+            Some(token) => Err(ParseError::new("expected a constant expression", token.span)),
+            None => Ok(value),
+        });
+        self.tokens = rest;
+        self.pos = pos;
+        result.map_err(|e| if e.span == self.eof { ParseError::new(&e.message, span) } else { e })
+    }
+
+    // Tokenizes a generated macro body, attributing its tokens to the macro's use at `span`.
+    fn generated(&self, src: &str, span: Span) -> Result<Vec<Token>, ParseError> {
+        let tokens = tokenize(src, span.file).map_err(|e| ParseError::new(&e.message, span))?;
+        Ok(tokens.into_iter().map(|token| Token { span, ..token }).collect())
+    }
+
+    // Parses `const NAME = expr`, recording the value for later expressions.
+    fn constant(&mut self) -> Result<(), ParseError> {
+        self.next();
+        let span = self.here();
+        let name = self.ident("a constant name")?;
+        if self.consts.contains_key(&name) {
+            return Err(ParseError::new(&format!("constant '{}' is declared twice", name), span));
+        }
+        self.expect(TokenKind::Equals, "'='")?;
+        let (value, _) = self.const_expr()?;
+        self.consts.insert(name, value);
+        Ok(())
+    }
+
+    // Evaluates a constant expression, returning its value and source span.
+    fn const_expr(&mut self) -> Result<(i128, Span), ParseError> {
+        let start = self.here().start;
+        let value = self.sum()?;
+        Ok((value, self.span_from(start)))
+    }
+
+    // Checks an intermediate result, which is kept well within the range of i128.
+    fn folded(&self, value: Option<i128>, start: usize) -> Result<i128, ParseError> {
+        match value {
+            Some(value) if value.abs() <= i64::MAX as i128 => Ok(value),
+            _ => Err(ParseError::new("constant expression overflows", self.span_from(start))),
+        }
+    }
+
+    // sum := product (('+' | '-') product)*
+    fn sum(&mut self) -> Result<i128, ParseError> {
+        let start = self.here().start;
+        let mut value = self.product()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token { kind: TokenKind::Plus, .. }) => TokenKind::Plus,
+                Some(Token { kind: TokenKind::Minus, .. }) => TokenKind::Minus,
+                _ => return Ok(value),
+            };
+            self.next();
+            let rhs = self.product()?;
+            let result = if op == TokenKind::Plus { value.checked_add(rhs) } else { value.checked_sub(rhs) };
+            value = self.folded(result, start)?;
+        }
+    }
+
+    // product := unary (('*' | '/' | '%') unary)*
+    fn product(&mut self) -> Result<i128, ParseError> {
+        let start = self.here().start;
+        let mut value = self.unary()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token { kind: TokenKind::Star, .. }) => TokenKind::Star,
+                Some(Token { kind: TokenKind::Slash, .. }) => TokenKind::Slash,
+                Some(Token { kind: TokenKind::Percent, .. }) => TokenKind::Percent,
+                _ => return Ok(value),
+            };
+            self.next();
+            let rhs_span = self.here();
+            let rhs = self.unary()?;
+            if op != TokenKind::Star && rhs == 0 {
+                return Err(ParseError::new("division by zero in constant expression", rhs_span));
+            }
+            let result = match op {
+                TokenKind::Star => value.checked_mul(rhs),
+                TokenKind::Slash => value.checked_div(rhs),
+                _ => value.checked_rem(rhs),
+            };
+            value = self.folded(result, start)?;
+        }
+    }
+
+    // unary := '-' unary | number | constant | '(' sum ')'
+    fn unary(&mut self) -> Result<i128, ParseError> {
+        match self.next() {
+            Some(Token { kind: TokenKind::Minus, .. }) => Ok(-self.unary()?),
+            Some(Token { kind: TokenKind::Number(n), span }) => self.folded(Some(n as i128), span.start),
+            Some(Token { kind: TokenKind::Ident(name), span }) => match self.consts.get(&name) {
+                Some(&value) => Ok(value),
+                None => Err(ParseError::new(&format!("unknown constant '{}'", name), span)),
+            },
+            Some(Token { kind: TokenKind::LParen, .. }) => {
+                let value = self.sum()?;
+                self.expect(TokenKind::RParen, "')'")?;
+                Ok(value)
+            }
+            Some(token) => Err(ParseError::new(
+                &format!("expected a number, found {}", token.kind.describe()),
+                token.span,
+            )),
+            None => Err(ParseError::new("expected a number, found end of input", self.eof)),
+        }
+    }
+
+    fn program(&mut self) -> Result<Program, ParseError> {
+        self.skip_separators();
+        let lets = self.lets(true)?;
+        let mut body = Vec::new();
+        loop {
+            self.skip_separators();
+            match self.peek() {
+                None => break,
+                Some(Token { kind: TokenKind::RBrace, span }) => {
+                    return Err(ParseError::new("unexpected '}'", *span));
+                }
+                Some(_) => {}
+            }
+            if self.definition()? || self.example()? {
+                continue;
+            }
+            if self.at_macro_call() {
+                self.expand_macro()?;
+            } else {
+                body.push(self.statement()?);
+            }
+        }
+        let procs = std::mem::take(&mut self.procs);
+        Ok(Program { lets, procs, body, examples: std::mem::take(&mut self.examples) })
+    }
+
+    // Parses a top-level `example input output`, if one comes next.
+    fn example(&mut self) -> Result<bool, ParseError> {
+        if !self.at_keyword("example") {
+            return Ok(false);
+        }
+        self.next();
+        let input = self.string()?;
+        let output = self.string()?;
+        self.examples.push(Case { name: None, input, output });
+        Ok(true)
+    }
+
+    // Parses a top-level `const`, `macro`, `proc` or `import` declaration, if one comes next.
+    fn definition(&mut self) -> Result<bool, ParseError> {
+        if self.at_keyword("const") {
+            self.constant()?;
+        } else if self.at_keyword("macro") {
+            self.macro_definition()?;
+        } else if self.at_keyword("proc") {
+            let span = self.here();
+            let proc = self.procedure()?;
+            if self.procs.iter().any(|p| p.name == proc.name) {
+                return Err(ParseError::new(&format!("procedure '{}' is declared twice", proc.name), span));
+            }
+            self.procs.push(proc);
+        } else if self.at_keyword("import") {
+            self.import()?;
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+
+    // Parses `import "file.bfs"`: reads the file's declarations into this program. The path is
+    // resolved against the importing file's directory, then the search path. Each file is read once.
+    fn import(&mut self) -> Result<(), ParseError> {
+        self.next();
+        let span = self.here();
+        let name = self.string()?;
+        if let Some(module) = stdlib::module(&name) {
+            if self.sources.names().contains(&name) {
+                return Ok(());
+            }
+            for sized in module.sized {
+                if self.macros.contains_key(sized.name) {
+                    return Err(ParseError::new(&format!("macro '{}' is declared twice", sized.name), span));
+                }
+                let params = sized.params.iter().map(|p| p.to_string()).collect();
+                self.macros.insert(sized.name.to_string(), Macro { params, body: Vec::new(), sized: Some(sized.body) });
+            }
+            let file = self.sources.add(&name, module.source.to_string());
+            return self.read_library(file);
+        }
+        let importer = Path::new(&self.sources.files[self.file].name);
+        let dirs = importer.parent().into_iter().map(Path::to_path_buf).chain(self.search_path.iter().cloned());
+        let path = dirs
+            .map(|dir| dir.join(&name))
+            .find(|path| path.is_file())
+            .ok_or_else(|| ParseError::new(&format!("cannot find imported file '{}'", name), span))?;
+        let canonical = fs::canonicalize(&path).unwrap_or_else(|_| path.clone());
+        if self.sources.contains(&canonical) {
+            return Ok(());
+        }
+        let src = fs::read_to_string(&path)
+            .map_err(|e| ParseError::new(&format!("cannot read '{}': {}", path.display(), e), span))?;
+        let file = self.sources.add(&path.to_string_lossy(), src);
+        self.read_library(file)
+    }
+
+    // Parses the declarations of imported file `file` into this program.
+    fn read_library(&mut self, file: usize) -> Result<(), ParseError> {
+        let src = &self.sources.files[file].src;
+        let mut library = Parser {
+            tokens: tokenize(src, file)?,
+            pos: 0,
+            eof: Span::in_file(file, src.len(), src.len()),
+            file,
+            consts: std::mem::take(&mut self.consts),
+            macros: std::mem::take(&mut self.macros),
+            procs: std::mem::take(&mut self.procs),
+            examples: Vec::new(),
+            expansions: self.expansions,
+            sources: &mut *self.sources,
+            search_path: self.search_path,
+        };
+        let result = library.library();
+        self.consts = library.consts;
+        self.macros = library.macros;
+        self.procs = library.procs;
+        self.expansions = library.expansions;
+        result
+    }
+
+    // Parses an imported file, which may only contain declarations.
+    fn library(&mut self) -> Result<(), ParseError> {
+        loop {
+            self.skip_separators();
+            if self.peek().is_none() {
+                return Ok(());
+            }
+            if !self.definition()? {
+                return Err(ParseError::new(
+                    "imported files may only contain const, macro, proc and import declarations",
+                    self.here(),
+                ));
+            }
+        }
+    }
+
+    // Parses the `let` declarations at the start of the program or of a scope, along with any
+    // `const` (or, at the top level, other) declarations between them.
+    fn lets(&mut self, top_level: bool) -> Result<Vec<Let>, ParseError> {
+        let mut lets = Vec::new();
+        loop {
+            if self.at_keyword("const") {
+                self.constant()?;
+                self.skip_separators();
+                continue;
+            }
+            if top_level && (self.definition()? || self.example()?) {
+                self.skip_separators();
+                continue;
+            }
+            if top_level && self.at_keyword("heap") {
+                let span = self.here();
+                self.next();
+                if lets.iter().any(|l: &Let| l.name == heap::NAME) {
+                    return Err(ParseError::new("the heap is declared twice", span));
+                }
+                let len_span = self.here();
+                let len = self.size()?;
+                if len == 0 {
+                    return Err(ParseError::new("the heap needs at least 1 cell", len_span));
+                }
+                lets.push(Let::array(heap::NAME, len));
+                self.skip_separators();
+                continue;
+            }
+            if !self.at_keyword("let") {
+                break;
+            }
+            self.next();
+            loop {
+                let span = self.here();
+                let name = self.ident("a variable name")?;
+                if lets.iter().any(|l: &Let| l.name == name) {
+                    return Err(ParseError::new(&format!("variable '{}' is declared twice", name), span));
+                }
+                if matches!(self.peek(), Some(Token { kind: TokenKind::LBracket, .. })) {
+                    self.next();
+                    let len_span = self.here();
+                    let len = self.size()?;
+                    if len == 0 {
+                        return Err(ParseError::new("array length must be at least 1", len_span));
+                    }
+                    self.expect(TokenKind::RBracket, "']'")?;
+                    lets.push(Let::array(&name, len));
+                } else if matches!(self.peek(), Some(Token { kind: TokenKind::Equals, .. })) {
+                    self.next();
+                    if matches!(self.peek(), Some(Token { kind: TokenKind::LBracket, .. })) {
+                        lets.push(Let::table(&name, self.table()?));
+                        if matches!(self.peek(), Some(Token { kind: TokenKind::Comma, .. })) {
+                            self.next();
+                            continue;
+                        }
+                        break;
+                    }
+                    let counted = self.at_keyword("counted");
+                    if counted {
+                        self.next();
+                    }
+                    let text_span = self.here();
+                    let text = self.string()?;
+                    if counted && text.len() > 255 {
+                        return Err(ParseError::new("counted strings can hold at most 255 bytes", text_span));
+                    }
+                    lets.push(Let::string(&name, &text, counted));
+                } else {
+                    lets.push(Let::scalar(&name));
+                }
+                if matches!(self.peek(), Some(Token { kind: TokenKind::Comma, .. })) {
+                    self.next();
+                } else {
+                    break;
+                }
+            }
+            self.skip_separators();
+        }
+        Ok(lets)
+    }
+
+    // Parses `proc [inline|frame] name(params) [returns n] { body }`.
+    fn procedure(&mut self) -> Result<Proc, ParseError> {
+        self.next();
+        let conv = if self.at_keyword("frame") {
+            self.next();
+            CallConv::Frame
+        } else {
+            if self.at_keyword("inline") {
+                self.next();
+            }
+            CallConv::Inline
+        };
+        let name = self.ident("a procedure name")?;
+        self.expect(TokenKind::LParen, "'('")?;
+        let mut params: Vec<String> = Vec::new();
+        if !matches!(self.peek(), Some(Token { kind: TokenKind::RParen, .. })) {
+            loop {
+                let span = self.here();
+                let param = self.ident("a parameter name")?;
+                if params.contains(&param) {
+                    return Err(ParseError::new(&format!("parameter '{}' is declared twice", param), span));
+                }
+                params.push(param);
+                if matches!(self.peek(), Some(Token { kind: TokenKind::Comma, .. })) {
+                    self.next();
+                } else {
+                    break;
+                }
+            }
+        }
+        self.expect(TokenKind::RParen, "')'")?;
+        let returns = if self.at_keyword("returns") {
+            self.next();
+            self.size()?
+        } else {
+            0
+        };
+        let body = self.block()?;
+        Ok(Proc { name, params, returns, conv, body })
+    }
+
+    fn skip_separators(&mut self) {
+        while matches!(self.peek(), Some(Token { kind: TokenKind::Semicolon, .. })) {
+            self.next();
+        }
+    }
+
+    // Parses statements until '}' or end of input.
+    fn statements(&mut self) -> Result<Vec<Cmd>, ParseError> {
+        let mut cmds = Vec::new();
+        loop {
+            self.skip_separators();
+            match self.peek() {
+                None | Some(Token { kind: TokenKind::RBrace, .. }) => return Ok(cmds),
+                Some(_) if self.at_keyword("const") => self.constant()?,
+                Some(_) if self.at_macro_call() => self.expand_macro()?,
+                Some(_) => cmds.push(self.statement()?),
+            }
+        }
+    }
+
+    fn block(&mut self) -> Result<Vec<Cmd>, ParseError> {
+        self.expect(TokenKind::LBrace, "'{'")?;
+        let cmds = self.statements()?;
+        self.expect(TokenKind::RBrace, "'}'")?;
+        Ok(cmds)
+    }
+
+    // Parses one statement and wraps it with its source span.
+    fn statement(&mut self) -> Result<Cmd, ParseError> {
+        let start = self.here().start;
+        let cmd = self.bare_statement()?;
+        Ok(Cmd::At(self.span_from(start), Box::new(cmd)))
+    }
+
+    fn bare_statement(&mut self) -> Result<Cmd, ParseError> {
+        let span = self.here();
+        let word = self.ident("a statement")?;
+        let cmd = match word.as_str() {
+            "clear" => Cmd::Clear,
+            "copy" | "dup" => Cmd::Copy,
+            "swap" => Cmd::Swap,
+            "drop" => Cmd::Drop,
+            "over" => Cmd::Over,
+            "read" => Cmd::Read,
+            "write" => Cmd::Write,
+            "inc" => Cmd::Inc,
+            "dec" => Cmd::Dec,
+            "add" => Cmd::Add,
+            "sub" => Cmd::Sub,
+            "mul" => Cmd::Mul,
+            "bool" => Cmd::Bool,
+            "eq" => Cmd::Eq,
+            "neq" => Cmd::Neq,
+            "lt" => Cmd::Lt,
+            "le" => Cmd::Le,
+            "gt" => Cmd::Gt,
+            "ge" => Cmd::Ge,
+            "and" => Cmd::And,
+            "or" => Cmd::Or,
+            "not" => Cmd::Not,
+            "slt" => Cmd::Slt,
+            "sle" => Cmd::Sle,
+            "sgt" => Cmd::Sgt,
+            "sge" => Cmd::Sge,
+            "neg" => Cmd::Neg,
+            "break" => Cmd::Break,
+            "continue" => Cmd::Continue,
+            "get" | "set" => {
+                let name = self.ident("a variable name")?;
+                match (self.index()?, word.as_str()) {
+                    (Some(index), "get") => Cmd::GetIdx(name, index),
+                    (Some(index), _) => Cmd::SetIdx(name, index),
+                    (None, "get") => Cmd::Get(name),
+                    (None, _) => Cmd::Set(name),
+                }
+            }
+            "pick" => Cmd::Pick(self.size()?),
+            "poke" => Cmd::Poke(self.size()?),
+            "push" => Cmd::Push(self.number()?),
+            "push16" | "push32" => {
+                let width = wide_width(&word);
+                Cmd::PushWide(width, self.wide_number(width)?)
+            }
+            "add16" | "add32" => Cmd::AddWide(wide_width(&word)),
+            "sub16" | "sub32" => Cmd::SubWide(wide_width(&word)),
+            "cmp16" | "cmp32" => Cmd::CmpWide(wide_width(&word)),
+            "print16" | "print32" => Cmd::PrintWide(wide_width(&word)),
+            "pushfx" => Cmd::PushWide(2, self.fixed_number()?),
+            "addfx" => Cmd::AddWide(2),
+            "subfx" => Cmd::SubWide(2),
+            "mulfx" => Cmd::MulFixed,
+            "printfx" => {
+                let span = self.here();
+                match self.size()? {
+                    digits @ 0..=8 => Cmd::PrintFixed(digits),
+                    _ => return Err(ParseError::new("printfx prints at most 8 decimals", span)),
+                }
+            }
+            "addc" => Cmd::Addc(self.number()?),
+            "subc" => Cmd::Subc(self.number()?),
+            "print" => match self.peek() {
+                Some(Token { kind: TokenKind::Ident(_), .. }) => Cmd::PrintVar(self.ident("a string variable")?),
+                _ => Cmd::PrintStr(self.string()?),
+            },
+            "printnum" => Cmd::PrintNum,
+            "sprintnum" => Cmd::PrintSigned,
+            "readnum" => Cmd::ReadNum,
+            "readline" => Cmd::ReadLine(self.ident("an array name")?),
+            "lookup" => Cmd::Lookup(self.ident("an array name")?),
+            "store" => Cmd::Store(self.ident("an array name")?),
+            "alloc" => {
+                let name = self.ident("a block name")?;
+                let span = self.here();
+                match self.size()? {
+                    0 => return Err(ParseError::new("a heap block needs at least 1 cell", span)),
+                    len => Cmd::Alloc(name, len),
+                }
+            }
+            "free" => Cmd::Free(self.ident("a block name")?),
+            "addr" => Cmd::Addr(self.ident("a block name")?),
+            "call" => Cmd::Call(self.ident("a procedure name")?),
+            "assert_stack" => {
+                let (value, span) = self.const_expr()?;
+                Cmd::AssertStack(isize::try_from(value).map_err(|_| ParseError::new("stack height out of range", span))?)
+            }
+            "stat" => Cmd::Stat(self.block()?),
+            "scope" => {
+                self.expect(TokenKind::LBrace, "'{'")?;
+                self.skip_separators();
+                let lets = self.lets(false)?;
+                let body = self.statements()?;
+                self.expect(TokenKind::RBrace, "'}'")?;
+                Cmd::Scope { lets, body }
+            }
+            "if" => {
+                let cond = self.block()?;
+                if !self.at_keyword("then") {
+                    return Err(ParseError::new("expected 'then' after the if condition", self.here()));
+                }
+                self.next();
+                let then_block = self.block()?;
+                if self.at_keyword("else") {
+                    self.next();
+                    let else_block = self.block()?;
+                    Cmd::IfThenElse { cond, then_block, else_block }
+                } else {
+                    Cmd::IfThen { cond, then_block }
+                }
+            }
+            "switch" => {
+                let scrutinee = self.block()?;
+                self.expect(TokenKind::LBrace, "'{'")?;
+                let mut arms: Vec<(u8, Vec<Cmd>)> = Vec::new();
+                let mut default = None;
+                loop {
+                    self.skip_separators();
+                    let case_span = self.here();
+                    if self.at_keyword("case") {
+                        self.next();
+                        let value_span = self.here();
+                        let value = self.number()? as u8;
+                        if arms.iter().any(|(k, _)| *k == value) {
+                            return Err(ParseError::new(&format!("duplicate case {}", value), value_span));
+                        }
+                        arms.push((value, self.block()?));
+                    } else if self.at_keyword("default") {
+                        self.next();
+                        if default.is_some() {
+                            return Err(ParseError::new("a switch can only have one default arm", case_span));
+                        }
+                        default = Some(self.block()?);
+                    } else {
+                        break;
+                    }
+                }
+                self.expect(TokenKind::RBrace, "'}' or another arm")?;
+                Cmd::Switch { scrutinee, arms, default: default.unwrap_or_default() }
+            }
+            "while" => {
+                let cond = self.block()?;
+                if !self.at_keyword("do") {
+                    return Err(ParseError::new("expected 'do' after the while condition", self.here()));
+                }
+                self.next();
+                let body = self.block()?;
+                Cmd::While { cond, body }
+            }
+            "let" => {
+                return Err(ParseError::new(
+                    "'let' declarations must come before any other statement in the program or scope",
+                    span,
+                ))
+            }
+            "proc" => {
+                return Err(ParseError::new("procedures must be declared at the top level", span))
+            }
+            "macro" | "import" => {
+                return Err(ParseError::new(&format!("'{}' is only allowed at the top level", word), span))
+            }
+            _ => return Err(ParseError::new(&format!("unknown statement '{}'", word), span)),
+        };
+        Ok(cmd)
+    }
+}
@@ -0,0 +1,202 @@
+// Peephole optimization of the emitted Brainfuck text.
+//
+// The snippet helpers are written to be correct in isolation, so neighbouring snippets often
+// undo each other's pointer moves or clear cells that are already zero. This pass removes:
+//
+// - adjacent `><`, `<>`, `+-` and `-+` pairs (repeatedly, so `>><<` disappears too),
+// - loops on a cell that is known to be zero, which never run: right after a loop, after a
+//   clear, or before anything has been written to the tape. `[-]` (or `[+]`) is removed silently;
+//   other loops are reported as dead loops, since their code disappears from the output.
+//
+// `/* ... */` comments are copied unchanged, even inside a dead loop, as are source map markers.
+// `#` (the interpreter's dump command, used for the stack height annotations) is kept, and
+// nothing cancels across it, so dumps still show the same pointer position. The commands of
+// Extended Brainfuck Type I are barriers too, and all but `$` (which only reads the cell) may
+// change the current cell.
+
+use crate::lexer::Span;
+use crate::optimize::Removal;
+use crate::sourcemap;
+
+// Why the current cell is known to be zero.
+const AFTER_LOOP: &str = "after a loop";
+const AFTER_CLEAR: &str = "after a clear";
+const AT_START: &str = "before anything is written to the tape";
+
+// Loop text shown in a dead loop's report before the rest is elided.
+const SHOWN: usize = 24;
+
+// Returns the optimized code, the number of commands removed and the dead loops among them,
+// located at the command whose code holds their `[` if the code has source map markers.
+pub fn optimize(code: &str) -> (String, usize, Vec<Removal>) {
+    let chars: Vec<char> = code.chars().collect();
+    let mut out = String::with_capacity(code.len());
+    // Byte offsets in `out` of the moves and additions since the last barrier, with the value of
+    // `zero` before each; the last one may cancel with the next command.
+    let mut pending: Vec<(usize, Option<&str>)> = Vec::new();
+    let mut zero = Some(AT_START); // Why the current cell is known to be zero, if it is.
+    let mut untouched = true; // No cell has been written yet.
+    let mut removed = 0;
+    let mut dead = Vec::new();
+    let mut spans: Vec<Span> = Vec::new(); // The commands whose markers are open, innermost last.
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if let Some((span, len)) = sourcemap::opening(&chars[i..]) {
+            spans.push(span);
+            out.extend(&chars[i..i + len]);
+            i += len;
+            continue;
+        }
+        if c == sourcemap::close() {
+            spans.pop();
+        }
+        if c == '/' && chars.get(i + 1) == Some(&'*') {
+            let end = comment_end(&chars, i);
+            out.extend(&chars[i..end]);
+            i = end;
+            continue;
+        }
+        match c {
+            '>' | '<' | '+' | '-' => {
+                let opposite = match c {
+                    '>' => '<',
+                    '<' => '>',
+                    '+' => '-',
+                    _ => '+',
+                };
+                match pending.last() {
+                    Some(&(at, before)) if out[at..].starts_with(opposite) => {
+                        pending.pop();
+                        out.remove(at);
+                        removed += 2;
+                        // The pair does nothing, so whatever was known before it still holds.
+                        zero = before;
+                    }
+                    _ => {
+                        pending.push((out.len(), zero));
+                        out.push(c);
+                        if c == '>' || c == '<' {
+                            zero = untouched.then_some(AT_START);
+                        } else {
+                            zero = None;
+                            untouched = false;
+                        }
+                    }
+                }
+            }
+            '[' if is_clear(&chars[i..]) => {
+                if zero.is_some() {
+                    removed += 3;
+                } else {
+                    out.push_str("[-]");
+                    zero = Some(AFTER_CLEAR);
+                    pending.clear();
+                }
+                i += 3;
+                continue;
+            }
+            '[' if zero.is_some() => {
+                // Keep what is not code, and the markers balanced.
+                let end = matching(&chars, i);
+                let mut text = String::new();
+                let mut j = i;
+                while j < end {
+                    if let Some((span, len)) = sourcemap::opening(&chars[j..]) {
+                        spans.push(span);
+                        out.extend(&chars[j..j + len]);
+                        j += len;
+                    } else if chars[j] == '/' && chars.get(j + 1) == Some(&'*') {
+                        let comment = comment_end(&chars, j);
+                        out.extend(&chars[j..comment]);
+                        j = comment;
+                    } else {
+                        match chars[j] {
+                            c if is_command(c) => text.push(c),
+                            c => {
+                                if c == sourcemap::close() {
+                                    spans.pop();
+                                }
+                                out.push(c);
+                            }
+                        }
+                        j += 1;
+                    }
+                }
+                removed += text.chars().count();
+                let shown: String = text.chars().take(SHOWN).collect();
+                let ellipsis = if shown.len() < text.len() { "..." } else { "" };
+                let why = zero.unwrap_or(AT_START);
+                let what = format!("a loop that never runs, since its cell is zero {}: {}{}", why, shown, ellipsis);
+                dead.push(Removal { span: spans.last().copied(), what });
+                // The loop did nothing, so moves on either side of it may still cancel.
+                i = end;
+                continue;
+            }
+            '[' | ']' | ',' | '.' | '#' => {
+                out.push(c);
+                pending.clear();
+                match c {
+                    ']' => zero = Some(AFTER_LOOP),
+                    '[' | ',' => {
+                        zero = None;
+                        untouched = false;
+                    }
+                    _ => {}
+                }
+            }
+            '$' => {
+                out.push(c);
+                pending.clear();
+            }
+            '@' | '!' | '{' | '}' | '~' | '^' | '&' | '|' => {
+                out.push(c);
+                pending.clear();
+                zero = None;
+                untouched = false;
+            }
+            _ => out.push(c),
+        }
+        i += 1;
+    }
+    (out, removed, dead)
+}
+
+// Whether `c` is a command of Brainfuck, the interpreter or Extended Brainfuck Type I.
+fn is_command(c: char) -> bool {
+    "<>+-[].,#$@!{}~^&|".contains(c)
+}
+
+// The index just past the comment starting at `start`, or the end of the code if it is unclosed.
+fn comment_end(chars: &[char], start: usize) -> usize {
+    (start + 2..chars.len().saturating_sub(1)).find(|&j| chars[j] == '*' && chars[j + 1] == '/').map_or(chars.len(), |j| j + 2)
+}
+
+// The index just past the `]` matching the `[` at `open`, skipping comments.
+fn matching(chars: &[char], open: usize) -> usize {
+    let mut depth = 0;
+    let mut j = open;
+    while j < chars.len() {
+        match chars[j] {
+            '/' if chars.get(j + 1) == Some(&'*') => {
+                j = comment_end(chars, j);
+                continue;
+            }
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return j + 1;
+                }
+            }
+            _ => {}
+        }
+        j += 1;
+    }
+    chars.len()
+}
+
+// Whether `s` starts with a loop that only clears the current cell.
+fn is_clear(s: &[char]) -> bool {
+    matches!(s, ['[', '-' | '+', ']', ..])
+}
@@ -0,0 +1,326 @@
+// Procedures and their calling conventions.
+//
+// A procedure takes its arguments from the top of the stack and replaces them with its results.
+// Its body can only see its parameters (and the stack above them), so both conventions below
+// compile it the same way and only differ in where the code ends up:
+//
+// - `inline` expands the body at every call site.
+// - `frame` emits the body once. Programs that call frame procedures run inside a dispatcher
+//   loop, and calls and returns are transfers between numbered blocks. Frame layout, from the
+//   bottom of the callee's frame:
+//
+//       arg 1 .. arg n | return block | body's stack ... | 0 (t) | next block (pc)
+//
+//   The `t` and `pc` cells always sit just above the stack top: a block ends by writing the
+//   number of the next block into `pc` and leaving the pointer on `t`. On return the arguments
+//   and the return cell are replaced by the results. Block 0 halts the program.
+//
+// Frame calls split the surrounding code into blocks, so they must appear directly in the main
+// program or a frame procedure body, not inside `stat`, `if` or `while` blocks.
+
+use crate::error::CompileError;
+use crate::layout::Kind;
+use crate::lexer::Span;
+use crate::temps::Temps;
+use crate::{append_code, block_comment, make_indent, process_cmd_list, replicate, Cmd, CompilerState, NOT_CODE};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CallConv {
+    Inline,
+    Frame,
+}
+
+// A procedure declared with `proc`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Proc {
+    pub name: String,
+    #[serde(default)]
+    pub params: Vec<String>,
+    #[serde(default)]
+    pub returns: usize,
+    pub conv: CallConv,
+    pub body: Vec<Cmd>,
+}
+
+// Moves the pointer from cell `from` to cell `to`.
+fn goto(from: isize, to: isize) -> String {
+    if to >= from {
+        replicate((to - from) as usize, ">")
+    } else {
+        replicate((from - to) as usize, "<")
+    }
+}
+
+// Moves the value at `src` (where the pointer is) onto the zero cell `dst`.
+fn transfer(src: isize, dst: isize) -> String {
+    format!("[-{}+{}]", goto(src, dst), goto(dst, src))
+}
+
+// Replaces `n` arguments starting at cell `base` with the `m` results above them, leaving the
+// pointer on the new top. With `ret` set, a return cell sits between the arguments and the
+// results; its value is moved into the `pc` cell of the new top and the pointer is left on `t`.
+fn epilogue(base: isize, n: usize, m: usize, ret: bool) -> String {
+    let (n, m) = (n as isize, m as isize);
+    let results = base + n + ret as isize;
+    let top = results + m - 1;
+    let mut code = String::new();
+    let mut at = top;
+    for i in 0..n {
+        code += &goto(at, base + i);
+        code += "[-]";
+        at = base + i;
+    }
+    if ret {
+        code += &goto(at, base + n);
+        code += &transfer(base + n, top + 2);
+        at = base + n;
+    }
+    for i in 0..m {
+        code += &goto(at, results + i);
+        code += &transfer(results + i, base + i);
+        at = results + i;
+    }
+    if ret {
+        code += &goto(at, top + 2);
+        code += &transfer(top + 2, base + m + 1);
+        at = top + 2;
+        code += &goto(at, base + m);
+    } else {
+        code += &goto(at, base + m - 1);
+    }
+    code
+}
+
+// Looks up a procedure called by `call`.
+fn lookup<'a>(state: &'a CompilerState, name: &str) -> Result<&'a Proc, CompileError> {
+    state
+        .procs
+        .get(name)
+        .ok_or_else(|| CompileError::UndefinedProcedure { name: name.to_string() })
+}
+
+// Checks that the stack holds the procedure's arguments and returns the cell of the first one.
+fn args_base(state: &CompilerState, proc: &Proc) -> Result<usize, CompileError> {
+    if state.next_cell < state.floor + proc.params.len() {
+        return Err(CompileError::StackUnderflow { cmd: format!("call {}", proc.name) });
+    }
+    Ok(state.next_cell + 1 - proc.params.len())
+}
+
+// A state for compiling a procedure body: only the parameters, stored from cell `base`, are visible,
+// and the body cannot pop them.
+fn body_state(state: &CompilerState, proc: &Proc, base: usize, next_cell: usize) -> CompilerState {
+    let mut body = state.detached_child(next_cell);
+    body.floor = next_cell;
+    body.env = proc
+        .params
+        .iter()
+        .enumerate()
+        .map(|(i, name)| (name.clone(), base + i))
+        .collect();
+    body.arrays = HashMap::new();
+    body.temps = Temps::default();
+    body.inlining.push(proc.name.clone());
+    body
+}
+
+// Records the cells of the procedure's parameters, stored from cell `base`.
+fn own_params(state: &CompilerState, proc: &Proc, base: usize) {
+    for (i, param) in proc.params.iter().enumerate() {
+        state.own(base + i, 1, &format!("{}.{}", proc.name, param), Kind::Parameter);
+    }
+}
+
+// Compiles `call name` outside of the dispatcher, which is only possible for inline procedures.
+pub fn call(state: CompilerState, name: &str, indent: usize, indentsize: usize) -> Result<CompilerState, CompileError> {
+    let proc = lookup(&state, name)?.clone();
+    if proc.conv == CallConv::Frame {
+        return Err(CompileError::NestedFrameCall { name: name.to_string() });
+    }
+    if state.inlining.contains(&proc.name) {
+        return Err(CompileError::RecursiveInline { name: name.to_string() });
+    }
+    let base = args_base(&state, &proc)?;
+    own_params(&state, &proc, base);
+    let body = process_cmd_list(body_state(&state, &proc, base, state.next_cell), &proc.body, indent + 1, indentsize)?;
+    crate::check_block(
+        &format!("procedure '{}'", name),
+        &proc.body,
+        state.next_cell,
+        &body,
+        proc.returns as isize,
+    )?;
+    let code_str = format!(
+        "\n{}{}{}   {}",
+        body.code,
+        block_comment("return", indent, indentsize),
+        make_indent(indent, indentsize),
+        epilogue(base as isize, proc.params.len(), proc.returns, false)
+    );
+    let delta = proc.returns as isize - proc.params.len() as isize;
+    append_code(state, &format!("call {}", name), &code_str, delta, indent, indentsize)
+}
+
+// Whether a command list calls a frame procedure at its top level.
+pub fn calls_frame(state: &CompilerState, cmds: &[Cmd]) -> bool {
+    cmds.iter().any(|cmd| frame_call(state, cmd).is_some())
+}
+
+// The frame procedure called by `cmd`, with the call's source span if it has one.
+fn frame_call(state: &CompilerState, cmd: &Cmd) -> Option<(String, Option<Span>)> {
+    match cmd {
+        Cmd::Call(name) => match state.procs.get(name) {
+            Some(proc) if proc.conv == CallConv::Frame => Some((name.clone(), None)),
+            _ => None,
+        },
+        Cmd::At(span, inner) => frame_call(state, inner).map(|(name, _)| (name, Some(*span))),
+        _ => None,
+    }
+}
+
+// How a block list ends once its last block has run.
+enum Tail {
+    Halt,
+    Return { name: String, params: usize, returns: usize },
+}
+
+// Collects the dispatcher's blocks; block numbers are stored in a single cell.
+struct Dispatcher {
+    cases: String,
+    next_id: usize,
+    entries: HashMap<String, usize>,
+    pending: Vec<String>,
+}
+
+impl Dispatcher {
+    fn fresh_id(&mut self) -> Result<usize, CompileError> {
+        let id = self.next_id;
+        if id > 255 {
+            return Err(CompileError::TooManyBlocks);
+        }
+        self.next_id += 1;
+        Ok(id)
+    }
+
+    // Returns the entry block of a frame procedure, queueing its body the first time it is called.
+    fn entry(&mut self, name: &str) -> Result<usize, CompileError> {
+        if let Some(&id) = self.entries.get(name) {
+            return Ok(id);
+        }
+        let id = self.fresh_id()?;
+        self.entries.insert(name.to_string(), id);
+        self.pending.push(name.to_string());
+        Ok(id)
+    }
+
+    // Splits `cmds` at its frame calls and emits one case per piece, starting with block `id`.
+    fn lower(
+        &mut self,
+        mut state: CompilerState,
+        cmds: &[Cmd],
+        mut id: usize,
+        tail: Tail,
+        indent: usize,
+        indentsize: usize,
+    ) -> Result<CompilerState, CompileError> {
+        let mut rest = cmds;
+        loop {
+            let split = rest.iter().position(|cmd| frame_call(&state, cmd).is_some());
+            let (segment, call) = match split {
+                Some(i) => (&rest[..i], Some(&rest[i])),
+                None => (rest, None),
+            };
+            // A block continues the statement list before it, so `assert_stack` counts from the same base.
+            let continued = CompilerState {
+                base: state.base,
+                depth: state.depth,
+                heights: std::mem::take(&mut state.heights),
+                ..state.child(state.next_cell)
+            };
+            let mut block = process_cmd_list(continued, segment, indent + 2, indentsize)?;
+            let (label, transition) = match call {
+                Some(cmd) => {
+                    let (name, span) = frame_call(&state, cmd).expect("split at a frame call");
+                    let located = |e: CompileError| match span {
+                        Some(span) => e.at(span),
+                        None => e,
+                    };
+                    let proc = lookup(&block, &name)?.clone();
+                    args_base(&block, &proc).map_err(located)?;
+                    let ret = self.fresh_id().map_err(located)?;
+                    let entry = self.entry(&name).map_err(located)?;
+                    let code = format!(">{}>>{}<", replicate(ret, "+"), replicate(entry, "+"));
+                    block.next_cell = block.next_cell + proc.returns - proc.params.len();
+                    block.heights.push(block.next_cell);
+                    (format!("call {}", name), (code, Some(ret)))
+                }
+                None => match tail {
+                    Tail::Halt => ("halt".to_string(), (">".to_string(), None)),
+                    Tail::Return { ref name, params, returns } => {
+                        let what = format!("procedure '{}'", name);
+                        crate::check_block(&what, cmds, params, &block, returns as isize)?;
+                        ("return".to_string(), (epilogue(0, params, returns, true), None))
+                    }
+                },
+            };
+            self.cases += &format!(
+                "{} >{}{}{}[<<+>>-]<<[[-]>[-]<<\n{}{}{}   {}]\n",
+                block_comment(&format!("block {}", id), indent + 1, indentsize),
+                crate::COPY_CODE,
+                replicate(id, "-"),
+                NOT_CODE,
+                block.code,
+                block_comment(&label, indent + 1, indentsize),
+                make_indent(indent + 1, indentsize),
+                transition.0
+            );
+            state = block;
+            match (call, transition.1) {
+                (Some(_), Some(ret)) => {
+                    id = ret;
+                    rest = &rest[split.map_or(rest.len(), |i| i + 1)..];
+                }
+                _ => return Ok(state),
+            }
+        }
+    }
+}
+
+// Compiles the main program as a dispatcher loop so that frame procedures can be shared between
+// call sites. The pointer starts and ends on the stack top.
+pub fn dispatch(state: CompilerState, cmds: &[Cmd], indent: usize, indentsize: usize) -> Result<CompilerState, CompileError> {
+    // Each case tests `pc - id` for zero, which runs below zero for the cases before `pc`.
+    if !state.config.target.wrap {
+        return Err(CompileError::NeedsWrap { what: "frame procedures".to_string() });
+    }
+    let mut dispatcher = Dispatcher {
+        cases: String::new(),
+        next_id: 1,
+        entries: HashMap::new(),
+        pending: Vec::new(),
+    };
+    let first = dispatcher.fresh_id()?;
+    let end = dispatcher.lower(state.child(state.next_cell), cmds, first, Tail::Halt, indent, indentsize)?;
+    while let Some(name) = dispatcher.pending.pop() {
+        let proc = lookup(&state, &name)?.clone();
+        let n = proc.params.len();
+        let body = body_state(&state, &proc, 0, n);
+        state.layout.borrow_mut().set_frame(Some(&name));
+        own_params(&body, &proc, 0);
+        body.own(n, 1, &format!("{} return block", name), Kind::Temporary);
+        let tail = Tail::Return { name: name.clone(), params: n, returns: proc.returns };
+        let entry = dispatcher.entries[&name];
+        dispatcher.lower(body, &proc.body, entry, tail, indent, indentsize)?;
+    }
+    state.layout.borrow_mut().set_frame(None);
+    let code_str = format!(
+        ">>+[<\n{}{}{}   >]<<",
+        dispatcher.cases,
+        block_comment("end dispatch", indent, indentsize),
+        make_indent(indent, indentsize)
+    );
+    let delta = end.next_cell as isize - state.next_cell as isize;
+    append_code(state, "dispatch", &code_str, delta, indent, indentsize)
+}
@@ -0,0 +1,107 @@
+// Python bindings, built with the `python` feature into an extension module named `brainfucktool`:
+//
+//     import brainfucktool
+//     brainfucktool.run("++++++++[>++++++++<-]>+.", input=b"", cells=30000, cell_size=8)  # b'A'
+//
+//     m = brainfucktool.Machine(",[.,]", input=b"hi")
+//     while m.step():
+//         print(m.position, m.pointer, m.tape[:4])
+//
+// `run` returns what the program printed, and `Machine` runs one op at a time so a notebook can
+// show the tape as it changes; `machine` describes how programs run. A pointer that leaves the
+// tape raises `RuntimeError`.
+
+// The code pyo3's macros generate converts errors that already have the right type.
+#![allow(clippy::useless_conversion)]
+
+use crate::machine;
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+/// Runs `source` on `input` and returns the bytes it printed.
+#[pyfunction]
+#[pyo3(signature = (source, input = b"".to_vec(), cells = 30000, cell_size = 8, max_steps = None))]
+fn run<'py>(
+    py: Python<'py>,
+    source: &str,
+    input: Vec<u8>,
+    cells: usize,
+    cell_size: u32,
+    max_steps: Option<u64>,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let mut machine = Machine::new(source, input, cells, cell_size)?;
+    machine.run(max_steps)?;
+    Ok(machine.output(py))
+}
+
+/// A program being run one op at a time.
+#[pyclass]
+pub struct Machine(machine::Machine);
+
+#[pymethods]
+impl Machine {
+    #[new]
+    #[pyo3(signature = (source, input = b"".to_vec(), cells = 30000, cell_size = 8))]
+    fn new(source: &str, input: Vec<u8>, cells: usize, cell_size: u32) -> PyResult<Machine> {
+        machine::Machine::new(source, input, cells, cell_size).map(Machine).map_err(PyValueError::new_err)
+    }
+
+    /// Runs the next op. Returns `False`, doing nothing, once the program has ended.
+    fn step(&mut self) -> PyResult<bool> {
+        self.0.step().map_err(PyRuntimeError::new_err)
+    }
+
+    /// Runs until the program ends, or raises `RuntimeError` after `max_steps` more ops.
+    #[pyo3(signature = (max_steps = None))]
+    fn run(&mut self, max_steps: Option<u64>) -> PyResult<()> {
+        self.0.run(max_steps).map_err(PyRuntimeError::new_err)
+    }
+
+    /// Whether the program has ended.
+    #[getter]
+    fn finished(&self) -> bool {
+        self.0.finished()
+    }
+
+    /// The byte offset in the source of the next op, or `None` once the program has ended.
+    #[getter]
+    fn position(&self) -> Option<usize> {
+        self.0.position()
+    }
+
+    /// The cells of the tape.
+    #[getter]
+    fn tape(&self) -> Vec<u64> {
+        self.0.tape().to_vec()
+    }
+
+    #[getter]
+    fn pointer(&self) -> usize {
+        self.0.pointer()
+    }
+
+    /// The index of the next op.
+    #[getter]
+    fn pc(&self) -> usize {
+        self.0.pc()
+    }
+
+    #[getter]
+    fn steps(&self) -> u64 {
+        self.0.steps()
+    }
+
+    /// What the program has printed so far.
+    #[getter]
+    fn output<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new_bound(py, self.0.output())
+    }
+}
+
+#[pymodule]
+fn brainfucktool(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(run, module)?)?;
+    module.add_class::<Machine>()?;
+    Ok(())
+}
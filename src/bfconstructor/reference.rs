@@ -0,0 +1,111 @@
+// A deliberately simple Brainfuck interpreter, to check the optimized ones against.
+//
+// It runs the source text one character at a time, with nothing folded or lowered, so there is
+// little in it that can be wrong. `selftest` runs a program on it and on the interpreter of the
+// folded ops (`verify::execute`) with the same input and reports the first difference in the
+// output or the final tape; `bfir --selftest` does the same for bfir's own interpreter.
+//
+// Cells are 8-bit and wrap, the tape has a fixed number of cells and `,` gives 0 at the end of
+// the input.
+
+use crate::ir::{self, Syntax};
+use crate::verify;
+use crate::Target;
+
+// Steps a run may take before it is considered stuck.
+const MAX_STEPS: u64 = 10_000_000_000;
+// The cells of `selftest`'s runs, as `verify::execute` has.
+const CELLS: usize = 30000;
+
+/// The state a program stopped in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub output: Vec<u8>,
+    pub tape: Vec<u8>,
+    pub ptr: usize,
+}
+
+/// Runs Brainfuck text `src` on `input` with `cells` cells until it ends.
+pub fn run(src: &str, input: &[u8], cells: usize) -> Result<Run, String> {
+    run_within(src, input, cells, MAX_STEPS)
+}
+
+/// Like `run`, but fails after `max_steps` characters have run.
+pub fn run_within(src: &str, input: &[u8], cells: usize, max_steps: u64) -> Result<Run, String> {
+    if cells == 0 {
+        return Err("the tape has no cells".to_string());
+    }
+    let code = src.as_bytes();
+    // The index of the matching bracket of each bracket.
+    let mut jump = vec![0; code.len()];
+    let mut open = Vec::new();
+    for (i, &c) in code.iter().enumerate() {
+        match c {
+            b'[' => open.push(i),
+            b']' => {
+                let j = open.pop().ok_or("unbalanced brackets: extra ']'")?;
+                (jump[i], jump[j]) = (j, i);
+            }
+            _ => {}
+        }
+    }
+    if !open.is_empty() {
+        return Err("unbalanced brackets: missing ']'".to_string());
+    }
+    let mut tape = vec![0u8; cells];
+    let (mut ptr, mut pc, mut steps) = (0usize, 0usize, 0u64);
+    let mut input = input.iter();
+    let mut output = Vec::new();
+    while pc < code.len() {
+        steps += 1;
+        if steps > max_steps {
+            return Err(format!("no result after {} steps", max_steps));
+        }
+        match code[pc] {
+            b'+' => tape[ptr] = tape[ptr].wrapping_add(1),
+            b'-' => tape[ptr] = tape[ptr].wrapping_sub(1),
+            b'>' if ptr + 1 == cells => return Err("pointer moved past the last cell".to_string()),
+            b'>' => ptr += 1,
+            b'<' if ptr == 0 => return Err("pointer moved left of cell 0".to_string()),
+            b'<' => ptr -= 1,
+            b'.' => output.push(tape[ptr]),
+            b',' => tape[ptr] = input.next().copied().unwrap_or(0),
+            b'[' if tape[ptr] == 0 => pc = jump[pc],
+            b']' if tape[ptr] != 0 => pc = jump[pc],
+            _ => {}
+        }
+        pc += 1;
+    }
+    Ok(Run { output, tape, ptr })
+}
+
+/// Runs `src` on `input` with the reference interpreter and the interpreter of the folded ops,
+/// and describes the first way they disagree.
+pub fn selftest(src: &str, input: &[u8]) -> Result<(), String> {
+    let expected = run(src, input, CELLS).map_err(|e| format!("reference: {}", e))?;
+    let program = ir::parse(src, Syntax::default())?;
+    let actual = verify::execute(&program, input, Target::default()).map_err(|e| format!("optimized: {}", e))?;
+    let tape: Vec<u8> = actual.tape.iter().map(|&cell| cell as u8).collect();
+    compare(&expected, &Run { output: actual.output, tape, ptr: actual.ptr })
+}
+
+/// Describes the first way `actual` differs from `expected`, the run of the reference interpreter.
+pub fn compare(expected: &Run, actual: &Run) -> Result<(), String> {
+    if let Some(i) = first_difference(&expected.output, &actual.output) {
+        let byte = |run: &Run| run.output.get(i).map_or("nothing".to_string(), |b| format!("{:#04x}", b));
+        return Err(format!("output byte {} is {}, but the reference printed {}", i, byte(actual), byte(expected)));
+    }
+    if let Some(i) = first_difference(&expected.tape, &actual.tape) {
+        let cell = |run: &Run| run.tape.get(i).map_or("missing".to_string(), u8::to_string);
+        return Err(format!("cell {} ends as {}, but as {} in the reference", i, cell(actual), cell(expected)));
+    }
+    if expected.ptr != actual.ptr {
+        return Err(format!("the pointer ends at {}, but at {} in the reference", actual.ptr, expected.ptr));
+    }
+    Ok(())
+}
+
+// The first index where `a` and `b` differ, including where one ends before the other.
+fn first_difference(a: &[u8], b: &[u8]) -> Option<usize> {
+    (0..a.len().max(b.len())).find(|&i| a.get(i) != b.get(i))
+}
@@ -0,0 +1,225 @@
+// `--repl`: compiles DSL statements one at a time.
+//
+// Every entry is added to the session program, which is recompiled from scratch; entries that do
+// not compile are reported and left out. `let` entries join the declarations at the start of the
+// program, `proc`, `const`, `macro` and `import` entries the definitions before them, and
+// anything else the body. After each entry the Brainfuck lines it added are printed, and unless
+// running is switched off, the whole program is run with the interpreter to show the new output,
+// the stack and the top-level variables. An entry with unclosed braces continues on the next line.
+//
+//     :run on|off     run the program after each entry (on by default)
+//     :input TEXT     bytes fed to `,` on every run; `\n` stands for a newline
+//     :show           print the session program
+//     :undo           remove the last entry
+//     :reset          start a new session
+//     :quit           end the session (as does the end of input)
+
+use crate::ir::{self, Syntax};
+use crate::parser::{self, Program, SourceMap};
+use crate::{scope, verify, Config};
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Definitions,
+    Lets,
+    Body,
+}
+
+// Where an entry goes in the session program.
+fn section(entry: &str) -> Section {
+    match entry.split_whitespace().next() {
+        Some("let") => Section::Lets,
+        Some("proc" | "const" | "macro" | "import") => Section::Definitions,
+        _ => Section::Body,
+    }
+}
+
+#[derive(Default)]
+struct Session {
+    entries: Vec<(Section, String)>,
+    code: String,
+    output: Vec<u8>,
+}
+
+impl Session {
+    // The session program with `extra` added, as DSL source.
+    fn source(&self, extra: Option<&(Section, String)>) -> String {
+        let entries: Vec<&(Section, String)> = self.entries.iter().chain(extra).collect();
+        let mut src = String::new();
+        for part in [Section::Definitions, Section::Lets, Section::Body] {
+            for (_, entry) in entries.iter().filter(|(s, _)| *s == part) {
+                src += entry;
+                src.push('\n');
+            }
+        }
+        src
+    }
+}
+
+// Whether `text` opens more braces than it closes, outside of literals and comments.
+fn unclosed(text: &str) -> bool {
+    let mut depth = 0;
+    let mut quote = None;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (Some(_), '\\') => {
+                chars.next();
+            }
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '/') if chars.peek() == Some(&'/') => {
+                chars.by_ref().find(|&c| c == '\n');
+            }
+            (None, '{') => depth += 1,
+            (None, '}') => depth -= 1,
+            _ => {}
+        }
+    }
+    depth > 0
+}
+
+// The lines of `new` that are not in `old`, found by trimming the lines both share at each end.
+fn added_lines<'a>(old: &str, new: &'a str) -> Vec<&'a str> {
+    let (old, new): (Vec<&str>, Vec<&str>) = (old.lines().collect(), new.lines().collect());
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    new[prefix..new.len() - suffix].to_vec()
+}
+
+fn unescape(text: &str) -> Vec<u8> {
+    text.replace("\\n", "\n").replace("\\t", "\t").into_bytes()
+}
+
+// Runs the session until `:quit` or the end of `input`, writing prompts and results to `out`.
+pub fn run(input: impl BufRead, out: &mut impl Write, config: Config, include: &[PathBuf]) -> io::Result<()> {
+    let mut session = Session::default();
+    let mut running = true;
+    let mut stdin: Vec<u8> = Vec::new();
+    let mut pending = String::new();
+    let mut lines = input.lines();
+    loop {
+        write!(out, "{}", if pending.is_empty() { "bf> " } else { "..> " })?;
+        out.flush()?;
+        let Some(line) = lines.next() else { break };
+        let line = line?;
+        if pending.is_empty() {
+            let command = line.trim();
+            let (name, arg) = command.split_once(' ').unwrap_or((command, ""));
+            match name {
+                "" => continue,
+                ":quit" => break,
+                ":run" => {
+                    running = arg.trim() != "off";
+                    continue;
+                }
+                ":input" => {
+                    stdin = unescape(arg);
+                    session.output.clear();
+                    continue;
+                }
+                ":show" => {
+                    write!(out, "{}", session.source(None))?;
+                    continue;
+                }
+                ":reset" => {
+                    session = Session::default();
+                    continue;
+                }
+                ":undo" => {
+                    session.entries.pop();
+                    let program = compile(&session.source(None), config, include).ok();
+                    session.code = program.map(|(_, code)| code).unwrap_or_default();
+                    session.output.clear();
+                    continue;
+                }
+                name if name.starts_with(':') => {
+                    writeln!(out, "error: unknown command '{}'", name)?;
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        pending += &line;
+        pending.push('\n');
+        if unclosed(&pending) {
+            continue;
+        }
+        let entry = std::mem::take(&mut pending);
+        let entry = (section(&entry), entry.trim_end().to_string());
+        let (program, code) = match compile(&session.source(Some(&entry)), config, include) {
+            Ok(compiled) => compiled,
+            Err(message) => {
+                write!(out, "{}", message)?;
+                continue;
+            }
+        };
+        for line in added_lines(&session.code, &code) {
+            writeln!(out, "{}", line)?;
+        }
+        session.entries.push(entry);
+        session.code = code;
+        if running {
+            report(out, &mut session, &program, config, &stdin)?;
+        }
+    }
+    writeln!(out)?;
+    Ok(())
+}
+
+// Parses and compiles the session source, returning the program and its commented code.
+fn compile(src: &str, config: Config, include: &[PathBuf]) -> Result<(Program, String), String> {
+    let mut sources = SourceMap::default();
+    let file = sources.add("<repl>", src.to_string());
+    let program = parser::parse(&mut sources, file, include).map_err(|e| e.render(&sources))?;
+    match scope(&program.lets, &program.procs, config, &program.body, 0, 4) {
+        Ok(state) => Ok((program, state.code)),
+        Err(e) => Err(match e.span() {
+            Some(span) => sources.render(span, &e.to_string()),
+            None => format!("error: {}\n", e),
+        }),
+    }
+}
+
+// Runs the session program and prints the output it added, the stack and the variables.
+fn report(out: &mut impl Write, session: &mut Session, program: &Program, config: Config, stdin: &[u8]) -> io::Result<()> {
+    let ops = ir::parse(&session.code, Syntax::default()).expect("generated code has balanced brackets");
+    let run = match verify::execute(&ops, stdin, config.target) {
+        Ok(run) => run,
+        Err(e) => return writeln!(out, "run: {}", e),
+    };
+    let new = match run.output.strip_prefix(session.output.as_slice()) {
+        Some(new) => new,
+        None => &run.output,
+    };
+    if !new.is_empty() {
+        writeln!(out, "output: {:?}", String::from_utf8_lossy(new))?;
+    }
+    session.output = run.output.clone();
+    // Variables fill the cells from 0 up; the stack starts above the cell after them.
+    let mut cell = 0;
+    let mut vars = Vec::new();
+    for var in &program.lets {
+        let len = var.len.unwrap_or(1);
+        let values = &run.tape[cell..cell + len];
+        vars.push(match var.len {
+            None => format!("{}={}", var.name, values[0]),
+            Some(_) => format!("{}={:?}", var.name, values),
+        });
+        cell += len;
+    }
+    let stack = run.tape.get(cell + 1..=run.ptr).unwrap_or_default();
+    writeln!(out, "stack: {:?}", stack)?;
+    if !vars.is_empty() {
+        writeln!(out, "vars: {}", vars.join(" "))?;
+    }
+    Ok(())
+}
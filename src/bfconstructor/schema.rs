@@ -0,0 +1,71 @@
+// The JSON the tools write and read, so that external tools need to know only one schema.
+//
+// Each document is an object with a `kind` naming what it holds and the `version` of its schema,
+// next to the fields of the value itself:
+//
+//     {"kind": "program", "version": 1, "lets": [...], "procs": [...], "body": [...]}   --emit ast
+//     {"kind": "ir", "version": 1, "ops": [{"op": {"Add": 3}, "pos": 0}, ...]}          --emit ir-json
+//     {"kind": "machine", "version": 1, "tape": [...], "pointer": 0, "pc": 4, ...}      machine::MachineState
+//     {"kind": "run", "version": 1, "program": "...", "output": [...], ...}             bfir --save-run
+//
+// `version` goes up when a change to a schema would make older readers misread a document.
+// Documents without `kind` and `version`, as `--emit ast` wrote before they were added, are read
+// as the current version.
+
+use crate::ir::Inst;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+pub const VERSION: u32 = 1;
+
+/// A value the tools write as JSON.
+pub trait Document: Serialize + DeserializeOwned {
+    /// The `kind` of its documents.
+    const KIND: &'static str;
+}
+
+impl Document for crate::parser::Program {
+    const KIND: &'static str = "program";
+}
+
+/// The optimized ops of a program, as `ir::parse` gives them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Ir {
+    pub ops: Vec<Inst>,
+}
+
+impl Document for Ir {
+    const KIND: &'static str = "ir";
+}
+
+#[derive(Serialize)]
+struct Write<'a, T> {
+    kind: &'static str,
+    version: u32,
+    #[serde(flatten)]
+    value: &'a T,
+}
+
+// The fields that say what a document holds, checked before the rest is read.
+#[derive(Deserialize)]
+struct Header {
+    kind: Option<String>,
+    version: Option<u32>,
+}
+
+/// `value` as an indented JSON document.
+pub fn to_json<T: Document>(value: &T) -> String {
+    let document = Write { kind: T::KIND, version: VERSION, value };
+    serde_json::to_string_pretty(&document).expect("documents serialize")
+}
+
+/// Reads a JSON document of `T`, rejecting other kinds and versions.
+pub fn from_json<T: Document>(json: &str) -> Result<T, String> {
+    let header: Header = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    match (header.kind.as_deref(), header.version.unwrap_or(VERSION)) {
+        (Some(kind), _) if kind != T::KIND => Err(format!("expected a '{}' document, not '{}'", T::KIND, kind)),
+        // The value's own fields leave out `kind` and `version`, and no document denies unknown fields.
+        (_, VERSION) => serde_json::from_str(json).map_err(|e| e.to_string()),
+        (_, version) => Err(format!("unsupported version {} of the '{}' schema", version, T::KIND)),
+    }
+}
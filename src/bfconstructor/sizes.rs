@@ -0,0 +1,107 @@
+// `--size-report`: how many Brainfuck commands each statement and procedure compiles to.
+//
+// The report reuses the source map markers: the code of every located statement is measured once
+// before the peephole pass and once after it. A statement's code includes the code of the
+// statements nested in it, which are listed under it, indented. The code of an inline procedure
+// is counted at each call, and a procedure's total is that of its body's statements.
+
+use crate::lexer::Span;
+use crate::parser::{Program, SourceMap};
+use crate::ir::Ext;
+use crate::{Cmd, Target};
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+// Brainfuck commands in `code[start..end]`. Comment labels never contain any, and code for Extended
+// Brainfuck, whose commands they may contain, has no comments.
+fn commands(code: &str, start: usize, end: usize, extended: bool) -> usize {
+    let command = |c: char| "+-<>.,[]".contains(c) || (extended && Ext::from_char(c).is_some());
+    code[start..end].chars().filter(|&c| command(c)).count()
+}
+
+// Code sizes by span, in source order with enclosing spans first.
+fn measure(code: &str, ranges: &[(usize, usize, Span)], extended: bool) -> BTreeMap<(usize, usize, Reverse<usize>), usize> {
+    let mut sizes = BTreeMap::new();
+    for &(start, end, span) in ranges {
+        *sizes.entry((span.file, span.start, Reverse(span.end))).or_insert(0) += commands(code, start, end, extended);
+    }
+    sizes
+}
+
+// Formats the report for `program` from its marked code before and after the peephole pass, as
+// `sourcemap::resolve` gives it.
+pub fn report(
+    sources: &SourceMap,
+    program: &Program,
+    target: Target,
+    before: &(String, Vec<(usize, usize, Span)>),
+    after: &(String, Vec<(usize, usize, Span)>),
+) -> String {
+    let sizes_before = measure(&before.0, &before.1, target.extended);
+    let sizes_after = measure(&after.0, &after.1, target.extended);
+    let size = |sizes: &BTreeMap<_, usize>, span: Span| sizes.get(&(span.file, span.start, Reverse(span.end))).copied();
+    let mut rows: Vec<[String; 4]> = Vec::new();
+    let mut open: Vec<Span> = Vec::new(); // The spans enclosing the current one.
+    for &(file, start, Reverse(end)) in sizes_before.keys() {
+        let span = Span::in_file(file, start, end);
+        while open.last().is_some_and(|outer| outer.file != file || outer.end <= start) {
+            open.pop();
+        }
+        let text = sources.text(span).lines().next().unwrap_or("").trim_end();
+        rows.push([
+            size(&sizes_before, span).unwrap_or(0).to_string(),
+            size(&sizes_after, span).unwrap_or(0).to_string(),
+            sources.location(span),
+            format!("{}{}", "  ".repeat(open.len()), text),
+        ]);
+        open.push(span);
+    }
+    for proc in &program.procs {
+        let spans: Vec<Span> = proc
+            .body
+            .iter()
+            .filter_map(|cmd| match cmd {
+                Cmd::At(span, _) => Some(*span),
+                _ => None,
+            })
+            .collect();
+        let total = |sizes| spans.iter().filter_map(|&span| size(sizes, span)).sum::<usize>();
+        if spans.iter().any(|&span| size(&sizes_before, span).is_some()) {
+            rows.push([
+                total(&sizes_before).to_string(),
+                total(&sizes_after).to_string(),
+                String::new(),
+                format!("proc {}", proc.name),
+            ]);
+        }
+    }
+    rows.push([
+        commands(&before.0, 0, before.0.len(), target.extended).to_string(),
+        commands(&after.0, 0, after.0.len(), target.extended).to_string(),
+        String::new(),
+        "total".to_string(),
+    ]);
+    let header = ["before", "after", "location", "statement"].map(str::to_string);
+    let mut widths = [0; 3];
+    for row in std::iter::once(&header).chain(&rows) {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let mut out = String::new();
+    for row in std::iter::once(&header).chain(&rows) {
+        let line = format!(
+            "{:>w0$}  {:>w1$}  {:<w2$}  {}",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2]
+        );
+        out += line.trim_end();
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,138 @@
+// Source map generation.
+//
+// With `--source-map`, every located command wraps its code in a pair of markers made of
+// private-use characters. The markers travel through comment stripping and the peephole pass
+// (which only touch Brainfuck commands and comments), and are resolved into byte ranges of the
+// final text at the very end:
+//
+//     OPEN digits(file) SEP digits(start) SEP digits(end) TERM  ...code...  CLOSE
+//
+// where digits are hexadecimal, each digit written as `DIGIT0 + d`.
+
+use crate::bfmap::{self, BfMap, Mapping, Variable};
+use crate::layout::Layout;
+use crate::lexer::Span;
+use crate::parser;
+
+const OPEN: char = '\u{E000}';
+const SEP: char = '\u{E001}';
+const TERM: char = '\u{E002}';
+const CLOSE: char = '\u{E003}';
+const DIGIT0: u32 = 0xE010;
+
+// Whether `c` is part of a marker.
+pub fn is_marker(c: char) -> bool {
+    ('\u{E000}'..='\u{E01F}').contains(&c)
+}
+
+fn digits(n: usize) -> String {
+    format!("{:x}", n)
+        .chars()
+        .map(|d| char::from_u32(DIGIT0 + d.to_digit(16).unwrap_or(0)).unwrap_or(OPEN))
+        .collect()
+}
+
+// The marker opening the code of the command at `span`.
+pub fn open(span: Span) -> String {
+    format!("{}{}{}{}{}{}{}", OPEN, digits(span.file), SEP, digits(span.start), SEP, digits(span.end), TERM)
+}
+
+// The marker closing the code of the innermost open command.
+pub fn close() -> char {
+    CLOSE
+}
+
+// The span of the opening marker at the start of `chars` and the number of chars it takes, if
+// `chars` starts with one.
+pub fn opening(chars: &[char]) -> Option<(Span, usize)> {
+    if chars.first() != Some(&OPEN) {
+        return None;
+    }
+    let mut fields = [0usize; 3];
+    let mut field = 0;
+    for (i, &d) in chars.iter().enumerate().skip(1) {
+        match d {
+            SEP => field = (field + 1).min(2),
+            TERM => return Some((Span::in_file(fields[0], fields[1], fields[2]), i + 1)),
+            d => fields[field] = fields[field] * 16 + (d as u32 - DIGIT0) as usize,
+        }
+    }
+    None
+}
+
+// Removes the markers from `code`, returning the plain code and the byte range each marked span covers.
+pub fn resolve(code: &str) -> (String, Vec<(usize, usize, Span)>) {
+    let mut out = String::with_capacity(code.len());
+    let mut open: Vec<(usize, Span)> = Vec::new();
+    let mut ranges = Vec::new();
+    let mut chars = code.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            OPEN => {
+                let mut fields = [0usize; 3];
+                let mut field = 0;
+                for d in chars.by_ref() {
+                    match d {
+                        SEP => field = (field + 1).min(2),
+                        TERM => break,
+                        d => fields[field] = fields[field] * 16 + (d as u32 - DIGIT0) as usize,
+                    }
+                }
+                let span = Span::in_file(fields[0], fields[1], fields[2]);
+                open.push((out.len(), span));
+            }
+            CLOSE => {
+                if let Some((start, span)) = open.pop() {
+                    ranges.push((start, out.len(), span));
+                }
+            }
+            c if is_marker(c) => {}
+            c => out.push(c),
+        }
+    }
+    ranges.sort_by_key(|&(start, end, _)| (start, std::cmp::Reverse(end)));
+    (out, ranges)
+}
+
+// Builds the sidecar map for resolved ranges of code `len` bytes long, with the cells of `layout`
+// as its debug symbols. Cells owned during a statement are live in each range of its code.
+pub fn build(sources: &parser::SourceMap, ranges: &[(usize, usize, Span)], layout: &Layout, len: usize) -> BfMap {
+    let variables = layout
+        .owners
+        .iter()
+        .flat_map(|owner| {
+            let live: Vec<(usize, usize)> = match owner.span {
+                Some(span) => ranges.iter().filter(|r| r.2 == span).map(|&(start, end, _)| (start, end)).collect(),
+                None => vec![(0, len)],
+            };
+            live.into_iter().map(|(start, end)| Variable {
+                name: owner.name.clone(),
+                kind: owner.kind.to_string(),
+                cell: owner.start,
+                len: owner.len,
+                start,
+                end,
+                frame: owner.frame.clone(),
+            })
+        })
+        .collect();
+    BfMap {
+        version: bfmap::VERSION,
+        sources: sources.names(),
+        mappings: ranges
+            .iter()
+            .map(|&(start, end, span)| {
+                let (line, column) = sources.line_col(span);
+                Mapping {
+                    start,
+                    end,
+                    source: span.file,
+                    line,
+                    column,
+                    text: sources.text(span).lines().next().unwrap_or("").trim_end().to_string(),
+                }
+            })
+            .collect(),
+        variables,
+    }
+}
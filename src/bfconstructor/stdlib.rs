@@ -0,0 +1,71 @@
+// The standard library, imported with `import "std/<module>"`.
+//
+// Modules are DSL source built into the constructor, so they are found without a search path and
+// take precedence over files of the same name. Their procedures and macros are only compiled
+// where they are called. The routines of `std/mem` work on arrays, which the DSL cannot index
+// with a variable; they behave like macros whose body is generated at each use for the constant
+// length given as their last argument.
+
+// A macro of a module whose body depends on a constant length.
+#[derive(Debug, Clone, Copy)]
+pub struct Sized {
+    pub name: &'static str,
+    pub params: &'static [&'static str], // Substituted like macro parameters; the length follows them.
+    pub body: fn(usize) -> String,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Module {
+    pub source: &'static str,
+    pub sized: &'static [Sized],
+}
+
+const MEM: &[Sized] = &[
+    Sized { name: "memset", params: &["arr", "value"], body: memset },
+    Sized { name: "memcpy", params: &["dst", "src"], body: memcpy },
+    Sized { name: "strcmp", params: &["lhs", "rhs"], body: strcmp },
+];
+
+// The module imported as `name`, if it is part of the standard library.
+pub fn module(name: &str) -> Option<Module> {
+    match name {
+        "std/bits" => Some(Module { source: include_str!("std/bits.bfs"), sized: &[] }),
+        "std/mem" => Some(Module { source: include_str!("std/mem.bfs"), sized: MEM }),
+        "std/num" => Some(Module { source: include_str!("std/num.bfs"), sized: &[] }),
+        _ => None,
+    }
+}
+
+// Stores `value` in each of the first `len` cells of `arr`.
+fn memset(len: usize) -> String {
+    let mut body = "push value\n".to_string();
+    for i in 0..len {
+        body += &format!("copy; set arr[{}]\n", i);
+    }
+    body + "drop\n"
+}
+
+// Copies the first `len` cells of `src` to `dst`.
+fn memcpy(len: usize) -> String {
+    (0..len).map(|i| format!("get src[{}]; set dst[{}]\n", i, i)).collect()
+}
+
+// Pushes -1, 0 or 1 as the NUL-terminated string in `lhs` sorts before, equal to or after the
+// one in `rhs`, looking at no more than `len` cells.
+fn strcmp(len: usize) -> String {
+    let mut body = "push 0\nscope {\n    let order, more\n    push 1; set more\n".to_string();
+    for i in 0..len {
+        let (l, r) = (format!("get lhs[{}]", i), format!("get rhs[{}]", i));
+        body += &format!(
+            "    if {{ get more }} then {{
+        if {{ {l}; {r}; lt }} then {{ push -1; set order; push 0; set more }}
+        else {{ if {{ {l}; {r}; gt }} then {{ push 1; set order; push 0; set more }}
+        else {{ if {{ {l}; not }} then {{ push 0; set more }} }} }}
+    }}
+",
+            l = l,
+            r = r
+        );
+    }
+    body + "    get order; poke 3\n}\n"
+}
@@ -0,0 +1,41 @@
+// Cells of dead variables, handed out again to the variables of nested `scope`s.
+//
+// Variables are pushed on the stack when their block starts and popped when it ends, so a
+// top-level `let` holds its cell for the whole program even if only the first statements use it.
+// Once the rest of a block no longer mentions one of its variables (see `process_block`), the
+// variable's cells go to the block's `Temps`. A `scope` declared after that takes its variables
+// from them instead of pushing new cells above the stack top, which keeps the tape shorter.
+//
+// The cells are not cleared when their variable dies, since most are never taken again: whoever
+// takes them clears them, like `alloc` does with heap blocks. Each state has its own copy, so
+// cells a scope takes are free again for the statements after it without being given back.
+
+use std::collections::BTreeSet;
+
+#[derive(Debug, Clone, Default)]
+pub struct Temps {
+    free: BTreeSet<usize>,
+}
+
+impl Temps {
+    // Adds cells `start..start + len`, which no variable uses any more.
+    pub fn give(&mut self, start: usize, len: usize) {
+        self.free.extend(start..start + len);
+    }
+
+    // Takes `len` consecutive free cells, the highest run so they lie nearest the stack top, and
+    // returns the first.
+    pub fn take(&mut self, len: usize) -> Option<usize> {
+        let mut run = 0;
+        let mut prev = None;
+        for &cell in self.free.iter().rev() {
+            run = if prev == Some(cell + 1) { run + 1 } else { 1 };
+            prev = Some(cell);
+            if run == len {
+                self.free.retain(|c| !(cell..cell + len).contains(c));
+                return Some(cell);
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,166 @@
+// `--verify`: runs the generated code in-process against the cases of a TOML file.
+//
+//     [[test]]
+//     name = "countdown"    # optional, defaults to the case number
+//     input = ""            # optional, bytes fed to `,`
+//     output = "54321"
+//
+// Cells follow the codegen target, so code compiled with `--no-wrap` fails when it wraps.
+// `--with-tests` writes the `example` declarations of a program in the same format, and
+// `bfir --test` runs a compiled program against such a file.
+
+use crate::ir::{self, Ext, Inst, Op, Syntax};
+use crate::Target;
+use serde::{Deserialize, Serialize};
+
+// Steps a case may run before it is considered stuck.
+const MAX_STEPS: u64 = 1_000_000_000;
+const CELLS: usize = 30000;
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Suite {
+    #[serde(rename = "test")]
+    pub tests: Vec<Case>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Case {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub input: String,
+    pub output: String,
+}
+
+pub fn load(src: &str) -> Result<Suite, String> {
+    toml::from_str(src).map_err(|e| e.to_string())
+}
+
+// Formats `suite` as a file `load` reads, with one-line strings as in the hand-written files.
+pub fn save(suite: &Suite) -> String {
+    // JSON string literals are also TOML basic strings.
+    let quote = |text: &str| serde_json::to_string(text).expect("strings serialize");
+    let mut out = String::new();
+    for case in &suite.tests {
+        out += "\n[[test]]\n";
+        if let Some(name) = &case.name {
+            out += &format!("name = {}\n", quote(name));
+        }
+        out += &format!("input = {}\noutput = {}\n", quote(&case.input), quote(&case.output));
+    }
+    out
+}
+
+// The state a program stopped in.
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub output: Vec<u8>,
+    pub tape: Vec<u64>,
+    pub ptr: usize,
+}
+
+// Runs `program` on `input` until it ends.
+pub fn execute(program: &[Inst], input: &[u8], target: Target) -> Result<Run, String> {
+    execute_within(program, input, target, MAX_STEPS)
+}
+
+// Runs `program` on `input` until it ends, or fails after `max_steps` steps.
+pub fn execute_within(program: &[Inst], input: &[u8], target: Target, max_steps: u64) -> Result<Run, String> {
+    let max = if target.bits >= 64 { u64::MAX } else { (1u64 << target.bits) - 1 };
+    let modulus = max as u128 + 1;
+    let mut tape = vec![0u64; CELLS];
+    let (mut ptr, mut pc, mut steps) = (0usize, 0usize, 0u64);
+    let mut storage = 0u64;
+    let mut input = input.iter();
+    let mut output = Vec::new();
+    while pc < program.len() {
+        steps += 1;
+        if steps > max_steps {
+            return Err(format!("no result after {} steps", max_steps));
+        }
+        let cell = &mut tape[ptr];
+        match program[pc].op {
+            Op::Add(n) => {
+                let sum = *cell as i128 + n as i128;
+                if !target.wrap && sum > max as i128 {
+                    return Err(format!("cell {} overflowed", ptr));
+                }
+                if !target.wrap && sum < 0 {
+                    return Err(format!("cell {} went below zero", ptr));
+                }
+                *cell = sum.rem_euclid(modulus as i128) as u64;
+            }
+            Op::Move(n) => {
+                ptr = ptr.checked_add_signed(n as isize).ok_or("pointer moved left of cell 0")?;
+                if ptr >= CELLS {
+                    return Err("pointer moved past the last cell".to_string());
+                }
+            }
+            Op::Output => output.push(*cell as u8),
+            Op::Input => *cell = input.next().copied().unwrap_or(0) as u64,
+            Op::Open(end) if *cell == 0 => pc = end,
+            Op::Close(start) if *cell != 0 => pc = start,
+            Op::Ext(Ext::End) => break,
+            Op::Ext(ext) => ext.apply(cell, &mut storage, target.bits),
+            _ => {}
+        }
+        pc += 1;
+    }
+    Ok(Run { output, tape, ptr })
+}
+
+// Formats the lines where `expected` and `actual` differ.
+fn diff(expected: &str, actual: &str) -> String {
+    let (expected, actual): (Vec<&str>, Vec<&str>) = (expected.split('\n').collect(), actual.split('\n').collect());
+    let mut out = String::new();
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(e), Some(a)) if e == a => {}
+            (e, a) => {
+                out += &format!("  line {}:\n", i + 1);
+                if let Some(e) = e {
+                    out += &format!("  - {:?}\n", e);
+                }
+                if let Some(a) = a {
+                    out += &format!("  + {:?}\n", a);
+                }
+            }
+        }
+    }
+    out
+}
+
+// Runs every case, reporting failures to stderr. Returns whether all passed.
+pub fn check(suite: &Suite, code: &str, target: Target) -> bool {
+    match ir::parse(code, Syntax { extended: target.extended, ..Syntax::default() }) {
+        Ok(program) => check_program(suite, &program, target),
+        Err(e) => {
+            eprintln!("verify: {}", e);
+            false
+        }
+    }
+}
+
+// Runs every case on a parsed program, reporting failures to stderr. Returns whether all passed.
+pub fn check_program(suite: &Suite, program: &[Inst], target: Target) -> bool {
+    let mut failed = 0;
+    for (i, case) in suite.tests.iter().enumerate() {
+        let name = case.name.clone().unwrap_or_else(|| format!("#{}", i + 1));
+        match execute(program, case.input.as_bytes(), target) {
+            Ok(run) if run.output == case.output.as_bytes() => {}
+            Ok(run) => {
+                let output = String::from_utf8_lossy(&run.output);
+                eprintln!("FAIL {}: output differs (- expected, + actual)\n{}", name, diff(&case.output, &output));
+                failed += 1;
+            }
+            Err(e) => {
+                eprintln!("FAIL {}: {}", name, e);
+                failed += 1;
+            }
+        }
+    }
+    eprintln!("verify: {} passed, {} failed", suite.tests.len() - failed, failed);
+    failed == 0
+}
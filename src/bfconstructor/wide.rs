@@ -0,0 +1,393 @@
+// Multi-cell unsigned integers, and 8.8 fixed-point numbers built on them.
+//
+// A `width`-cell number occupies `width` adjacent stack cells, most significant byte deepest
+// and least significant byte on top. The operations below expand into ordinary stack
+// programs; `Builder` tracks the stack height so operands can be addressed by position.
+//
+// An 8.8 fixed-point number is a 2-cell number counting 256ths: the integer part is the deeper
+// cell and the fraction the top one, so 0 to 255.996 in steps of 1/256. Adding and subtracting
+// them is `add(2)` and `sub(2)`; `mul_fixed` and `print_fixed` are below.
+
+use crate::Cmd;
+
+// Builds a stack program, converting stack positions (0 = first operand cell) to pick/poke depths.
+struct Builder {
+    cmds: Vec<Cmd>,
+    height: usize,
+}
+
+impl Builder {
+    fn new(height: usize) -> Builder {
+        Builder { cmds: Vec::new(), height }
+    }
+
+    fn op(&mut self, cmd: Cmd, delta: isize) {
+        self.cmds.push(cmd);
+        self.height = (self.height as isize + delta) as usize;
+    }
+
+    // Pushes a copy of the cell at `pos`.
+    fn pick(&mut self, pos: usize) {
+        let depth = self.height - 1 - pos;
+        self.op(Cmd::Pick(depth), 1);
+    }
+
+    // Pops the top of the stack into the cell at `pos`.
+    fn poke(&mut self, pos: usize) {
+        let depth = self.height - 1 - pos;
+        self.op(Cmd::Poke(depth), -1);
+    }
+
+    fn push(&mut self, n: usize) {
+        self.op(Cmd::Push(n), 1);
+    }
+
+    // Applies a binary operator that pops two values and pushes one.
+    fn binary(&mut self, cmd: Cmd) {
+        self.op(cmd, -1);
+    }
+
+    // Emits `if { cond } then { then_block }`, where `cond` pushes one value.
+    fn if_then(&mut self, cond: impl FnOnce(&mut Builder), then_block: impl FnOnce(&mut Builder)) {
+        let mut cond_builder = Builder::new(self.height);
+        cond(&mut cond_builder);
+        let mut then_builder = Builder::new(self.height + 1);
+        then_block(&mut then_builder);
+        self.cmds.push(Cmd::IfThen {
+            cond: cond_builder.cmds,
+            then_block: then_builder.cmds,
+        });
+    }
+
+    // Pushes the 2-cell product of the cells at `x` and `y`, by adding `x` to it `y` times.
+    fn mul_bytes(&mut self, x: usize, y: usize) {
+        let (hi, lo, count) = (self.height, self.height + 1, self.height + 2);
+        self.push(0);
+        self.push(0);
+        self.pick(y);
+        self.while_do(
+            |c| c.pick(count),
+            |body| {
+                // lo += x, carrying into hi when the sum wraps below x
+                body.pick(lo);
+                body.pick(x);
+                body.binary(Cmd::Add);
+                body.op(Cmd::Copy, 1);
+                body.pick(x);
+                body.binary(Cmd::Lt);
+                body.pick(hi);
+                body.binary(Cmd::Add);
+                body.poke(hi);
+                body.poke(lo);
+                body.op(Cmd::Dec, 0);
+            },
+        );
+        self.op(Cmd::Drop, -1);
+    }
+
+    // Pushes a copy of the `width`-cell number at `start`.
+    fn pick_number(&mut self, start: usize, width: usize) {
+        for pos in start..start + width {
+            self.pick(pos);
+        }
+    }
+
+    // Appends the commands of an operation on multi-cell numbers that pops `popped` cells more
+    // than it pushes.
+    fn wide(&mut self, cmds: Vec<Cmd>, popped: usize) {
+        self.cmds.extend(cmds);
+        self.height -= popped;
+    }
+
+    // Emits `while { cond } do { body }`, where `cond` pushes one value.
+    fn while_do(&mut self, cond: impl FnOnce(&mut Builder), body: impl FnOnce(&mut Builder)) {
+        let mut cond_builder = Builder::new(self.height);
+        cond(&mut cond_builder);
+        let mut body_builder = Builder::new(self.height);
+        body(&mut body_builder);
+        self.cmds.push(Cmd::While {
+            cond: cond_builder.cmds,
+            body: body_builder.cmds,
+        });
+    }
+}
+
+// Position of byte `i` (0 = least significant) of the number whose first cell is at `start`.
+fn byte_pos(start: usize, width: usize, i: usize) -> usize {
+    start + width - 1 - i
+}
+
+// Bytes of `value` truncated to `width` cells, most significant first.
+fn bytes_of(value: u64, width: usize) -> Vec<usize> {
+    (0..width)
+        .rev()
+        .map(|i| ((value >> (8 * i)) & 0xff) as usize)
+        .collect()
+}
+
+// Pushes `value` as a `width`-cell number.
+pub fn push(width: usize, value: u64) -> Vec<Cmd> {
+    bytes_of(value, width).into_iter().map(Cmd::Push).collect()
+}
+
+// Pops b and a, pushes a + b (mod 256^width). Carries ripple from the least significant byte.
+pub fn add(width: usize) -> Vec<Cmd> {
+    let mut b = Builder::new(2 * width);
+    let carry = 2 * width;
+    b.push(0);
+    for i in 0..width {
+        let (a_i, b_i) = (byte_pos(0, width, i), byte_pos(width, width, i));
+        // t = a + b; c1 = t < b
+        b.pick(a_i);
+        b.pick(b_i);
+        b.binary(Cmd::Add);
+        b.op(Cmd::Copy, 1);
+        b.pick(b_i);
+        b.binary(Cmd::Lt);
+        // a = t + carry; c2 = carry && a == 0
+        b.op(Cmd::Swap, 0);
+        b.pick(carry);
+        b.binary(Cmd::Add);
+        b.op(Cmd::Copy, 1);
+        b.op(Cmd::Not, 0);
+        b.pick(carry);
+        b.binary(Cmd::And);
+        b.op(Cmd::Swap, 0);
+        b.poke(a_i);
+        // carry = c1 || c2
+        b.binary(Cmd::Or);
+        b.poke(carry);
+    }
+    finish(b, width)
+}
+
+// Pops b and a, pushes a - b (mod 256^width). Borrows ripple from the least significant byte.
+pub fn sub(width: usize) -> Vec<Cmd> {
+    let mut b = Builder::new(2 * width);
+    let borrow = 2 * width;
+    b.push(0);
+    for i in 0..width {
+        let (a_i, b_i) = (byte_pos(0, width, i), byte_pos(width, width, i));
+        // b1 = a < b; t = a - b
+        b.pick(a_i);
+        b.pick(b_i);
+        b.binary(Cmd::Lt);
+        b.pick(a_i);
+        b.pick(b_i);
+        b.binary(Cmd::Sub);
+        // b2 = borrow && t == 0; a = t - borrow
+        b.op(Cmd::Copy, 1);
+        b.op(Cmd::Not, 0);
+        b.pick(borrow);
+        b.binary(Cmd::And);
+        b.op(Cmd::Swap, 0);
+        b.pick(borrow);
+        b.binary(Cmd::Sub);
+        b.poke(a_i);
+        // borrow = b1 || b2
+        b.binary(Cmd::Or);
+        b.poke(borrow);
+    }
+    finish(b, width)
+}
+
+// Drops the scratch cell and the second operand, leaving the result in the first operand's cells.
+fn finish(mut b: Builder, width: usize) -> Vec<Cmd> {
+    for _ in 0..=width {
+        b.op(Cmd::Drop, -1);
+    }
+    b.cmds
+}
+
+// Pops b and a, pushes a single cell: 0 if a == b, 1 if a > b, 255 if a < b.
+pub fn cmp(width: usize) -> Vec<Cmd> {
+    let mut b = Builder::new(2 * width);
+    let result = 2 * width;
+    b.push(0);
+    for i in (0..width).rev() {
+        let (a_i, b_i) = (byte_pos(0, width, i), byte_pos(width, width, i));
+        // The first differing byte from the most significant end decides: result = (a > b) - (a < b).
+        b.if_then(
+            |c| {
+                c.pick(result);
+                c.op(Cmd::Not, 0);
+            },
+            |t| {
+                t.pick(a_i);
+                t.pick(b_i);
+                t.binary(Cmd::Gt);
+                t.pick(a_i);
+                t.pick(b_i);
+                t.binary(Cmd::Lt);
+                t.binary(Cmd::Sub);
+                t.poke(result);
+            },
+        );
+    }
+    b.poke(0);
+    for _ in 1..2 * width {
+        b.op(Cmd::Drop, -1);
+    }
+    b.cmds
+}
+
+// Pops a number and prints it in decimal without leading zeros, by repeatedly subtracting
+// each power of ten and counting the subtractions.
+pub fn print(width: usize) -> Vec<Cmd> {
+    print_point(width, 0)
+}
+
+// Pops a number and prints it in decimal with a point before the last `point` digits, as a count
+// of 10^-point: all of those digits and the one before the point are printed, zeros or not.
+fn print_point(width: usize, point: usize) -> Vec<Cmd> {
+    let mut b = Builder::new(width);
+    let started = width;
+    let digit = width + 1;
+    b.push(0);
+    let max = if width >= 8 { u64::MAX } else { (1u64 << (8 * width)) - 1 };
+    let mut power = 1u64;
+    while power <= max / 10 {
+        power *= 10;
+    }
+    loop {
+        b.push(0);
+        // while a >= power: a -= power; digit += 1
+        b.while_do(
+            |c| {
+                for i in (0..width).rev() {
+                    c.pick(byte_pos(0, width, i));
+                }
+                c.cmds.extend(push(width, power));
+                c.height += width;
+                c.cmds.extend(cmp(width));
+                c.height -= 2 * width - 1;
+                c.push(255);
+                c.binary(Cmd::Neq);
+            },
+            |body| {
+                for i in (0..width).rev() {
+                    body.pick(byte_pos(0, width, i));
+                }
+                body.cmds.extend(push(width, power));
+                body.cmds.extend(sub(width));
+                for i in 0..width {
+                    body.poke(byte_pos(0, width, i));
+                }
+                body.op(Cmd::Inc, 0);
+            },
+        );
+        // Print the digit once a nonzero digit was seen, and always from the ones place on.
+        let last = power == 1;
+        let shown = power <= 10u64.pow(point as u32);
+        if point > 0 && power * 10 == 10u64.pow(point as u32) {
+            b.op(Cmd::PrintStr(".".to_string()), 0);
+        }
+        b.if_then(
+            |c| {
+                c.pick(digit);
+                c.pick(started);
+                c.binary(Cmd::Or);
+                if shown {
+                    c.push(1);
+                    c.binary(Cmd::Or);
+                }
+            },
+            |t| {
+                t.pick(digit);
+                t.op(Cmd::Addc(48), 0);
+                t.op(Cmd::Write, -1);
+                t.push(1);
+                t.poke(started);
+            },
+        );
+        b.op(Cmd::Drop, -1);
+        if last {
+            break;
+        }
+        power /= 10;
+    }
+    for _ in 0..=width {
+        b.op(Cmd::Drop, -1);
+    }
+    b.cmds
+}
+
+// Pops b and a, 8.8 fixed-point numbers, and pushes a * b with the fraction truncated to 1/256
+// (mod 256 in the integer part). Of the 32-bit product of the two 16-bit numbers this keeps bits
+// 8 to 23, so the low byte of a0 * b0 and the high byte of a1 * b1 are never computed.
+pub fn mul_fixed() -> Vec<Cmd> {
+    let mut b = Builder::new(4);
+    let (a1, a0, b1, b0) = (0, 1, 2, 3);
+    let (r2, r1) = (4, 5);
+    b.push(0);
+    b.push(0);
+    b.mul_bytes(a0, b0);
+    b.op(Cmd::Drop, -1);
+    b.poke(r1);
+    for (x, y) in [(a1, b0), (a0, b1)] {
+        b.mul_bytes(x, y);
+        // r1 += lo, carrying into r2; r2 += hi
+        b.pick(r1);
+        b.binary(Cmd::Add);
+        b.op(Cmd::Copy, 1);
+        b.pick(r1);
+        b.binary(Cmd::Lt);
+        b.op(Cmd::Swap, 0);
+        b.poke(r1);
+        b.binary(Cmd::Add);
+        b.pick(r2);
+        b.binary(Cmd::Add);
+        b.poke(r2);
+    }
+    b.pick(a1);
+    b.pick(b1);
+    b.binary(Cmd::Mul);
+    b.pick(r2);
+    b.binary(Cmd::Add);
+    b.poke(r2);
+    b.pick(r2);
+    b.poke(a1);
+    b.pick(r1);
+    b.poke(a0);
+    for _ in 0..4 {
+        b.op(Cmd::Drop, -1);
+    }
+    b.cmds
+}
+
+// Pops an 8.8 fixed-point number and prints it in decimal with `digits` digits after the point
+// (none, and no point, for 0), rounded half up. The number counts 256ths, so it is multiplied by
+// 10^digits in as many cells as that needs, 128 is added and the low cell dropped: what is left
+// counts 10^-digits, (v * 10^digits + 128) / 256, and is printed with the point inserted.
+pub fn print_fixed(digits: usize) -> Vec<Cmd> {
+    let mut b = Builder::new(2);
+    let (int, frac) = (0, 1);
+    let scaled = 0xFFFF * 10u128.pow(digits as u32) + 128;
+    let width = (1..).find(|&w| scaled >> (8 * w) == 0).expect("some width holds it");
+    // The number again, widened with zero cells above it: v at `start`, `width` cells.
+    let start = b.height;
+    for _ in 2..width {
+        b.push(0);
+    }
+    b.pick(int);
+    b.pick(frac);
+    for _ in 0..digits {
+        // v * 10 = (v * 4 + v) * 2
+        b.pick_number(start, width);
+        b.pick_number(start, width);
+        b.wide(add(width), width);
+        b.pick_number(start + width, width);
+        b.wide(add(width), width);
+        b.wide(add(width), width);
+        b.pick_number(start, width);
+        b.wide(add(width), width);
+    }
+    b.cmds.extend(push(width, 128));
+    b.height += width;
+    b.wide(add(width), width);
+    b.op(Cmd::Drop, -1);
+    b.wide(print_point(width - 1, digits), width - 1);
+    b.op(Cmd::Drop, -1);
+    b.op(Cmd::Drop, -1);
+    b.cmds
+}
@@ -0,0 +1,122 @@
+// `--animate`: an animated SVG of the tape over the course of the run.
+//
+// Every `--animate-every` steps the cells of the current tape are recorded, and each recording
+// becomes a frame of the animation: one square per cell, darker for small values and brighter
+// for large ones, with the value written in it and the pointer marked below. The squares shown
+// are the cells up to the highest the pointer reached, at most `MAX_CELLS`. A long run is sampled
+// evenly down to `MAX_FRAMES` frames. The SVG plays in a loop in any browser; it uses CSS
+// animations and no scripts.
+
+use crate::sample::Samples;
+use crate::tapes::TapeSet;
+use std::fmt::Write;
+use std::fs;
+
+const MAX_FRAMES: usize = 1000;
+const MAX_CELLS: usize = 64;
+const CELL: usize = 28; // The side of a cell's square, in pixels.
+const FRAME_SECONDS: f64 = 0.1;
+
+struct Frame {
+    step: u64,
+    tape: usize,
+    ptr: usize,
+    cells: Vec<u8>,
+}
+
+pub struct Animation {
+    samples: Samples<Frame>,
+}
+
+impl Animation {
+    pub fn new(every: u64) -> Animation {
+        Animation { samples: Samples::new(every, MAX_FRAMES) }
+    }
+
+    /// Counts an instruction, first recording the tape if this step is sampled.
+    #[inline]
+    pub fn record(&mut self, tapes: &TapeSet) {
+        if let Some(step) = self.samples.tick() {
+            self.push(step, tapes);
+        }
+    }
+
+    /// Records the state the run ended in.
+    pub fn finish(&mut self, tapes: &TapeSet) {
+        let steps = self.samples.steps();
+        if self.samples.frames.last().is_none_or(|frame| frame.step != steps) {
+            self.push(steps, tapes);
+        }
+    }
+
+    fn push(&mut self, step: u64, tapes: &TapeSet) {
+        let (tape, ptr) = tapes.tapes().nth(tapes.current()).expect("the current tape exists");
+        let cells = tape[..MAX_CELLS.min(tape.len())].to_vec();
+        self.samples.frames.push(Frame { step, tape: tapes.current(), ptr, cells });
+    }
+
+    /// Writes the SVG.
+    pub fn write(&self, path: &str) -> Result<(), String> {
+        fs::write(path, self.svg()).map_err(|e| format!("{}: {}", path, e))
+    }
+
+    fn svg(&self) -> String {
+        let frames = &self.samples.frames;
+        let shown = frames.iter().map(|f| f.ptr + 1).max().unwrap_or(1).clamp(8, MAX_CELLS);
+        let (width, height) = (shown * CELL + 20, 26 + CELL + 20);
+        let total = frames.len() as f64 * FRAME_SECONDS;
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" font-family="monospace" font-size="10">"#,
+            width, height
+        );
+        // Each frame is visible for the first 1/n of a cycle, shifted by its own delay.
+        let _ = writeln!(
+            svg,
+            "<style>.f {{ visibility: hidden; animation: show {:.3}s step-end infinite; }} \
+             @keyframes show {{ 0% {{ visibility: visible; }} {:.4}% {{ visibility: hidden; }} }}</style>",
+            total,
+            100.0 / frames.len().max(1) as f64
+        );
+        let _ = writeln!(svg, r##"<rect width="100%" height="100%" fill="#1e1e1e"/>"##);
+        for (n, frame) in frames.iter().enumerate() {
+            let _ = writeln!(svg, r#"<g class="f" style="animation-delay: {:.3}s">"#, n as f64 * FRAME_SECONDS);
+            let _ = writeln!(
+                svg,
+                r##"<text x="10" y="16" fill="#ddd">step {}, tape {}, pointer {}</text>"##,
+                frame.step, frame.tape, frame.ptr
+            );
+            for (i, &value) in frame.cells.iter().take(shown).enumerate() {
+                let (x, y) = (10 + i * CELL, 26);
+                let light = 12 + value as usize * 60 / 255;
+                let _ = write!(
+                    svg,
+                    r#"<rect x="{}" y="{}" width="{}" height="{}" fill="hsl(210,70%,{}%)" stroke="{}"/>"#,
+                    x,
+                    y,
+                    CELL - 2,
+                    CELL - 2,
+                    light,
+                    if i == frame.ptr { "#f80" } else { "#333" }
+                );
+                let text = if light > 50 { "#000" } else { "#eee" };
+                let _ = writeln!(
+                    svg,
+                    r#"<text x="{}" y="{}" fill="{}" text-anchor="middle">{}</text>"#,
+                    x + CELL / 2 - 1,
+                    y + CELL / 2 + 3,
+                    text,
+                    value
+                );
+            }
+            if frame.ptr < shown {
+                let x = 10 + frame.ptr * CELL + CELL / 2 - 1;
+                let _ = writeln!(svg, r##"<text x="{}" y="{}" fill="#f80" text-anchor="middle">^</text>"##, x, 26 + CELL + 12);
+            }
+            let _ = writeln!(svg, "</g>");
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
@@ -0,0 +1,36 @@
+// `bf.toml`: settings for running the programs of a project, read from the current directory.
+//
+//     theme = "solarized"   # as --theme; a built-in theme or a theme file
+//
+//     [extensions]          # the dialect of files with an extension, as --from names it
+//     cow = "map:cow.toml"
+//     b = "extended1"
+//
+// Extensions listed here take precedence over the built-in ones (see `dialect`).
+// Options on the command line take precedence.
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+
+const FILE: &str = "bf.toml";
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub theme: Option<String>,
+    #[serde(default)]
+    pub extensions: BTreeMap<String, String>,
+}
+
+impl Config {
+    /// Reads `bf.toml`, or gives the defaults if there is none.
+    pub fn load() -> Result<Config, String> {
+        match fs::read_to_string(FILE) {
+            Ok(text) => toml::from_str(&text).map_err(|e| format!("{}: {}", FILE, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(format!("{}: {}", FILE, e)),
+        }
+    }
+}
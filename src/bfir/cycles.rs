@@ -0,0 +1,48 @@
+// `--detect-cycles`: stops programs that have provably entered an endless loop.
+//
+// Without input, a run is determined by its state: the instruction index, the tapes and their
+// pointers, and the storage cell of Extended Brainfuck. If the same state comes back, the run will repeat from there forever. Every
+// `SAMPLE_EVERY` instructions the state is hashed and remembered; a hash seen before since the
+// last `,` ends the run. Samples at a fixed interval meet a loop of period P within P samples.
+// Only `MAX_SAMPLES` hashes are kept, so a loop with a long period may go unnoticed, and a hash
+// collision could in principle stop a run that would have ended.
+
+use crate::tapes::TapeSet;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+// Instructions between two samples; a power of two so the test is a mask. Hashing a tape of
+// 30000 cells takes a small fraction of the time these instructions do.
+const SAMPLE_EVERY: u64 = 1 << 16;
+const MAX_SAMPLES: usize = 1 << 16;
+
+#[derive(Default)]
+pub struct CycleDetector {
+    steps: u64,
+    seen: HashMap<u64, u64>, // State hash -> the step it was sampled at.
+}
+
+impl CycleDetector {
+    /// Counts one instruction; if the state at instruction `pc` repeats one sampled since the
+    /// last input, returns the number of instructions in between.
+    #[inline]
+    pub fn tick(&mut self, pc: usize, tapes: &TapeSet, storage: u8) -> Option<u64> {
+        self.steps += 1;
+        if self.steps & (SAMPLE_EVERY - 1) != 0 {
+            return None;
+        }
+        let mut hasher = DefaultHasher::new();
+        (pc, tapes, storage).hash(&mut hasher);
+        if self.seen.len() >= MAX_SAMPLES {
+            self.seen.clear();
+        }
+        let previous = self.seen.insert(hasher.finish(), self.steps)?;
+        Some(self.steps - previous)
+    }
+
+    /// Forgets the states seen so far, since input can lead the program somewhere new.
+    pub fn input(&mut self) {
+        self.seen.clear();
+    }
+}
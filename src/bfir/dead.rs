@@ -0,0 +1,79 @@
+// Dead loops, removed before running.
+//
+// A loop reached while its cell is zero never runs. That is known right after another loop, which
+// only ends once its cell is zero (a clear like `[-]` among them), and before anything has been
+// written to the tape, as for the comment loop many programs start with:
+//
+//     [comment, loop.]+++[-][>+<-]+.
+//
+// runs as `+++[-]+.`. The removed loops are dropped from the program before it runs or is
+// translated with `--to`, and `-v` lists them as notes pointing at their source, so that code
+// missing from `--to ir` can be traced back.
+//
+// Output, dumps and comments leave the current cell as it is. `,` and the commands of Extended
+// Brainfuck Type I may change it, except `$`, which only reads it. A move or a tape switch lands
+// on a cell that is only known to be zero while nothing has been written.
+
+use brainfucktool::diagnostic::{Diagnostic, Severity};
+use brainfucktool::ir::{self, Ext, Inst, Op};
+use brainfucktool::lexer::Span;
+
+// Why the current cell is known to be zero.
+const AFTER_LOOP: &str = "after a loop";
+const AFTER_CLEAR: &str = "after a clear";
+const AT_START: &str = "before anything is written to the tape";
+
+// Loop text shown in a note before the rest is elided.
+const SHOWN: usize = 24;
+
+/// Removes the loops of `program` that never run; notes about them locate them in file `file` of
+/// a source map.
+pub fn eliminate(program: &[Inst], file: usize) -> (Vec<Inst>, Vec<Diagnostic>) {
+    let mut kept = Vec::with_capacity(program.len());
+    let mut notes = Vec::new();
+    let mut zero = Some(AT_START); // Why the current cell is known to be zero, if it is.
+    let mut untouched = true; // No cell has been written yet.
+    let mut i = 0;
+    while i < program.len() {
+        let inst = &program[i];
+        if let (Op::Open(close), Some(why)) = (&inst.op, zero) {
+            notes.push(note(&program[i..=*close], why, file));
+            i = close + 1;
+            continue;
+        }
+        match inst.op {
+            Op::Add(0) | Op::Move(0) | Op::Tape(0) => {}
+            Op::Add(_) | Op::Input | Op::Open(_) => {
+                zero = None;
+                untouched = false;
+            }
+            Op::Move(_) | Op::Tape(_) => zero = untouched.then_some(AT_START),
+            Op::Close(open) => zero = Some(if is_clear(&program[open..=i]) { AFTER_CLEAR } else { AFTER_LOOP }),
+            Op::Output | Op::Dump | Op::Comment(_) | Op::Ext(Ext::Store | Ext::End) => {}
+            Op::Ext(_) => {
+                zero = None;
+                untouched = false;
+            }
+        }
+        kept.push(inst.clone());
+        i += 1;
+    }
+    ir::link(&mut kept).expect("removing whole loops keeps the brackets balanced");
+    (kept, notes)
+}
+
+// Whether `ops`, a whole loop, only clears its cell.
+fn is_clear(ops: &[Inst]) -> bool {
+    matches!(ops, [_, Inst { op: Op::Add(1 | -1), .. }, _])
+}
+
+// The note about the removed loop `ops`, from its `[` to its `]`.
+fn note(ops: &[Inst], why: &str, file: usize) -> Diagnostic {
+    let code: Vec<Inst> = ops.iter().filter(|inst| !matches!(inst.op, Op::Comment(_))).cloned().collect();
+    let text = ir::emit(&code);
+    let shown: String = text.chars().take(SHOWN).collect();
+    let ellipsis = if shown.len() < text.len() { "..." } else { "" };
+    let message = format!("removed a loop that never runs, since its cell is zero {}: {}{}", why, shown, ellipsis);
+    let (start, end) = (ops[0].pos, ops[ops.len() - 1].pos + 1);
+    Diagnostic::new(Severity::Note, "dead-loop", message, Some(Span { start, end, file }))
+}
@@ -0,0 +1,201 @@
+// Runtime error reports.
+//
+// When a run fails, the report names the fault, points at the failing command in the Brainfuck
+// source, gives the data pointer and the cells around it, and for the checks of the pointer,
+// wraparound, loop iterations and cycles states the constraint that was broken:
+//
+//     error: pointer out of range
+//      --> prog.bf:2:1
+//       |
+//     2 | >>>>
+//       | ^^^^
+//       = the pointer must stay within cells 0..=2 (-c 3)
+//       = moving by +4 from cell 0 on tape 0
+//
+//       index     0     1     2
+//         dec     0     1     0
+//
+// With `--error-format json` the same report is one line of JSON in the format bfconstructor
+// uses, without the cells.
+
+use crate::highlight::{Role, Theme};
+use crate::tapes::TapeSet;
+use brainfucktool::diagnostic::{Diagnostic, Severity};
+use brainfucktool::ir::{Inst, Op};
+use brainfucktool::lexer::Span;
+use brainfucktool::parser::SourceMap;
+use std::fmt::Write;
+use std::io;
+
+// Cells shown on each side of the pointer.
+const CONTEXT: usize = 5;
+
+/// Why a run stopped.
+pub enum Fault {
+    /// A move would take the pointer off its tape.
+    PointerOutOfRange { step: i32, cells: usize },
+    /// With `-w`, an add would take the cell past 0 or 255.
+    Wraparound { value: u8, add: i32 },
+    /// With `--max-loop-iterations`, the loop began another iteration beyond `max`.
+    LoopLimit { max: u64 },
+    /// With `--detect-cycles`, the state repeated after `period` instructions without input.
+    Cycle { period: u64 },
+    Io(io::Error),
+}
+
+impl Fault {
+    pub fn title(&self) -> String {
+        match self {
+            Fault::PointerOutOfRange { .. } => "pointer out of range".to_string(),
+            Fault::Wraparound { add, .. } if *add > 0 => "cell overflow (-w)".to_string(),
+            Fault::Wraparound { .. } => "cell underflow (-w)".to_string(),
+            Fault::LoopLimit { .. } => "loop iteration limit exceeded".to_string(),
+            Fault::Cycle { .. } => "non-terminating loop detected".to_string(),
+            Fault::Io(e) => e.to_string(),
+        }
+    }
+
+    /// A stable name for the kind of fault, for `--error-format json`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Fault::PointerOutOfRange { .. } => "pointer-out-of-range",
+            Fault::Wraparound { add, .. } if *add > 0 => "cell-overflow",
+            Fault::Wraparound { .. } => "cell-underflow",
+            Fault::LoopLimit { .. } => "loop-limit",
+            Fault::Cycle { .. } => "non-terminating-loop",
+            Fault::Io(_) => "io",
+        }
+    }
+
+    // The broken constraint and how the command broke it.
+    fn constraint(&self, ptr: usize, tape: usize) -> Vec<String> {
+        match *self {
+            Fault::PointerOutOfRange { step, cells } => vec![
+                format!("the pointer must stay within cells 0..={} (-c {})", cells - 1, cells),
+                format!("moving by {:+} from cell {} on tape {}", step, ptr, tape),
+            ],
+            Fault::Wraparound { value, add } => vec![
+                "cells must stay within 0..=255 when wraparound is disallowed".to_string(),
+                format!("adding {:+} to {} in cell {} on tape {}", add, value, ptr, tape),
+            ],
+            Fault::LoopLimit { max } => vec![
+                format!("a loop may run at most {} iterations in a row (--max-loop-iterations)", max),
+                format!("at cell {} on tape {}", ptr, tape),
+            ],
+            Fault::Cycle { period } => vec![
+                format!("the tapes, the pointers and this instruction were the same {} instructions earlier", period),
+                "no input is read in between, so the program cannot end (--detect-cycles)".to_string(),
+                format!("at cell {} on tape {}", ptr, tape),
+            ],
+            Fault::Io(_) => vec![format!("at cell {} on tape {}", ptr, tape)],
+        }
+    }
+}
+
+impl From<io::Error> for Fault {
+    fn from(e: io::Error) -> Fault {
+        Fault::Io(e)
+    }
+}
+
+/// A failed run: the fault, the index of the failing command and the tapes as they were.
+pub struct RuntimeError {
+    pub index: usize,
+    pub fault: Fault,
+    pub tapes: TapeSet,
+}
+
+/// Formats the report for `error`, which happened running `program` parsed from `src`.
+pub fn render(error: &RuntimeError, program: &[Inst], src: &str, filename: &str, theme: &Theme) -> String {
+    let red = theme.start(Role::Error);
+    let blue = theme.start(Role::Gutter);
+    let reset = theme.reset();
+    let mut out = format!("{}error{}: {}\n", red, reset, error.fault.title());
+
+    let inst = program.get(error.index);
+    let (line_no, col, line, width) = match inst {
+        Some(inst) => excerpt(src, program, inst),
+        None => (src.lines().count().max(1), 1, "", 1),
+    };
+    let gutter = " ".repeat(line_no.to_string().len());
+    let _ = writeln!(out, "{}{}-->{} {}:{}:{}", gutter, blue, reset, filename, line_no, col);
+    if inst.is_some() {
+        let _ = writeln!(out, "{} {}|{}", gutter, blue, reset);
+        let _ = writeln!(out, "{}{} |{} {}", blue, line_no, reset, line);
+        let marker = format!("{}{}{}", red, "^".repeat(width), reset);
+        let _ = writeln!(out, "{} {}|{} {}{}", gutter, blue, reset, " ".repeat(col - 1), marker);
+    }
+    for note in notes(error, program, src) {
+        let _ = writeln!(out, "{} {}={} {}", gutter, blue, reset, note);
+    }
+
+    let tapes = &error.tapes;
+    let (tape, ptr) = tapes.tapes().nth(tapes.current()).expect("the current tape exists");
+    let around = ptr.saturating_sub(CONTEXT)..(ptr + CONTEXT + 1).min(tape.len());
+    let _ = write!(out, "\n{} index", gutter);
+    for i in around.clone() {
+        let _ = write!(out, " {:>5}", i);
+    }
+    let _ = write!(out, "\n{}   dec", gutter);
+    for i in around {
+        let cell = format!("{:>5}", tape[i]);
+        if i == ptr {
+            let _ = write!(out, " {}", theme.paint(Role::Pointer, cell));
+        } else {
+            let _ = write!(out, " {}", cell);
+        }
+    }
+    out.push('\n');
+    out
+}
+
+/// Formats the report for `error` as one line of JSON, adding `extra` to its notes.
+pub fn to_json(error: &RuntimeError, program: &[Inst], src: &str, filename: &str, extra: &[String]) -> String {
+    let mut sources = SourceMap::default();
+    let file = sources.add(filename, src.to_string());
+    let span = program.get(error.index).map(|inst| {
+        let start = inst.pos.min(src.len());
+        let (.., width) = excerpt(src, program, inst);
+        let end = start + src[start..].chars().take(width).map(char::len_utf8).sum::<usize>();
+        Span { start, end, file }
+    });
+    let mut diagnostic = Diagnostic::new(Severity::Error, error.fault.code(), error.fault.title(), span);
+    diagnostic.notes = notes(error, program, src);
+    diagnostic.notes.extend_from_slice(extra);
+    diagnostic.to_json(&sources)
+}
+
+// The notes under the excerpt: where the failing loop ends and the broken constraint.
+fn notes(error: &RuntimeError, program: &[Inst], src: &str) -> Vec<String> {
+    let tapes = &error.tapes;
+    let mut notes = error.fault.constraint(tapes.ptr(), tapes.current());
+    if let Some(Inst { op: Op::Open(end), .. }) = program.get(error.index) {
+        let (line, col) = line_col(src, program[*end].pos);
+        notes.insert(0, format!("the loop ends at {}:{}", line, col));
+    }
+    notes
+}
+
+/// The 1-based line and column of byte `pos` of `src`.
+pub fn line_col(src: &str, pos: usize) -> (usize, usize) {
+    let pos = pos.min(src.len());
+    let line_start = src[..pos].rfind('\n').map_or(0, |i| i + 1);
+    (src[..pos].matches('\n').count() + 1, src[line_start..pos].chars().count() + 1)
+}
+
+// The line, column, text of the line and width of the code `inst` comes from: a folded run of
+// commands, or a whole loop as far as it fits on the line.
+fn excerpt<'a>(src: &'a str, program: &[Inst], inst: &Inst) -> (usize, usize, &'a str, usize) {
+    let start = inst.pos.min(src.len());
+    let line_start = src[..start].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = src[start..].find('\n').map_or(src.len(), |i| start + i);
+    let (line_no, col) = line_col(src, start);
+    let rest = &src[start..line_end];
+    let width = match inst.op {
+        Op::Add(_) => rest.chars().take_while(|c| ['+', '-'].contains(c)).count(),
+        Op::Move(_) => rest.chars().take_while(|c| ['<', '>'].contains(c)).count(),
+        Op::Open(end) => rest[..(program[end].pos + 1).clamp(start, line_end) - start].chars().count(),
+        _ => 1,
+    };
+    (line_no, col, &src[line_start..line_end], width.max(1))
+}
@@ -0,0 +1,184 @@
+// `--dialect`: which language the source file is written in.
+//
+// Besides Brainfuck, bfir runs Ook!, which spells each command as a pair of the words `Ook.`,
+// `Ook?` and `Ook!`. An Ook! program is translated to Brainfuck of the same length, with each
+// command at the first byte of its pair and everything else blanked out, so positions and line
+// numbers in reports are those of the Ook! file.
+//
+// `extended1` is Extended Brainfuck Type I, Brainfuck with a storage cell and the commands
+// `@ $ ! { } ~ ^ & |` (see `ir::Ext`), which `bfconstructor --target extended1` generates. It is
+// only chosen with `--dialect`.
+//
+// pbrain is Brainfuck with procedures: `(` and `)` define one, `:` calls one. bfir has no
+// procedures, so it runs pbrain programs that do not use them and stops at the first that does.
+//
+// Without `--dialect`, the file's extension decides: the `[extensions]` of bf.toml (see `config`)
+// first, then `.ook` for Ook!, `.pb` and `.pbrain` for pbrain, and Brainfuck for anything else.
+// bfir says on stderr which it chose and why.
+//
+// `--from map:FILE` and `--to map:FILE` (see `translate`) read and write a dialect that spells
+// each command as a word given in a TOML file:
+//
+//     right = "moo"    left = "mOo"    inc = "MoO"    dec = "MOo"
+//     output = "OOM"   input = "oom"   open = "MOO"   close = "moo!"
+//
+// Words are matched longest first, and anything between them is ignored, as in Ook!.
+
+use crate::translate::{self, Format};
+use clap::ValueEnum;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Dialect {
+    Brainfuck,
+    Ook,
+    Extended1,
+    Pbrain,
+}
+
+/// How to read `filename` by its extension, given the `[extensions]` of bf.toml, and why.
+pub fn detect(filename: &str, extensions: &BTreeMap<String, String>) -> Result<(Format, String), String> {
+    let extension = Path::new(filename).extension().and_then(|e| e.to_str()).unwrap_or("").to_ascii_lowercase();
+    let configured = extensions.iter().find(|(name, _)| name.trim_start_matches('.').eq_ignore_ascii_case(&extension));
+    if let Some((name, value)) = configured {
+        let format = translate::parse_format(value).map_err(|e| format!("bf.toml: [extensions] {} = {:?}: {}", name, value, e))?;
+        if !format.readable() {
+            return Err(format!("bf.toml: [extensions] {} = {:?}: bfir can write {} but not read it", name, value, value));
+        }
+        return Ok((format, format!("as bf.toml maps .{}", extension)));
+    }
+    Ok(match extension.as_str() {
+        "ook" => (Format::Ook, "from its extension".to_string()),
+        "pb" | "pbrain" => (Format::Pbrain, "from its extension".to_string()),
+        "b" | "bf" => (Format::Brainfuck, "from its extension".to_string()),
+        "" => (Format::Brainfuck, "since it has no extension".to_string()),
+        _ => (Format::Brainfuck, format!("since no dialect has the extension .{}", extension)),
+    })
+}
+
+/// Translates `src` from `dialect` to Brainfuck.
+pub fn translate(src: String, dialect: Dialect) -> Result<String, String> {
+    match dialect {
+        Dialect::Brainfuck | Dialect::Extended1 => Ok(src),
+        Dialect::Ook => ook(&src),
+        Dialect::Pbrain => pbrain(src),
+    }
+}
+
+// pbrain without procedures is Brainfuck as it is.
+fn pbrain(src: String) -> Result<String, String> {
+    match src.find(['(', ')', ':']) {
+        Some(pos) => {
+            let (line, col) = crate::diagnostic::line_col(&src, pos);
+            let command = &src[pos..pos + 1];
+            Err(format!("{}:{}: `{}` is a pbrain procedure command, and bfir has no procedures", line, col, command))
+        }
+        None => Ok(src),
+    }
+}
+
+// The marks of the two words of each Ook! command.
+const OOK: [([u8; 2], char); 8] = [
+    ([b'.', b'?'], '>'),
+    ([b'?', b'.'], '<'),
+    ([b'.', b'.'], '+'),
+    ([b'!', b'!'], '-'),
+    ([b'!', b'.'], '.'),
+    ([b'.', b'!'], ','),
+    ([b'!', b'?'], '['),
+    ([b'?', b'!'], ']'),
+];
+
+/// The Ook! spelling of a Brainfuck command.
+pub fn ook_word(command: char) -> Option<String> {
+    let &([first, second], _) = OOK.iter().find(|&&(_, c)| c == command)?;
+    Some(format!("Ook{} Ook{}", first as char, second as char))
+}
+
+fn ook(src: &str) -> Result<String, String> {
+    let mut out: Vec<u8> = src.bytes().map(|b| if b == b'\n' { b'\n' } else { b' ' }).collect();
+    let mut words = src.match_indices("Ook").filter_map(|(pos, _)| {
+        let mark = *src.as_bytes().get(pos + 3)?;
+        b".?!".contains(&mark).then_some((pos, mark))
+    });
+    while let Some((pos, first)) = words.next() {
+        let (line, col) = crate::diagnostic::line_col(src, pos);
+        let (_, second) = words.next().ok_or(format!("{}:{}: `Ook{}` has no second word", line, col, first as char))?;
+        let &(_, command) = OOK
+            .iter()
+            .find(|(marks, _)| *marks == [first, second])
+            .ok_or(format!("{}:{}: `Ook? Ook?` is not a command", line, col))?;
+        out[pos] = command as u8;
+    }
+    Ok(String::from_utf8(out).expect("the translation is ASCII"))
+}
+
+/// A dialect read from a mapping file: the word for each Brainfuck command.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Mapping {
+    right: String,
+    left: String,
+    inc: String,
+    dec: String,
+    output: String,
+    input: String,
+    open: String,
+    close: String,
+}
+
+impl Mapping {
+    pub fn load(path: &Path) -> Result<Mapping, String> {
+        let src = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let mapping: Mapping = toml::from_str(&src).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let words = mapping.words();
+        for (i, &(word, command)) in words.iter().enumerate() {
+            if word.trim().is_empty() {
+                return Err(format!("{}: the word for `{}` is empty", path.display(), command));
+            }
+            if let Some(&(_, other)) = words[..i].iter().find(|&&(other, _)| other == word) {
+                return Err(format!("{}: `{}` and `{}` are both spelled {:?}", path.display(), other, command, word));
+            }
+        }
+        Ok(mapping)
+    }
+
+    fn words(&self) -> [(&str, char); 8] {
+        [
+            (&self.right, '>'),
+            (&self.left, '<'),
+            (&self.inc, '+'),
+            (&self.dec, '-'),
+            (&self.output, '.'),
+            (&self.input, ','),
+            (&self.open, '['),
+            (&self.close, ']'),
+        ]
+    }
+
+    /// The word for a Brainfuck command.
+    pub fn word(&self, command: char) -> Option<&str> {
+        self.words().into_iter().find(|&(_, c)| c == command).map(|(word, _)| word)
+    }
+
+    /// Translates `src` to Brainfuck with the same layout, as for Ook!.
+    pub fn read(&self, src: &str) -> String {
+        let mut words = self.words();
+        words.sort_by_key(|(word, _)| std::cmp::Reverse(word.len()));
+        let mut out: Vec<u8> = src.bytes().map(|b| if b == b'\n' { b'\n' } else { b' ' }).collect();
+        let mut pos = 0;
+        while pos < src.len() {
+            match words.iter().find(|(word, _)| src[pos..].starts_with(word)) {
+                Some(&(word, command)) => {
+                    out[pos] = command as u8;
+                    pos += word.len();
+                }
+                None => pos += src[pos..].chars().next().map_or(1, char::len_utf8),
+            }
+        }
+        String::from_utf8(out).expect("the translation is ASCII")
+    }
+}
@@ -0,0 +1,234 @@
+// Memory dumps: of the end state with `-d`, at `#` and at the steps of `--dump-at`.
+//
+// Each dump goes to every sink given with `--dump-to FORMAT[:WHERE]`, or to a table on stdout if
+// none is given, so people and tools can read the same run:
+//
+//     bfir -d 32 --dump-to table:stderr --dump-to json:dump.json --dump-to png:heat.png prog.bf
+//
+// `table` is the colored table of cells; `json` is a document of kind `dump` with every tape's
+// pointer and first cells; `png` is a heatmap with a square per cell, shaded like the table, in
+// rows of `ROW` cells and a band of rows per tape. A sink is `stdout`, `stderr` or a file, which
+// holds the last dump of the run.
+
+use crate::highlight::{Color, Role, Theme};
+use crate::tapes::TapeSet;
+use brainfucktool::schema::{self, Document};
+use brainfucktool::term::HighlightMode;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+use std::fs;
+use std::path::PathBuf;
+
+const ROW: usize = 32; // Cells in a row of the heatmap.
+const CELL: usize = 12; // The side of a cell's square in the heatmap, in pixels, with a 1 pixel gap.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DumpFormat {
+    Table,
+    Json,
+    Png,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Sink {
+    Stdout,
+    Stderr,
+    File(PathBuf),
+}
+
+/// Where a dump goes, and in which format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DumpSpec {
+    pub format: DumpFormat,
+    pub sink: Sink,
+}
+
+/// Parses `FORMAT[:WHERE]`, where `WHERE` is `stdout` (the default), `stderr` or a file.
+pub fn parse_spec(arg: &str) -> Result<DumpSpec, String> {
+    let (format, sink) = arg.split_once(':').unwrap_or((arg, "stdout"));
+    let format = match format {
+        "table" => DumpFormat::Table,
+        "json" => DumpFormat::Json,
+        "png" => DumpFormat::Png,
+        _ => return Err(format!("unknown dump format '{}' (expected table, json or png)", format)),
+    };
+    let sink = match sink {
+        "stdout" => Sink::Stdout,
+        "stderr" => Sink::Stderr,
+        "" => return Err("expected stdout, stderr or a file after ':'".to_string()),
+        path => Sink::File(PathBuf::from(path)),
+    };
+    if format == DumpFormat::Png && !matches!(sink, Sink::File(_)) {
+        return Err("png dumps need a file".to_string());
+    }
+    Ok(DumpSpec { format, sink })
+}
+
+/// The tapes at one point of the run, as the `json` format writes them.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Dump {
+    pub index: usize, // The index of the program's next instruction.
+    pub step: u64,
+    pub current: usize,
+    pub tapes: Vec<DumpTape>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DumpTape {
+    pub pointer: usize,
+    pub cells: Vec<u8>, // The first `-d` cells.
+}
+
+impl Document for Dump {
+    const KIND: &'static str = "dump";
+}
+
+/// Writes the first `count` cells of every tape to each of `specs`, reporting sinks that fail.
+/// Tables start with `title`, if any.
+pub fn write(specs: &[DumpSpec], title: Option<&str>, tapes: &TapeSet, index: usize, step: u64, count: usize, theme: &Theme) {
+    let table_on_stdout = [DumpSpec { format: DumpFormat::Table, sink: Sink::Stdout }];
+    for spec in if specs.is_empty() { &table_on_stdout[..] } else { specs } {
+        let Sink::File(path) = &spec.sink else {
+            let text = match spec.format {
+                DumpFormat::Table => table(title, tapes, index, count, theme),
+                DumpFormat::Json => json(tapes, index, step, count) + "\n",
+                DumpFormat::Png => unreachable!("parse_spec gives png dumps a file"),
+            };
+            match spec.sink {
+                Sink::Stderr => eprint!("{}", text),
+                _ => print!("{}", text),
+            }
+            continue;
+        };
+        let bytes = match spec.format {
+            DumpFormat::Table => table(title, tapes, index, count, &Theme::new(HighlightMode::None)).into_bytes(),
+            DumpFormat::Json => (json(tapes, index, step, count) + "\n").into_bytes(),
+            DumpFormat::Png => heatmap(tapes, count, theme),
+        };
+        if let Err(e) = fs::write(path, bytes) {
+            eprintln!("Error writing the dump to {}: {}", path.display(), e);
+        }
+    }
+}
+
+/// The dump of every tape as a table, including the current program index and data pointers.
+fn table(title: Option<&str>, tapes: &TapeSet, index: usize, count: usize, theme: &Theme) -> String {
+    let mut out = title.map_or_else(String::new, |title| format!("{}\n", title));
+    let _ = writeln!(out, "Program Index: {}", index);
+    let several = tapes.tapes().count() > 1;
+    for (t, (tape, ptr)) in tapes.tapes().enumerate() {
+        if several {
+            let current = if t == tapes.current() { " (current)" } else { "" };
+            let _ = writeln!(out, "Tape {}{}", t, current);
+        }
+        tape_table(&mut out, tape, ptr, count, theme);
+    }
+    out
+}
+
+// The first `count` cells of one tape, highlighting the cell at its data pointer.
+fn tape_table(out: &mut String, tape: &[u8], ptr: usize, count: usize, theme: &Theme) {
+    let count = std::cmp::min(count, tape.len());
+    let _ = writeln!(out, "Data Pointer : {}", ptr);
+    // Shade each cell by its value, and color the cell at the data pointer differently.
+    let cell_role = |i: usize| if i == ptr { Role::Pointer } else { Role::DumpCell(tape[i]) };
+    let _ = write!(out, "{: ^5} ", "index");
+    for i in 0..count {
+        let _ = write!(out, "{} ", theme.paint(Role::DumpHeader, format!("{: ^3}", i)));
+    }
+    let _ = write!(out, "\n{: ^5} ", "dec");
+    for (i, cell) in tape.iter().enumerate().take(count) {
+        let _ = write!(out, "{} ", theme.paint(cell_role(i), format!("{: >3}", cell)));
+    }
+    let _ = write!(out, "\n{: ^5} ", "hex");
+    for (i, cell) in tape.iter().enumerate().take(count) {
+        let _ = write!(out, "{} ", theme.paint(cell_role(i), format!("{: >3x}", cell)));
+    }
+    out.push_str("\n\n");
+}
+
+fn json(tapes: &TapeSet, index: usize, step: u64, count: usize) -> String {
+    let cells = |tape: &[u8]| tape[..count.min(tape.len())].to_vec();
+    let dump = Dump {
+        index,
+        step,
+        current: tapes.current(),
+        tapes: tapes.tapes().map(|(tape, pointer)| DumpTape { pointer, cells: cells(tape) }).collect(),
+    };
+    schema::to_json(&dump)
+}
+
+// The first `count` cells of every tape as a PNG: the theme's dump colors from 0 to 255, and its
+// pointer color at each pointer.
+fn heatmap(tapes: &TapeSet, count: usize, theme: &Theme) -> Vec<u8> {
+    let [dark, bright] = theme.dump_gradient.unwrap_or([Color::rgb(0, 0, 0), Color::rgb(255, 255, 255)]);
+    let pointer = theme.pointer.bg.unwrap_or(Color::rgb(255, 136, 0));
+    let background = (30, 30, 30);
+    let rows = count.div_ceil(ROW).max(1);
+    let band = rows * CELL + CELL / 2; // A tape's rows and the gap below them.
+    let (width, height) = (ROW.min(count.max(1)) * CELL + 1, tapes.tapes().count() * band);
+    let mut pixels = vec![background; width * height];
+    for (t, (tape, ptr)) in tapes.tapes().enumerate() {
+        for (i, &value) in tape.iter().enumerate().take(count) {
+            let color = if i == ptr { pointer } else { dark.mix(bright, value) };
+            let (x, y) = (i % ROW * CELL + 1, t * band + i / ROW * CELL + 1);
+            for row in y..y + CELL - 1 {
+                pixels[row * width + x..row * width + x + CELL - 1].fill(color.rgb);
+            }
+        }
+    }
+    png(width, height, &pixels)
+}
+
+// An 8-bit RGB PNG, its image data stored in uncompressed deflate blocks.
+fn png(width: usize, height: usize, pixels: &[(u8, u8, u8)]) -> Vec<u8> {
+    let mut raw = Vec::with_capacity(height * (width * 3 + 1));
+    for row in pixels.chunks(width) {
+        raw.push(0); // No filter.
+        raw.extend(row.iter().flat_map(|&(r, g, b)| [r, g, b]));
+    }
+    let mut zlib = vec![0x78, 0x01];
+    let blocks: Vec<&[u8]> = raw.chunks(0xffff).collect();
+    for (n, block) in blocks.iter().enumerate() {
+        zlib.push((n + 1 == blocks.len()) as u8);
+        zlib.extend((block.len() as u16).to_le_bytes());
+        zlib.extend((!(block.len() as u16)).to_le_bytes());
+        zlib.extend_from_slice(block);
+    }
+    zlib.extend(adler32(&raw).to_be_bytes());
+
+    let mut header = Vec::new();
+    header.extend((width as u32).to_be_bytes());
+    header.extend((height as u32).to_be_bytes());
+    header.extend([8, 2, 0, 0, 0]); // 8 bits per channel, RGB, no interlacing.
+    let mut out = b"\x89PNG\r\n\x1a\n".to_vec();
+    for (kind, data) in [(b"IHDR", &header), (b"IDAT", &zlib), (b"IEND", &Vec::new())] {
+        out.extend((data.len() as u32).to_be_bytes());
+        let start = out.len();
+        out.extend_from_slice(kind);
+        out.extend_from_slice(data);
+        let crc = crc32(&out[start..]);
+        out.extend(crc.to_be_bytes());
+    }
+    out
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xedb8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
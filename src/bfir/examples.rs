@@ -0,0 +1,59 @@
+// `--examples` and `--example NAME`: classic Brainfuck programs built into bfir.
+//
+// The programs are the files in `programs/`, each with the output it should print (`.out`) and,
+// if it reads any, the input it is tested with (`.in`). `tests/classics.rs` runs them all against
+// these, so they guard the interpreter while it changes, and `--example` runs one without a copy
+// of the file at hand:
+//
+//     bfir --examples
+//     bfir --example sierpinski
+//     echo 'Hello' | bfir --example rot13
+
+pub struct Example {
+    pub name: &'static str,
+    pub about: &'static str,
+    pub source: &'static str,
+}
+
+pub const EXAMPLES: &[Example] = &[
+    Example { name: "hello", about: "prints Hello World!", source: include_str!("../../programs/hello.bf") },
+    Example {
+        name: "rot13",
+        about: "reads a line and prints it with each letter moved 13 places in the alphabet",
+        source: include_str!("../../programs/rot13.bf"),
+    },
+    Example {
+        name: "squares",
+        about: "prints the squares from 0 to 225 in decimal",
+        source: include_str!("../../programs/squares.bf"),
+    },
+    Example {
+        name: "sierpinski",
+        about: "draws a Sierpinski triangle of 32 lines, by Daniel B. Cristofani",
+        source: include_str!("../../programs/sierpinski.bf"),
+    },
+    Example {
+        name: "cellsize",
+        about: "prints how many bits a cell has: 8, 16 or 32",
+        source: include_str!("../../programs/cellsize.bf"),
+    },
+    Example {
+        name: "mandelbrot",
+        about: "draws a Mandelbrot set of 19 lines in fixed point",
+        source: include_str!("../../programs/mandelbrot.bf"),
+    },
+];
+
+/// The example called `name`.
+pub fn find(name: &str) -> Result<&'static Example, String> {
+    EXAMPLES.iter().find(|example| example.name == name).ok_or_else(|| {
+        let names: Vec<&str> = EXAMPLES.iter().map(|example| example.name).collect();
+        format!("no example called '{}'; the examples are {}", name, names.join(", "))
+    })
+}
+
+/// The names of the examples with what each does, one per line.
+pub fn list() -> String {
+    let width = EXAMPLES.iter().map(|example| example.name.len()).max().unwrap_or(0);
+    EXAMPLES.iter().map(|example| format!("{:<width$}  {}\n", example.name, example.about, width = width)).collect()
+}
@@ -0,0 +1,616 @@
+// Terminal colors for bfir's reports.
+//
+// A `Style` is a foreground and background color and text attributes. A `Theme` gives the style of
+// each role text plays in the output, such as an error title, the cell at the pointer or a loop
+// bracket at some depth, so every report colors the same things the same way. Code asks the
+// theme for a role and never names a color itself:
+//
+//     let red = theme.start(Role::Error);
+//     format!("{}error{}: {}", red, theme.reset(), message)
+//
+// The escape sequences depend on what the terminal supports, the theme's `HighlightMode` (see
+// `brainfucktool::term::choose`); with `HighlightMode::None` every style is empty.
+//
+// `--theme` picks one of the built-in themes in `THEMES` or loads a TOML file that changes some
+// roles of one of them, for example:
+//
+//     base = "solarized"            # the theme to start from; "default" if not given
+//     error = { fg = "#ff5555", bold = true }
+//     pointer = { bg = "#444444", underline = true }
+//     brackets = [{ fg = "#e5c07b" }, { fg = "#c678dd" }, { fg = "#56b6c2" }]
+//     dump-gradient = ["#000000", "#ffffff"]   # the background of cells holding 0 and 255
+//
+// `diff` shows how one output differs from another in the theme's `added`, `removed` and `changed`
+// styles, as lines for text and as a hex dump for anything else.
+//
+// The keys are the roles in kebab-case (`op-arith`, `dump-header`, ...), and colors are `#rrggbb`.
+// The 256- and 16-color modes use the nearest color they have.
+
+use brainfucktool::ir::Op;
+pub use brainfucktool::term::HighlightMode;
+use serde::Deserialize;
+use std::fmt::{Display, Write};
+use std::fs;
+
+/// A color as each mode shows it: 24-bit, an index into the 256-color palette, and the SGR code
+/// of the nearest of the 16 colors as a foreground (30-37, 90-97).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Color {
+    pub rgb: (u8, u8, u8),
+    pub ansi256: u8,
+    pub ansi16: u8,
+}
+
+impl Color {
+    pub const fn new(rgb: (u8, u8, u8), ansi256: u8, ansi16: u8) -> Color {
+        Color { rgb, ansi256, ansi16 }
+    }
+
+    /// The color `#rrggbb`, with the nearest colors of the smaller palettes.
+    pub fn parse(text: &str) -> Result<Color, String> {
+        let hex = text.strip_prefix('#').filter(|hex| hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()));
+        let hex = hex.ok_or_else(|| format!("expected a color as #rrggbb, found '{}'", text))?;
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).expect("checked to be hex digits");
+        Ok(Color::rgb(channel(0), channel(2), channel(4)))
+    }
+
+    /// The color with channels `r`, `g` and `b`, with the nearest colors of the smaller palettes.
+    pub fn rgb(r: u8, g: u8, b: u8) -> Color {
+        // The 6x6x6 cube of the 256-color palette, and the usual values of the 16 colors.
+        let level = |v: u8| (v as u16 * 5 + 127) / 255;
+        let ansi256 = (16 + 36 * level(r) + 6 * level(g) + level(b)) as u8;
+        let distance = |&(_, (x, y, z)): &(u8, (u8, u8, u8))| {
+            let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+            d(r, x) + d(g, y) + d(b, z)
+        };
+        let (ansi16, _) = ANSI16.iter().min_by_key(|c| distance(c)).expect("the palette is not empty");
+        Color::new((r, g, b), ansi256, *ansi16)
+    }
+
+    /// The color `t`/255 of the way from `self` to `other`.
+    pub fn mix(&self, other: Color, t: u8) -> Color {
+        // The ends keep their own nearest colors, which a palette may have picked by hand.
+        match t {
+            0 => return *self,
+            255 => return other,
+            _ => {}
+        }
+        let channel = |a: u8, b: u8| ((a as u32 * (255 - t as u32) + b as u32 * t as u32) / 255) as u8;
+        let ((r1, g1, b1), (r2, g2, b2)) = (self.rgb, other.rgb);
+        Color::rgb(channel(r1, r2), channel(g1, g2), channel(b1, b2))
+    }
+
+    // Whether dark text reads better on the color than light text.
+    fn is_light(&self) -> bool {
+        let (r, g, b) = self.rgb;
+        299 * r as u32 + 587 * g as u32 + 114 * b as u32 > 128_000
+    }
+
+    // The escape sequence selecting the color, as a background if `background`.
+    fn escape(&self, mode: HighlightMode, background: bool) -> String {
+        let layer = if background { 48 } else { 38 };
+        let (r, g, b) = self.rgb;
+        match mode {
+            HighlightMode::None => String::new(),
+            HighlightMode::Color16 => format!("\x1b[{}m", self.ansi16 + if background { 10 } else { 0 }),
+            HighlightMode::Color256 => format!("\x1b[{};5;{}m", layer, self.ansi256),
+            HighlightMode::TrueColor => format!("\x1b[{};2;{};{};{}m", layer, r, g, b),
+        }
+    }
+}
+
+// The 16 colors as terminals commonly show them, by foreground code.
+const ANSI16: [(u8, (u8, u8, u8)); 16] = [
+    (30, (0, 0, 0)),
+    (31, (205, 0, 0)),
+    (32, (0, 205, 0)),
+    (33, (205, 205, 0)),
+    (34, (0, 0, 238)),
+    (35, (205, 0, 205)),
+    (36, (0, 205, 205)),
+    (37, (229, 229, 229)),
+    (90, (127, 127, 127)),
+    (91, (255, 0, 0)),
+    (92, (0, 255, 0)),
+    (93, (255, 255, 0)),
+    (94, (92, 92, 255)),
+    (95, (255, 0, 255)),
+    (96, (0, 255, 255)),
+    (97, (255, 255, 255)),
+];
+
+/// Text attributes besides color.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Attrs {
+    pub bold: bool,
+    pub underline: bool,
+}
+
+/// How a piece of text looks. Built up from `Style::new()`, e.g. `Style::new().fg(RED).bold()`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Style {
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub attrs: Attrs,
+}
+
+impl Style {
+    pub const fn new() -> Style {
+        Style { fg: None, bg: None, attrs: Attrs { bold: false, underline: false } }
+    }
+
+    pub const fn fg(self, color: Color) -> Style {
+        Style { fg: Some(color), ..self }
+    }
+
+    pub const fn bg(self, color: Color) -> Style {
+        Style { bg: Some(color), ..self }
+    }
+
+    pub const fn bold(self) -> Style {
+        Style { attrs: Attrs { bold: true, ..self.attrs }, ..self }
+    }
+
+    pub const fn underline(self) -> Style {
+        Style { attrs: Attrs { underline: true, ..self.attrs }, ..self }
+    }
+
+    /// The escape sequence that switches to this style.
+    pub fn escape(&self, mode: HighlightMode) -> String {
+        if mode == HighlightMode::None {
+            return String::new();
+        }
+        let mut out = String::new();
+        if self.attrs.bold {
+            out += "\x1b[1m";
+        }
+        if self.attrs.underline {
+            out += "\x1b[4m";
+        }
+        if let Some(fg) = self.fg {
+            out += &fg.escape(mode, false);
+        }
+        if let Some(bg) = self.bg {
+            out += &bg.escape(mode, true);
+        }
+        out
+    }
+}
+
+/// The colors of the default theme.
+pub mod palette {
+    use super::Color;
+
+    pub const RED: Color = Color::new((250, 80, 50), 196, 31);
+    pub const GREEN: Color = Color::new((100, 230, 60), 82, 32);
+    pub const YELLOW: Color = Color::new((240, 230, 0), 11, 33);
+    pub const BLUE: Color = Color::new((50, 50, 255), 27, 34);
+    pub const PINK: Color = Color::new((250, 105, 200), 207, 35);
+    pub const LIGHTBLUE: Color = Color::new((53, 255, 255), 153, 94);
+    pub const BRACKETS: [Color; 6] = [
+        Color::new((164, 219, 211), 196, 91),
+        Color::new((217, 201, 145), 202, 92),
+        Color::new((145, 189, 217), 208, 93),
+        Color::new((217, 187, 145), 214, 94),
+        Color::new((132, 137, 140), 220, 95),
+        Color::new((200, 160, 220), 226, 96),
+    ];
+    // Backgrounds.
+    pub const DARK_BLUE: Color = Color::new((20, 30, 60), 18, 34);
+    pub const DARK_ORANGE: Color = Color::new((70, 40, 10), 95, 33);
+    pub const PALE_BLUE: Color = Color::new((215, 225, 255), 189, 97);
+
+    /// Solarized, by Ethan Schoonover.
+    pub mod solarized {
+        use super::Color;
+
+        pub const BASE03: Color = Color::new((0, 43, 54), 234, 30);
+        pub const BASE02: Color = Color::new((7, 54, 66), 235, 30);
+        pub const BASE01: Color = Color::new((88, 110, 117), 240, 90);
+        pub const BASE3: Color = Color::new((253, 246, 227), 230, 97);
+        pub const YELLOW: Color = Color::new((181, 137, 0), 136, 33);
+        pub const ORANGE: Color = Color::new((203, 75, 22), 166, 91);
+        pub const RED: Color = Color::new((220, 50, 47), 160, 31);
+        pub const MAGENTA: Color = Color::new((211, 54, 130), 125, 35);
+        pub const VIOLET: Color = Color::new((108, 113, 196), 61, 95);
+        pub const BLUE: Color = Color::new((38, 139, 210), 33, 34);
+        pub const CYAN: Color = Color::new((42, 161, 152), 37, 36);
+        pub const GREEN: Color = Color::new((133, 153, 0), 64, 32);
+    }
+}
+
+/// The names of the built-in themes: the usual colors, Solarized, and one with only bold and
+/// underlined text for terminals whose colors are hard to read.
+pub const THEMES: [&str; 3] = ["default", "solarized", "mono"];
+
+/// What a piece of text is, which decides its style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    /// `+` and `-`.
+    OpArith,
+    /// `<`, `>` and tape switches.
+    OpMove,
+    /// `.`, `,` and the memory dump.
+    OpIo,
+    /// A loop bracket nested in N others.
+    BracketDepth(usize),
+    Comment,
+    /// The cell numbers of a memory dump.
+    DumpHeader,
+    /// A cell of a memory dump holding the value, shaded by it in the 256-color and 24-bit modes.
+    DumpCell(u8),
+    /// The cell at the data pointer.
+    Pointer,
+    Error,
+    Warning,
+    /// The frame of a source excerpt: the arrow, the gutter and the notes.
+    Gutter,
+    /// What a diff adds and removes, and the bytes it changes in place.
+    Added,
+    Removed,
+    Changed,
+}
+
+impl Role {
+    /// The role of an op, which is `depth` loops deep.
+    pub fn of(op: &Op, depth: usize) -> Role {
+        match op {
+            Op::Add(_) | Op::Ext(_) => Role::OpArith,
+            Op::Move(_) | Op::Tape(_) => Role::OpMove,
+            Op::Output | Op::Input | Op::Dump => Role::OpIo,
+            Op::Open(_) | Op::Close(_) => Role::BracketDepth(depth),
+            Op::Comment(_) => Role::Comment,
+        }
+    }
+}
+
+/// The style of every role, and the mode they are shown in.
+pub struct Theme {
+    pub mode: HighlightMode,
+    pub op_arith: Style,
+    pub op_move: Style,
+    pub op_io: Style,
+    pub brackets: Vec<Style>, // By depth, repeating.
+    pub comment: Style,
+    pub dump_header: Style,
+    pub dump_cell: Style,
+    pub dump_gradient: Option<[Color; 2]>, // The background of cells holding 0 and 255.
+    pub pointer: Style,
+    pub error: Style,
+    pub warning: Style,
+    pub gutter: Style,
+    pub added: Style,
+    pub removed: Style,
+    pub changed: Style,
+}
+
+impl Theme {
+    /// The default theme, shown in `mode`.
+    pub fn new(mode: HighlightMode) -> Theme {
+        use palette::*;
+        Theme {
+            mode,
+            op_arith: Style::new().fg(GREEN),
+            op_move: Style::new().fg(LIGHTBLUE),
+            op_io: Style::new().fg(PINK),
+            brackets: BRACKETS.iter().map(|&color| Style::new().fg(color)).collect(),
+            comment: Style::new().fg(GREEN),
+            dump_header: Style::new().bg(DARK_BLUE),
+            dump_cell: Style::new().bg(DARK_BLUE),
+            dump_gradient: Some([DARK_BLUE, PALE_BLUE]),
+            pointer: Style::new().bg(DARK_ORANGE),
+            error: Style::new().fg(RED).bold(),
+            warning: Style::new().fg(YELLOW),
+            gutter: Style::new().fg(BLUE),
+            added: Style::new().fg(GREEN),
+            removed: Style::new().fg(RED),
+            changed: Style::new().fg(YELLOW),
+        }
+    }
+
+    /// The built-in theme `name`, shown in `mode`.
+    pub fn builtin(name: &str, mode: HighlightMode) -> Option<Theme> {
+        use palette::solarized::*;
+        let plain = Style::new();
+        match name {
+            "default" => Some(Theme::new(mode)),
+            "solarized" => Some(Theme {
+                mode,
+                op_arith: plain.fg(GREEN),
+                op_move: plain.fg(CYAN),
+                op_io: plain.fg(MAGENTA),
+                brackets: [YELLOW, ORANGE, VIOLET, BLUE, CYAN, GREEN].iter().map(|&color| plain.fg(color)).collect(),
+                comment: plain.fg(BASE01),
+                dump_header: plain.bg(BASE02),
+                dump_cell: plain.bg(BASE02),
+                dump_gradient: Some([BASE03, BASE3]),
+                pointer: plain.fg(BASE03).bg(YELLOW),
+                error: plain.fg(RED).bold(),
+                warning: plain.fg(YELLOW),
+                gutter: plain.fg(BLUE),
+                added: plain.fg(GREEN),
+                removed: plain.fg(RED),
+                changed: plain.fg(YELLOW),
+            }),
+            "mono" => Some(Theme {
+                mode,
+                op_arith: plain,
+                op_move: plain,
+                op_io: plain.bold(),
+                brackets: vec![plain],
+                comment: plain,
+                dump_header: plain.underline(),
+                dump_cell: plain,
+                dump_gradient: None,
+                pointer: plain.bold().underline(),
+                error: plain.bold(),
+                warning: plain.bold(),
+                gutter: plain,
+                added: plain.bold(),
+                removed: plain.underline(),
+                changed: plain.bold().underline(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Loads `--theme`: the name of a built-in theme, or a TOML file that changes one.
+    pub fn load(spec: &str, mode: HighlightMode) -> Result<Theme, String> {
+        if let Some(theme) = Theme::builtin(spec, mode) {
+            return Ok(theme);
+        }
+        let text = fs::read_to_string(spec).map_err(|e| {
+            format!("{}: {} (the built-in themes are {})", spec, e, THEMES.join(", "))
+        })?;
+        let file: ThemeFile = toml::from_str(&text).map_err(|e| format!("{}: {}", spec, e))?;
+        let base = file.base.as_deref().unwrap_or("default");
+        let mut theme = Theme::builtin(base, mode).ok_or_else(|| format!("{}: unknown base theme '{}'", spec, base))?;
+        let styles = [
+            (&mut theme.op_arith, file.op_arith),
+            (&mut theme.op_move, file.op_move),
+            (&mut theme.op_io, file.op_io),
+            (&mut theme.comment, file.comment),
+            (&mut theme.dump_header, file.dump_header),
+            (&mut theme.dump_cell, file.dump_cell),
+            (&mut theme.pointer, file.pointer),
+            (&mut theme.error, file.error),
+            (&mut theme.warning, file.warning),
+            (&mut theme.gutter, file.gutter),
+            (&mut theme.added, file.added),
+            (&mut theme.removed, file.removed),
+            (&mut theme.changed, file.changed),
+        ];
+        let in_file = |e| format!("{}: {}", spec, e);
+        for (style, spec_style) in styles {
+            if let Some(spec_style) = spec_style {
+                *style = spec_style.style().map_err(in_file)?;
+            }
+        }
+        if let Some(gradient) = file.dump_gradient {
+            let [dark, bright] = gradient.map(|color| Color::parse(&color).map_err(in_file));
+            theme.dump_gradient = Some([dark?, bright?]);
+        }
+        if let Some(brackets) = file.brackets {
+            theme.brackets = brackets.iter().map(StyleSpec::style).collect::<Result<_, _>>().map_err(in_file)?;
+        }
+        Ok(theme)
+    }
+
+    pub fn style(&self, role: Role) -> Style {
+        match role {
+            Role::OpArith => self.op_arith,
+            Role::OpMove => self.op_move,
+            Role::OpIo => self.op_io,
+            Role::BracketDepth(depth) => self.brackets.get(depth % self.brackets.len().max(1)).copied().unwrap_or_default(),
+            Role::Comment => self.comment,
+            Role::DumpHeader => self.dump_header,
+            Role::DumpCell(value) => match (self.mode, self.dump_gradient) {
+                (HighlightMode::Color256 | HighlightMode::TrueColor, Some([dark, bright])) => {
+                    let bg = dark.mix(bright, value);
+                    let fg = if bg.is_light() { Color::rgb(0, 0, 0) } else { Color::rgb(255, 255, 255) };
+                    Style { fg: Some(fg), bg: Some(bg), ..self.dump_cell }
+                }
+                _ => self.dump_cell,
+            },
+            Role::Pointer => self.pointer,
+            Role::Error => self.error,
+            Role::Warning => self.warning,
+            Role::Gutter => self.gutter,
+            Role::Added => self.added,
+            Role::Removed => self.removed,
+            Role::Changed => self.changed,
+        }
+    }
+
+    /// The escape sequence that starts text in `role`.
+    pub fn start(&self, role: Role) -> String {
+        self.style(role).escape(self.mode)
+    }
+
+    /// The escape sequence that ends styled text.
+    pub fn reset(&self) -> String {
+        match self.mode {
+            HighlightMode::None => String::new(),
+            _ => "\x1b[0m".to_string(),
+        }
+    }
+
+    /// `text` in the style of `role`.
+    pub fn paint(&self, role: Role, text: impl Display) -> String {
+        format!("{}{}{}", self.start(role), text, self.reset())
+    }
+}
+
+// A theme file: the theme it starts from and the styles it changes.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+struct ThemeFile {
+    base: Option<String>,
+    op_arith: Option<StyleSpec>,
+    op_move: Option<StyleSpec>,
+    op_io: Option<StyleSpec>,
+    brackets: Option<Vec<StyleSpec>>,
+    comment: Option<StyleSpec>,
+    dump_header: Option<StyleSpec>,
+    dump_cell: Option<StyleSpec>,
+    dump_gradient: Option<[String; 2]>,
+    pointer: Option<StyleSpec>,
+    error: Option<StyleSpec>,
+    warning: Option<StyleSpec>,
+    gutter: Option<StyleSpec>,
+    added: Option<StyleSpec>,
+    removed: Option<StyleSpec>,
+    changed: Option<StyleSpec>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StyleSpec {
+    fg: Option<String>,
+    bg: Option<String>,
+    #[serde(default)]
+    bold: bool,
+    #[serde(default)]
+    underline: bool,
+}
+
+impl StyleSpec {
+    fn style(&self) -> Result<Style, String> {
+        let mut style = Style::new();
+        style.fg = self.fg.as_deref().map(Color::parse).transpose()?;
+        style.bg = self.bg.as_deref().map(Color::parse).transpose()?;
+        style.attrs = Attrs { bold: self.bold, underline: self.underline };
+        Ok(style)
+    }
+}
+
+// Rows of a hex dump diff shown before the rest are only counted.
+const MAX_ROWS: usize = 20;
+// Bytes in a row of a hex dump.
+const ROW: usize = 16;
+
+/// How output `new` differs from `old`, or "" if they are the same. Text is shown as the lines
+/// that differ, marked `-` when only in `old` and `+` when only in `new`; other output as the rows
+/// of a hex dump that differ, with the changed bytes marked.
+pub fn diff(old: &[u8], new: &[u8], theme: &Theme) -> String {
+    let mut out = String::new();
+    if old == new {
+        return out;
+    }
+    if !is_text(old) || !is_text(new) {
+        return hex_diff(old, new, theme);
+    }
+    let (old, new) = (lines(old), lines(new));
+    let (first, changes) = line_diff(&old, &new);
+    let _ = writeln!(out, "from line {}:", first + 1);
+    for (sign, line) in changes {
+        let text = format!("{} {}", sign, line);
+        let _ = match sign {
+            '-' => writeln!(out, "{}", theme.paint(Role::Removed, text)),
+            '+' => writeln!(out, "{}", theme.paint(Role::Added, text)),
+            _ => writeln!(out, "{}", text),
+        };
+    }
+    out
+}
+
+// Whether `output` reads as text: UTF-8 with no control characters but line breaks and tabs.
+fn is_text(output: &[u8]) -> bool {
+    std::str::from_utf8(output).is_ok_and(|text| text.chars().all(|c| !c.is_control() || "\n\r\t".contains(c)))
+}
+
+// The rows of `old` and `new` that differ, each as a `-` and a `+` line of offset, bytes and the
+// bytes as ASCII. Bytes on both sides that differ are `changed`; bytes past the end of the other
+// side are `removed` or `added`.
+fn hex_diff(old: &[u8], new: &[u8], theme: &Theme) -> String {
+    let mut out = String::new();
+    let rows: Vec<usize> = (0..old.len().max(new.len()).div_ceil(ROW))
+        .filter(|&row| (row * ROW..(row + 1) * ROW).any(|i| old.get(i) != new.get(i)))
+        .collect();
+    let _ = writeln!(out, "bytes, from offset {:#x}:", rows[0] * ROW);
+    for &row in rows.iter().take(MAX_ROWS) {
+        for (sign, bytes, other, only) in [('-', old, new, Role::Removed), ('+', new, old, Role::Added)] {
+            let (mut hex, mut ascii) = (String::new(), String::new());
+            for i in row * ROW..(row + 1) * ROW {
+                let Some(&byte) = bytes.get(i) else {
+                    hex += "   ";
+                    continue;
+                };
+                let c = if byte.is_ascii_graphic() || byte == b' ' { byte as char } else { '.' };
+                let (h, a) = (format!("{:02x}", byte), c.to_string());
+                let (h, a) = match other.get(i) {
+                    Some(&b) if b == byte => (h, a),
+                    Some(_) => (theme.paint(Role::Changed, h), theme.paint(Role::Changed, a)),
+                    None => (theme.paint(only, h), theme.paint(only, a)),
+                };
+                hex += &format!(" {}", h);
+                ascii += &a;
+            }
+            let _ = writeln!(out, "{} {:08x} {}  |{}|", sign, row * ROW, hex, ascii);
+        }
+    }
+    if rows.len() > MAX_ROWS {
+        let _ = writeln!(out, "... and {} more rows", rows.len() - MAX_ROWS);
+    }
+    out
+}
+
+// The output split into lines, with control characters escaped.
+fn lines(output: &[u8]) -> Vec<String> {
+    let escape = |line: &str| {
+        line.chars()
+            .map(|c| if c.is_control() { c.escape_default().to_string() } else { c.to_string() })
+            .collect::<String>()
+    };
+    String::from_utf8_lossy(output)
+        .split_inclusive('\n')
+        .map(|line| match line.strip_suffix('\n') {
+            Some(line) => escape(line),
+            None => format!("{} (no newline at end)", escape(line)),
+        })
+        .collect()
+}
+
+// A line diff: the lines both share at each end are dropped, and the rest is matched with a
+// longest common subsequence when small enough, or else shown as replaced wholesale. Returns the
+// index of the first line that differs with the changes from there.
+fn line_diff<'a>(old: &'a [String], new: &'a [String]) -> (usize, Vec<(char, &'a str)>) {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let (old, new) = (&old[prefix..old.len() - suffix], &new[prefix..new.len() - suffix]);
+    let mut changes = Vec::new();
+    if old.len() * new.len() > 1_000_000 {
+        changes.extend(old.iter().map(|line| ('-', line.as_str())));
+        changes.extend(new.iter().map(|line| ('+', line.as_str())));
+        return (prefix, changes);
+    }
+    // common[i][j]: length of the longest common subsequence of old[i..] and new[j..].
+    let mut common = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = match old[i] == new[j] {
+                true => common[i + 1][j + 1] + 1,
+                false => common[i + 1][j].max(common[i][j + 1]),
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            changes.push((' ', old[i].as_str()));
+            (i, j) = (i + 1, j + 1);
+        } else if j == new.len() || (i < old.len() && common[i + 1][j] >= common[i][j + 1]) {
+            changes.push(('-', old[i].as_str()));
+            i += 1;
+        } else {
+            changes.push(('+', new[j].as_str()));
+            j += 1;
+        }
+    }
+    (prefix, changes)
+}
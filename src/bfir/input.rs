@@ -0,0 +1,209 @@
+// Where `,` takes its bytes from (`--input`).
+//
+// Bytes come from stdin unless `--input random` asks for pseudo-random bytes instead. These come
+// from a generator seeded with `--seed`, so a run can be repeated exactly, for stress tests and
+// for comparing two programs on the same input. Short inputs can also be given on the command
+// line with `--input-string` (C-style escapes) or `--input-hex`, which carry NUL bytes that shell
+// here-strings cannot, or read from a file with `--input-file`. `--input-limit` ends any source
+// after that many bytes, just as the end of stdin does.
+//
+// With `--nonblocking`, a thread reads stdin in the background and `,` takes whatever byte it has
+// read so far; if there is none, or stdin has ended, `,` gives `--idle-value` (0 by default) at
+// once. Programs that react to keys while animating poll the keyboard this way, best together
+// with `--tty-raw`.
+//
+// At the end of the input `,` stops the run with an error, unless `--eof zero` makes it give 0
+// instead, as the reference interpreter, `--test` and the programs `--to` writes do. Code from
+// bfconstructor that reads to the end of a line relies on that to stop at the end of the input.
+//
+// `--tee-input` writes every byte `,` gives the program to a file, as the program saw it (after
+// `-n`), so that an interactive session can be replayed exactly with `--input-file`. The file is
+// written unbuffered so that it is complete even when the run ends in an error or is killed.
+
+use clap::ValueEnum;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+
+/// The source named by `--input`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Source {
+    Stdin,
+    Random,
+}
+
+/// What `,` does at the end of the input (`--eof`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Eof {
+    /// Stop the run with an error
+    Error,
+    /// Set the cell to 0
+    Zero,
+}
+
+/// Input bytes given on the command line.
+#[derive(Debug, Clone)]
+pub struct Literal(pub Vec<u8>);
+
+enum Stream {
+    Stdin,
+    Random(u64), // The splitmix64 state.
+    Literal(std::vec::IntoIter<u8>),
+    Polled { bytes: Receiver<io::Result<u8>>, idle: u8 },
+}
+
+pub struct Input {
+    stream: Stream,
+    left: Option<u64>,
+    tee: Option<File>,
+}
+
+impl Input {
+    pub fn new(source: Source, seed: u64, limit: Option<u64>) -> Input {
+        let stream = match source {
+            Source::Stdin => Stream::Stdin,
+            Source::Random => Stream::Random(seed),
+        };
+        Input { stream, left: limit, tee: None }
+    }
+
+    pub fn literal(bytes: Literal, limit: Option<u64>) -> Input {
+        Input { stream: Stream::Literal(bytes.0.into_iter()), left: limit, tee: None }
+    }
+
+    /// Makes reads from stdin return `idle` instead of waiting when no byte is available.
+    pub fn nonblocking(self, idle: u8) -> Input {
+        if !matches!(self.stream, Stream::Stdin) {
+            return self;
+        }
+        let (sender, bytes) = mpsc::channel();
+        thread::spawn(move || {
+            for byte in io::stdin().lock().bytes() {
+                if sender.send(byte).is_err() {
+                    break;
+                }
+            }
+        });
+        Input { stream: Stream::Polled { bytes, idle }, ..self }
+    }
+
+    /// Records the bytes the program consumes in `file`.
+    pub fn tee(self, file: File) -> Input {
+        Input { tee: Some(file), ..self }
+    }
+
+    /// Notes that the program consumed `byte`, as `read` returned it or translated.
+    pub fn consumed(&mut self, byte: u8) -> io::Result<()> {
+        match &mut self.tee {
+            Some(file) => file.write_all(&[byte]),
+            None => Ok(()),
+        }
+    }
+
+    /// Whether the bytes come from stdin, which may be a terminal.
+    pub fn is_stdin(&self) -> bool {
+        matches!(self.stream, Stream::Stdin | Stream::Polled { .. })
+    }
+
+    /// Reads the next byte, failing with `UnexpectedEof` at the end of the input.
+    pub fn read(&mut self) -> io::Result<u8> {
+        if self.left == Some(0) {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "end of input (--input-limit)"));
+        }
+        let byte = match &mut self.stream {
+            Stream::Stdin => {
+                let mut buffer = [0; 1];
+                io::stdin().read_exact(&mut buffer)?;
+                buffer[0]
+            }
+            Stream::Random(state) => (splitmix64(state) >> 56) as u8,
+            Stream::Literal(bytes) => bytes
+                .next()
+                .ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "end of input"))?,
+            // An idle read delivers no byte, so it does not count towards the limit.
+            Stream::Polled { bytes, idle } => match bytes.try_recv() {
+                Ok(byte) => byte?,
+                Err(_) => return Ok(*idle),
+            },
+        };
+        if let Some(left) = &mut self.left {
+            *left -= 1;
+        }
+        Ok(byte)
+    }
+}
+
+// Advances the generator and returns its next output.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Parses `--input-string`: UTF-8 text with the escapes `\n`, `\t`, `\r`, `\0`, `\a`, `\b`,
+/// `\f`, `\v`, `\\`, `\'`, `\"`, `\xHH` and up to three octal digits `\ooo`.
+pub fn unescape(text: &str) -> Result<Literal, String> {
+    let mut bytes = Vec::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+            continue;
+        }
+        let escape = chars.next().ok_or("trailing '\\'")?;
+        let byte = match escape {
+            'n' => b'\n',
+            't' => b'\t',
+            'r' => b'\r',
+            'a' => 0x07,
+            'b' => 0x08,
+            'f' => 0x0c,
+            'v' => 0x0b,
+            '\\' | '\'' | '"' => escape as u8,
+            'x' => {
+                let digits: String = (0..2).filter_map(|_| chars.next_if(char::is_ascii_hexdigit)).collect();
+                u8::from_str_radix(&digits, 16).map_err(|_| "expected hex digits after '\\x'")?
+            }
+            '0'..='7' => {
+                let mut value = escape.to_digit(8).unwrap();
+                for _ in 0..2 {
+                    match chars.next_if(|c| c.is_digit(8)) {
+                        Some(digit) => value = value * 8 + digit.to_digit(8).unwrap(),
+                        None => break,
+                    }
+                }
+                u8::try_from(value).map_err(|_| format!("octal escape {:o} is larger than a byte", value))?
+            }
+            other => return Err(format!("unknown escape '\\{}'", other)),
+        };
+        bytes.push(byte);
+    }
+    Ok(Literal(bytes))
+}
+
+/// Reads the bytes of `--input-file`.
+pub fn read_file(path: &str) -> Result<Literal, String> {
+    fs::read(path).map(Literal).map_err(|e| e.to_string())
+}
+
+/// Parses `--input-hex`: pairs of hex digits, optionally separated by whitespace.
+pub fn unhex(text: &str) -> Result<Literal, String> {
+    let digits: Vec<char> = text.chars().filter(|c| !c.is_whitespace()).collect();
+    if let Some(c) = digits.iter().find(|c| !c.is_ascii_hexdigit()) {
+        return Err(format!("'{}' is not a hex digit", c));
+    }
+    if !digits.len().is_multiple_of(2) {
+        return Err("expected an even number of hex digits".to_string());
+    }
+    let bytes = digits
+        .chunks(2)
+        .map(|pair| {
+            let pair: String = pair.iter().collect();
+            u8::from_str_radix(&pair, 16).expect("checked to be hex digits")
+        })
+        .collect::<Vec<u8>>();
+    Ok(Literal(bytes))
+}
@@ -0,0 +1,150 @@
+// `--inspect`: a prompt for looking at the tapes after the program halts, whether it finished or
+// failed. Commands are read from stdin; prompts and answers go to stderr, after the program's
+// output.
+//
+//     p [RANGE]       the cells in RANGE in decimal (the 16 around the pointer by default)
+//     x [RANGE]       the cells in RANGE as hex and ASCII, 16 to a row
+//     s PATTERN       where the bytes of PATTERN start: "text" (with \n, \t, \\ and \") or hex
+//                     bytes such as `48 69`
+//     t N             look at tape N instead (with --tapes)
+//     help            list the commands
+//     q               quit (as does the end of input)
+//
+// A RANGE is `N`, `A..B` (B excluded) or `A..` (to the end of the tape).
+
+use crate::highlight::{Role, Theme};
+use crate::tapes::TapeSet;
+use std::io::{self, BufRead, Write};
+use std::ops::Range;
+
+const ROW: usize = 16; // Cells in a row of `p` and `x`.
+const MAX_MATCHES: usize = 20; // Matches of `s` listed before the rest are only counted.
+
+const HELP: &str = "\
+p [RANGE]    print cells in decimal (around the pointer by default)
+x [RANGE]    print cells as hex and ASCII
+s PATTERN    search for \"text\" or hex bytes like 48 69
+t N          switch to tape N
+q            quit
+RANGE is N, A..B or A..
+";
+
+/// Answers commands from `input` until `q` or its end.
+pub fn run(input: impl BufRead, out: &mut impl Write, tapes: &TapeSet, theme: &Theme) -> io::Result<()> {
+    let all: Vec<(&[u8], usize)> = tapes.tapes().collect();
+    let mut current = tapes.current();
+    writeln!(out, "[inspect] the program halted; `help` lists the commands")?;
+    let mut lines = input.lines();
+    loop {
+        write!(out, "inspect> ")?;
+        out.flush()?;
+        let Some(line) = lines.next() else {
+            writeln!(out)?;
+            break;
+        };
+        let line = line?;
+        let (tape, ptr) = all[current];
+        let command = line.trim();
+        let (name, arg) = command.split_once(' ').map_or((command, ""), |(name, arg)| (name, arg.trim()));
+        let answer = match name {
+            "" => continue,
+            "q" | "quit" => break,
+            "help" | "h" => Ok(HELP.to_string()),
+            "p" => range(arg, tape.len(), ptr).map(|range| decimal(tape, ptr, range, theme)),
+            "x" => range(arg, tape.len(), ptr).map(|range| hex(tape, ptr, range, theme)),
+            "s" => pattern(arg).map(|pattern| search(tape, &pattern)),
+            "t" => match arg.parse::<usize>() {
+                Ok(n) if n < all.len() => {
+                    current = n;
+                    Ok(format!("tape {}, pointer at {}\n", n, all[n].1))
+                }
+                _ => Err(format!("expected a tape from 0 to {}", all.len() - 1)),
+            },
+            _ => Err(format!("unknown command '{}' (try `help`)", name)),
+        };
+        match answer {
+            Ok(text) => write!(out, "{}", text)?,
+            Err(e) => writeln!(out, "{}: {}", theme.paint(Role::Error, "error"), e)?,
+        }
+    }
+    Ok(())
+}
+
+// The cells `arg` names on a tape of `len` cells, or the 16 around `ptr` if it is empty.
+fn range(arg: &str, len: usize, ptr: usize) -> Result<Range<usize>, String> {
+    let number = |text: &str| text.trim().parse::<usize>().map_err(|_| format!("expected a cell number, found '{}'", text));
+    let range = match arg.split_once("..") {
+        _ if arg.is_empty() => {
+            let start = ptr.saturating_sub(ROW / 2);
+            start..(start + ROW).min(len)
+        }
+        None => number(arg).map(|n| n..n + 1)?,
+        Some((start, "")) => number(start)?..len,
+        Some((start, end)) => number(start)?..number(end)?,
+    };
+    match range {
+        Range { start, end } if start >= end => Err("the range is empty".to_string()),
+        Range { end, .. } if end > len => Err(format!("the tape has {} cells", len)),
+        range => Ok(range),
+    }
+}
+
+// The bytes of a `s` pattern.
+fn pattern(arg: &str) -> Result<Vec<u8>, String> {
+    let bytes = match arg.strip_prefix('"').and_then(|text| text.strip_suffix('"')) {
+        Some(text) => text.replace("\\n", "\n").replace("\\t", "\t").replace("\\\"", "\"").replace("\\\\", "\\").into_bytes(),
+        None => arg
+            .split_whitespace()
+            .map(|byte| u8::from_str_radix(byte, 16).map_err(|_| format!("expected \"text\" or hex bytes, found '{}'", byte)))
+            .collect::<Result<_, _>>()?,
+    };
+    match bytes.is_empty() {
+        true => Err("expected \"text\" or hex bytes to search for".to_string()),
+        false => Ok(bytes),
+    }
+}
+
+fn decimal(tape: &[u8], ptr: usize, range: Range<usize>, theme: &Theme) -> String {
+    let mut out = String::new();
+    for row in range.clone().step_by(ROW) {
+        out += &format!("{:>6}:", row);
+        for (i, value) in tape.iter().enumerate().take((row + ROW).min(range.end)).skip(row) {
+            let cell = format!("{:>4}", value);
+            out += &if i == ptr { theme.paint(Role::Pointer, cell) } else { cell };
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn hex(tape: &[u8], ptr: usize, range: Range<usize>, theme: &Theme) -> String {
+    let mut out = String::new();
+    for row in range.clone().step_by(ROW) {
+        let cells = row..(row + ROW).min(range.end);
+        out += &format!("{:>6}:", row);
+        for (i, value) in tape.iter().enumerate().take(cells.end).skip(row) {
+            let cell = format!(" {:02x}", value);
+            out += &if i == ptr { theme.paint(Role::Pointer, cell) } else { cell };
+        }
+        out += &"   ".repeat(ROW - cells.len());
+        let ascii: String = tape[cells].iter().map(|&b| if b.is_ascii_graphic() || b == b' ' { b as char } else { '.' }).collect();
+        out += &format!("  |{}|\n", ascii);
+    }
+    out
+}
+
+fn search(tape: &[u8], pattern: &[u8]) -> String {
+    let found: Vec<usize> = tape.windows(pattern.len()).enumerate().filter(|(_, w)| *w == pattern).map(|(i, _)| i).collect();
+    let mut out = match found.len() {
+        0 => "not found".to_string(),
+        1 => "found at".to_string(),
+        n => format!("found {} times, at", n),
+    };
+    for i in found.iter().take(MAX_MATCHES) {
+        out += &format!(" {}", i);
+    }
+    if found.len() > MAX_MATCHES {
+        out += " ...";
+    }
+    out + "\n"
+}
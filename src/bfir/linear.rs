@@ -0,0 +1,102 @@
+// Balanced loops, run in closed form.
+//
+// A loop whose body only adds constants to cells and moves the pointer back to where it started,
+// changing its own cell by exactly 1 per iteration, like `[->+>++<<]` or `[->+>>+++<<<<+>>]`, runs
+// as many times as its cell says (the value itself for -1, 256 minus it for +1), and every
+// iteration adds the same amounts to the same cells. The interpreter applies them all at once as
+// multiply-adds, `cell[1] += n; cell[2] += 2 * n; cell[0] = 0`, instead of running the n
+// iterations, and counts the steps they would have taken. A body may add to any number of cells,
+// to one cell in several places, and pass over cells it does not change.
+//
+// Whenever that would not do the same as running the iterations, the loop runs one iteration at a
+// time: when a cell would wrap with `-w` or the pointer would leave the tape, so the error is
+// reported where it happens, and while something watches every step (see `interprete`).
+
+use brainfucktool::ir::{Inst, Op};
+use std::collections::BTreeMap;
+
+/// What one iteration of a balanced loop does, relative to its cell.
+pub struct Linear {
+    step: i32,             // What an iteration adds to the loop cell: 1 or -1.
+    adds: Vec<(i32, i32)>, // What an iteration adds to each other cell, by offset.
+    reach: (i32, i32),     // The lowest and highest offsets the pointer visits.
+    monotonic: bool,       // Whether each cell only goes one way within an iteration.
+    pub steps: u64,        // The steps an iteration takes, its body and the `]`.
+}
+
+/// The loop opened at `open` in closed form, or why it has none.
+pub fn analyze(program: &[Inst], open: usize) -> Result<Linear, String> {
+    let Op::Open(close) = program[open].op else {
+        unreachable!("loops are analyzed at their `[`")
+    };
+    let mut offset = 0i32;
+    let mut reach = (0, 0);
+    let mut adds: BTreeMap<i32, (i32, bool, bool)> = BTreeMap::new(); // Net, up, down.
+    for inst in &program[open + 1..close] {
+        match inst.op {
+            Op::Add(n) => {
+                let (net, up, down) = adds.entry(offset).or_default();
+                *net += n;
+                *up |= n > 0;
+                *down |= n < 0;
+            }
+            Op::Move(n) => {
+                offset += n;
+                reach = (reach.0.min(offset), reach.1.max(offset));
+            }
+            Op::Open(_) => return Err("contains another loop".to_string()),
+            Op::Output | Op::Input => return Err("reads or writes a byte".to_string()),
+            Op::Dump => return Err("contains a memory dump".to_string()),
+            Op::Comment(_) => return Err("prints a comment".to_string()),
+            Op::Tape(_) => return Err("switches tapes".to_string()),
+            Op::Ext(_) => return Err("uses an Extended Brainfuck command".to_string()),
+            Op::Close(_) => unreachable!("the loop's own `]` is not scanned"),
+        }
+    }
+    let monotonic = adds.values().all(|&(_, up, down)| !(up && down));
+    let step = adds.remove(&0).map_or(0, |(net, _, _)| net);
+    match (offset, step) {
+        (0, -1 | 1) => {}
+        (0, 0) => return Err("does not change the loop cell".to_string()),
+        (0, step) => return Err(format!("changes the loop cell by {:+}, not by 1, per iteration", step)),
+        (offset, _) => return Err(format!("moves the pointer by {:+} per iteration", offset)),
+    }
+    Ok(Linear {
+        step,
+        monotonic,
+        adds: adds.into_iter().filter(|&(_, (net, _, _))| net != 0).map(|(offset, (net, _, _))| (offset, net)).collect(),
+        reach,
+        steps: (close - open) as u64,
+    })
+}
+
+/// The closed form of every balanced loop, at the index of its `[`.
+pub fn plan(program: &[Inst]) -> Vec<Option<Linear>> {
+    (0..program.len()).map(|i| matches!(program[i].op, Op::Open(_)).then(|| analyze(program, i).ok()).flatten()).collect()
+}
+
+impl Linear {
+    /// Runs the loop on `tape` with its cell at `ptr`, which is not 0, and returns how many times
+    /// it iterated; or returns `None`, changing nothing, if it must run one iteration at a time.
+    pub fn run(&self, tape: &mut [u8], ptr: usize, nowrap: bool) -> Option<u64> {
+        let (low, high) = (ptr as i64 + self.reach.0 as i64, ptr as i64 + self.reach.1 as i64);
+        if low < 0 || high >= tape.len() as i64 || (nowrap && (!self.monotonic || self.step > 0)) {
+            return None;
+        }
+        let n = match self.step {
+            -1 => tape[ptr] as i64,
+            _ => 256 - tape[ptr] as i64,
+        };
+        let at = |offset: i32| (ptr as i64 + offset as i64) as usize;
+        let value = |cell: u8, add: i32| cell as i64 + n * add as i64;
+        if nowrap && self.adds.iter().any(|&(offset, add)| !(0..=255).contains(&value(tape[at(offset)], add))) {
+            return None;
+        }
+        for &(offset, add) in &self.adds {
+            let cell = &mut tape[at(offset)];
+            *cell = value(*cell, add).rem_euclid(256) as u8;
+        }
+        tape[ptr] = 0;
+        Some(n as u64)
+    }
+}
@@ -0,0 +1,92 @@
+// `--loop-report`: which loops the run spent its time in, and why an optimizer could not turn
+// them into straight-line code.
+//
+// A loop that only adds to cells at fixed offsets, returns the pointer to where it started and
+// changes its own cell by exactly 1 per iteration, like `[->+>++<<]`, can be lowered to a few
+// multiply-adds (`cell[1] += cell[0]; cell[2] += 2 * cell[0]; cell[0] = 0`), however many times
+// it would have iterated. Every other loop runs one iteration at a time. The report lists the
+// loops of the second kind that ran, by iterations, with the reason, so the hot ones can be
+// restructured; the loops of the first kind are only counted. The interpreter runs those in
+// closed form (see `linear`).
+
+use crate::diagnostic::line_col;
+use crate::highlight::{Role, Theme};
+use crate::linear;
+use brainfucktool::ir::Inst;
+use std::fmt::Write;
+
+// Loops listed before the rest are only counted.
+const MAX_LISTED: usize = 20;
+
+/// How often each loop was entered and iterated, indexed by the position of its `[`.
+pub struct LoopProfile {
+    entries: Vec<u64>,
+    iterations: Vec<u64>,
+}
+
+impl LoopProfile {
+    pub fn new(program: &[Inst]) -> LoopProfile {
+        LoopProfile { entries: vec![0; program.len()], iterations: vec![0; program.len()] }
+    }
+
+    /// Counts the first iteration of the loop opened at `open`.
+    pub fn enter(&mut self, open: usize) {
+        self.entries[open] += 1;
+        self.iterations[open] += 1;
+    }
+
+    /// Counts another iteration of the loop opened at `open`.
+    pub fn repeat(&mut self, open: usize) {
+        self.iterations[open] += 1;
+    }
+
+    /// Counts all `n` iterations of the loop opened at `open`, run at once.
+    pub fn run(&mut self, open: usize, n: u64) {
+        self.entries[open] += 1;
+        self.iterations[open] += n;
+    }
+
+    /// Formats the report, locating loops in `src` as `filename:line:col`.
+    pub fn report(&self, program: &[Inst], src: &str, filename: &str, theme: &Theme) -> String {
+        let ran: Vec<usize> = (0..program.len()).filter(|&i| self.entries[i] > 0).collect();
+        let total: u64 = ran.iter().map(|&i| self.iterations[i]).sum();
+        let mut slow: Vec<(usize, String)> =
+            ran.iter().filter_map(|&i| lowering(program, i).err().map(|reason| (i, reason))).collect();
+        slow.sort_by_key(|&(i, _)| std::cmp::Reverse(self.iterations[i]));
+        let slow_total: u64 = slow.iter().map(|&(i, _)| self.iterations[i]).sum();
+
+        let mut out = format!(
+            "[loop report] {} loops ran {} iterations; {} of them ({} iterations) cannot be lowered to multiply-adds\n",
+            ran.len(),
+            total,
+            slow.len(),
+            slow_total
+        );
+        if slow.is_empty() {
+            return out;
+        }
+        let _ = writeln!(out, "{:>12} {:>9}  location", "iterations", "entries");
+        for (i, reason) in slow.iter().take(MAX_LISTED) {
+            let (line, col) = line_col(src, program[*i].pos);
+            let _ = writeln!(
+                out,
+                "{:>12} {:>9}  {}:{}:{}  {}",
+                self.iterations[*i],
+                self.entries[*i],
+                filename,
+                line,
+                col,
+                theme.paint(Role::Warning, reason)
+            );
+        }
+        if slow.len() > MAX_LISTED {
+            let _ = writeln!(out, "... and {} more loops", slow.len() - MAX_LISTED);
+        }
+        out
+    }
+}
+
+// Checks that the loop opened at `open` could be lowered to multiply-adds, or says why not.
+fn lowering(program: &[Inst], open: usize) -> Result<(), String> {
+    linear::analyze(program, open).map(|_| ())
+}
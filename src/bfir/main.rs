@@ -43,11 +43,55 @@
     - Block comments (/* */) are output at the corresponding location when -m is used.
 */
 
+use brainfucktool::bfmap::{self, BfMap};
+use brainfucktool::diagnostic::{Diagnostic, Format, Severity};
+use brainfucktool::ir::{self, Ext, Inst, Op, Syntax};
+use brainfucktool::lexer::Span;
+use brainfucktool::parser::SourceMap;
+use brainfucktool::reference;
+use brainfucktool::term;
+use brainfucktool::{verify, Target};
 use clap::Parser;
+use std::borrow::Cow;
 use std::fs;
-use std::io::{self, Read, Write};
-use std::iter::Peekable;
-use std::str::Chars;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::time::Instant;
+
+mod animate;
+mod config;
+mod cycles;
+mod dead;
+mod diagnostic;
+mod dialect;
+mod dump;
+mod examples;
+mod highlight;
+mod input;
+mod inspect;
+mod linear;
+mod loops;
+mod mmio;
+mod preprocess;
+mod profile;
+mod progress;
+mod rpc;
+mod sample;
+mod sha256;
+mod snapshot;
+mod step;
+mod strict;
+mod tapes;
+mod trace;
+mod translate;
+mod tty;
+
+use diagnostic::{Fault, RuntimeError};
+use dialect::Dialect;
+use highlight::{Role, Theme};
+use input::Input;
+use snapshot::Snapshot;
+use tapes::TapeSet;
 
 /// Brainfuck Interpreter in Rust
 #[derive(Parser, Debug)]
@@ -57,7 +101,7 @@ struct Opt {
     #[arg(short = 'c', default_value = "30000")]
     cells: usize,
 
-    /// Show used code input (prints to stderr)
+    /// Show the parsed program before running it (prints to stderr)
     #[arg(short = 'i', action)]
     showinput: bool,
 
@@ -69,6 +113,62 @@ struct Opt {
     #[arg(short = 'w', action)]
     nowrap: bool,
 
+    /// Where `,` reads from: stdin, or pseudo-random bytes generated from --seed
+    #[arg(long, value_enum, default_value = "stdin")]
+    input: input::Source,
+
+    /// Seed for `--input random`; the same seed gives the same bytes
+    #[arg(long, default_value = "0")]
+    seed: u64,
+
+    /// Feed these bytes to `,` instead of stdin; C-style escapes such as \n, \0 and \xHH are allowed
+    #[arg(long, value_name = "TEXT", value_parser = input::unescape, conflicts_with_all = ["input", "input_hex"])]
+    input_string: Option<input::Literal>,
+
+    /// Feed these bytes, given as hex digits (e.g. 6162000a), to `,` instead of stdin
+    #[arg(long, value_name = "HEX", value_parser = input::unhex, conflicts_with = "input")]
+    input_hex: Option<input::Literal>,
+
+    /// Feed the bytes of this file to `,` instead of stdin, e.g. a recording made with --tee-input
+    #[arg(long, value_name = "FILE", value_parser = input::read_file, conflicts_with_all = ["input", "input_string", "input_hex"])]
+    input_file: Option<input::Literal>,
+
+    /// Also write the bytes `.` prints to this file, as they are, while still printing them
+    #[arg(long, value_name = "FILE")]
+    tee_output: Option<String>,
+
+    /// Write every byte `,` consumes (after -n) to this file, to replay the run with --input-file
+    #[arg(long, value_name = "FILE")]
+    tee_input: Option<String>,
+
+    /// End the input after this many bytes
+    #[arg(long, value_name = "BYTES")]
+    input_limit: Option<u64>,
+
+    /// Make `,` give --idle-value at once instead of waiting when no input byte is available yet
+    #[arg(long, conflicts_with_all = ["input", "input_string", "input_hex", "input_file"])]
+    nonblocking: bool,
+
+    /// What `,` gives with --nonblocking when no byte is available, or after the end of input
+    #[arg(long, value_name = "BYTE", default_value = "0", requires = "nonblocking")]
+    idle_value: u8,
+
+    /// What `,` does at the end of the input: stop with an error, or give 0 (as `--test` and `--selftest` do)
+    #[arg(long, value_enum, value_name = "WHAT", default_value = "error")]
+    eof: input::Eof,
+
+    /// Put the terminal in raw mode while running, so `,` gets each key at once without echo (Ctrl-C stops)
+    #[arg(long)]
+    tty_raw: bool,
+
+    /// Wait for a key before each instruction (or, with `loops`, each top-level instruction and loop iteration), showing it and the nearby cells
+    #[arg(long, value_enum, value_name = "MODE", num_args = 0..=1, require_equals = true, default_missing_value = "instructions")]
+    step: Option<step::StepMode>,
+
+    /// Run this many instructions at full speed, or up to a `#`, then stop as --step does (in its mode, if given)
+    #[arg(long, value_name = "N", conflicts_with_all = ["test", "to"])]
+    debug_after: Option<u64>,
+
     /// Set input mode (0-4); only mode 0 is implemented in this version
     #[arg(short = ',', default_value = "0")]
     inputmode: u8,
@@ -77,516 +177,928 @@ struct Opt {
     #[arg(short = 'd', long = "dump", default_value = "0")]
     dump: usize,
 
+    /// Dump the tapes once this many instructions have run, without stopping (repeatable); shows -d cells, or 16
+    #[arg(long, value_name = "STEP")]
+    dump_at: Vec<u64>,
+
+    /// Where dumps go and in which format, as FORMAT[:WHERE] (repeatable): table, json or png, to stdout, stderr or a file; a table on stdout by default
+    #[arg(long, value_name = "SPEC", value_parser = dump::parse_spec)]
+    dump_to: Vec<dump::DumpSpec>,
+
     /// Output block comments (/* */) at corresponding locations
     #[arg(short = 'm', action)]
     comments: bool,
 
-    /// Input file containing Brainfuck source code
-    filename: String,
-}
+    /// Source map written by `bfconstructor --source-map`, used to report DSL locations
+    #[arg(long, value_name = "FILE")]
+    source_map: Option<String>,
+
+    /// Map a cell to an output file: `.` on that cell writes there instead of stdout (repeatable)
+    #[arg(long = "map-out", value_name = "CELL=FILE", value_parser = mmio::parse_mapping)]
+    map_out: Vec<mmio::Mapping>,
+
+    /// Map a cell to an input file: `,` on that cell reads from it instead of stdin, 0 at its end (repeatable)
+    #[arg(long = "map-in", value_name = "CELL=FILE", value_parser = mmio::parse_mapping)]
+    map_in: Vec<mmio::Mapping>,
+
+    /// Refuse to run if the tapes would take more memory than this, e.g. 64MiB (units B, KiB, MiB, GiB, KB, MB, GB)
+    #[arg(long, value_name = "SIZE", value_parser = parse_size)]
+    max_memory: Option<u64>,
+
+    /// The language of the source file; by default chosen by its extension (see the [extensions] of bf.toml)
+    #[arg(long, value_enum)]
+    dialect: Option<Dialect>,
+
+    /// Characters that start a comment to the end of the line, e.g. "#;", so commands in annotations are ignored
+    #[arg(long, value_name = "CHARS", default_value = "", value_parser = parse_line_comments)]
+    line_comments: String,
+
+    /// Expand `@include "file.bf"` lines and `@define` macros before parsing; errors point at the original files
+    #[arg(long)]
+    preprocess: bool,
+
+    /// Directory searched for included files, after the including file's directory (repeatable)
+    #[arg(short = 'I', long = "include", value_name = "DIR", requires = "preprocess")]
+    include: Vec<PathBuf>,
+
+    /// Number of tapes; with two or more, the tape-switch characters move between them
+    #[arg(long, default_value = "1", value_parser = clap::value_parser!(u16).range(1..))]
+    tapes: u16,
+
+    /// The characters that switch to the previous and the next tape (with --tapes)
+    #[arg(long, value_name = "CHARS", default_value = "{}", value_parser = parse_tape_chars)]
+    tape_chars: [char; 2],
+
+    /// Show the number of executed instructions, the rate and the elapsed time on stderr while running
+    #[arg(long)]
+    progress: bool,
+
+    /// After the run, print one line to stderr with the wall time, the instructions run and millions of instructions per second
+    #[arg(long)]
+    time: bool,
+
+    /// Report on stderr what was removed before running or translating: loops that never run, with where they are
+    #[arg(short = 'v', long)]
+    verbose: bool,
+
+    /// After the run, list on stderr the loops that ran but could not be lowered to multiply-adds, with why
+    #[arg(long)]
+    loop_report: bool,
 
-/// Structure representing a single aggregated Brainfuck command.
-#[derive(Debug)]
-struct Progr {
-    // For commands that are not aggregated (like [ ] , .), op holds the character.
-    op: Option<char>,
-    // Aggregated count for '+' or '-' commands.
-    plus: i32,
-    // Aggregated count for '>' or '<' commands.
-    step: i32,
-    // The index of the matching bracket for loops.
-    matching: Option<usize>,
-    // For comment commands: the content of the block comment.
-    comment: Option<String>,
+    /// Write the steps taken in each stack of running loops to this file as folded stacks, for flamegraph tools
+    #[arg(long, value_name = "FILE")]
+    profile_output: Option<String>,
+
+    /// Stop with an error when a loop runs more than N iterations in a row, to find a runaway loop
+    #[arg(long, value_name = "N")]
+    max_loop_iterations: Option<u64>,
+
+    /// Stop with an error when the program returns to a state it was in since the last input, since it would loop forever
+    #[arg(long)]
+    detect_cycles: bool,
+
+    /// Write an HTML page that replays the run with a step slider (long runs are sampled)
+    #[arg(long, value_name = "FILE")]
+    trace_html: Option<String>,
+
+    /// Write an animated SVG of the tape's cells over the run, one frame every --animate-every steps
+    #[arg(long, value_name = "FILE")]
+    animate: Option<String>,
+
+    /// Steps between two frames of --animate (long runs are sampled further)
+    #[arg(long, value_name = "N", default_value = "1", requires = "animate")]
+    animate_every: u64,
+
+    /// Save the output, the final tapes and how the run ended to a JSON file, for --diff
+    #[arg(long, value_name = "FILE")]
+    save_run: Option<String>,
+
+    /// Refuse to run programs with unclosed comments, non-ASCII characters, unreachable code or characters of a dialect that is not enabled
+    #[arg(long)]
+    strict: bool,
+
+    /// Colors for dumps, reports and errors: default, solarized, mono, or a TOML theme file (also `theme` in ./bf.toml)
+    #[arg(long, value_name = "THEME")]
+    theme: Option<String>,
+
+    /// When to use colors: auto (if output is a terminal; see NO_COLOR, CLICOLOR and CLICOLOR_FORCE), always or never
+    #[arg(long, value_enum, value_name = "WHEN", default_value = "auto")]
+    color: term::ColorChoice,
+
+    /// Make the run reproducible: `,` reads --input random with --seed unless input is given as text, hex or a file, there are no colors unless --color always, and nothing depends on the clock
+    #[arg(long, conflicts_with_all = ["progress", "time", "nonblocking", "tty_raw"])]
+    deterministic: bool,
+
+    /// Also run the program on a simple reference interpreter with the same input, and fail if the output or the final tape differ
+    #[arg(long, conflicts_with_all = ["comments", "line_comments", "null", "map_out", "map_in", "tapes"])]
+    selftest: bool,
+
+    /// Run the program on each input/output case of a TOML file (as `bfconstructor --with-tests` writes) instead of once, and fail if any output differs
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["map_out", "map_in", "tapes", "step", "selftest"])]
+    test: Option<String>,
+
+    /// After the run, compare the output with a file, print a colored diff and exit with 1 if they differ
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["test", "to"])]
+    expect_output: Option<String>,
+
+    /// After the run, compare the SHA-256 of the output with a digest and exit with 1 if they differ
+    #[arg(long, value_name = "HASH", value_parser = sha256::parse, conflicts_with_all = ["test", "to"])]
+    expect_sha256: Option<String>,
+
+    /// After the program halts, read commands from stdin to print cells as decimal or hex and ASCII and search for bytes (`help` lists them)
+    #[arg(long, conflicts_with_all = ["nonblocking", "test", "to"])]
+    inspect: bool,
+
+    /// How to print runtime and --strict errors and notes: for people, or as JSON objects for editors and CI
+    #[arg(long, value_enum, value_name = "FORMAT", default_value = "human")]
+    error_format: Format,
+
+    /// Compare two runs saved with --save-run instead of running a program; exits with 1 if they differ
+    #[arg(long, num_args = 2, value_names = ["RUN1", "RUN2"], conflicts_with = "filename")]
+    diff: Vec<String>,
+
+    /// List the classic programs built into bfir, for --example
+    #[arg(long, conflicts_with_all = ["filename", "diff"])]
+    examples: bool,
+
+    /// Run one of the built-in programs (see --examples) instead of a file
+    #[arg(long, value_name = "NAME", conflicts_with_all = ["filename", "diff", "preprocess"])]
+    example: Option<String>,
+
+    /// Answer JSON-RPC requests on stdin, one per line, to load, step and inspect programs instead of running a file
+    #[arg(long, conflicts_with_all = ["filename", "diff", "examples", "example"])]
+    rpc: bool,
+
+    /// Translate the program to another format (see --list-targets) on stdout instead of running it
+    #[arg(long, value_name = "FORMAT", value_parser = translate::parse_format, conflicts_with_all = ["step", "selftest", "test", "strict"])]
+    to: Option<translate::Format>,
+
+    /// The format --to reads, instead of the one --dialect or the file's extension gives
+    #[arg(long, value_name = "FORMAT", value_parser = translate::parse_format, requires = "to")]
+    from: Option<translate::Format>,
+
+    /// List the formats --from reads and --to writes
+    #[arg(long, conflicts_with_all = ["filename", "diff", "examples", "example", "rpc"])]
+    list_targets: bool,
+
+    /// Input file containing Brainfuck source code
+    #[arg(required_unless_present_any = ["diff", "examples", "example", "rpc", "list_targets"])]
+    filename: Option<String>,
 }
 
-impl Progr {
-    fn new() -> Self {
-        Progr {
-            op: None,
-            plus: 0,
-            step: 0,
-            matching: None,
-            comment: None,
+/// Parses the two tape-switch characters, which must not already have a meaning.
+fn parse_tape_chars(arg: &str) -> Result<[char; 2], String> {
+    let chars: Vec<char> = arg.chars().collect();
+    match chars[..] {
+        [prev, next] if prev == next => Err("the two characters must differ".to_string()),
+        [prev, next] if "+-<>[].,#/*".contains(prev) || "+-<>[].,#/*".contains(next) => {
+            Err("the characters must not be Brainfuck commands, '#' or comment delimiters".to_string())
         }
+        [prev, next] => Ok([prev, next]),
+        _ => Err("expected two characters".to_string()),
     }
 }
 
-/// Reads the Brainfuck program from a string and aggregates consecutive commands.
-/// Now includes block comments (/* */) if show_comments is enabled.
-fn read_program(contents: &str, dump: usize, show_comments: bool) -> Vec<Progr> {
-    // Include '#' as a valid command only if dump > 0.
-    let valid_chars = if dump > 0 { "+-<>.,[]#" } else { "+-<>.,[]" };
-    let mut program: Vec<Progr> = Vec::new();
-    let mut last_char: Option<char> = None;
-    let mut iter: Peekable<Chars> = contents.chars().peekable();
-
-    while let Some(c) = iter.next() {
-        // If block comment output is enabled and we encounter "/*", capture the comment.
-        if show_comments && c == '/' && iter.peek() == Some(&'*') {
-            iter.next(); // consume '*'
-            let mut comment_content = String::new();
-            while let Some(nc) = iter.next() {
-                if nc == '*' && iter.peek() == Some(&'/') {
-                    iter.next(); // consume '/'
-                    break;
-                } else {
-                    comment_content.push(nc);
-                }
-            }
-            let mut cmd = Progr::new();
-            cmd.op = Some('C'); // 'C' denotes a comment command.
-            cmd.comment = Some(comment_content);
-            program.push(cmd);
-            last_char = None; // Reset aggregation.
-            continue;
-        }
-
-        // Process only valid Brainfuck characters.
-        if valid_chars.contains(c) {
-            let mut new_cmd = false;
-            // Always start a new command if the character is '#' (memory dump command)
-            if c == '#' {
-                new_cmd = true;
-            } else if let Some(last) = last_char {
-                if (last == '+' || last == '-') && (c == '+' || c == '-') {
-                    if let Some(last_cmd) = program.last_mut() {
-                        if last_cmd.op.is_none() {
-                            last_cmd.plus += if c == '+' { 1 } else { -1 };
-                            last_char = Some(c);
-                            continue;
-                        }
-                    }
-                } else if (last == '>' || last == '<') && (c == '>' || c == '<') {
-                    if let Some(last_cmd) = program.last_mut() {
-                        if last_cmd.op.is_none() {
-                            last_cmd.step += if c == '>' { 1 } else { -1 };
-                            last_char = Some(c);
-                            continue;
-                        }
-                    }
-                } else {
-                    new_cmd = true;
-                }
-            } else {
-                new_cmd = true;
-            }
-            if new_cmd || program.is_empty() {
-                let mut cmd = Progr::new();
-                match c {
-                    '+' => cmd.plus = 1,
-                    '-' => cmd.plus = -1,
-                    '>' => cmd.step = 1,
-                    '<' => cmd.step = -1,
-                    '#' => cmd.op = Some('#'),
-                    _   => cmd.op = Some(c),
-                }
-                program.push(cmd);
-            }
-            last_char = Some(c);
-        }
+/// Parses the line-comment characters, which must not be Brainfuck commands.
+fn parse_line_comments(arg: &str) -> Result<String, String> {
+    match arg.chars().find(|&c| "+-<>[].,\n".contains(c)) {
+        Some(c) => Err(format!("{:?} is a Brainfuck command or a newline", c)),
+        None => Ok(arg.to_string()),
     }
-    program
 }
 
-/// Finds matching brackets for loops using index-based iteration.
-fn find_matching_brackets(program: &mut Vec<Progr>) -> Result<(), String> {
-    let mut stack: Vec<usize> = Vec::new();
-    for i in 0..program.len() {
-        if let Some(op) = program[i].op {
-            if op == '[' {
-                stack.push(i);
-            } else if op == ']' {
-                if let Some(j) = stack.pop() {
-                    program[j].matching = Some(i);
-                    program[i].matching = Some(j);
-                } else {
-                    return Err("Unbalanced brackets: extra ']' found".to_string());
-                }
-            }
-        }
-    }
-    if !stack.is_empty() {
-        return Err("Unbalanced brackets: missing ']'".to_string());
-    }
-    Ok(())
+/// Parses a byte count with an optional unit: KiB, MiB and GiB (or K, M and G) are powers of 1024,
+/// KB, MB and GB powers of 1000.
+fn parse_size(arg: &str) -> Result<u64, String> {
+    let digits = arg.find(|c: char| !c.is_ascii_digit()).unwrap_or(arg.len());
+    let (number, unit) = arg.split_at(digits);
+    let number: u64 = number.parse().map_err(|_| format!("'{}' does not start with a number", arg))?;
+    let scale: u64 = match unit.trim() {
+        "" | "B" => 1,
+        "K" | "KiB" => 1 << 10,
+        "M" | "MiB" => 1 << 20,
+        "G" | "GiB" => 1 << 30,
+        "KB" => 1_000,
+        "MB" => 1_000_000,
+        "GB" => 1_000_000_000,
+        unit => return Err(format!("unknown unit '{}'", unit)),
+    };
+    number.checked_mul(scale).ok_or_else(|| format!("'{}' is too large", arg))
+}
+
+/// The number of cells of each tape: `-c`, extended to reach the mapped cells.
+fn tape_len(opt: &Opt, ports: &mmio::Ports) -> usize {
+    ports.highest().map_or(opt.cells, |cell| opt.cells.max(cell + 1))
 }
 
 /// Reads a single byte of input. For simplicity, only input mode 0 is implemented.
-fn get_input(opt: &Opt) -> io::Result<u8> {
-    let mut buffer = [0; 1];
-    io::stdin().read_exact(&mut buffer)?;
-    let mut byte = buffer[0];
+fn get_input(input: &mut Input, opt: &Opt) -> io::Result<u8> {
+    let mut byte = match input.read() {
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof && opt.eof == input::Eof::Zero => return Ok(0),
+        read => read?,
+    };
+    // In raw mode the terminal sends Ctrl-C as a byte instead of stopping the program.
+    if opt.tty_raw && input.is_stdin() && byte == tty::INTERRUPT {
+        return Err(io::Error::new(io::ErrorKind::Interrupted, "interrupted (Ctrl-C)"));
+    }
     // If the -n option is enabled, translate newline to null.
     if opt.null && byte == b'\n' {
         byte = 0;
     }
+    input.consumed(byte)?;
     Ok(byte)
 }
 
-/// Prints a memory dump of the tape, including the current program index and data pointer.
-fn memory_dump(tape: &Vec<u8>, current_i: usize, ptr: usize, dump_count: usize, mode: &highlight::HighlightMode) {
-    let count = std::cmp::min(dump_count, tape.len());
-    println!("Program Index: {}", current_i);
-    println!("Data Pointer : {}", ptr);
-    // Color the cell at the data pointer differently.
-    let cell_color = |i| {
-        if i == ptr {
-            highlight::bgcolors::orange(mode)
-        } else {
-            highlight::bgcolors::blue(mode)
+/// The parsed program as Brainfuck for `-i`, with brackets colored by depth and a run of the same
+/// command written once with its count, as in `+×7`.
+fn show_program(program: &[Inst], tape_chars: [char; 2], theme: &Theme) -> String {
+    let mut out = String::new();
+    let mut depth = 0;
+    for inst in program {
+        if let Op::Close(_) = inst.op {
+            depth -= 1;
+        }
+        let run = |c: char, n: i32| match n.unsigned_abs() {
+            1 => c.to_string(),
+            n => format!("{}×{}", c, n),
+        };
+        let text = match inst.op {
+            Op::Add(n) => run(if n > 0 { '+' } else { '-' }, n),
+            Op::Move(n) => run(if n > 0 { '>' } else { '<' }, n),
+            Op::Tape(n) => run(tape_chars[(n > 0) as usize], n),
+            Op::Open(_) => "[".to_string(),
+            Op::Close(_) => "]".to_string(),
+            Op::Output => ".".to_string(),
+            Op::Input => ",".to_string(),
+            Op::Dump => "#".to_string(),
+            Op::Comment(ref text) => format!("/*{}*/", text),
+            Op::Ext(ext) => ext.to_char().to_string(),
+        };
+        out += &theme.paint(Role::of(&inst.op, depth), text);
+        if let Op::Open(_) = inst.op {
+            depth += 1;
         }
-    };
-    print!("{: ^5} ", "index");
-    for i in 0..count {
-        print!("{}{: ^3}{} ", highlight::bgcolors::blue(mode), i, highlight::reset(mode));
-    }
-    println!();
-    print!("{: ^5} ", "dec");
-    for i in 0..count {
-        print!("{}{: >3}{} ", cell_color(i), tape[i], highlight::reset(mode));
-    }
-    println!();
-    print!("{: ^5} ", "hex");
-    for i in 0..count {
-        print!("{}{: >3x}{} ", cell_color(i), tape[i], highlight::reset(mode));
     }
-    println!("\n");
+    out
+}
+
+/// How the run uses the terminal, for `--tty-raw` and `--step`.
+struct Terminal<'a> {
+    raw: Option<&'a tty::RawMode>,
+    stepper: Option<step::Stepper<'a>>,
+}
+
+/// What a run collects besides its effects, for `--save-run`, `--tee-output`, `--loop-report`,
+/// `--profile-output`, `--time` and `--selftest`.
+struct Records {
+    steps: u64, // Instructions run, counting folded runs of `+-` and `<>` once.
+    output: Option<Vec<u8>>, // What was printed to stdout.
+    input: Option<Vec<u8>>,  // What `,` read.
+    tee: Option<fs::File>,   // A copy of stdout, written unbuffered so it survives a failed run.
+    loops: Option<loops::LoopProfile>,
+    profile: Option<profile::Profile>,
+    trace: Option<trace::Trace>,
+    animation: Option<animate::Animation>,
 }
 
-/// Interprets the Brainfuck program. Returns the tape, final instruction index (i), and data pointer (ptr).
-fn interprete(program: &Vec<Progr>, opt: &Opt, mode: &highlight::HighlightMode) -> Result<(Vec<u8>, usize, usize), String> {
-    // Create the Brainfuck tape with the specified number of cells.
-    let mut tape = vec![0u8; opt.cells];
-    let mut ptr: usize = 0;
+/// Interprets the Brainfuck program. Returns the tapes and the final instruction index (i).
+/// On error, returns the fault with the index of the failing command and the tapes at that point.
+fn interprete(
+    program: &[Inst],
+    opt: &Opt,
+    input: &mut Input,
+    ports: &mut mmio::Ports,
+    terminal: &mut Terminal,
+    theme: &Theme,
+    records: &mut Records,
+) -> Result<(TapeSet, usize), RuntimeError> {
+    // Create the Brainfuck tapes with the specified number of cells, extended to reach the mapped cells.
+    let mut tapes = TapeSet::new(opt.tapes as usize, tape_len(opt, ports));
+    let mut progress = opt.progress.then(progress::Progress::start);
+    let mut cycles = opt.detect_cycles.then(cycles::CycleDetector::default);
+    let mut loops: Vec<u64> = Vec::new(); // Iterations of the loops being run, innermost last.
+    let mut dump_at = opt.dump_at.clone();
+    dump_at.sort_unstable_by(|a, b| b.cmp(a)); // The next step to dump at is last.
+    dump_at.dedup();
+    let mut storage: u8 = 0; // The storage cell of Extended Brainfuck.
+    // Balanced loops run in closed form, unless something watches the steps they would take.
+    let watched = terminal.stepper.is_some()
+        || records.trace.is_some()
+        || records.animation.is_some()
+        || records.profile.is_some()
+        || cycles.is_some()
+        || !dump_at.is_empty()
+        || opt.max_loop_iterations.is_some();
+    let linear = (!watched).then(|| linear::plan(program));
     let mut i = 0;
+    let raw = terminal.raw;
+    let fail = |index, fault, tapes| Err(RuntimeError { index, fault, tapes });
     while i < program.len() {
         let cmd = &program[i];
-        if opt.showinput {
-            if let Some(ch) = cmd.op {
-                eprint!("{}", ch);
-            } else if cmd.plus != 0 {
-                eprint!("{}", if cmd.plus > 0 { '+' } else { '-' });
-            } else if cmd.step != 0 {
-                eprint!("{}", if cmd.step > 0 { '>' } else { '<' });
+        if dump_at.last() == Some(&records.steps) {
+            dump_at.pop();
+            let dump = || {
+                let title = format!("[Step {}]", records.steps);
+                dump::write(&opt.dump_to, Some(&title), &tapes, i, records.steps, if opt.dump > 0 { opt.dump } else { 16 }, theme);
+            };
+            raw.map_or_else(dump, |raw| raw.cooked(dump));
+        }
+        if let Some(stepper) = &mut terminal.stepper {
+            if opt.debug_after == Some(records.steps) {
+                stepper.attach(records.steps, raw);
+            }
+            if let Err(e) = stepper.pause(program, i, records.steps, &tapes, raw, theme) {
+                return fail(i, e.into(), tapes);
             }
         }
-        if let Some(op) = cmd.op {
-            match op {
-                '[' => {
-                    if tape[ptr] == 0 {
-                        if let Some(m) = cmd.matching {
-                            i = m;
-                        } else {
-                            return Err("No matching bracket for '['".to_string());
-                        }
+        records.steps += 1;
+        if let Some(trace) = &mut records.trace {
+            trace.record(cmd.pos, &tapes, records.output.as_ref().map_or(0, Vec::len));
+        }
+        if let Some(animation) = &mut records.animation {
+            animation.record(&tapes);
+        }
+        if let Some(progress) = &mut progress {
+            progress.tick();
+        }
+        if let Some(profile) = &mut records.profile {
+            profile.step();
+        }
+        if let Some(period) = cycles.as_mut().and_then(|cycles| cycles.tick(i, &tapes, storage)) {
+            return fail(i, Fault::Cycle { period }, tapes);
+        }
+        if let (Some(Some(loop_)), Op::Open(close)) = (linear.as_ref().map(|plan| &plan[i]), &cmd.op) {
+            let (tape, ptr) = tapes.current_mut();
+            if let Some(n) = (tape[ptr] != 0).then(|| loop_.run(tape, ptr, opt.nowrap)).flatten() {
+                records.steps += n * loop_.steps;
+                if let Some(progress) = &mut progress {
+                    progress.skip(n * loop_.steps);
+                }
+                if let Some(profile) = &mut records.loops {
+                    profile.run(i, n);
+                }
+                i = close + 1;
+                continue;
+            }
+        }
+        // Mapped cells are on the first tape.
+        let port = (tapes.current() == 0).then(|| tapes.ptr());
+        match &cmd.op {
+            Op::Open(m) if tapes.cell() == 0 => i = *m,
+            Op::Open(_) => {
+                if opt.max_loop_iterations.is_some() {
+                    loops.push(1);
+                }
+                if let Some(profile) = &mut records.loops {
+                    profile.enter(i);
+                }
+                if let Some(profile) = &mut records.profile {
+                    profile.enter(i);
+                }
+            }
+            Op::Close(m) if tapes.cell() != 0 => {
+                if let (Some(max), Some(count)) = (opt.max_loop_iterations, loops.last_mut()) {
+                    *count += 1;
+                    if *count > max {
+                        return fail(*m, Fault::LoopLimit { max }, tapes);
                     }
                 }
-                ']' => {
-                    if tape[ptr] != 0 {
-                        if let Some(m) = cmd.matching {
-                            i = m;
-                        } else {
-                            return Err("No matching bracket for ']'".to_string());
+                if let Some(profile) = &mut records.loops {
+                    profile.repeat(*m);
+                }
+                i = *m;
+            }
+            Op::Close(_) => {
+                loops.pop();
+                if let Some(profile) = &mut records.profile {
+                    profile.exit();
+                }
+            }
+            Op::Output => match port.map_or(Ok(false), |ptr| ports.output(ptr, tapes.cell())) {
+                Ok(true) => {}
+                Ok(false) => {
+                    if let Some(output) = &mut records.output {
+                        output.push(tapes.cell());
+                    }
+                    // The copy gets the bytes themselves, whatever the terminal is sent.
+                    if let Some(tee) = &mut records.tee {
+                        if let Err(e) = tee.write_all(&[tapes.cell()]) {
+                            return fail(i, e.into(), tapes);
                         }
                     }
+                    let mut stdout = io::stdout().lock();
+                    let written = match raw.is_some() && tapes.cell() == b'\n' {
+                        true => stdout.write_all(b"\r\n"),
+                        false => write!(stdout, "{}", tapes.cell() as char),
+                    };
+                    // A closed pipe ends the run with an error instead of a panic.
+                    if let Err(e) = written.and_then(|()| stdout.flush()) {
+                        return fail(i, e.into(), tapes);
+                    }
                 }
-                '.' => {
-                    print!("{}", tape[ptr] as char);
-                    io::stdout().flush().unwrap();
+                Err(e) => return fail(i, e.into(), tapes),
+            },
+            Op::Input => {
+                if let Some(cycles) = &mut cycles {
+                    cycles.input();
                 }
-                ',' => {
-                    match get_input(opt) {
-                        Ok(val) => tape[ptr] = val,
-                        Err(e) => return Err(e.to_string()),
+                let mapped = port.map_or(Ok(None), |ptr| ports.input(ptr));
+                match mapped.transpose().unwrap_or_else(|| get_input(input, opt)) {
+                    Ok(val) => {
+                        *tapes.cell_mut() = val;
+                        if let Some(read) = &mut records.input {
+                            read.push(val);
+                        }
                     }
+                    Err(e) => return fail(i, e.into(), tapes),
+                }
+            }
+            Op::Dump => {
+                // Memory dump command: dump the tapes immediately including current i and pointers.
+                if opt.dump > 0 {
+                    let dump = || dump::write(&opt.dump_to, None, &tapes, i, records.steps, opt.dump, theme);
+                    raw.map_or_else(dump, |raw| raw.cooked(dump));
                 }
-                '#' => {
-                    // Memory dump command: dump the tape immediately including current i and ptr.
-                    let mode = highlight::HighlightMode::TrueColor;
-                    memory_dump(&tape, i, ptr, opt.dump, &mode);
+                // With --debug-after, `#` is also a breakpoint.
+                if let (Some(stepper), Some(_)) = (&mut terminal.stepper, opt.debug_after) {
+                    stepper.attach(records.steps, raw);
                 }
-                'C' => {
-                    // Comment command: output the comment content.
-                    if let Some(ref comment) = cmd.comment {
-                        println!("[comment] {}{}{} ", highlight::colors::green(mode), comment, highlight::reset(mode));
+            }
+            Op::Comment(comment) => {
+                // Comment command: output the comment content.
+                let print = || println!("[comment] {} ", theme.paint(Role::Comment, comment));
+                raw.map_or_else(print, |raw| raw.cooked(print));
+            }
+            &Op::Add(plus) if plus != 0 => {
+                let value = tapes.cell();
+                let cell = tapes.cell_mut();
+                if opt.nowrap {
+                    let new_val = value as i32 + plus;
+                    if !(0..=255).contains(&new_val) {
+                        return fail(i, Fault::Wraparound { value, add: plus }, tapes);
                     }
+                    *cell = new_val as u8;
+                } else {
+                    *cell = cell.wrapping_add(plus as u8);
                 }
-                _ => {}
             }
-        }
-        if cmd.plus != 0 {
-            if opt.nowrap {
-                let new_val = tape[ptr] as i32 + cmd.plus;
-                if new_val > 255 {
-                    return Err("Out of range! Incrementing 0xFF is disallowed (-w).".to_string());
-                } else if new_val < 0 {
-                    return Err("Out of range! Decrementing 0x00 is disallowed (-w).".to_string());
+            &Op::Move(step) if step != 0 => {
+                if let Err(fault) = tapes.move_by(step) {
+                    return fail(i, fault, tapes);
                 }
-                tape[ptr] = new_val as u8;
-            } else {
-                tape[ptr] = tape[ptr].wrapping_add(cmd.plus as u8);
             }
-        }
-        if cmd.step != 0 {
-            let new_ptr = ptr as isize + cmd.step as isize;
-            if new_ptr < 0 || (new_ptr as usize) >= opt.cells {
-                return Err("Pointer out of range! Check the '-c' option.".to_string());
+            &Op::Tape(step) => tapes.switch(step),
+            Op::Ext(Ext::End) => {
+                i = program.len();
+                continue;
             }
-            ptr = new_ptr as usize;
+            &Op::Ext(ext) => {
+                let (mut cell, mut stored) = (tapes.cell() as u64, storage as u64);
+                ext.apply(&mut cell, &mut stored, 8);
+                *tapes.cell_mut() = cell as u8;
+                storage = stored as u8;
+            }
+            _ => {}
         }
         i += 1;
     }
-    Ok((tape, i, ptr))
+    if let Err(e) = ports.flush() {
+        return fail(i, e.into(), tapes);
+    }
+    Ok((tapes, i))
+}
+
+/// Returns the DSL constructs whose code contains byte `pos` of the program, innermost first.
+fn dsl_locations(map: &BfMap, pos: usize) -> Vec<&bfmap::Mapping> {
+    let mut found: Vec<&bfmap::Mapping> = map.mappings.iter().filter(|m| m.start <= pos && pos < m.end).collect();
+    found.sort_by_key(|m| m.end - m.start);
+    found
 }
 
 fn main() {
     // Parse command-line arguments.
     let opt = Opt::parse();
-    let mode = highlight::HighlightMode::TrueColor;
-
-    // Read the Brainfuck source file.
-    let content = fs::read_to_string(&opt.filename).unwrap_or_else(|e| {
-        eprintln!("Error reading file: {}", e);
-        std::process::exit(1);
-    });
-
-    // Parse and aggregate the program commands, including block comments if enabled.
-    let mut program = read_program(&content, opt.dump, opt.comments);
-
-    // Find matching brackets for loop constructs.
-    if let Err(e) = find_matching_brackets(&mut program) {
+    let config = config::Config::load().unwrap_or_else(|e| {
         eprintln!("Error: {}", e);
         std::process::exit(1);
-    }
-
-    // Interpret (execute) the Brainfuck program.
-    let (tape, final_i, final_ptr) = match interprete(&program, &opt, &mode) {
-        Ok(res) => res,
-        Err(e) => {
-            eprintln!("Error during interpretation: {}", e);
-            std::process::exit(1);
-        }
+    });
+    let theme_name = opt.theme.as_deref().or(config.theme.as_deref()).unwrap_or("default");
+    // Colors depend on the terminal, so a deterministic run only has them when asked for.
+    let color = match (opt.deterministic, opt.color) {
+        (true, term::ColorChoice::Auto) => term::ColorChoice::Never,
+        (_, color) => color,
     };
+    let theme = Theme::load(theme_name, term::choose(color)).unwrap_or_else(|e| {
+        eprintln!("Error reading theme: {}", e);
+        std::process::exit(1);
+    });
 
-    // If a dump size > 0 is specified, print that many cells from the start.
-    if opt.dump > 0 {
-        println!("[End state]");
-        memory_dump(&tape, final_i, final_ptr, opt.dump, &mode);
-    }
-}
-
-/// Highlighter module for syntax highlighting.
-pub mod highlight {
-    /// Highlight mode enum.
-    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-    pub enum HighlightMode {
-        None,
-        Color16,
-        Color256,
-        TrueColor,
-    }
-
-    impl HighlightMode {
-        pub fn from_str(s: &str) -> HighlightMode {
-            match s {
-                "false" => HighlightMode::None,
-                "16" => HighlightMode::Color16,
-                "256" => HighlightMode::Color256,
-                "true" => HighlightMode::TrueColor,
-                _ => HighlightMode::None,
+    // Compare two saved runs instead of running a program.
+    if let [a, b] = &opt.diff[..] {
+        let (a, b) = match (Snapshot::load(a), Snapshot::load(b)) {
+            (Ok(a), Ok(b)) => (a, b),
+            (Err(e), _) | (_, Err(e)) => {
+                eprintln!("Error reading saved run: {}", e);
+                std::process::exit(2);
+            }
+        };
+        match snapshot::diff(&a, &b, &theme) {
+            Some(diff) => {
+                print!("{}", diff);
+                std::process::exit(1);
             }
+            None => return,
         }
     }
 
-    /// Returns the reset escape sequence.
-    pub fn reset(mode: &HighlightMode) -> String {
-        match mode {
-            HighlightMode::None => "".to_string(),
-            _ => "\x1b[0m".to_string(),
+    if opt.examples {
+        print!("{}", examples::list());
+        return;
+    }
+    if opt.list_targets {
+        print!("{}", translate::list());
+        return;
+    }
+    if opt.rpc {
+        if let Err(e) = rpc::serve(io::stdin().lock(), io::stdout().lock()) {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
         }
+        return;
     }
+    let example = opt.example.as_deref().map(|name| {
+        examples::find(name).unwrap_or_else(|e| {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        })
+    });
+
+    // Read the Brainfuck source file.
+    let example_name = example.map(|example| format!("{}.bf", example.name));
+    let filename = opt.filename.as_deref().or(example_name.as_deref()).expect("required unless --diff or --example is given");
+    // With --preprocess, run the text with its includes expanded.
+    let expanded = opt.preprocess.then(|| {
+        preprocess::expand(filename, &opt.include).unwrap_or_else(|e| {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        })
+    });
+    let content = match (&expanded, example) {
+        (Some(expanded), _) => expanded.text.clone(),
+        (None, Some(example)) => example.source.to_string(),
+        (None, None) => fs::read_to_string(filename).unwrap_or_else(|e| {
+            eprintln!("Error reading file: {}", e);
+            std::process::exit(1);
+        }),
+    };
 
-    /// Color functions.
-    pub mod colors {
-        use super::HighlightMode;
-        pub fn pink(mode: &HighlightMode) -> String {
-            match mode {
-                HighlightMode::Color16 => "\x1b[35m".to_string(),
-                HighlightMode::Color256 => "\x1b[38;5;207m".to_string(),
-                HighlightMode::TrueColor => "\x1b[38;2;250;105;200m".to_string(),
-                HighlightMode::None => "".to_string(),
+    // Diagnostics point at the file they are about, the included one with --preprocess.
+    let mut sources = SourceMap::default();
+    match &expanded {
+        Some(expanded) => {
+            for (name, src) in &expanded.files {
+                sources.add(name, src.clone());
             }
         }
-        pub fn blue(mode: &HighlightMode) -> String {
-            match mode {
-                HighlightMode::Color16 => "\x1b[34m".to_string(),
-                HighlightMode::Color256 => "\x1b[38;5;27m".to_string(),
-                HighlightMode::TrueColor => "\x1b[38;2;50;50;255m".to_string(),
-                HighlightMode::None => "".to_string(),
-            }
+        None => {
+            sources.add(filename, content.clone());
         }
-        pub fn white(mode: &HighlightMode) -> String {
-            match mode {
-                HighlightMode::Color16 => "\x1b[37m".to_string(),
-                HighlightMode::Color256 => "\x1b[38;5;15m".to_string(),
-                HighlightMode::TrueColor => "\x1b[38;2;255;255;255m".to_string(),
-                HighlightMode::None => "".to_string(),
+    }
+    let report = |found: &mut [Diagnostic]| {
+        if let Some(expanded) = &expanded {
+            for span in found.iter_mut().filter_map(|d| d.span.as_mut()) {
+                let (file, start) = expanded.locate(span.start);
+                *span = Span { start, end: start + (span.end - span.start), file };
             }
         }
-        pub fn green(mode: &HighlightMode) -> String {
-            match mode {
-                HighlightMode::Color16 => "\x1b[32m".to_string(),
-                HighlightMode::Color256 => "\x1b[38;5;82m".to_string(),
-                HighlightMode::TrueColor => "\x1b[38;2;100;230;60m".to_string(),
-                HighlightMode::None => "".to_string(),
-            }
+        for diagnostic in found.iter() {
+            eprint!("{}", diagnostic.format(&sources, opt.error_format));
         }
-        pub fn red(mode: &HighlightMode) -> String {
-            match mode {
-                HighlightMode::Color16 => "\x1b[31m".to_string(),
-                HighlightMode::Color256 => "\x1b[38;5;196m".to_string(),
-                HighlightMode::TrueColor => "\x1b[38;2;250;80;50m".to_string(),
-                HighlightMode::None => "".to_string(),
-            }
+    };
+
+    // Without --dialect, the file's extension says how to read it, and bfir says what it chose.
+    let reading = || match (opt.dialect, example) {
+        (Some(dialect), _) => translate::Format::of(dialect),
+        (None, Some(_)) => translate::Format::Brainfuck,
+        (None, None) => {
+            let (format, why) = dialect::detect(filename, &config.extensions).unwrap_or_else(|e| {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            });
+            let message = format!("reading {} as {}, {} (override with --dialect)", filename, format.title(), why);
+            report(&mut [Diagnostic::new(Severity::Note, "dialect", message, None)]);
+            format
         }
-        pub fn yellow(mode: &HighlightMode) -> String {
-            match mode {
-                HighlightMode::Color16 => "\x1b[33m".to_string(),
-                HighlightMode::Color256 => "\x1b[38;5;11m".to_string(),
-                HighlightMode::TrueColor => "\x1b[38;2;240;230;0m".to_string(),
-                HighlightMode::None => "".to_string(),
+    };
+
+    // With --to, translate the program instead of running it.
+    if let Some(to) = &opt.to {
+        let from = opt.from.clone().unwrap_or_else(reading);
+        let translated = translate::translate(&content, filename, &from, to, opt.cells).map(|(bytes, mut notes)| {
+            if opt.verbose {
+                report(&mut notes);
             }
-        }
-        pub fn orange(mode: &HighlightMode) -> String {
-            match mode {
-                HighlightMode::Color16 => "\x1b[33m".to_string(),
-                HighlightMode::Color256 => "\x1b[38;5;208m".to_string(),
-                HighlightMode::TrueColor => "\x1b[38;2;255;165;0m".to_string(),
-                HighlightMode::None => "".to_string(),
+            bytes
+        });
+        match translated.and_then(|bytes| io::stdout().write_all(&bytes).map_err(|e| e.to_string())) {
+            Ok(()) => return,
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
             }
         }
-        pub fn lightblue(mode: &HighlightMode) -> String {
-            match mode {
-                HighlightMode::Color16 => "\x1b[94m".to_string(),
-                HighlightMode::Color256 => "\x1b[38;5;153m".to_string(),
-                HighlightMode::TrueColor => "\x1b[38;2;53;255;255m".to_string(),
-                HighlightMode::None => "".to_string(),
-            }
+    }
+
+    // Parse and aggregate the program commands, including block comments if enabled,
+    // and find matching brackets for loop constructs.
+    // Ook! is translated to Brainfuck with the same layout, so `content` still locates commands.
+    let format = reading();
+    let extended = format == translate::Format::Extended1;
+    if extended && opt.selftest {
+        eprintln!("Error: --selftest does not support --dialect extended1");
+        std::process::exit(1);
+    }
+    if !opt.dump_to.is_empty() && opt.dump == 0 && opt.dump_at.is_empty() {
+        eprintln!("Error: --dump-to needs -d or --dump-at, which say what to dump");
+        std::process::exit(1);
+    }
+    let syntax = Syntax {
+        dump: opt.dump > 0 || opt.debug_after.is_some(),
+        comments: opt.comments,
+        tapes: (opt.tapes > 1).then_some(opt.tape_chars),
+        line_comments: &opt.line_comments,
+        extended,
+    };
+    let code = translate::read(&content, filename, &format).unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    });
+    let program = ir::parse(&code, syntax).unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    });
+
+    // With --strict, stop at text the parser would have ignored.
+    if opt.strict {
+        let mut found = strict::check(&content, &program, syntax, 0);
+        report(&mut found);
+        if !found.is_empty() {
+            std::process::exit(1);
         }
     }
-    pub mod bgcolors {
-        use super::HighlightMode;
-        pub fn pink(mode: &HighlightMode) -> String {
-            match mode {
-                HighlightMode::Color16   => "\x1b[45m".to_string(),
-                HighlightMode::Color256  => "\x1b[48;5;88m".to_string(),
-                HighlightMode::TrueColor => "\x1b[48;2;60;20;60m".to_string(),
-                HighlightMode::None      => "".to_string(),
-            }
+
+    // Leave out the loops that never run, saying which with -v.
+    let (program, mut notes) = dead::eliminate(&program, 0);
+    if opt.verbose {
+        report(&mut notes);
+    }
+
+    // With --test, run the cases of a suite instead of the input.
+    if let Some(path) = &opt.test {
+        let suite = fs::read_to_string(path).map_err(|e| e.to_string()).and_then(|src| verify::load(&src)).unwrap_or_else(|e| {
+            eprintln!("Error reading {}: {}", path, e);
+            std::process::exit(1);
+        });
+        let target = Target { bits: 8, wrap: !opt.nowrap, extended };
+        if !verify::check_program(&suite, &program, target) {
+            std::process::exit(1);
         }
-        pub fn blue(mode: &HighlightMode) -> String {
-            match mode {
-                HighlightMode::Color16   => "\x1b[44m".to_string(),
-                HighlightMode::Color256  => "\x1b[48;5;18m".to_string(),
-                HighlightMode::TrueColor => "\x1b[48;2;20;30;60m".to_string(),
-                HighlightMode::None      => "".to_string(),
-            }
+        return;
+    }
+
+    // Load the source map, if given, to report errors at DSL locations.
+    let source_map = opt.source_map.as_ref().map(|path| {
+        fs::read_to_string(path)
+            .map_err(|e| e.to_string())
+            .and_then(|json| serde_json::from_str::<BfMap>(&json).map_err(|e| e.to_string()))
+            .and_then(|map| match map.version {
+                bfmap::VERSION => Ok(map),
+                v => Err(format!("unsupported version {}", v)),
+            })
+            .unwrap_or_else(|e| {
+                eprintln!("Error reading source map: {}", e);
+                std::process::exit(1);
+            })
+    });
+
+    // Open the files of the memory-mapped cells.
+    let mut ports = mmio::Ports::open(&opt.map_out, &opt.map_in).unwrap_or_else(|e| {
+        eprintln!("Error opening mapped cells: {}", e);
+        std::process::exit(1);
+    });
+
+    // Check that the tapes fit in --max-memory before allocating them.
+    let memory = tape_len(&opt, &ports) as u128 * opt.tapes as u128;
+    if let Some(max) = opt.max_memory.filter(|&max| memory > max as u128) {
+        eprintln!(
+            "Error: {} tape(s) of {} cells need {} bytes, more than --max-memory allows ({} bytes)",
+            opt.tapes,
+            tape_len(&opt, &ports),
+            memory,
+            max
+        );
+        std::process::exit(1);
+    }
+
+    if opt.showinput {
+        eprintln!("{}", show_program(&program, opt.tape_chars, &theme));
+    }
+
+    // Interpret (execute) the Brainfuck program.
+    let mut input = match opt.input_string.clone().or(opt.input_hex.clone()).or(opt.input_file.clone()) {
+        Some(bytes) => Input::literal(bytes, opt.input_limit),
+        None if opt.deterministic => Input::new(input::Source::Random, opt.seed, opt.input_limit),
+        None => Input::new(opt.input, opt.seed, opt.input_limit),
+    };
+    if opt.nonblocking {
+        input = input.nonblocking(opt.idle_value);
+    }
+    if let Some(path) = &opt.tee_input {
+        let file = fs::File::create(path).unwrap_or_else(|e| {
+            eprintln!("Error creating {}: {}", path, e);
+            std::process::exit(1);
+        });
+        input = input.tee(file);
+    }
+    let expected = opt.expect_output.as_ref().map(|path| {
+        fs::read(path).unwrap_or_else(|e| {
+            eprintln!("Error reading {}: {}", path, e);
+            std::process::exit(1);
+        })
+    });
+    let step_mode = opt.step.or(opt.debug_after.map(|_| step::StepMode::Instructions));
+    let stepper = step_mode.map(|mode| {
+        let attached = opt.debug_after.is_none();
+        step::Stepper::open(mode, &program, &content, source_map.as_ref(), attached).unwrap_or_else(|e| {
+            eprintln!("Error: --step and --debug-after need a terminal: {}", e);
+            std::process::exit(1);
+        })
+    });
+    let tee = opt.tee_output.as_ref().map(|path| {
+        fs::File::create(path).unwrap_or_else(|e| {
+            eprintln!("Error creating {}: {}", path, e);
+            std::process::exit(1);
+        })
+    });
+    // Everything that can fail is opened before raw mode, since exiting skips its destructor.
+    let raw = opt.tty_raw.then(|| {
+        tty::RawMode::enable().unwrap_or_else(|e| {
+            eprintln!("Error: --tty-raw needs a terminal: {}", e);
+            std::process::exit(1);
+        })
+    });
+    let mut records = Records {
+        steps: 0,
+        output: (opt.save_run.is_some() || opt.trace_html.is_some() || opt.selftest || expected.is_some() || opt.expect_sha256.is_some())
+            .then(Vec::new),
+        input: opt.selftest.then(Vec::new),
+        tee,
+        loops: opt.loop_report.then(|| loops::LoopProfile::new(&program)),
+        profile: opt.profile_output.is_some().then(profile::Profile::default),
+        trace: opt.trace_html.is_some().then(|| trace::Trace::new(&content)),
+        animation: opt.animate.is_some().then(|| animate::Animation::new(opt.animate_every)),
+    };
+    let mut terminal = Terminal { raw: raw.as_ref(), stepper };
+    let started = Instant::now();
+    let result = interprete(&program, &opt, &mut input, &mut ports, &mut terminal, &theme, &mut records);
+    let elapsed = started.elapsed().as_secs_f64();
+    drop(terminal);
+    // Restore the terminal before reporting, since exiting skips destructors.
+    drop(raw);
+    // Likewise keep what a failed run wrote to mapped files; a finished run has flushed them.
+    if result.is_err() {
+        if let Err(e) = ports.flush() {
+            eprintln!("Error writing a mapped output: {}", e);
         }
-        pub fn white(mode: &HighlightMode) -> String {
-            match mode {
-                HighlightMode::Color16   => "\x1b[47m".to_string(),
-                HighlightMode::Color256  => "\x1b[48;5;237m".to_string(),
-                HighlightMode::TrueColor => "\x1b[48;2;40;40;40m".to_string(),
-                HighlightMode::None      => "".to_string(),
-            }
+    }
+    if opt.time {
+        let mips = if elapsed > 0.0 { records.steps as f64 / elapsed / 1e6 } else { 0.0 };
+        eprintln!("[time] {:.3}s, {} instructions, {:.2} MIPS", elapsed, records.steps, mips);
+    }
+    if let Some(profile) = &records.loops {
+        eprint!("{}", profile.report(&program, &content, filename, &theme));
+    }
+    if let (Some(path), Some(profile)) = (&opt.profile_output, &records.profile) {
+        if let Err(e) = fs::write(path, profile.folded(&program, &content, filename)) {
+            eprintln!("Error writing the profile: {}", e);
         }
-        pub fn yellow(mode: &HighlightMode) -> String {
-            match mode {
-                HighlightMode::Color16   => "\x1b[43m".to_string(),
-                HighlightMode::Color256  => "\x1b[48;5;100m".to_string(),
-                HighlightMode::TrueColor => "\x1b[48;2;60;60;20m".to_string(),
-                HighlightMode::None      => "".to_string(),
-            }
+    }
+    let (end_tapes, end_pos) = match &result {
+        Ok((tapes, _)) => (tapes, content.len()),
+        Err(error) => (&error.tapes, program.get(error.index).map_or(content.len(), |inst| inst.pos)),
+    };
+    if let (Some(path), Some(trace)) = (&opt.trace_html, &mut records.trace) {
+        let output = records.output.as_deref().unwrap_or_default();
+        trace.finish(end_pos, end_tapes, output.len());
+        if let Err(e) = trace.write(path, filename, &content, output) {
+            eprintln!("Error writing the trace: {}", e);
         }
-        pub fn orange(mode: &HighlightMode) -> String {
-            match mode {
-                HighlightMode::Color16   => "\x1b[43m".to_string(),
-                HighlightMode::Color256  => "\x1b[48;5;95m".to_string(),
-                HighlightMode::TrueColor => "\x1b[48;2;70;40;10m".to_string(),
-                HighlightMode::None      => "".to_string(),
-            }
+    }
+    if let (Some(path), Some(animation)) = (&opt.animate, &mut records.animation) {
+        animation.finish(end_tapes);
+        if let Err(e) = animation.write(path) {
+            eprintln!("Error writing the animation: {}", e);
         }
-        pub fn lightblue(mode: &HighlightMode) -> String {
-            match mode {
-                HighlightMode::Color16   => "\x1b[104m".to_string(),
-                HighlightMode::Color256  => "\x1b[48;5;20m".to_string(),
-                HighlightMode::TrueColor => "\x1b[48;2;20;40;120m".to_string(),
-                HighlightMode::None      => "".to_string(),
-            }
+    }
+    let ran = result.as_ref().ok().map(|(tapes, _)| tapes.tapes().next().expect("there is a tape"));
+    let selftest = ran.filter(|_| opt.selftest).map(|(tape, ptr)| {
+        let read = records.input.as_deref().unwrap_or_default();
+        let actual = reference::Run { output: records.output.clone().unwrap_or_default(), tape: tape.to_vec(), ptr };
+        reference::run(&code, read, opt.cells).and_then(|expected| reference::compare(&expected, &actual))
+    });
+    let mismatches = expectations(records.output.as_deref().unwrap_or_default(), expected.as_deref(), &opt, &theme);
+    if let Some(path) = &opt.save_run {
+        let error = result.as_ref().err().map(|error| error.fault.title());
+        let saved = Snapshot::new(filename, records.output.unwrap_or_default(), error, end_tapes);
+        if let Err(e) = saved.save(path) {
+            eprintln!("Error saving the run: {}", e);
         }
-        pub fn green(mode: &HighlightMode) -> String {
-            match mode {
-                HighlightMode::Color16   => "\x1b[42m".to_string(),
-                HighlightMode::Color256  => "\x1b[48;5;64m".to_string(),
-                HighlightMode::TrueColor => "\x1b[48;2;40;80;24m".to_string(),
-                HighlightMode::None      => "".to_string(),
+    }
+    let (tapes, final_i) = match result {
+        Ok(res) => res,
+        Err(error) => {
+            let mut locations = Vec::new();
+            if let (Some(map), Some(cmd)) = (&source_map, program.get(error.index)) {
+                for (depth, m) in dsl_locations(map, cmd.pos).iter().enumerate() {
+                    let source = map.sources.get(m.source).map_or("<unknown>", |s| s.as_str());
+                    let how = if depth == 0 { "at" } else { "in" };
+                    locations.push(format!("{} {}:{}:{}: {}", how, source, m.line, m.column, m.text));
+                }
             }
-        }
-        pub fn red(mode: &HighlightMode) -> String {
-            match mode {
-                HighlightMode::Color16   => "\x1b[41m".to_string(),
-                HighlightMode::Color256  => "\x1b[48;5;90m".to_string(),
-                HighlightMode::TrueColor => "\x1b[48;2;60;20;20m".to_string(),
-                HighlightMode::None      => "".to_string(),
+            // Point at the file the failing command was written in.
+            let (program, content, filename) = match &expanded {
+                Some(expanded) => {
+                    let (file, program) = expanded.remap(&program, error.index);
+                    let (name, src) = &expanded.files[file];
+                    (Cow::Owned(program), src.as_str(), name.as_str())
+                }
+                None => (Cow::Borrowed(&program[..]), content.as_str(), filename),
+            };
+            match opt.error_format {
+                Format::Human => {
+                    eprint!("{}", diagnostic::render(&error, &program, content, filename, &theme));
+                    for location in locations {
+                        eprintln!("  {}", location);
+                    }
+                }
+                Format::Json => eprintln!("{}", diagnostic::to_json(&error, &program, content, filename, &locations)),
+            }
+            if opt.inspect {
+                inspect(&error.tapes, &theme);
             }
+            std::process::exit(1);
         }
+    };
+
+    // If a dump size > 0 is specified, print that many cells from the start.
+    if opt.dump > 0 {
+        dump::write(&opt.dump_to, Some("[End state]"), &tapes, final_i, records.steps, opt.dump, &theme);
+    }
+    if opt.inspect {
+        inspect(&tapes, &theme);
     }
 
-    /// Returns an escape code for opening parentheses color based on depth.
-    pub fn paren_color(depth: usize, mode: &HighlightMode) -> String {
-        if *mode == HighlightMode::None {
-            return "".to_string();
+    match selftest {
+        Some(Ok(())) => eprintln!("[selftest] the reference interpreter agrees"),
+        Some(Err(e)) => {
+            eprintln!("{}: {}", theme.paint(Role::Error, "selftest failed"), e);
+            std::process::exit(1);
         }
-        match mode {
-            HighlightMode::Color16 => {
-                let palette = [91, 92, 93, 94, 95, 96];
-                let code = palette[depth % palette.len()];
-                format!("\x1b[{}m", code)
-            },
-            HighlightMode::Color256 => {
-                let palette = [196, 202, 208, 214, 220, 226];
-                let code = palette[depth % palette.len()];
-                format!("\x1b[38;5;{}m", code)
-            },
-            HighlightMode::TrueColor => {
-                let palette = [
-                    (164, 219, 211),
-                    (217, 201, 145),
-                    (145, 189, 217),
-                    (217, 187, 145),
-                    (132, 137, 140),
-                ];
-                let (r, g, b) = palette[depth % palette.len()];
-                format!("\x1b[38;2;{};{};{}m", r, g, b)
-            },
-            HighlightMode::None => "".to_string(),
+        None => {}
+    }
+    if !mismatches.is_empty() {
+        for mismatch in mismatches {
+            eprint!("{}", mismatch);
         }
+        std::process::exit(1);
+    }
+}
+
+/// Runs `--inspect` on the tapes the program halted with.
+fn inspect(tapes: &TapeSet, theme: &Theme) {
+    let _ = io::stdout().flush();
+    if let Err(e) = inspect::run(io::stdin().lock(), &mut io::stderr(), tapes, theme) {
+        eprintln!("Error: --inspect: {}", e);
     }
+}
 
-    /// Colorize a plain string with the given color (by name) for foreground.
-    pub fn colorize_plain(text: &str, color: &str, mode: &HighlightMode) -> String {
-        if *mode == HighlightMode::None {
-            return text.to_string();
+/// Compares what the run printed with `--expect-output`'s contents and `--expect-sha256`,
+/// describing each check that failed.
+fn expectations(output: &[u8], expected: Option<&[u8]>, opt: &Opt, theme: &Theme) -> Vec<String> {
+    let mut mismatches = Vec::new();
+    if let Some(expected) = expected.filter(|&expected| expected != output) {
+        let title = theme.paint(Role::Error, "the output differs from");
+        let path = opt.expect_output.as_deref().unwrap_or_default();
+        mismatches.push(format!("{} {}, {}", title, path, highlight::diff(expected, output, theme)));
+    }
+    if let Some(digest) = &opt.expect_sha256 {
+        let actual = sha256::hex(output);
+        if &actual != digest {
+            let title = theme.paint(Role::Error, "the output's SHA-256 differs");
+            let red = theme.paint(Role::Removed, format!("- {}", digest));
+            let green = theme.paint(Role::Added, format!("+ {}", actual));
+            mismatches.push(format!("{}:\n{}\n{}\n", title, red, green));
         }
-        let color_code = match color {
-            "pink" => colors::pink(mode),
-            "blue" => colors::blue(mode),
-            "white" => colors::white(mode),
-            "green" => colors::green(mode),
-            "red" => colors::red(mode),
-            _ => "".to_string(),
-        };
-        format!("{}{}{}", color_code, text, reset(mode))
     }
+    mismatches
 }
@@ -0,0 +1,80 @@
+// Memory-mapped I/O: tape cells that stand for extra byte streams (`--map-out`, `--map-in`).
+//
+// `.` on a cell mapped to an output file writes the cell's value to that file instead of stdout,
+// and `,` on a cell mapped to an input file reads the file's next byte instead of stdin (0 once
+// the file is exhausted). A program picks a stream by where it moves the pointer, so it can
+// interleave several streams without new instructions; the tape is extended to reach the
+// mapped cells, which can lie beyond `-c`. With several tapes (`--tapes`), the mapped cells are
+// those of the first tape.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Bytes, Read, Write};
+use std::path::PathBuf;
+
+/// A `CELL=FILE` mapping given on the command line.
+#[derive(Debug, Clone)]
+pub struct Mapping {
+    pub cell: usize,
+    pub path: PathBuf,
+}
+
+/// Parses `CELL=FILE`.
+pub fn parse_mapping(arg: &str) -> Result<Mapping, String> {
+    let (cell, path) = arg.split_once('=').ok_or("expected CELL=FILE")?;
+    let cell = cell.parse().map_err(|_| format!("'{}' is not a cell index", cell))?;
+    Ok(Mapping { cell, path: PathBuf::from(path) })
+}
+
+/// The open streams of the mapped cells.
+#[derive(Default)]
+pub struct Ports {
+    outputs: HashMap<usize, BufWriter<File>>,
+    inputs: HashMap<usize, Bytes<BufReader<File>>>,
+}
+
+impl Ports {
+    /// Creates the output files and opens the input files.
+    pub fn open(outputs: &[Mapping], inputs: &[Mapping]) -> Result<Ports, String> {
+        let mut ports = Ports::default();
+        for m in outputs {
+            let file = File::create(&m.path).map_err(|e| format!("{}: {}", m.path.display(), e))?;
+            if ports.outputs.insert(m.cell, BufWriter::new(file)).is_some() {
+                return Err(format!("cell {} is mapped to two output files", m.cell));
+            }
+        }
+        for m in inputs {
+            let file = File::open(&m.path).map_err(|e| format!("{}: {}", m.path.display(), e))?;
+            if ports.inputs.insert(m.cell, BufReader::new(file).bytes()).is_some() {
+                return Err(format!("cell {} is mapped to two input files", m.cell));
+            }
+        }
+        Ok(ports)
+    }
+
+    /// The highest mapped cell, which the tape must reach.
+    pub fn highest(&self) -> Option<usize> {
+        self.outputs.keys().chain(self.inputs.keys()).max().copied()
+    }
+
+    /// Writes `value` to the file of `cell`; returns `false` if the cell is not mapped to output.
+    pub fn output(&mut self, cell: usize, value: u8) -> io::Result<bool> {
+        match self.outputs.get_mut(&cell) {
+            Some(out) => out.write_all(&[value]).map(|()| true),
+            None => Ok(false),
+        }
+    }
+
+    /// Reads the next byte of the file of `cell`, or `None` if the cell is not mapped to input.
+    pub fn input(&mut self, cell: usize) -> io::Result<Option<u8>> {
+        match self.inputs.get_mut(&cell) {
+            Some(input) => input.next().transpose().map(|byte| Some(byte.unwrap_or(0))),
+            None => Ok(None),
+        }
+    }
+
+    /// Writes out what the output files have buffered.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.outputs.values_mut().try_for_each(|out| out.flush())
+    }
+}
@@ -0,0 +1,304 @@
+// `--preprocess`: expands `@include "file.bf"` lines and `@define` macros before the program is
+// parsed.
+//
+// A line holding only `@include "path"` is replaced by the text of that file, which may include
+// others in turn. The path is looked up next to the including file, then in each `-I` directory.
+// A file may be included any number of times, since each copy is code that runs where it is
+// included, but not from itself, directly or through others.
+//
+// After the includes, a line `@define NAME(a, b) body` defines a macro for the rest of the text,
+// and `@NAME(x, y)` is replaced by its body with every word `a` and `b` in it replaced by `x` and
+// `y`. A macro without parameters is `@define NAME body` and is used as `@NAME`. The replacement is
+// expanded again, so macros can use others, up to `MAX_DEPTH` deep. An `@` before a word that is
+// not a macro is left alone.
+//
+// The expanded text remembers where each of its pieces came from, so errors can point at the line
+// in the file that was written rather than at the expanded text. Code from a macro body points at
+// the definition, and arguments at the place they were written.
+
+use crate::diagnostic::line_col;
+use brainfucktool::ir::Inst;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const DIRECTIVE: &str = "@include";
+const DEFINE: &str = "@define";
+// How deep macros may expand inside each other, which stops one that uses itself.
+const MAX_DEPTH: usize = 32;
+
+// A piece of the expanded text, copied from `file` starting at byte `offset`.
+struct Segment {
+    start: usize,
+    file: usize,
+    offset: usize,
+}
+
+pub struct Expanded {
+    pub text: String,
+    pub files: Vec<(String, String)>, // The name and the text of every file read.
+    segments: Vec<Segment>,
+}
+
+impl Expanded {
+    /// The file and the byte in it that byte `pos` of the expanded text came from.
+    pub fn locate(&self, pos: usize) -> (usize, usize) {
+        let n = self.segments.partition_point(|s| s.start <= pos).saturating_sub(1);
+        match self.segments.get(n) {
+            Some(segment) => (segment.file, segment.offset + (pos - segment.start)),
+            None => (0, pos),
+        }
+    }
+
+    /// The file that the instruction at `index` came from, and `program` with the positions in that
+    /// file moved to the file's own text. Instructions from other files are placed at its end.
+    pub fn remap(&self, program: &[Inst], index: usize) -> (usize, Vec<Inst>) {
+        let file = program.get(index).map_or(0, |inst| self.locate(inst.pos).0);
+        let end = self.files[file].1.len();
+        let program = program
+            .iter()
+            .map(|inst| match self.locate(inst.pos) {
+                (f, pos) if f == file => Inst { op: inst.op.clone(), pos },
+                _ => Inst { op: inst.op.clone(), pos: end },
+            })
+            .collect();
+        (file, program)
+    }
+
+    fn push(&mut self, file: usize, offset: usize, text: &str) {
+        let start = self.text.len();
+        let continues = self.segments.last().is_some_and(|last| {
+            last.file == file && last.offset + (start - last.start) == offset
+        });
+        if !continues {
+            self.segments.push(Segment { start, file, offset });
+        }
+        self.text.push_str(text);
+    }
+}
+
+/// Reads `filename` and expands its includes, searching `include` after each file's directory.
+pub fn expand(filename: &str, include: &[PathBuf]) -> Result<Expanded, String> {
+    let src = fs::read_to_string(filename).map_err(|e| format!("{}: {}", filename, e))?;
+    let mut expanded = Expanded { text: String::new(), files: Vec::new(), segments: Vec::new() };
+    let canonical = fs::canonicalize(filename).unwrap_or_else(|_| PathBuf::from(filename));
+    insert(&mut expanded, filename, src, &mut vec![canonical], include)?;
+    expand_macros(&expanded)
+}
+
+// Appends the expansion of file `name` with text `src`; `stack` holds the files being expanded.
+fn insert(
+    expanded: &mut Expanded,
+    name: &str,
+    src: String,
+    stack: &mut Vec<PathBuf>,
+    include: &[PathBuf],
+) -> Result<(), String> {
+    let file = expanded.files.len();
+    expanded.files.push((name.to_string(), src.clone()));
+    let mut offset = 0;
+    for line in src.split_inclusive('\n') {
+        let body = line.trim();
+        let Some(arg) = body.strip_prefix(DIRECTIVE) else {
+            expanded.push(file, offset, line);
+            offset += line.len();
+            continue;
+        };
+        let (line_no, col) = line_col(&src, offset + line.find(DIRECTIVE).expect("the line has the directive"));
+        let at = |message: String| format!("{}:{}:{}: {}", name, line_no, col, message);
+        let path = arg
+            .trim()
+            .strip_prefix('"')
+            .and_then(|rest| rest.strip_suffix('"'))
+            .ok_or_else(|| at(format!("expected {} \"path\"", DIRECTIVE)))?;
+        let dirs = Path::new(name).parent().into_iter().map(Path::to_path_buf).chain(include.iter().cloned());
+        let found = dirs
+            .map(|dir| dir.join(path))
+            .find(|candidate| candidate.is_file())
+            .ok_or_else(|| at(format!("cannot find included file '{}'", path)))?;
+        let canonical = fs::canonicalize(&found).unwrap_or_else(|_| found.clone());
+        if let Some(first) = stack.iter().position(|p| *p == canonical) {
+            let chain: Vec<String> = stack[first..].iter().chain([&canonical]).map(|p| p.display().to_string()).collect();
+            return Err(at(format!("include cycle: {}", chain.join(" -> "))));
+        }
+        let text = fs::read_to_string(&found).map_err(|e| at(format!("cannot read '{}': {}", found.display(), e)))?;
+        stack.push(canonical);
+        insert(expanded, &found.to_string_lossy(), text, stack, include)?;
+        stack.pop();
+        // Keep the directive's line break so the next line of this file still starts a line.
+        if line.ends_with('\n') && !expanded.text.ends_with('\n') {
+            expanded.push(file, offset + line.len() - 1, "\n");
+        }
+        offset += line.len();
+    }
+    Ok(())
+}
+
+// A char of the expanded text with the file and the byte in it that it came from.
+type Traced = (char, (usize, usize));
+
+struct Macro {
+    params: Vec<String>,
+    body: Vec<Traced>,
+}
+
+// Defines and expands the macros of the text with its includes expanded.
+fn expand_macros(input: &Expanded) -> Result<Expanded, String> {
+    let text: Vec<Traced> = input.text.char_indices().map(|(pos, c)| (c, input.locate(pos))).collect();
+    let mut macros = HashMap::new();
+    let mut out = Vec::with_capacity(text.len());
+    for line in text.split_inclusive(|&(c, _)| c == '\n') {
+        let start = line.iter().position(|&(c, _)| !c.is_whitespace()).unwrap_or(line.len());
+        if starts_with(&line[start..], DEFINE) {
+            let (name, def) = define(input, &line[start..])?;
+            if macros.insert(name.clone(), def).is_some() {
+                return Err(at(input, &line[start..], format!("macro '{}' is defined twice", name)));
+            }
+            out.extend(line.last().filter(|&&(c, _)| c == '\n'));
+        } else {
+            expand_line(input, line, &macros, 0, &mut out)?;
+        }
+    }
+    let mut expanded = Expanded { text: String::new(), files: input.files.clone(), segments: Vec::new() };
+    let mut buf = [0; 4];
+    for (c, (file, offset)) in out {
+        expanded.push(file, offset, c.encode_utf8(&mut buf));
+    }
+    Ok(expanded)
+}
+
+// Parses `@define NAME(params) body`.
+fn define(input: &Expanded, line: &[Traced]) -> Result<(String, Macro), String> {
+    let rest = skip_spaces(&line[DEFINE.len()..]);
+    let name = word(rest);
+    if name.is_empty() || rest.len() == line.len() - DEFINE.len() {
+        return Err(at(input, line, format!("expected {} NAME(parameters) body", DEFINE)));
+    }
+    let mut rest = &rest[name.len()..];
+    let mut params = Vec::new();
+    if rest.first().is_some_and(|&(c, _)| c == '(') {
+        let (args, after) = arguments(rest).ok_or_else(|| at(input, line, "unclosed '(' in the parameters".to_string()))?;
+        for arg in args {
+            let param = word(arg);
+            if param.is_empty() || param.len() != arg.len() {
+                return Err(at(input, line, format!("the parameters of '{}' must be names", name)));
+            }
+            params.push(param);
+        }
+        rest = after;
+    }
+    let body = skip_spaces(rest);
+    let end = body.iter().rposition(|&(c, _)| !c.is_whitespace()).map_or(0, |i| i + 1);
+    Ok((name, Macro { params, body: body[..end].to_vec() }))
+}
+
+// Appends `line` to `out` with the macros in it expanded; `depth` is how deep in macros it is.
+fn expand_line(
+    input: &Expanded,
+    line: &[Traced],
+    macros: &HashMap<String, Macro>,
+    depth: usize,
+    out: &mut Vec<Traced>,
+) -> Result<(), String> {
+    let mut i = 0;
+    while i < line.len() {
+        let name = if line[i].0 == '@' { word(&line[i + 1..]) } else { String::new() };
+        let Some(def) = macros.get(&name) else {
+            out.push(line[i]);
+            i += 1;
+            continue;
+        };
+        if depth == MAX_DEPTH {
+            return Err(at(input, &line[i..], format!("macros are nested more than {} deep; does '{}' use itself?", MAX_DEPTH, name)));
+        }
+        let mut rest = &line[i + 1 + name.len()..];
+        let mut args = Vec::new();
+        if !def.params.is_empty() {
+            let parsed = rest.first().filter(|&&(c, _)| c == '(').and_then(|_| arguments(rest));
+            let (found, after) = parsed.ok_or_else(|| at(input, &line[i..], format!("expected arguments after '@{}'", name)))?;
+            (args, rest) = (found, after);
+        }
+        if args.len() != def.params.len() {
+            let message = format!("macro '{}' takes {} arguments but {} were given", name, def.params.len(), args.len());
+            return Err(at(input, &line[i..], message));
+        }
+        let mut body = Vec::new();
+        let mut j = 0;
+        while j < def.body.len() {
+            let w = word(&def.body[j..]);
+            let after_word = j == 0 || !is_word_char(def.body[j - 1].0);
+            match def.params.iter().position(|p| after_word && *p == w) {
+                Some(n) => {
+                    body.extend_from_slice(args[n]);
+                    j += w.len();
+                }
+                None => {
+                    body.push(def.body[j]);
+                    j += 1;
+                }
+            }
+        }
+        expand_line(input, &body, macros, depth + 1, out)?;
+        i = line.len() - rest.len();
+    }
+    Ok(())
+}
+
+// Splits `(a, b)` at `text`'s start into trimmed arguments, and returns the text after it.
+fn arguments(text: &[Traced]) -> Option<(Vec<&[Traced]>, &[Traced])> {
+    let (mut depth, mut start, mut args) = (0, 1, Vec::new());
+    for (i, &(c, _)) in text.iter().enumerate() {
+        match c {
+            '(' => depth += 1,
+            ')' | ',' if depth == 1 => {
+                let arg = trim(&text[start..i]);
+                if !(c == ')' && args.is_empty() && arg.is_empty()) {
+                    args.push(arg);
+                }
+                if c == ')' {
+                    return Some((args, &text[i + 1..]));
+                }
+                start = i + 1;
+            }
+            ')' => depth -= 1,
+            '\n' => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+// The name at `text`'s start, or "" if there is none.
+fn word(text: &[Traced]) -> String {
+    if text.first().is_none_or(|&(c, _)| c.is_ascii_digit()) {
+        return String::new();
+    }
+    text.iter().map(|&(c, _)| c).take_while(|&c| is_word_char(c)).collect()
+}
+
+fn starts_with(text: &[Traced], prefix: &str) -> bool {
+    text.len() >= prefix.len() && text.iter().zip(prefix.chars()).all(|(&(c, _), p)| c == p)
+}
+
+fn skip_spaces(text: &[Traced]) -> &[Traced] {
+    let start = text.iter().position(|&(c, _)| c != ' ' && c != '\t').unwrap_or(text.len());
+    &text[start..]
+}
+
+fn trim(text: &[Traced]) -> &[Traced] {
+    let text = skip_spaces(text);
+    let end = text.iter().rposition(|&(c, _)| !c.is_whitespace()).map_or(0, |i| i + 1);
+    &text[..end]
+}
+
+// `message` located at the start of `text`, as `file:line:col: message`.
+fn at(input: &Expanded, text: &[Traced], message: String) -> String {
+    let (file, pos) = text.first().map_or((0, 0), |&(_, origin)| origin);
+    let (name, src) = &input.files[file];
+    let (line, col) = line_col(src, pos);
+    format!("{}:{}:{}: {}", name, line, col, message)
+}
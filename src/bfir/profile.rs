@@ -0,0 +1,78 @@
+// `--profile-output`: where the run spent its steps, as folded stacks for flamegraph tools.
+//
+// Each line is a stack of the loops that were running, outermost first, under a root frame named
+// after the file, and the number of steps taken there (the same steps as `--progress` counts):
+//
+//     prog.bf 12
+//     prog.bf;loop@3:1 4000
+//     prog.bf;loop@3:1;loop@4:5 120000
+//
+// A loop is named by the line and column of its `[`. `flamegraph.pl folded.txt > flame.svg` or
+// `inferno-flamegraph` draw the file as is.
+
+use crate::diagnostic::line_col;
+use brainfucktool::ir::Inst;
+use std::collections::HashMap;
+
+// A stack of running loops, as a node of the tree of the stacks seen so far.
+struct Frame {
+    parent: usize,
+    open: usize, // The index of the loop's `[` in the program.
+    steps: u64,
+}
+
+pub struct Profile {
+    frames: Vec<Frame>, // The root, the top level of the program, is the first.
+    children: HashMap<(usize, usize), usize>,
+    current: usize,
+}
+
+impl Default for Profile {
+    fn default() -> Profile {
+        Profile { frames: vec![Frame { parent: 0, open: 0, steps: 0 }], children: HashMap::new(), current: 0 }
+    }
+}
+
+impl Profile {
+    /// Counts a step in the current stack.
+    pub fn step(&mut self) {
+        self.frames[self.current].steps += 1;
+    }
+
+    /// Starts running the loop opened at `open`, inside the current one.
+    pub fn enter(&mut self, open: usize) {
+        let frames = &mut self.frames;
+        let parent = self.current;
+        self.current = *self.children.entry((parent, open)).or_insert_with(|| {
+            frames.push(Frame { parent, open, steps: 0 });
+            frames.len() - 1
+        });
+    }
+
+    /// Leaves the innermost running loop.
+    pub fn exit(&mut self) {
+        self.current = self.frames[self.current].parent;
+    }
+
+    /// Formats the stacks that took steps, one per line, locating loops in `src`.
+    pub fn folded(&self, program: &[Inst], src: &str, filename: &str) -> String {
+        let name = |frame: &Frame| {
+            let (line, col) = line_col(src, program[frame.open].pos);
+            format!("loop@{}:{}", line, col)
+        };
+        let mut lines = Vec::new();
+        for (i, frame) in self.frames.iter().enumerate().filter(|(_, frame)| frame.steps > 0) {
+            let mut stack = Vec::new();
+            let mut at = i;
+            while at != 0 {
+                stack.push(name(&self.frames[at]));
+                at = self.frames[at].parent;
+            }
+            stack.push(filename.to_string());
+            stack.reverse();
+            lines.push(format!("{} {}\n", stack.join(";"), frame.steps));
+        }
+        lines.sort();
+        lines.concat()
+    }
+}
@@ -0,0 +1,80 @@
+// `--progress`: a status line on stderr for long runs.
+//
+// The line shows how many instructions have run (folded runs of `+`, `-`, `<`, `>` count once),
+// the rate over the run so far and the elapsed time, and is redrawn in place a few times a second.
+// The clock is only read every `CHECK_EVERY` instructions, so the count is all the interpreter
+// loop pays for. The final figures stay on screen when the run ends.
+
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+// Instructions between two looks at the clock; a power of two so the test is a mask.
+const CHECK_EVERY: u64 = 1 << 16;
+const REDRAW_EVERY: Duration = Duration::from_millis(250);
+
+pub struct Progress {
+    start: Instant,
+    last_drawn: Instant,
+    executed: u64,
+}
+
+impl Progress {
+    pub fn start() -> Progress {
+        let now = Instant::now();
+        Progress { start: now, last_drawn: now, executed: 0 }
+    }
+
+    /// Counts one instruction, redrawing the line when it is due.
+    #[inline]
+    pub fn tick(&mut self) {
+        self.executed += 1;
+        if self.executed & (CHECK_EVERY - 1) == 0 {
+            self.check();
+        }
+    }
+
+    /// Counts `n` instructions run at once, as a loop in closed form is.
+    pub fn skip(&mut self, n: u64) {
+        let before = self.executed;
+        self.executed += n;
+        if before / CHECK_EVERY != self.executed / CHECK_EVERY {
+            self.check();
+        }
+    }
+
+    fn check(&mut self) {
+        let now = Instant::now();
+        if now - self.last_drawn >= REDRAW_EVERY {
+            self.last_drawn = now;
+            self.draw(now);
+        }
+    }
+
+    fn draw(&self, now: Instant) {
+        let elapsed = (now - self.start).as_secs_f64();
+        let rate = if elapsed > 0.0 { self.executed as f64 / elapsed } else { 0.0 };
+        let mut err = io::stderr().lock();
+        let _ = write!(err, "\r\x1b[K{} instructions, {}/s, {:.1}s", self.executed, metric(rate), elapsed);
+        let _ = err.flush();
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        self.draw(Instant::now());
+        // `\r` as well, in case the terminal is still in raw mode.
+        eprint!("\r\n");
+    }
+}
+
+// Formats `value` with a metric prefix, e.g. 12.3M.
+fn metric(value: f64) -> String {
+    let prefixes = ["", "k", "M", "G", "T"];
+    let mut value = value;
+    let mut prefix = 0;
+    while value >= 1000.0 && prefix < prefixes.len() - 1 {
+        value /= 1000.0;
+        prefix += 1;
+    }
+    format!("{:.1}{}", value, prefixes[prefix])
+}
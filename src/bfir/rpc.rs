@@ -0,0 +1,253 @@
+// `--rpc`: drives the interpreter with JSON-RPC 2.0 requests, one per line on stdin.
+//
+// For GUIs and editor plugins that want to run a program step by step without a terminal. Each
+// request gets one response line on stdout; notifications (requests without an `id`) get none.
+//
+//     {"jsonrpc": "2.0", "id": 1, "method": "load", "params": {"source": ",[.,]", "input": "hi"}}
+//     {"jsonrpc": "2.0", "id": 1, "result": {"ops": 5}}
+//     {"jsonrpc": "2.0", "id": 2, "method": "setBreakpoint", "params": {"position": 2}}
+//     {"jsonrpc": "2.0", "id": 2, "result": {"position": 2}}
+//     {"jsonrpc": "2.0", "id": 3, "method": "run"}
+//     {"jsonrpc": "2.0", "id": 3, "result": {"stopped": "breakpoint", "pc": 2, "position": 2, ...}}
+//
+// The methods are
+//   load            {source | file, input?, cells?, cellSize?}  parses a program and starts a run of it
+//   step            {count?}                 runs `count` (1) ops
+//   run             {maxSteps?}              runs until the end, a breakpoint or `maxSteps` ops
+//   readTape        {start?, length?}        the cells from `start`, all of them by default
+//   setBreakpoint   {position}               stops `run` before the op at or after a byte offset of the source
+//   clearBreakpoint {position}
+//   state           {}                       the whole run as a `schema` document of a `MachineState`
+//   exit            {}                       ends bfir after the response
+// `step` and `run` return where the run stopped, as `stopped` ("step", "end", "breakpoint" or
+// "maxSteps"), `pc`, `position`, `pointer` and `steps`, with `output`, the bytes printed since
+// the last `step` or `run`. The run is the library's `machine::Machine`: runs of `+-` and `<>` are
+// one op, and `,` gives 0 at the end of the input.
+
+use brainfucktool::machine::Machine;
+use brainfucktool::schema;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::collections::BTreeSet;
+use std::fs;
+use std::io::{self, BufRead, Write};
+
+// The error codes of JSON-RPC, and the one for requests the run cannot carry out.
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const FAILED: i64 = -32000;
+
+struct Error {
+    code: i64,
+    message: String,
+}
+
+impl Error {
+    fn new(code: i64, message: impl Into<String>) -> Error {
+        Error { code, message: message.into() }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+struct Load {
+    source: Option<String>,
+    file: Option<String>,
+    #[serde(default)]
+    input: String,
+    cells: Option<usize>,
+    cell_size: Option<u32>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Step {
+    count: Option<u64>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+struct Run {
+    max_steps: Option<u64>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ReadTape {
+    #[serde(default)]
+    start: usize,
+    length: Option<usize>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Breakpoint {
+    position: usize,
+}
+
+#[derive(Default)]
+struct Session {
+    machine: Option<Machine>,
+    breakpoints: BTreeSet<usize>, // Byte offsets of ops in the source.
+    shown: usize,                 // Bytes of output already returned by `step` or `run`.
+    exit: bool,
+}
+
+impl Session {
+    fn call(&mut self, method: &str, params: Value) -> Result<Value, Error> {
+        match method {
+            "load" => self.load(parse(params)?),
+            "step" => {
+                let Step { count } = parse(params)?;
+                let machine = self.machine()?;
+                for _ in 0..count.unwrap_or(1) {
+                    if !machine.step().map_err(|e| Error::new(FAILED, e))? {
+                        break;
+                    }
+                }
+                let stopped = if machine.finished() { "end" } else { "step" };
+                Ok(self.stop(stopped))
+            }
+            "run" => {
+                let Run { max_steps } = parse(params)?;
+                let breakpoints = &self.breakpoints;
+                let machine = self.machine.as_mut().ok_or_else(not_loaded)?;
+                let mut left = max_steps;
+                let stopped = loop {
+                    if machine.finished() {
+                        break "end";
+                    }
+                    if left == Some(0) {
+                        break "maxSteps";
+                    }
+                    left = left.map(|n| n - 1);
+                    machine.step().map_err(|e| Error::new(FAILED, e))?;
+                    if machine.position().is_some_and(|pos| breakpoints.contains(&pos)) {
+                        break "breakpoint";
+                    }
+                };
+                Ok(self.stop(stopped))
+            }
+            "readTape" => {
+                let ReadTape { start, length } = parse(params)?;
+                let machine = self.machine()?;
+                let tape = machine.tape();
+                let end = length.map_or(tape.len(), |length| start.saturating_add(length).min(tape.len()));
+                let cells = tape.get(start..end).ok_or_else(|| Error::new(INVALID_PARAMS, "start is past the end of the tape"))?;
+                Ok(json!({ "start": start, "cells": cells, "pointer": machine.pointer() }))
+            }
+            "setBreakpoint" => {
+                let Breakpoint { position } = parse(params)?;
+                let found = self.machine()?.op_at(position);
+                let position = found.ok_or_else(|| Error::new(INVALID_PARAMS, "no op at or after position"))?;
+                self.breakpoints.insert(position);
+                Ok(json!({ "position": position }))
+            }
+            "clearBreakpoint" => {
+                let Breakpoint { position } = parse(params)?;
+                Ok(json!({ "cleared": self.breakpoints.remove(&position) }))
+            }
+            "state" => {
+                parse::<Nothing>(params)?;
+                let state = schema::to_json(&self.machine()?.state());
+                Ok(serde_json::from_str(&state).expect("documents are JSON"))
+            }
+            "exit" => {
+                parse::<Nothing>(params)?;
+                self.exit = true;
+                Ok(Value::Null)
+            }
+            _ => Err(Error::new(METHOD_NOT_FOUND, format!("no method called '{}'", method))),
+        }
+    }
+
+    fn load(&mut self, load: Load) -> Result<Value, Error> {
+        let source = match (load.source, load.file) {
+            (Some(source), None) => source,
+            (None, Some(file)) => fs::read_to_string(&file).map_err(|e| Error::new(FAILED, format!("{}: {}", file, e)))?,
+            _ => return Err(Error::new(INVALID_PARAMS, "give one of source and file")),
+        };
+        let (cells, cell_size) = (load.cells.unwrap_or(30000), load.cell_size.unwrap_or(8));
+        let machine = Machine::new(&source, load.input.into_bytes(), cells, cell_size).map_err(|e| Error::new(FAILED, e))?;
+        let ops = machine.ops();
+        *self = Session { machine: Some(machine), ..Session::default() };
+        Ok(json!({ "ops": ops }))
+    }
+
+    fn machine(&mut self) -> Result<&mut Machine, Error> {
+        self.machine.as_mut().ok_or_else(not_loaded)
+    }
+
+    // Where the run stopped, with the output since the last stop.
+    fn stop(&mut self, stopped: &str) -> Value {
+        let machine = self.machine.as_ref().expect("stopped runs are loaded");
+        let output = &machine.output()[self.shown..];
+        let value = json!({
+            "stopped": stopped,
+            "pc": machine.pc(),
+            "position": machine.position(),
+            "pointer": machine.pointer(),
+            "steps": machine.steps(),
+            "output": output,
+        });
+        self.shown = machine.output().len();
+        value
+    }
+}
+
+fn not_loaded() -> Error {
+    Error::new(FAILED, "no program is loaded")
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Nothing {}
+
+// The params of a method, which may be left out when all of them are optional.
+fn parse<T: for<'de> Deserialize<'de>>(params: Value) -> Result<T, Error> {
+    let params = if params.is_null() { json!({}) } else { params };
+    serde_json::from_value(params).map_err(|e| Error::new(INVALID_PARAMS, e.to_string()))
+}
+
+// The response to one line, or `None` for a notification.
+fn respond(session: &mut Session, line: &str) -> Option<Value> {
+    let request: Value = match serde_json::from_str(line) {
+        Ok(request) => request,
+        Err(e) => return Some(reply(Value::Null, Err(Error::new(PARSE_ERROR, e.to_string())))),
+    };
+    let id = request.get("id").cloned();
+    let method = request.get("method").and_then(Value::as_str);
+    let result = match (request.get("jsonrpc").and_then(Value::as_str), method) {
+        (Some("2.0"), Some(method)) => session.call(method, request.get("params").cloned().unwrap_or(Value::Null)),
+        _ => Err(Error::new(INVALID_REQUEST, "expected a JSON-RPC 2.0 request with a method")),
+    };
+    id.map(|id| reply(id, result))
+}
+
+fn reply(id: Value, result: Result<Value, Error>) -> Value {
+    match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(Error { code, message }) => json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } }),
+    }
+}
+
+/// Answers requests from `input` on `output` until `input` ends or an `exit` request.
+pub fn serve(input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+    let mut session = Session::default();
+    for line in input.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(response) = respond(&mut session, &line) {
+            writeln!(output, "{}", response)?;
+            output.flush()?;
+        }
+        if session.exit {
+            break;
+        }
+    }
+    Ok(())
+}
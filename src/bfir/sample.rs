@@ -0,0 +1,44 @@
+// Even sampling of a run of unknown length, for `--trace-html` and `--animate`.
+//
+// Every `every`-th step is recorded until `max` frames are kept; then every other frame is dropped
+// and the interval doubles, so however long the run, the frames cover all of it evenly.
+
+pub struct Samples<T> {
+    pub frames: Vec<T>,
+    steps: u64,
+    every: u64,
+    max: usize,
+}
+
+impl<T> Samples<T> {
+    pub fn new(every: u64, max: usize) -> Samples<T> {
+        Samples { frames: Vec::new(), steps: 0, every: every.max(1), max }
+    }
+
+    /// Counts a step; returns its number if a frame should be recorded for it.
+    #[inline]
+    pub fn tick(&mut self) -> Option<u64> {
+        let step = self.steps;
+        self.steps += 1;
+        if !step.is_multiple_of(self.every) {
+            return None;
+        }
+        if self.frames.len() >= self.max {
+            let mut keep = false;
+            self.frames.retain(|_| {
+                keep = !keep;
+                keep
+            });
+            self.every *= 2;
+            if !step.is_multiple_of(self.every) {
+                return None;
+            }
+        }
+        Some(step)
+    }
+
+    /// The number of steps counted so far.
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+}
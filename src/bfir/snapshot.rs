@@ -0,0 +1,109 @@
+// Saved runs (`--save-run`) and the differences between two of them (`--diff`).
+//
+// A snapshot records what a run printed to stdout, how it ended and the final tapes, so that two
+// runs of a program, say with and without `-w` or before and after optimizing it, can be compared
+// afterwards:
+//
+//     bfir --save-run before.json prog.bf
+//     bfir --save-run after.json optimized.bf
+//     bfir --diff before.json after.json
+//
+// `--diff` prints the output lines and tape cells that differ, and exits with status 1 if there
+// are any, like `diff`.
+
+use crate::highlight::{self, Role, Theme};
+use crate::tapes::TapeSet;
+use brainfucktool::schema::{self, Document};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+use std::fs;
+
+// Differing cells listed per tape before the rest are only counted.
+const MAX_CELLS: usize = 20;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub program: String,
+    pub output: Vec<u8>,
+    pub error: Option<String>, // The fault that ended the run, if it did not finish.
+    // Each tape up to its last non-zero cell; the cells after it are 0.
+    pub tapes: Vec<Vec<u8>>,
+    pub pointers: Vec<usize>,
+    pub current: usize,
+}
+
+impl Snapshot {
+    pub fn new(program: &str, output: Vec<u8>, error: Option<String>, tapes: &TapeSet) -> Snapshot {
+        let cells = tapes.tapes().map(|(tape, _)| {
+            let used = tape.iter().rposition(|&cell| cell != 0).map_or(0, |i| i + 1);
+            tape[..used].to_vec()
+        });
+        Snapshot {
+            program: program.to_string(),
+            output,
+            error,
+            tapes: cells.collect(),
+            pointers: tapes.tapes().map(|(_, ptr)| ptr).collect(),
+            current: tapes.current(),
+        }
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        fs::write(path, schema::to_json(self)).map_err(|e| format!("{}: {}", path, e))
+    }
+
+    pub fn load(path: &str) -> Result<Snapshot, String> {
+        let json = fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+        schema::from_json(&json).map_err(|e| format!("{}: {}", path, e))
+    }
+}
+
+impl Document for Snapshot {
+    const KIND: &'static str = "run";
+}
+
+/// Describes how run `b` differs from run `a`, or returns `None` if they agree. Lines only in `a`
+/// are marked `-`, lines only in `b` are marked `+`.
+pub fn diff(a: &Snapshot, b: &Snapshot, theme: &Theme) -> Option<String> {
+    let red = theme.start(Role::Removed);
+    let green = theme.start(Role::Added);
+    let reset = theme.reset();
+    let mut out = String::new();
+
+    if a.error != b.error {
+        let ending = |s: &Snapshot| s.error.clone().unwrap_or_else(|| "finished".to_string());
+        let _ = writeln!(out, "ending:");
+        let _ = writeln!(out, "{}- {}{}", red, ending(a), reset);
+        let _ = writeln!(out, "{}+ {}{}", green, ending(b), reset);
+    }
+
+    if a.output != b.output {
+        let _ = write!(out, "output, {}", highlight::diff(&a.output, &b.output, theme));
+    }
+
+    for t in 0..a.tapes.len().max(b.tapes.len()) {
+        let cell = |s: &Snapshot, i: usize| s.tapes.get(t).and_then(|tape| tape.get(i)).copied().unwrap_or(0);
+        let len = |s: &Snapshot| s.tapes.get(t).map_or(0, Vec::len);
+        let differing: Vec<usize> = (0..len(a).max(len(b))).filter(|&i| cell(a, i) != cell(b, i)).collect();
+        let (pa, pb) = (a.pointers.get(t), b.pointers.get(t));
+        if differing.is_empty() && pa == pb {
+            continue;
+        }
+        let _ = writeln!(out, "tape {}:", t);
+        if pa != pb {
+            let show = |p: Option<&usize>| p.map_or("-".to_string(), usize::to_string);
+            let _ = writeln!(out, "  pointer {}{}{} -> {}{}{}", red, show(pa), reset, green, show(pb), reset);
+        }
+        for &i in differing.iter().take(MAX_CELLS) {
+            let _ = writeln!(out, "  cell {:>5}: {}{:>3}{} -> {}{:>3}{}", i, red, cell(a, i), reset, green, cell(b, i), reset);
+        }
+        if differing.len() > MAX_CELLS {
+            let _ = writeln!(out, "  ... and {} more cells", differing.len() - MAX_CELLS);
+        }
+    }
+    if a.current != b.current {
+        let _ = writeln!(out, "current tape: {}{}{} -> {}{}{}", red, a.current, reset, green, b.current, reset);
+    }
+
+    (!out.is_empty()).then_some(out)
+}
@@ -0,0 +1,186 @@
+// `--step`: runs the program one instruction at a time, waiting for a key in between.
+//
+// Before each instruction the step count, the source location, the instruction and the cells
+// around the pointer are printed to stderr. Enter or space runs the instruction, `c` runs the
+// rest of the program without stopping, and `q` or Ctrl-C ends it. With `--source-map`, `p` asks
+// for a variable name and prints the cells that hold it at that point of the program, or all the
+// variables in scope for an empty name. `--step=loops` stops only at
+// the top-level instructions and at the start of each iteration of a top-level loop, which is
+// easier to follow for programs built from many small loops. Keys are read from the terminal
+// itself, so stdin stays free for the program's input.
+//
+// `--debug-after N` runs the first N instructions, or up to the first `#`, without stopping, and
+// then attaches: from there on it stops as `--step` does, with the state the run built up.
+
+use crate::diagnostic::line_col;
+use crate::highlight::{Role, Theme};
+use crate::tapes::TapeSet;
+use crate::tty::{self, RawMode};
+use brainfucktool::bfmap::{BfMap, Variable};
+use brainfucktool::ir::{self, Inst, Op};
+use clap::ValueEnum;
+use std::fs::File;
+use std::io;
+
+// Cells shown on each side of the pointer.
+const WINDOW: usize = 4;
+
+/// Where `--step` stops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum StepMode {
+    Instructions,
+    Loops,
+}
+
+pub struct Stepper<'a> {
+    mode: StepMode,
+    src: &'a str,
+    depth: Vec<usize>, // How many loops each instruction is in; a bracket is outside its own loop.
+    tty: File,
+    running: bool, // Set by `c`, and until `--debug-after` attaches: no stops.
+    symbols: Option<&'a BfMap>,
+}
+
+impl<'a> Stepper<'a> {
+    /// Opens the terminal for the keys, failing if there is none. Unless `attached`, the stepper
+    /// does not stop until `attach` is called.
+    pub fn open(mode: StepMode, program: &[Inst], src: &'a str, symbols: Option<&'a BfMap>, attached: bool) -> io::Result<Stepper<'a>> {
+        let mut depth = 0;
+        let depth = program
+            .iter()
+            .map(|inst| {
+                if let Op::Close(_) = inst.op {
+                    depth -= 1;
+                }
+                let here = depth;
+                if let Op::Open(_) = inst.op {
+                    depth += 1;
+                }
+                here
+            })
+            .collect();
+        let tty = File::open("/dev/tty")?;
+        match symbols {
+            Some(_) => eprintln!("[step] Enter/space: next  c: continue  p: print a variable  q: quit"),
+            None => eprintln!("[step] Enter/space: next  c: continue  q: quit"),
+        }
+        Ok(Stepper { mode, src, depth, tty, running: !attached, symbols })
+    }
+
+    /// Starts stopping, after `steps` instructions run without stops.
+    pub fn attach(&mut self, steps: u64, raw: Option<&RawMode>) {
+        self.running = false;
+        let show = || eprintln!("[step] attached after {} instructions", steps);
+        raw.map_or_else(show, |raw| raw.cooked(show));
+    }
+
+    // Whether to stop before instruction `i`.
+    fn stops_at(&self, program: &[Inst], i: usize) -> bool {
+        match self.mode {
+            _ if self.running => false,
+            StepMode::Instructions => true,
+            StepMode::Loops => {
+                let bracket = matches!(program[i].op, Op::Open(_) | Op::Close(_));
+                let starts_iteration = i > 0 && self.depth[i - 1] == 0 && matches!(program[i - 1].op, Op::Open(_));
+                (self.depth[i] == 0 && !bracket) || starts_iteration
+            }
+        }
+    }
+
+    /// Shows the state before instruction `i` and waits for a key, if the mode stops there. Fails
+    /// with `Interrupted` when the user quits.
+    pub fn pause(
+        &mut self,
+        program: &[Inst],
+        i: usize,
+        steps: u64,
+        tapes: &TapeSet,
+        raw: Option<&RawMode>,
+        theme: &Theme,
+    ) -> io::Result<()> {
+        if !self.stops_at(program, i) {
+            return Ok(());
+        }
+        let (line, col) = line_col(self.src, program[i].pos);
+        let (tape, ptr) = tapes.tapes().nth(tapes.current()).expect("the current tape exists");
+        let mut cells = String::new();
+        let start = ptr.saturating_sub(WINDOW);
+        for (c, cell) in tape.iter().enumerate().take(ptr + WINDOW + 1).skip(start) {
+            cells += &match c == ptr {
+                true => format!(" {}", theme.paint(Role::Pointer, format!("[{}]", cell))),
+                false => format!(" {}", cell),
+            };
+        }
+        let show = || {
+            eprintln!(
+                "[step {}] {}:{}  {}",
+                steps,
+                line,
+                col,
+                theme.paint(Role::of(&program[i].op, self.depth[i]), ir::describe(&program[i].op))
+            );
+            eprintln!("  tape {} ptr {}:{}", tapes.current(), ptr, cells);
+        };
+        raw.map_or_else(show, |raw| raw.cooked(show));
+        loop {
+            match tty::read_key(&mut self.tty, raw)? {
+                b'\r' | b'\n' | b' ' => return Ok(()),
+                b'c' => {
+                    self.running = true;
+                    return Ok(());
+                }
+                b'p' if self.symbols.is_some() => {
+                    let ask = || eprint!("  print: ");
+                    raw.map_or_else(ask, |raw| raw.cooked(ask));
+                    let name = tty::read_line(&mut self.tty, raw)?;
+                    let lines = self.print(&name, program[i].pos, tape);
+                    let show = || lines.iter().for_each(|line| eprintln!("  {}", line));
+                    raw.map_or_else(show, |raw| raw.cooked(show));
+                }
+                b'q' | tty::INTERRUPT => return Err(io::Error::new(io::ErrorKind::Interrupted, "stopped (--step)")),
+                _ => {}
+            }
+        }
+    }
+
+    // The values of the variables called `name` whose code contains byte `pos`, or of all of them
+    // but temporaries for an empty name. Where names are shadowed, the innermost one is shown.
+    fn print(&self, name: &str, pos: usize, tape: &[u8]) -> Vec<String> {
+        let Some(symbols) = self.symbols else {
+            return Vec::new();
+        };
+        let mut live: Vec<&Variable> = symbols
+            .variables
+            .iter()
+            .filter(|v| v.start <= pos && pos < v.end)
+            .filter(|v| if name.is_empty() { v.kind != "temporary" } else { v.name == name })
+            .collect();
+        live.sort_by_key(|v| v.end - v.start);
+        let mut shown: Vec<&str> = Vec::new();
+        let mut lines = Vec::new();
+        for v in live {
+            if shown.contains(&v.name.as_str()) {
+                continue;
+            }
+            shown.push(&v.name);
+            lines.push(match &v.frame {
+                Some(frame) => format!("{}: in the frame of '{}', which is not known here", v.name, frame),
+                None => {
+                    let cells = v.cell..v.cell + v.len;
+                    let values: Vec<String> = cells.clone().map(|c| tape.get(c).map_or("?".to_string(), u8::to_string)).collect();
+                    match v.len {
+                        1 => format!("{} = {}  (cell {})", v.name, values[0], v.cell),
+                        _ => format!("{} = [{}]  (cells {}-{})", v.name, values.join(", "), cells.start, cells.end - 1),
+                    }
+                }
+            });
+        }
+        if lines.is_empty() {
+            lines.push(match name {
+                "" => "no variables here".to_string(),
+                name => format!("no variable '{}' here", name),
+            });
+        }
+        lines
+    }
+}
@@ -0,0 +1,161 @@
+// `--strict`: refuses to run programs with text that is probably a mistake.
+//
+// Brainfuck ignores every character that is not a command, so a typo or a feature of another
+// dialect silently changes nothing, or changes the program in a way nobody meant. In strict mode
+// these are errors:
+//
+// - a `/*` that is never closed or a `*/` that closes nothing, with `-m`;
+// - bytes that are not ASCII outside block comments, such as a full-width `＋` or a typographic
+//   minus that looks like a command but is not one;
+// - code after a top-level loop that provably never ends, because it can never run;
+// - `#`, the tape-switch characters and `/* */` when the option that gives them a meaning is not
+//   given, since the commands inside an unrecognized comment run. `{` and `}` are also commands of
+//   `--dialect extended1`.
+//
+// Line comments (`--line-comments`) are skipped like block comments.
+
+use crate::diagnostic::line_col;
+use brainfucktool::diagnostic::{Diagnostic, Severity};
+use brainfucktool::ir::{Ext, Inst, Op, Syntax};
+use brainfucktool::lexer::Span;
+use std::collections::HashMap;
+
+/// Checks `src`, parsed as `program` with `syntax`; spans refer to file `file` of a source map.
+pub fn check(src: &str, program: &[Inst], syntax: Syntax<'_>, file: usize) -> Vec<Diagnostic> {
+    let mut found = scan(src, syntax, file);
+    if let Some(diagnostic) = unreachable(src, program, file) {
+        found.push(diagnostic);
+    }
+    found.sort_by_key(|d| d.span.map(|span| span.start));
+    found
+}
+
+fn error(code: &'static str, message: impl Into<String>, start: usize, end: usize, file: usize) -> Diagnostic {
+    Diagnostic::new(Severity::Error, code, message, Some(Span { start, end, file }))
+}
+
+// The checks of the text: comment delimiters, non-ASCII bytes and dialect characters.
+fn scan(src: &str, syntax: Syntax<'_>, file: usize) -> Vec<Diagnostic> {
+    let mut found = Vec::new();
+    let bytes = src.as_bytes();
+    let mut i = 0;
+    while i < src.len() {
+        let c = src[i..].chars().next().expect("`i` is at a char boundary");
+        if syntax.line_comments.contains(c) {
+            i = src[i..].find('\n').map_or(src.len(), |end| i + end);
+            continue;
+        }
+        let pair = &bytes[i..(i + 2).min(bytes.len())];
+        if pair == b"/*" {
+            let end = src[i + 2..].find("*/").map(|end| i + 2 + end + 2);
+            match (syntax.comments, end) {
+                (true, Some(end)) => i = end,
+                (true, None) => {
+                    found.push(
+                        error("unterminated-comment", "block comment is never closed", i, i + 2, file)
+                            .with_note("everything after `/*` is ignored"),
+                    );
+                    break;
+                }
+                (false, _) => {
+                    found.push(
+                        error("dialect-character", "block comments are not enabled", i, i + 2, file)
+                            .with_note("the commands inside run; pass -m to treat `/* */` as a comment"),
+                    );
+                    i = end.unwrap_or(i + 2);
+                }
+            }
+            continue;
+        }
+        if pair == b"*/" && syntax.comments {
+            found.push(error("unmatched-comment-end", "`*/` without a `/*` before it", i, i + 2, file));
+            i += 2;
+            continue;
+        }
+        if !c.is_ascii() {
+            let len = src[i..].find(|c: char| c.is_ascii()).unwrap_or(src.len() - i);
+            found.push(
+                error("non-ascii", format!("non-ASCII character {:?}", c), i, i + len, file)
+                    .with_note("it is ignored, even if it looks like a command"),
+            );
+            i += len;
+            continue;
+        }
+        if c == '#' && !syntax.dump {
+            found.push(
+                error("dialect-character", "`#` is not a command here", i, i + 1, file)
+                    .with_note("it is ignored; it dumps memory only with -d"),
+            );
+        }
+        if (c == '{' || c == '}') && syntax.tapes.is_none() && !syntax.extended {
+            found.push(
+                error("dialect-character", format!("`{}` is not a command here", c), i, i + 1, file)
+                    .with_note("it is ignored; it switches tapes only with --tapes"),
+            );
+        }
+        i += 1;
+    }
+    found
+}
+
+// Code after the first top-level loop that is entered with a known nonzero cell and whose body
+// cannot change it.
+fn unreachable(src: &str, program: &[Inst], file: usize) -> Option<Diagnostic> {
+    // Cell values relative to where the pointer started, while they are known; `None` is
+    // unknown, and cells not in the map are 0 until the first loop or tape switch.
+    let mut cells: HashMap<i64, Option<u8>> = HashMap::new();
+    let mut zero = true;
+    let mut ptr = 0i64;
+    let mut i = 0;
+    while i < program.len() {
+        match program[i].op {
+            Op::Add(n) => {
+                let value = cells.get(&ptr).copied().unwrap_or(zero.then_some(0));
+                cells.insert(ptr, value.map(|v| (v as i32 + n).rem_euclid(256) as u8));
+            }
+            Op::Move(n) => ptr += n as i64,
+            Op::Input => {
+                cells.insert(ptr, None);
+            }
+            Op::Tape(_) => {
+                cells.clear();
+                zero = false;
+            }
+            Op::Open(close) => {
+                let value = cells.get(&ptr).copied().unwrap_or(zero.then_some(0));
+                let stuck = program[i + 1..close]
+                    .iter()
+                    .all(|inst| matches!(inst.op, Op::Output | Op::Dump | Op::Comment(_)));
+                if value.is_some_and(|v| v != 0) && stuck {
+                    let rest: Vec<&Inst> =
+                        program[close + 1..].iter().filter(|inst| !matches!(inst.op, Op::Comment(_))).collect();
+                    let (first, last) = (rest.first()?, rest.last()?);
+                    let (line, col) = line_col(src, program[i].pos);
+                    return Some(
+                        error("unreachable-code", "code after a loop that never ends", first.pos, last.pos + 1, file)
+                            .with_note(format!(
+                                "the loop at {}:{} starts with a nonzero cell that its body does not change",
+                                line, col
+                            )),
+                    );
+                }
+                if value != Some(0) {
+                    // After the loop only the current cell is known.
+                    cells.clear();
+                    cells.insert(ptr, Some(0));
+                    zero = false;
+                }
+                i = close;
+            }
+            // What follows `@` is data, not code.
+            Op::Ext(Ext::End) => return None,
+            Op::Ext(Ext::Store) => {}
+            Op::Ext(_) => {
+                cells.insert(ptr, None);
+            }
+            Op::Close(_) | Op::Output | Op::Dump | Op::Comment(_) => {}
+        }
+        i += 1;
+    }
+    None
+}
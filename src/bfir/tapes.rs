@@ -0,0 +1,71 @@
+// The tapes a program runs on, each with its own data pointer.
+//
+// Programs see a single tape unless the multi-tape dialect is enabled with `--tapes`; its switch
+// characters (`{` and `}` by default) then move to the previous or the next tape, wrapping around
+// from the last tape to the first. Every tape has `-c` cells.
+
+use crate::diagnostic::Fault;
+
+#[derive(Hash)]
+pub struct TapeSet {
+    tapes: Vec<Vec<u8>>,
+    ptrs: Vec<usize>,
+    current: usize,
+}
+
+impl TapeSet {
+    pub fn new(count: usize, cells: usize) -> TapeSet {
+        TapeSet {
+            tapes: vec![vec![0; cells]; count],
+            ptrs: vec![0; count],
+            current: 0,
+        }
+    }
+
+    /// The index of the tape the program is on.
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    /// The data pointer of the current tape.
+    pub fn ptr(&self) -> usize {
+        self.ptrs[self.current]
+    }
+
+    pub fn cell(&self) -> u8 {
+        self.tapes[self.current][self.ptr()]
+    }
+
+    pub fn cell_mut(&mut self) -> &mut u8 {
+        let ptr = self.ptr();
+        &mut self.tapes[self.current][ptr]
+    }
+
+    /// The current tape, and its data pointer.
+    pub fn current_mut(&mut self) -> (&mut [u8], usize) {
+        (&mut self.tapes[self.current], self.ptrs[self.current])
+    }
+
+    /// Moves the current tape's pointer by `step` cells, failing if it would leave the tape.
+    pub fn move_by(&mut self, step: i32) -> Result<(), Fault> {
+        let tape = &self.tapes[self.current];
+        match self.ptrs[self.current].checked_add_signed(step as isize) {
+            Some(ptr) if ptr < tape.len() => {
+                self.ptrs[self.current] = ptr;
+                Ok(())
+            }
+            _ => Err(Fault::PointerOutOfRange { step, cells: tape.len() }),
+        }
+    }
+
+    /// Moves `step` tapes forward (or back, if negative), wrapping around.
+    pub fn switch(&mut self, step: i32) {
+        let count = self.tapes.len() as i64;
+        self.current = (self.current as i64 + step as i64).rem_euclid(count) as usize;
+    }
+
+    /// Each tape with its data pointer.
+    pub fn tapes(&self) -> impl Iterator<Item = (&[u8], usize)> {
+        self.tapes.iter().map(Vec::as_slice).zip(self.ptrs.iter().copied())
+    }
+}
@@ -0,0 +1,153 @@
+// `--trace-html`: a self-contained HTML page for replaying a run.
+//
+// The page has a slider over the recorded steps and shows, for the selected one, the source with
+// the next command highlighted, the cells around the pointer and the output so far. Every step is
+// recorded until `MAX_FRAMES` are kept, after which a long run is sampled evenly.
+
+use crate::sample::Samples;
+use crate::tapes::TapeSet;
+use serde::Serialize;
+use std::fs;
+
+const MAX_FRAMES: usize = 4096;
+// Cells recorded on each side of the pointer.
+const WINDOW: usize = 16;
+
+#[derive(Serialize)]
+struct Frame {
+    step: u64,
+    at: usize,    // Char index of the next command in the source.
+    tape: usize,  // The current tape.
+    start: usize, // Index of the first cell in `cells`.
+    cells: Vec<u8>,
+    ptr: usize,
+    output: usize, // Bytes printed so far.
+}
+
+pub struct Trace {
+    samples: Samples<Frame>,
+    chars: Vec<usize>, // Char index of each byte of the source.
+}
+
+impl Trace {
+    pub fn new(src: &str) -> Trace {
+        let mut chars = vec![0; src.len() + 1];
+        for (n, (byte, c)) in src.char_indices().enumerate() {
+            chars[byte..byte + c.len_utf8()].fill(n);
+        }
+        chars[src.len()] = src.chars().count();
+        Trace { samples: Samples::new(1, MAX_FRAMES), chars }
+    }
+
+    /// Counts an instruction, first recording the state if this step is sampled; `pos` is the
+    /// source byte of the instruction and `output` the number of bytes printed so far.
+    #[inline]
+    pub fn record(&mut self, pos: usize, tapes: &TapeSet, output: usize) {
+        if let Some(step) = self.samples.tick() {
+            self.push(step, pos, tapes, output);
+        }
+    }
+
+    /// Records the state the run ended in, before the command at `pos` if it failed.
+    pub fn finish(&mut self, pos: usize, tapes: &TapeSet, output: usize) {
+        let steps = self.samples.steps();
+        if self.samples.frames.last().is_none_or(|frame| frame.step != steps) {
+            self.push(steps, pos, tapes, output);
+        }
+    }
+
+    fn push(&mut self, step: u64, pos: usize, tapes: &TapeSet, output: usize) {
+        let (tape, ptr) = tapes.tapes().nth(tapes.current()).expect("the current tape exists");
+        let start = ptr.saturating_sub(WINDOW);
+        self.samples.frames.push(Frame {
+            step,
+            at: self.chars[pos.min(self.chars.len() - 1)],
+            tape: tapes.current(),
+            start,
+            cells: tape[start..(ptr + WINDOW + 1).min(tape.len())].to_vec(),
+            ptr,
+            output,
+        });
+    }
+
+    /// Writes the page for the run of `src`, read from `filename`, which printed `output`.
+    pub fn write(&self, path: &str, filename: &str, src: &str, output: &[u8]) -> Result<(), String> {
+        let data = serde_json::json!({
+            "file": filename,
+            "source": src,
+            // One char per byte, as bfir prints them.
+            "output": output.iter().map(|&byte| byte as char).collect::<String>(),
+            "frames": self.samples.frames,
+        });
+        // `</` would end the script element early.
+        let data = data.to_string().replace("</", "<\\/");
+        let (head, tail) = PAGE.split_once("{{DATA}}").expect("the page has a place for the data");
+        let title = escape(filename);
+        let page = [head.replace("{{TITLE}}", &title), data, tail.replace("{{TITLE}}", &title)].concat();
+        fs::write(path, page).map_err(|e| format!("{}: {}", path, e))
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+const PAGE: &str = r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>{{TITLE}} - bfir trace</title>
+<style>
+body { font-family: sans-serif; margin: 1em 2em; background: #1e1e1e; color: #ddd; }
+pre { background: #111; padding: 0.5em; white-space: pre-wrap; word-break: break-all; max-height: 40vh; overflow: auto; }
+#at { background: #c60; color: #fff; }
+table { border-collapse: collapse; font-family: monospace; }
+td { border: 1px solid #444; padding: 2px 6px; text-align: right; min-width: 2em; }
+td.ptr { background: #c60; color: #fff; }
+tr.index td { color: #888; font-size: 80%; }
+input[type=range] { width: 100%; }
+</style>
+</head>
+<body>
+<h3>{{TITLE}}</h3>
+<div>
+  <button id="prev">&lt;</button> <button id="next">&gt;</button>
+  <span id="label"></span>
+  <input type="range" id="slider" min="0" value="0">
+</div>
+<h4>Source</h4>
+<pre id="source"></pre>
+<h4 id="tapelabel">Tape</h4>
+<table><tr class="index" id="index"></tr><tr id="cells"></tr></table>
+<h4>Output</h4>
+<pre id="output"></pre>
+<script>
+const trace = {{DATA}};
+const chars = Array.from(trace.source);
+const text = s => s.replace(/&/g, "&amp;").replace(/</g, "&lt;").replace(/>/g, "&gt;");
+const slider = document.getElementById("slider");
+slider.max = trace.frames.length - 1;
+function show(n) {
+  const f = trace.frames[n];
+  document.getElementById("label").textContent =
+    `step ${f.step} (frame ${n + 1} of ${trace.frames.length})`;
+  const before = chars.slice(0, f.at).join(""), at = chars[f.at] || "", after = chars.slice(f.at + 1).join("");
+  document.getElementById("source").innerHTML = text(before) + '<span id="at">' + text(at) + "</span>" + text(after);
+  const mark = document.getElementById("at");
+  if (mark) mark.scrollIntoView({ block: "nearest" });
+  document.getElementById("tapelabel").textContent = `Tape ${f.tape}, pointer ${f.ptr}`;
+  document.getElementById("index").innerHTML = f.cells.map((_, i) => `<td>${f.start + i}</td>`).join("");
+  document.getElementById("cells").innerHTML = f.cells
+    .map((c, i) => `<td${f.start + i === f.ptr ? ' class="ptr"' : ""}>${c}</td>`).join("");
+  document.getElementById("output").textContent = Array.from(trace.output).slice(0, f.output).join("");
+}
+slider.oninput = () => show(+slider.value);
+const go = d => { slider.value = Math.min(Math.max(+slider.value + d, 0), trace.frames.length - 1); show(+slider.value); };
+document.getElementById("prev").onclick = () => go(-1);
+document.getElementById("next").onclick = () => go(1);
+document.onkeydown = e => { if (e.key === "ArrowLeft") go(-1); if (e.key === "ArrowRight") go(1); };
+show(0);
+</script>
+</body>
+</html>
+"#;
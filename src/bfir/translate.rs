@@ -0,0 +1,472 @@
+// `--to`: translates a program to another dialect or language instead of running it.
+//
+// Every translation goes through the same pipeline as a run: the source is read as its dialect
+// (`--from`, else `--dialect` or the file's extension), parsed into `ir` ops, cleared of loops that
+// never run (see `dead`), and the ops are written out in the target format. So runs of `+-` and
+// `<>` are folded and comments are dropped whatever the two formats are.
+//
+//     bfir --to c hello.bf > hello.c
+//     bfir --from ook --to js prog.ook > prog.js
+//     bfir --to map:cow.toml hello.bf > hello.cow
+//
+// `--list-targets` prints which formats can be read and which written. The programs in other
+// languages have cells of 8 bits that wrap around, `-c` cells, and read 0 at the end of the input.
+
+use crate::dead;
+use crate::dialect::{self, Dialect, Mapping};
+use brainfucktool::diagnostic::Diagnostic;
+use brainfucktool::ir::{self, Ext, Inst, Op, Syntax};
+use brainfucktool::schema;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Format {
+    Brainfuck,
+    Ook,
+    Extended1,
+    Pbrain,
+    Map(PathBuf),
+    C,
+    Rust,
+    Js,
+    Wasm,
+    Ir,
+    IrJson,
+}
+
+// The formats by name, whether they can be read and written, and what they are.
+const FORMATS: [(&str, bool, bool, &str); 11] = [
+    ("bf", true, true, "Brainfuck"),
+    ("ook", true, true, "Ook!"),
+    ("extended1", true, true, "Extended Brainfuck Type I"),
+    ("pbrain", true, true, "Brainfuck with procedures; bfir has none, so it reads and writes pbrain without them"),
+    ("map:FILE", true, true, "a dialect spelling each command as a word from a TOML file (see src/bfir/dialect.rs)"),
+    ("c", false, true, "a C program"),
+    ("rust", false, true, "a Rust program"),
+    ("js", false, true, "a Node.js program"),
+    ("wasm", false, true, "a WebAssembly module exporting `main`, importing env.putchar and env.getchar"),
+    ("ir", false, true, "the numbered ops, as `bfconstructor --emit ir` prints them"),
+    ("ir-json", false, true, "the ops as a JSON document"),
+];
+
+impl Format {
+    fn name(&self) -> &'static str {
+        match self {
+            Format::Brainfuck => "bf",
+            Format::Ook => "ook",
+            Format::Extended1 => "extended1",
+            Format::Pbrain => "pbrain",
+            Format::Map(_) => "map:FILE",
+            Format::C => "c",
+            Format::Rust => "rust",
+            Format::Js => "js",
+            Format::Wasm => "wasm",
+            Format::Ir => "ir",
+            Format::IrJson => "ir-json",
+        }
+    }
+
+    /// The format a `--dialect` reads.
+    pub fn of(dialect: Dialect) -> Format {
+        match dialect {
+            Dialect::Brainfuck => Format::Brainfuck,
+            Dialect::Ook => Format::Ook,
+            Dialect::Extended1 => Format::Extended1,
+            Dialect::Pbrain => Format::Pbrain,
+        }
+    }
+
+    /// Whether bfir reads this format, as `--from` and `--dialect` do.
+    pub fn readable(&self) -> bool {
+        FORMATS.iter().any(|&(name, from, _, _)| from && name == self.name())
+    }
+
+    /// What the format is called in messages.
+    pub fn title(&self) -> String {
+        match self {
+            Format::Brainfuck => "Brainfuck".to_string(),
+            Format::Ook => "Ook!".to_string(),
+            Format::Extended1 => "Extended Brainfuck Type I".to_string(),
+            Format::Map(path) => format!("the words of {}", path.display()),
+            format => format.name().to_string(),
+        }
+    }
+}
+
+/// Parses the name of a format, as `--list-targets` lists them.
+pub fn parse_format(arg: &str) -> Result<Format, String> {
+    if let Some(path) = arg.strip_prefix("map:") {
+        return Ok(Format::Map(PathBuf::from(path)));
+    }
+    Ok(match arg {
+        "bf" | "brainfuck" => Format::Brainfuck,
+        "ook" => Format::Ook,
+        "extended1" => Format::Extended1,
+        "pbrain" => Format::Pbrain,
+        "c" => Format::C,
+        "rust" => Format::Rust,
+        "js" => Format::Js,
+        "wasm" => Format::Wasm,
+        "ir" => Format::Ir,
+        "ir-json" => Format::IrJson,
+        _ => return Err("unknown format; see --list-targets".to_string()),
+    })
+}
+
+/// The table `--list-targets` prints.
+pub fn list() -> String {
+    let mut out = format!("{:<10}  {:<4}  {}\n", "format", "from", "to");
+    for (name, from, to, about) in FORMATS {
+        let yes = |b: bool| if b { "yes" } else { "no" };
+        out += &format!("{:<10}  {:<4}  {:<3}  {}\n", name, yes(from), yes(to), about);
+    }
+    out
+}
+
+/// Reads the program `src`, from `filename`, in a format as Brainfuck with the same layout.
+pub fn read(src: &str, filename: &str, from: &Format) -> Result<String, String> {
+    Ok(match from {
+        Format::Brainfuck | Format::Extended1 => src.to_string(),
+        Format::Ook => dialect::translate(src.to_string(), Dialect::Ook).map_err(|e| format!("{}:{}", filename, e))?,
+        Format::Pbrain => dialect::translate(src.to_string(), Dialect::Pbrain).map_err(|e| format!("{}:{}", filename, e))?,
+        Format::Map(path) => Mapping::load(path)?.read(src),
+        _ => return Err(format!("{} can only be written, not read (see --list-targets)", from.name())),
+    })
+}
+
+/// Translates the program `src`, read from `filename`, from one format to another, with notes
+/// about the dead loops left out (in file 0 of a source map).
+pub fn translate(src: &str, filename: &str, from: &Format, to: &Format, cells: usize) -> Result<(Vec<u8>, Vec<Diagnostic>), String> {
+    let code = read(src, filename, from)?;
+    let parsed = ir::parse(&code, Syntax { extended: *from == Format::Extended1, ..Syntax::default() })?;
+    let (program, notes) = dead::eliminate(&parsed, 0);
+    let text = match to {
+        Format::Extended1 => ir::emit(&program),
+        Format::Brainfuck | Format::Pbrain => match program.iter().find(|inst| matches!(inst.op, Op::Ext(_))) {
+            Some(inst) => return Err(unspelled(to, &inst.op)),
+            None => ir::emit(&program),
+        },
+        Format::Ook => spell(&program, to, dialect::ook_word)?,
+        Format::Map(path) => {
+            let mapping = Mapping::load(path)?;
+            spell(&program, to, |c| mapping.word(c).map(str::to_string))?
+        }
+        Format::C => c(&program, filename, cells),
+        Format::Rust => rust(&program, filename, cells),
+        Format::Js => js(&program, filename, cells),
+        Format::Wasm => return Ok((wasm(&program, cells), notes)),
+        Format::Ir => ir::render(&program),
+        Format::IrJson => schema::to_json(&schema::Ir { ops: program }) + "\n",
+    };
+    Ok((text.into_bytes(), notes))
+}
+
+// The Brainfuck commands of an op, with runs unfolded, or `None` for an extended command.
+fn commands(op: &Op) -> Option<String> {
+    let run = |n: i32, up: &str, down: &str| if n > 0 { up.repeat(n as usize) } else { down.repeat(n.unsigned_abs() as usize) };
+    Some(match *op {
+        Op::Add(n) => run(n, "+", "-"),
+        Op::Move(n) => run(n, ">", "<"),
+        Op::Output => ".".to_string(),
+        Op::Input => ",".to_string(),
+        Op::Open(_) => "[".to_string(),
+        Op::Close(_) => "]".to_string(),
+        Op::Ext(_) => return None,
+        Op::Dump | Op::Tape(_) | Op::Comment(_) => String::new(),
+    })
+}
+
+fn unspelled(to: &Format, op: &Op) -> String {
+    format!("{} has no `{}` command", to.name(), ir::describe(op))
+}
+
+// Writes the program as words, eight commands to a line.
+fn spell(program: &[Inst], to: &Format, word: impl Fn(char) -> Option<String>) -> Result<String, String> {
+    let mut words = Vec::new();
+    for inst in program {
+        let commands = commands(&inst.op).ok_or_else(|| unspelled(to, &inst.op))?;
+        for c in commands.chars() {
+            words.push(word(c).expect("every format spells the eight commands"));
+        }
+    }
+    Ok(words.chunks(8).map(|line| line.join(" ") + "\n").collect())
+}
+
+// Whether the program has an op for which `pred` holds, to leave out unused declarations.
+fn uses(program: &[Inst], pred: impl Fn(&Op) -> bool) -> bool {
+    program.iter().any(|inst| pred(&inst.op))
+}
+
+fn c(program: &[Inst], filename: &str, cells: usize) -> String {
+    let mut out = format!("/* Translated from {} by bfir --to c. */\n#include <stdio.h>\n\n", filename);
+    out += &format!("static unsigned char tape[{}];\n\nint main(void) {{\n    unsigned char *p = tape;\n", cells);
+    if uses(program, |op| matches!(op, Op::Input)) {
+        out += "    int c;\n";
+    }
+    if uses(program, |op| matches!(op, Op::Ext(_))) {
+        out += "    unsigned char s = 0;\n";
+    }
+    let mut depth = 1;
+    for inst in program {
+        let line = match inst.op {
+            Op::Add(n) if n < 0 => format!("*p -= {};", -n),
+            Op::Add(n) => format!("*p += {};", n),
+            Op::Move(n) if n < 0 => format!("p -= {};", -n),
+            Op::Move(n) => format!("p += {};", n),
+            Op::Output => "putchar(*p);".to_string(),
+            Op::Input => "*p = (c = getchar()) == EOF ? 0 : c;".to_string(),
+            Op::Open(_) => "while (*p) {".to_string(),
+            Op::Close(_) => "}".to_string(),
+            Op::Ext(ext) => match ext {
+                Ext::End => "return 0;",
+                Ext::Store => "s = *p;",
+                Ext::Load => "*p = s;",
+                Ext::ShiftRight => "*p >>= 1;",
+                Ext::ShiftLeft => "*p <<= 1;",
+                Ext::Not => "*p = ~*p;",
+                Ext::Xor => "*p ^= s;",
+                Ext::And => "*p &= s;",
+                Ext::Or => "*p |= s;",
+            }
+            .to_string(),
+            Op::Dump | Op::Tape(_) | Op::Comment(_) => continue,
+        };
+        indent(&mut out, &mut depth, &inst.op, &line);
+    }
+    out + "    return 0;\n}\n"
+}
+
+fn rust(program: &[Inst], filename: &str, cells: usize) -> String {
+    let (input, output) = (uses(program, |op| matches!(op, Op::Input)), uses(program, |op| matches!(op, Op::Output)));
+    let mut out = format!("// Translated from {} by bfir --to rust.\n#![allow(unused_assignments)]\n", filename);
+    match (input, output) {
+        (true, true) => out += "use std::io::{Read, Write};\n",
+        (true, false) => out += "use std::io::Read;\n",
+        (false, true) => out += "use std::io::Write;\n",
+        (false, false) => {}
+    }
+    out += &format!("\nfn main() {{\n    let mut tape = vec![0u8; {}];\n    let mut p: usize = 0;\n", cells);
+    if input {
+        out += "    let mut input = std::io::stdin().lock().bytes();\n";
+    }
+    if output {
+        out += "    let mut output = std::io::stdout().lock();\n";
+    }
+    if uses(program, |op| matches!(op, Op::Ext(_))) {
+        out += "    let mut s: u8 = 0;\n";
+    }
+    let mut depth = 1;
+    for inst in program {
+        let line = match inst.op {
+            Op::Add(n) if n < 0 => format!("tape[p] = tape[p].wrapping_sub({});", -n % 256),
+            Op::Add(n) => format!("tape[p] = tape[p].wrapping_add({});", n % 256),
+            Op::Move(n) if n < 0 => format!("p -= {};", -n),
+            Op::Move(n) => format!("p += {};", n),
+            Op::Output => "output.write_all(&[tape[p]]).unwrap();".to_string(),
+            Op::Input => "tape[p] = input.next().and_then(Result::ok).unwrap_or(0);".to_string(),
+            Op::Open(_) => "while tape[p] != 0 {".to_string(),
+            Op::Close(_) => "}".to_string(),
+            Op::Ext(ext) => match ext {
+                Ext::End => "return;",
+                Ext::Store => "s = tape[p];",
+                Ext::Load => "tape[p] = s;",
+                Ext::ShiftRight => "tape[p] >>= 1;",
+                Ext::ShiftLeft => "tape[p] <<= 1;",
+                Ext::Not => "tape[p] = !tape[p];",
+                Ext::Xor => "tape[p] ^= s;",
+                Ext::And => "tape[p] &= s;",
+                Ext::Or => "tape[p] |= s;",
+            }
+            .to_string(),
+            Op::Dump | Op::Tape(_) | Op::Comment(_) => continue,
+        };
+        indent(&mut out, &mut depth, &inst.op, &line);
+    }
+    out + "}\n"
+}
+
+fn js(program: &[Inst], filename: &str, cells: usize) -> String {
+    let mut out = format!("// Translated from {} by bfir --to js. Run it with node; `,` reads stdin.\n", filename);
+    out += &format!("const tape = new Uint8Array({});\n", cells);
+    if uses(program, |op| matches!(op, Op::Input)) {
+        out += "const input = require('fs').readFileSync(0);\nlet next = 0;\n";
+    }
+    out += "const output = [];\nlet p = 0;\nlet s = 0;\n\nfunction main() {\n";
+    let mut depth = 1;
+    for inst in program {
+        let line = match inst.op {
+            Op::Add(n) if n < 0 => format!("tape[p] -= {};", -n),
+            Op::Add(n) => format!("tape[p] += {};", n),
+            Op::Move(n) if n < 0 => format!("p -= {};", -n),
+            Op::Move(n) => format!("p += {};", n),
+            Op::Output => "output.push(tape[p]);".to_string(),
+            Op::Input => "tape[p] = next < input.length ? input[next++] : 0;".to_string(),
+            Op::Open(_) => "while (tape[p]) {".to_string(),
+            Op::Close(_) => "}".to_string(),
+            Op::Ext(ext) => match ext {
+                Ext::End => "return;",
+                Ext::Store => "s = tape[p];",
+                Ext::Load => "tape[p] = s;",
+                Ext::ShiftRight => "tape[p] >>= 1;",
+                Ext::ShiftLeft => "tape[p] <<= 1;",
+                Ext::Not => "tape[p] = ~tape[p];",
+                Ext::Xor => "tape[p] ^= s;",
+                Ext::And => "tape[p] &= s;",
+                Ext::Or => "tape[p] |= s;",
+            }
+            .to_string(),
+            Op::Dump | Op::Tape(_) | Op::Comment(_) => continue,
+        };
+        indent(&mut out, &mut depth, &inst.op, &line);
+    }
+    out + "}\n\nmain();\nprocess.stdout.write(Buffer.from(output));\n"
+}
+
+// Appends a line of a C-like language at the depth of its loop.
+fn indent(out: &mut String, depth: &mut usize, op: &Op, line: &str) {
+    if let Op::Close(_) = op {
+        *depth -= 1;
+    }
+    *out += &format!("{}{}\n", "    ".repeat(*depth), line);
+    if let Op::Open(_) = op {
+        *depth += 1;
+    }
+}
+
+// Appends `n` as an unsigned LEB128 number.
+fn unsigned(out: &mut Vec<u8>, mut n: u32) {
+    loop {
+        let byte = (n & 0x7f) as u8;
+        n >>= 7;
+        if n == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+// Appends `n` as a signed LEB128 number.
+fn signed(out: &mut Vec<u8>, mut n: i32) {
+    loop {
+        let byte = (n & 0x7f) as u8;
+        n >>= 7;
+        if (n == 0 && byte & 0x40 == 0) || (n == -1 && byte & 0x40 != 0) {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+// Appends a section or other item prefixed with its length.
+fn sized(out: &mut Vec<u8>, id: Option<u8>, content: &[u8]) {
+    out.extend(id);
+    unsigned(out, content.len() as u32);
+    out.extend(content);
+}
+
+fn name(out: &mut Vec<u8>, name: &str) {
+    sized(out, None, name.as_bytes());
+}
+
+// The instructions of WebAssembly the translation uses.
+const LOCAL_GET: u8 = 0x20;
+const LOCAL_SET: u8 = 0x21;
+const LOAD8_U: [u8; 3] = [0x2d, 0, 0];
+const STORE8: [u8; 3] = [0x3a, 0, 0];
+const CONST: u8 = 0x41;
+const ADD: u8 = 0x6a;
+const CALL: u8 = 0x10;
+const END: u8 = 0x0b;
+const I32: u8 = 0x7f;
+// The locals of `main`: the pointer and the storage cell.
+const P: u8 = 0;
+const S: u8 = 1;
+
+fn wasm(program: &[Inst], cells: usize) -> Vec<u8> {
+    let mut body = vec![1, 2, I32]; // Two i32 locals.
+    let cell = [LOCAL_GET, P, LOAD8_U[0], LOAD8_U[1], LOAD8_U[2]];
+    for inst in program {
+        match inst.op {
+            Op::Add(n) => {
+                body.extend([LOCAL_GET, P]);
+                body.extend(cell);
+                body.push(CONST);
+                signed(&mut body, n);
+                body.push(ADD);
+                body.extend(STORE8);
+            }
+            Op::Move(n) => {
+                body.extend([LOCAL_GET, P, CONST]);
+                signed(&mut body, n);
+                body.extend([ADD, LOCAL_SET, P]);
+            }
+            Op::Output => {
+                body.extend(cell);
+                body.extend([CALL, 0]);
+            }
+            Op::Input => {
+                body.extend([LOCAL_GET, P, CALL, 1]);
+                body.extend(STORE8);
+            }
+            // block; loop; exit the block if the cell is 0
+            Op::Open(_) => {
+                body.extend([0x02, 0x40, 0x03, 0x40]);
+                body.extend(cell);
+                body.extend([0x45, 0x0d, 1]);
+            }
+            // branch back to the loop; end the loop and the block
+            Op::Close(_) => body.extend([0x0c, 0, END, END]),
+            Op::Ext(Ext::End) => body.push(0x0f),
+            Op::Ext(Ext::Store) => {
+                body.extend(cell);
+                body.extend([LOCAL_SET, S]);
+            }
+            Op::Ext(Ext::Load) => {
+                body.extend([LOCAL_GET, P, LOCAL_GET, S]);
+                body.extend(STORE8);
+            }
+            Op::Ext(ext) => {
+                body.extend([LOCAL_GET, P]);
+                body.extend(cell);
+                body.extend(match ext {
+                    Ext::ShiftRight => [CONST, 1, 0x76],
+                    Ext::ShiftLeft => [CONST, 1, 0x74],
+                    Ext::Not => [CONST, 0x7f, 0x73], // xor with -1
+                    Ext::Xor => [LOCAL_GET, S, 0x73],
+                    Ext::And => [LOCAL_GET, S, 0x71],
+                    _ => [LOCAL_GET, S, 0x72],
+                });
+                body.extend(STORE8);
+            }
+            Op::Dump | Op::Tape(_) | Op::Comment(_) => {}
+        }
+    }
+    body.push(END);
+
+    let mut out = b"\0asm\x01\0\0\0".to_vec();
+    // Types: putchar (i32) -> (), getchar () -> i32, main () -> ().
+    sized(&mut out, Some(1), &[3, 0x60, 1, I32, 0, 0x60, 0, 1, I32, 0x60, 0, 0]);
+    let mut imports = vec![2];
+    for (i, import) in ["putchar", "getchar"].into_iter().enumerate() {
+        name(&mut imports, "env");
+        name(&mut imports, import);
+        imports.extend([0, i as u8]);
+    }
+    sized(&mut out, Some(2), &imports);
+    sized(&mut out, Some(3), &[1, 2]);
+    let mut memory = vec![1, 0];
+    unsigned(&mut memory, cells.div_ceil(65536).max(1) as u32);
+    sized(&mut out, Some(5), &memory);
+    let mut exports = vec![2];
+    name(&mut exports, "main");
+    exports.extend([0, 2]);
+    name(&mut exports, "memory");
+    exports.extend([2, 0]);
+    sized(&mut out, Some(7), &exports);
+    let mut code = vec![1];
+    sized(&mut code, None, &body);
+    sized(&mut out, Some(10), &code);
+    out
+}
@@ -0,0 +1,74 @@
+// `--tty-raw`: puts the terminal in raw mode while the program runs, so `,` receives each key as
+// soon as it is pressed and nothing is echoed.
+//
+// Raw mode also turns off the terminal's own handling of Ctrl-C and of newlines. A Ctrl-C read by
+// `,` therefore ends the run, `.` writes a newline as `\r\n`, and memory dumps and comments are
+// printed with the terminal briefly restored. The terminal is restored when the run ends, whether
+// normally, with an error or with a panic.
+
+use crossterm::terminal;
+use std::fs::File;
+use std::io::{self, Read};
+
+/// The byte a terminal in raw mode sends for Ctrl-C.
+pub const INTERRUPT: u8 = 0x03;
+
+/// Keeps the terminal in raw mode until dropped.
+pub struct RawMode;
+
+impl RawMode {
+    pub fn enable() -> io::Result<RawMode> {
+        terminal::enable_raw_mode()?;
+        let default_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let _ = terminal::disable_raw_mode();
+            default_hook(info);
+        }));
+        Ok(RawMode)
+    }
+
+    /// Runs `f` with the terminal restored, for output that expects a normal terminal.
+    pub fn cooked<T>(&self, f: impl FnOnce() -> T) -> T {
+        let _ = terminal::disable_raw_mode();
+        let result = f();
+        let _ = terminal::enable_raw_mode();
+        result
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Waits for a key on the terminal `tty` and returns its first byte, with the terminal in raw mode
+/// for the wait unless `raw` already keeps it there.
+pub fn read_key(tty: &mut File, raw: Option<&RawMode>) -> io::Result<u8> {
+    if raw.is_none() {
+        terminal::enable_raw_mode()?;
+    }
+    let mut key = [0; 1];
+    let read = tty.read_exact(&mut key);
+    if raw.is_none() {
+        terminal::disable_raw_mode()?;
+    }
+    read.map(|()| key[0])
+}
+
+/// Reads a line typed on the terminal `tty`, with the terminal restored for it if `raw` keeps it in
+/// raw mode, so that it is echoed and can be edited.
+pub fn read_line(tty: &mut File, raw: Option<&RawMode>) -> io::Result<String> {
+    let read = |tty: &mut File| {
+        let mut line = Vec::new();
+        let mut byte = [0; 1];
+        while tty.read(&mut byte)? == 1 && byte[0] != b'\n' {
+            line.push(byte[0]);
+        }
+        Ok(String::from_utf8_lossy(&line).trim().to_string())
+    };
+    match raw {
+        Some(raw) => raw.cooked(|| read(tty)),
+        None => read(tty),
+    }
+}
@@ -0,0 +1,44 @@
+// Source maps from generated Brainfuck back to the DSL constructs it was compiled from.
+//
+// Written by `bfconstructor --source-map` and read by `bfir --source-map`. Ranges are byte
+// offsets into the generated file and nest like the constructs they come from, so the innermost
+// range containing an offset is the most specific construct.
+//
+// `variables` are the debug symbols: the cells each variable, array and temporary holds while
+// the code in its range runs, as `--layout` prints them. Cells of a frame procedure are relative
+// to the start of its frame, which is only known at run time.
+
+use serde::{Deserialize, Serialize};
+
+pub const VERSION: u32 = 1;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BfMap {
+    pub version: u32,
+    pub sources: Vec<String>, // DSL files, indexed by `Mapping::source`.
+    pub mappings: Vec<Mapping>,
+    #[serde(default)]
+    pub variables: Vec<Variable>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Mapping {
+    pub start: usize,
+    pub end: usize,
+    pub source: usize,
+    pub line: usize,
+    pub column: usize,
+    pub text: String, // The first line of the construct, as written.
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Variable {
+    pub name: String,
+    pub kind: String, // "variable", "array", "parameter" or "temporary".
+    pub cell: usize,
+    pub len: usize,
+    pub start: usize, // The code during which the cells hold it; all of it for top-level variables.
+    pub end: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frame: Option<String>, // The frame procedure whose frame `cell` is relative to.
+}
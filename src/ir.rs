@@ -0,0 +1,241 @@
+// The optimized op representation of a Brainfuck program, shared by the interpreter and the
+// constructor.
+//
+// Runs of `+`/`-` and of `>`/`<` are folded into a single op, and loops carry the index of their
+// matching bracket. `bfir` executes this form directly; the constructor lowers its output to it
+// to run `--verify` cases and to hand programs to library users without another text round-trip.
+
+use serde::{Deserialize, Serialize};
+
+// A single op.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Op {
+    // A run of `+` and `-`: adds the net amount to the current cell.
+    Add(i32),
+    // A run of `>` and `<`: moves the pointer by the net amount.
+    Move(i32),
+    // `[`, with the index of the matching `Close`.
+    Open(usize),
+    // `]`, with the index of the matching `Open`.
+    Close(usize),
+    Output,
+    Input,
+    // `#`, the interpreter's memory dump.
+    Dump,
+    // A run of tape switches in the multi-tape dialect: moves to another tape by the net amount.
+    Tape(i32),
+    // A `/* */` block comment.
+    Comment(String),
+    // A command of Extended Brainfuck Type I, with `Syntax::extended`.
+    Ext(Ext),
+}
+
+// The commands Extended Brainfuck Type I adds. They work on the current cell and a storage cell
+// outside the tape, which starts at 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Ext {
+    End,        // `@`: ends the program.
+    Store,      // `$`: copies the current cell to the storage cell.
+    Load,       // `!`: copies the storage cell to the current cell.
+    ShiftRight, // `}`
+    ShiftLeft,  // `{`
+    Not,        // `~`
+    Xor,        // `^`: with the storage cell, into the current cell, as are `&` and `|`.
+    And,
+    Or,
+}
+
+impl Ext {
+    const CHARS: [(char, Ext); 9] = [
+        ('@', Ext::End),
+        ('$', Ext::Store),
+        ('!', Ext::Load),
+        ('}', Ext::ShiftRight),
+        ('{', Ext::ShiftLeft),
+        ('~', Ext::Not),
+        ('^', Ext::Xor),
+        ('&', Ext::And),
+        ('|', Ext::Or),
+    ];
+
+    pub fn from_char(c: char) -> Option<Ext> {
+        Ext::CHARS.iter().find(|&&(ch, _)| ch == c).map(|&(_, ext)| ext)
+    }
+
+    pub fn to_char(self) -> char {
+        Ext::CHARS.iter().find(|&&(_, ext)| ext == self).map_or('@', |&(ch, _)| ch)
+    }
+
+    // Runs the command on a cell of `bits` bits and the storage cell. `End` does nothing here.
+    pub fn apply(self, cell: &mut u64, storage: &mut u64, bits: u32) {
+        let mask = if bits >= 64 { u64::MAX } else { (1 << bits) - 1 };
+        match self {
+            Ext::End => {}
+            Ext::Store => *storage = *cell,
+            Ext::Load => *cell = *storage,
+            Ext::ShiftRight => *cell >>= 1,
+            Ext::ShiftLeft => *cell = (*cell << 1) & mask,
+            Ext::Not => *cell = !*cell & mask,
+            Ext::Xor => *cell ^= *storage,
+            Ext::And => *cell &= *storage,
+            Ext::Or => *cell |= *storage,
+        }
+    }
+}
+
+// An op with the byte offset of its first character in the source text.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Inst {
+    pub op: Op,
+    pub pos: usize,
+}
+
+// Which characters beyond the eight commands are part of the program.
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Syntax<'a> {
+    // Treat `#` as `Op::Dump`.
+    pub dump: bool,
+    // Keep `/* */` block comments as `Op::Comment`.
+    pub comments: bool,
+    // The characters that switch to the previous and the next tape, for programs with several tapes.
+    pub tapes: Option<[char; 2]>,
+    // Characters that start a comment running to the end of the line, so that commands in the
+    // prose after them are ignored. They take precedence over every other meaning.
+    pub line_comments: &'a str,
+    // Read the commands of Extended Brainfuck Type I as `Op::Ext`. Tape switches take precedence.
+    pub extended: bool,
+}
+
+// Parses Brainfuck text, folding runs and linking brackets.
+pub fn parse(src: &str, syntax: Syntax) -> Result<Vec<Inst>, String> {
+    let mut program: Vec<Inst> = Vec::new();
+    // Whether the last op is a run that the next `+-` or `<>` may extend.
+    let mut extendable = false;
+    let mut iter = src.char_indices().peekable();
+    while let Some((pos, c)) = iter.next() {
+        if syntax.line_comments.contains(c) {
+            while iter.next_if(|&(_, c)| c != '\n').is_some() {}
+            continue;
+        }
+        if syntax.comments && c == '/' && iter.peek().map(|&(_, c)| c) == Some('*') {
+            iter.next();
+            let mut text = String::new();
+            while let Some((_, nc)) = iter.next() {
+                if nc == '*' && iter.peek().map(|&(_, c)| c) == Some('/') {
+                    iter.next();
+                    break;
+                }
+                text.push(nc);
+            }
+            program.push(Inst { op: Op::Comment(text), pos });
+            extendable = false;
+            continue;
+        }
+        let op = match c {
+            '+' => Op::Add(1),
+            '-' => Op::Add(-1),
+            '>' => Op::Move(1),
+            '<' => Op::Move(-1),
+            '[' => Op::Open(0),
+            ']' => Op::Close(0),
+            '.' => Op::Output,
+            ',' => Op::Input,
+            '#' if syntax.dump => Op::Dump,
+            c if syntax.tapes.is_some_and(|[prev, _]| c == prev) => Op::Tape(-1),
+            c if syntax.tapes.is_some_and(|[_, next]| c == next) => Op::Tape(1),
+            c if syntax.extended => match Ext::from_char(c) {
+                Some(ext) => Op::Ext(ext),
+                None => continue,
+            },
+            _ => continue,
+        };
+        match (program.last_mut(), &op) {
+            (Some(Inst { op: Op::Add(n), .. }), Op::Add(d)) if extendable => *n += d,
+            (Some(Inst { op: Op::Move(n), .. }), Op::Move(d)) if extendable => *n += d,
+            (Some(Inst { op: Op::Tape(n), .. }), Op::Tape(d)) if extendable => *n += d,
+            _ => {
+                extendable = matches!(op, Op::Add(_) | Op::Move(_) | Op::Tape(_));
+                program.push(Inst { op, pos });
+            }
+        }
+    }
+    link(&mut program)?;
+    Ok(program)
+}
+
+// Fills in the bracket indices of `Open` and `Close`, also after ops have been removed.
+pub fn link(program: &mut [Inst]) -> Result<(), String> {
+    let mut stack: Vec<usize> = Vec::new();
+    for i in 0..program.len() {
+        match program[i].op {
+            Op::Open(_) => stack.push(i),
+            Op::Close(_) => {
+                let j = stack.pop().ok_or("Unbalanced brackets: extra ']' found")?;
+                program[j].op = Op::Open(i);
+                program[i].op = Op::Close(j);
+            }
+            _ => {}
+        }
+    }
+    if !stack.is_empty() {
+        return Err("Unbalanced brackets: missing ']'".to_string());
+    }
+    Ok(())
+}
+
+// Writes the program back as Brainfuck text. Tape switches are written as `{` and `}`.
+pub fn emit(program: &[Inst]) -> String {
+    let mut out = String::new();
+    for inst in program {
+        match &inst.op {
+            Op::Add(n) => out.push_str(&(if *n > 0 { "+" } else { "-" }).repeat(n.unsigned_abs() as usize)),
+            Op::Move(n) => out.push_str(&(if *n > 0 { ">" } else { "<" }).repeat(n.unsigned_abs() as usize)),
+            Op::Open(_) => out.push('['),
+            Op::Close(_) => out.push(']'),
+            Op::Output => out.push('.'),
+            Op::Input => out.push(','),
+            Op::Dump => out.push('#'),
+            Op::Tape(n) => out.push_str(&(if *n > 0 { "}" } else { "{" }).repeat(n.unsigned_abs() as usize)),
+            Op::Comment(text) => {
+                out.push_str("/*");
+                out.push_str(text);
+                out.push_str("*/");
+            }
+            Op::Ext(ext) => out.push(ext.to_char()),
+        }
+    }
+    out
+}
+
+// Describes one op, as `--emit ir` and the interpreter's stepping mode show it.
+pub fn describe(op: &Op) -> String {
+    match op {
+        Op::Add(n) => format!("add {:+}", n),
+        Op::Move(n) => format!("move {:+}", n),
+        Op::Open(j) => format!("open -> {}", j),
+        Op::Close(j) => format!("close -> {}", j),
+        Op::Output => "output".to_string(),
+        Op::Input => "input".to_string(),
+        Op::Dump => "dump".to_string(),
+        Op::Tape(n) => format!("tape {:+}", n),
+        Op::Comment(text) => format!("/* {} */", text.trim()),
+        Op::Ext(ext) => format!("{:?}", ext).to_lowercase(),
+    }
+}
+
+// Lists the program one op per line, numbered and indented by loop depth, for `--emit ir`.
+pub fn render(program: &[Inst]) -> String {
+    let mut out = String::new();
+    let mut depth = 0;
+    for (i, inst) in program.iter().enumerate() {
+        if matches!(inst.op, Op::Close(_)) {
+            depth -= 1;
+        }
+        out += &format!("{:>5}  {}{}\n", i, "  ".repeat(depth), describe(&inst.op));
+        if matches!(inst.op, Op::Open(_)) {
+            depth += 1;
+        }
+    }
+    out
+}
@@ -0,0 +1,148 @@
+// What colors the terminal can show, shared by the interpreter's highlighting and library users.
+//
+// `detect` looks at the environment the way most terminal programs do, from the most to the least
+// specific hint, and settles on the richest mode the terminal is known to support: 24-bit color,
+// the 256-color palette, the 16 basic colors, or none.
+//
+// - `CLICOLOR_FORCE` set to anything but `0` means color even when output is not a terminal.
+// - `NO_COLOR` set to anything non-empty, output that is not a terminal, or `CLICOLOR=0` means no
+//   color.
+// - `COLORTERM=truecolor` or `24bit` means 24-bit color.
+// - `TERM=dumb` means no color; a `TERM` ending in `-256color` or `-direct` names its palette.
+// - Otherwise the terminfo entry for `TERM` gives the number of colors (`colors`), and a
+//   terminal that has one but no entry is assumed to have the 16 colors.
+//
+// `choose` puts a `--color` flag in front of all this: `always` and `never` decide whether there
+// is color at all, and only `auto` asks the environment.
+
+use clap::ValueEnum;
+use std::env;
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::path::PathBuf;
+
+/// How many colors the terminal shows.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum HighlightMode {
+    None,
+    Color16,
+    Color256,
+    TrueColor,
+}
+
+// Kept as an inherent function, as library callers use it, rather than `FromStr`: it cannot fail.
+#[allow(clippy::should_implement_trait)]
+impl HighlightMode {
+    /// The mode named `false`, `16`, `256` or `true`; anything else means no color.
+    pub fn from_str(s: &str) -> HighlightMode {
+        match s {
+            "false" => HighlightMode::None,
+            "16" => HighlightMode::Color16,
+            "256" => HighlightMode::Color256,
+            "true" => HighlightMode::TrueColor,
+            _ => HighlightMode::None,
+        }
+    }
+}
+
+/// Whether to use color, as given to `--color`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum ColorChoice {
+    /// Color if the environment allows it and output is a terminal.
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+/// The mode to show colors in for `choice`.
+pub fn choose(choice: ColorChoice) -> HighlightMode {
+    let var = |name: &str| env::var_os(name).filter(|value| !value.is_empty());
+    let forced = var("CLICOLOR_FORCE").is_some_and(|value| value != "0");
+    let terminal = io::stdout().is_terminal() || io::stderr().is_terminal();
+    let off = var("NO_COLOR").is_some() || !terminal || var("CLICOLOR").is_some_and(|value| value == "0");
+    match choice {
+        ColorChoice::Never => HighlightMode::None,
+        // Asked for color, so at least the 16 colors even where the terminal type says none.
+        ColorChoice::Always => depth().max(HighlightMode::Color16),
+        ColorChoice::Auto if forced => depth().max(HighlightMode::Color16),
+        ColorChoice::Auto if off => HighlightMode::None,
+        ColorChoice::Auto => depth(),
+    }
+}
+
+/// The richest mode the terminal on stdout or stderr supports, `choose(ColorChoice::Auto)`.
+pub fn detect() -> HighlightMode {
+    choose(ColorChoice::Auto)
+}
+
+// The richest mode the terminal type supports, whether or not output goes to it.
+fn depth() -> HighlightMode {
+    let set = |name: &str| env::var_os(name).is_some_and(|value| !value.is_empty());
+    let colorterm = env::var("COLORTERM").unwrap_or_default();
+    if colorterm == "truecolor" || colorterm == "24bit" {
+        return HighlightMode::TrueColor;
+    }
+    // Windows Terminal sets no TERM but shows 24-bit color.
+    if cfg!(windows) && set("WT_SESSION") {
+        return HighlightMode::TrueColor;
+    }
+    let term = env::var("TERM").unwrap_or_default();
+    from_term(&term, terminfo_colors(&term))
+}
+
+/// The mode for terminal type `term`, whose terminfo entry gives `colors` colors, if it has one.
+pub fn from_term(term: &str, colors: Option<i32>) -> HighlightMode {
+    match term {
+        "" | "dumb" => HighlightMode::None,
+        _ if term.ends_with("-direct") => HighlightMode::TrueColor,
+        _ if term.ends_with("-256color") => HighlightMode::Color256,
+        _ => match colors {
+            Some(n) if n >= 1 << 24 => HighlightMode::TrueColor,
+            Some(n) if n >= 256 => HighlightMode::Color256,
+            Some(n) if n >= 8 => HighlightMode::Color16,
+            Some(_) => HighlightMode::None,
+            None => HighlightMode::Color16,
+        },
+    }
+}
+
+// The `colors` capability of the compiled terminfo entry for `term`, if one is installed.
+fn terminfo_colors(term: &str) -> Option<i32> {
+    let first = term.chars().next()?;
+    let home = env::var_os("HOME").map(|home| PathBuf::from(home).join(".terminfo"));
+    let dirs = env::var_os("TERMINFO").map(PathBuf::from).into_iter().chain(home).chain(
+        ["/etc/terminfo", "/lib/terminfo", "/usr/share/terminfo", "/usr/lib/terminfo"].map(PathBuf::from),
+    );
+    // Entries are filed under their first letter, or its hex code on some systems.
+    let entry = dirs
+        .flat_map(|dir| [dir.join(first.to_string()).join(term), dir.join(format!("{:x}", first as u32)).join(term)])
+        .find_map(|path| fs::read(path).ok())?;
+    max_colors(&entry)
+}
+
+/// Reads the `colors` number from a compiled terminfo entry, 0 for a terminal without colors, or
+/// `None` if the entry is not one. The entry is a header of six 16-bit counts, the names, the
+/// booleans padded to an even length, then the numbers, 16- or 32-bit by format.
+pub fn max_colors(entry: &[u8]) -> Option<i32> {
+    const COLORS: usize = 13; // The index of `colors` among the numbers.
+    let word = |i: usize| Some(i16::from_le_bytes([*entry.get(2 * i)?, *entry.get(2 * i + 1)?]) as usize);
+    let width = match word(0)? {
+        0o432 => 2,
+        0o1036 => 4,
+        _ => return None,
+    };
+    let (names, booleans, numbers) = (word(1)?, word(2)?, word(3)?);
+    if COLORS >= numbers {
+        return Some(0);
+    }
+    let start = 12 + names + booleans;
+    let start = start + start % 2 + COLORS * width;
+    let bytes = entry.get(start..start + width)?;
+    let value = match width {
+        2 => i16::from_le_bytes([bytes[0], bytes[1]]) as i32,
+        _ => i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+    };
+    // An absent capability is -1.
+    Some(value.max(0))
+}
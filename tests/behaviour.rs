@@ -0,0 +1,63 @@
+// Interpreter-backed tests for what compiled programs do.
+//
+// Each case is a program in `tests/behaviour/` and a TOML file of input/output cases next to it,
+// checked like `tests/stdlib.rs`: the program is compiled with `--verify`, which runs the generated
+// code on every case, once for plain Brainfuck and once for `--target extended1`.
+
+use std::path::Path;
+use std::process::Command;
+
+fn verify(case: &str) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    for target in ["brainfuck", "extended1"] {
+        let output = Command::new(env!("CARGO_BIN_EXE_bfconstructor"))
+            .current_dir(root)
+            .arg(format!("tests/behaviour/{}.bfs", case))
+            .arg("--verify")
+            .arg(format!("tests/behaviour/{}.toml", case))
+            .args(["--target", target])
+            .output()
+            .expect("running bfconstructor");
+        assert!(output.status.success(), "{} ({}):\n{}", case, target, String::from_utf8_lossy(&output.stderr));
+    }
+}
+
+#[test]
+fn macro_locals_shadow_printed_strings() {
+    verify("hygiene");
+}
+
+#[test]
+fn scopes_reuse_dead_variables() {
+    verify("temps");
+}
+
+#[test]
+fn logical_operators() {
+    verify("logic");
+}
+
+#[test]
+fn fixed_point_rounds_half_up() {
+    verify("fixed");
+}
+
+#[test]
+fn tables_look_up_constants() {
+    verify("tables");
+}
+
+#[test]
+fn heap_blocks_are_linked_and_reused() {
+    verify("heap");
+}
+
+#[test]
+fn signed_bytes() {
+    verify("signed");
+}
+
+#[test]
+fn comparisons() {
+    verify("compare");
+}
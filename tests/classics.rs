@@ -0,0 +1,70 @@
+// Runs the classic programs in `programs/` with bfir and compares what they print with their
+// `.out` files. A program that reads input is given its `.in` file.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+// The programs, sorted so that reports are stable.
+fn programs(root: &Path) -> Vec<PathBuf> {
+    let mut found: Vec<PathBuf> = fs::read_dir(root.join("programs"))
+        .expect("reading programs/")
+        .map(|entry| entry.expect("directory entry").path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "bf"))
+        .collect();
+    found.sort();
+    found
+}
+
+fn bfir(root: &Path, args: &[&str]) -> Vec<u8> {
+    let output = Command::new(env!("CARGO_BIN_EXE_bfir")).current_dir(root).args(args).output().expect("running bfir");
+    assert!(output.status.success(), "bfir {}:\n{}", args.join(" "), String::from_utf8_lossy(&output.stderr));
+    output.stdout
+}
+
+#[test]
+fn programs_print_their_expected_output() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let programs = programs(root);
+    assert!(!programs.is_empty());
+    for program in programs {
+        let (source, input) = (program.to_string_lossy(), program.with_extension("in"));
+        let expected = fs::read(program.with_extension("out")).expect("reading the expected output");
+        let input = input.to_string_lossy();
+        let mut args = vec![source.as_ref(), "--selftest"];
+        if Path::new(input.as_ref()).exists() {
+            args.extend(["--input-file", input.as_ref()]);
+        }
+        let actual = bfir(root, &args);
+        assert!(actual == expected, "{}:\n{}", source, String::from_utf8_lossy(&actual));
+    }
+}
+
+#[test]
+fn every_program_is_built_in() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let listed = String::from_utf8(bfir(root, &["--examples"])).expect("utf-8 list");
+    for program in programs(root) {
+        let name = program.file_stem().expect("file name").to_string_lossy().into_owned();
+        assert!(listed.lines().any(|line| line.starts_with(&format!("{} ", name))), "{} is not built in", name);
+        let expected = fs::read(program.with_extension("out")).expect("reading the expected output");
+        if !program.with_extension("in").exists() {
+            assert_eq!(bfir(root, &["--example", &name]), expected, "{}", name);
+        }
+    }
+}
+
+#[test]
+fn expected_outputs_set_the_exit_code() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let run = |args: &[&str]| Command::new(env!("CARGO_BIN_EXE_bfir")).current_dir(root).args(args).output().expect("running bfir");
+    assert!(run(&["programs/hello.bf", "--expect-output", "programs/hello.out"]).status.success());
+    let failed = run(&["programs/hello.bf", "--expect-output", "programs/squares.out"]);
+    assert!(!failed.status.success());
+    assert!(String::from_utf8_lossy(&failed.stderr).contains("+ Hello World!"));
+
+    let digest = "03ba204e50d126e4674c005e04d82e84c21366780af1f43bd54a37816b6ab340";
+    assert!(run(&["programs/hello.bf", "--expect-sha256", digest]).status.success());
+    assert!(run(&["programs/hello.bf", "--expect-sha256", &digest.to_uppercase()]).status.success());
+    assert!(!run(&["programs/squares.bf", "--expect-sha256", digest]).status.success());
+}
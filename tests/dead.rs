@@ -0,0 +1,37 @@
+// Checks that bfir leaves out loops that never run, both when running and with `--to ir`, and
+// that `-v` says which and where.
+
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+// A comment loop at the start, and a loop right after a clear.
+const PROGRAM: &str = "[comment, loop.]+++[-][>+<-]+.";
+
+fn bfir(args: &[&str]) -> Output {
+    let program = Path::new(env!("CARGO_TARGET_TMPDIR")).join("dead.bf");
+    fs::write(&program, PROGRAM).expect("writing the program");
+    let output = Command::new(env!("CARGO_BIN_EXE_bfir")).args(args).arg(&program).output().expect("running bfir");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    output
+}
+
+#[test]
+fn dead_loops_are_not_in_the_ir() {
+    let ir = String::from_utf8(bfir(&["--to", "ir"]).stdout).expect("utf-8 ir");
+    let ops: Vec<String> = ir.lines().map(|line| line.split_whitespace().skip(1).collect::<Vec<_>>().join(" ")).collect();
+    assert_eq!(ops, ["add +3", "open -> 3", "add -1", "close -> 1", "add +1", "output"], "{}", ir);
+}
+
+#[test]
+fn dead_loops_are_reported_with_their_location() {
+    let stderr = String::from_utf8(bfir(&["-v", "--to", "ir"]).stderr).expect("utf-8 notes");
+    assert!(stderr.contains("zero before anything is written to the tape: [,.] at "), "{}", stderr);
+    assert!(stderr.contains("dead.bf:1:1"), "{}", stderr);
+    assert!(stderr.contains("zero after a clear: [>+<-] at "), "{}", stderr);
+    assert!(stderr.contains("dead.bf:1:23"), "{}", stderr);
+    let output = bfir(&["-v"]);
+    assert_eq!(output.stdout, [1]);
+    assert_eq!(String::from_utf8_lossy(&output.stderr).matches("never runs").count(), 2);
+    assert!(!String::from_utf8_lossy(&bfir(&[]).stderr).contains("never runs"));
+}
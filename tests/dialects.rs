@@ -0,0 +1,103 @@
+// Runs programs without --dialect, so their extensions and the `[extensions]` of bf.toml decide
+// how bfir reads them, and checks what bfir says it chose.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+const HELLO: &str = "++++++++[>++++++++<-]>+.";
+
+const COW: &str = r#"
+right = "moo"
+left = "mOo"
+inc = "MoO"
+dec = "MOo"
+output = "OOM"
+input = "oom"
+open = "MOO"
+close = "moo!"
+"#;
+
+fn project(name: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    fs::create_dir_all(&dir).expect("creating the project");
+    dir
+}
+
+fn bfir(dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_bfir")).current_dir(dir).args(args).output().expect("running bfir")
+}
+
+fn chose(output: &Output) -> String {
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    stderr.lines().find(|line| line.starts_with("note: reading")).expect("a note on the dialect").to_string()
+}
+
+#[test]
+fn extensions_choose_the_dialect() {
+    let dir = project("dialects");
+    fs::write(dir.join("a.bf"), HELLO).expect("writing the program");
+    fs::write(dir.join("a.pb"), HELLO).expect("writing the program");
+    fs::write(dir.join("a.txt"), HELLO).expect("writing the program");
+    for (file, expected) in [
+        ("a.bf", "note: reading a.bf as Brainfuck, from its extension (override with --dialect)"),
+        ("a.pb", "note: reading a.pb as pbrain, from its extension (override with --dialect)"),
+        ("a.txt", "note: reading a.txt as Brainfuck, since no dialect has the extension .txt (override with --dialect)"),
+    ] {
+        let output = bfir(&dir, &[file]);
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        assert_eq!(output.stdout, b"A");
+        assert_eq!(chose(&output), expected);
+    }
+    let output = bfir(&dir, &["--dialect", "brainfuck", "a.pb"]);
+    assert!(!String::from_utf8_lossy(&output.stderr).contains("note: reading"));
+}
+
+#[test]
+fn pbrain_procedures_are_refused() {
+    let dir = project("pbrain");
+    fs::write(dir.join("call.pbrain"), "+(.)\n:").expect("writing the program");
+    let output = bfir(&dir, &["call.pbrain"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("call.pbrain:1:2: `(` is a pbrain procedure command"), "{}", stderr);
+}
+
+#[test]
+fn bf_toml_maps_extensions() {
+    let dir = project("mapped");
+    fs::write(dir.join("cow.toml"), COW).expect("writing the mapping");
+    fs::write(dir.join("bf.toml"), "[extensions]\ncow = \"map:cow.toml\"\nb = \"ook\"\n").expect("writing bf.toml");
+    fs::write(dir.join("hello.bf"), HELLO).expect("writing the program");
+    let cow = bfir(&dir, &["--to", "map:cow.toml", "hello.bf"]);
+    fs::write(dir.join("hello.cow"), cow.stdout).expect("writing the translation");
+    let ook = bfir(&dir, &["--to", "ook", "hello.bf"]);
+    fs::write(dir.join("hello.b"), ook.stdout).expect("writing the translation");
+    for (file, expected) in [
+        ("hello.cow", "note: reading hello.cow as the words of cow.toml, as bf.toml maps .cow (override with --dialect)"),
+        ("hello.b", "note: reading hello.b as Ook!, as bf.toml maps .b (override with --dialect)"),
+    ] {
+        let output = bfir(&dir, &[file]);
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        assert_eq!(output.stdout, b"A");
+        assert_eq!(chose(&output), expected);
+    }
+    fs::write(dir.join("bf.toml"), "[extensions]\ncow = \"c\"\n").expect("writing bf.toml");
+    let output = bfir(&dir, &["hello.cow"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("bfir can write c but not read it"));
+}
+
+#[test]
+fn json_errors_keep_stderr_json() {
+    let dir = project("json");
+    fs::write(dir.join("fail.bf"), "<").expect("writing the program");
+    let output = bfir(&dir, &["--error-format", "json", "fail.bf"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let objects: Vec<serde_json::Value> =
+        stderr.lines().map(|line| serde_json::from_str(line).unwrap_or_else(|e| panic!("{}: {}", e, line))).collect();
+    assert_eq!(objects[0]["code"], "dialect");
+    assert_eq!(objects[0]["severity"], "note");
+    assert_eq!(objects[1]["code"], "pointer-out-of-range");
+}
@@ -0,0 +1,38 @@
+// Sends the dumps of one run to several sinks with `bfir --dump-to`, and checks each got them.
+
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+fn bfir(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_bfir")).args(args).output().expect("running bfir")
+}
+
+#[test]
+fn every_sink_gets_the_dump() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR"));
+    let program = dir.join("dumps.bf");
+    fs::write(&program, "++++++++[>++++<-]>+.").expect("writing the program");
+    let (json, png) = (dir.join("dump.json"), dir.join("dump.png"));
+    let (json_spec, png_spec) = (format!("json:{}", json.display()), format!("png:{}", png.display()));
+    let program = program.to_string_lossy();
+    let output = bfir(&["-d", "8", "--dump-to", "table:stderr", "--dump-to", &json_spec, "--dump-to", &png_spec, &program]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(output.stdout, b"!");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("[End state]") && stderr.contains("Data Pointer : 1"), "{}", stderr);
+
+    let dump: serde_json::Value = serde_json::from_str(&fs::read_to_string(&json).expect("reading the JSON")).expect("a JSON dump");
+    assert_eq!(dump["kind"], "dump");
+    assert_eq!(dump["tapes"][0]["pointer"], 1);
+    assert_eq!(dump["tapes"][0]["cells"], serde_json::json!([0, 33, 0, 0, 0, 0, 0, 0]));
+    assert!(fs::read(&png).expect("reading the PNG").starts_with(b"\x89PNG\r\n\x1a\n"));
+}
+
+#[test]
+fn sinks_need_something_to_dump() {
+    let output = bfir(&["--dump-to", "json:never.json", "programs/hello.bf"]);
+    assert!(!output.status.success());
+    let output = bfir(&["-d", "4", "--dump-to", "png", "programs/hello.bf"]);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("png dumps need a file"));
+}
@@ -0,0 +1,28 @@
+// Runs a compiled `readline` on input without a trailing newline: bfir stops at the end of the
+// input unless `--eof zero` makes `,` give 0, which ends the line.
+
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+fn run(exe: &str, root: &Path, args: &[&str]) -> Output {
+    Command::new(exe).current_dir(root).args(args).output().expect("running the binary")
+}
+
+#[test]
+fn lines_end_at_the_end_of_input() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let program = Path::new(env!("CARGO_TARGET_TMPDIR")).join("readline.bf");
+    let output = run(env!("CARGO_BIN_EXE_bfconstructor"), root, &["examples/reverse.bfs"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    fs::write(&program, output.stdout).expect("writing the program");
+    let program = program.to_string_lossy();
+
+    let output = run(env!("CARGO_BIN_EXE_bfir"), root, &["--input-string", "ab", &program]);
+    assert!(!output.status.success());
+    let output = run(env!("CARGO_BIN_EXE_bfir"), root, &["--eof", "zero", "--input-string", "ab", &program]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(output.stdout, b"ba\n");
+    let output = run(env!("CARGO_BIN_EXE_bfir"), root, &["--eof", "zero", "--input-string", "", &program]);
+    assert_eq!(output.stdout, b"\n");
+}
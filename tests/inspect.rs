@@ -0,0 +1,46 @@
+// Drives `bfir --inspect` with scripted commands on stdin after a program halts.
+
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+
+fn inspect(program: &str, script: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_bfir"))
+        .args(["--inspect", program])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("running bfir");
+    child.stdin.take().expect("stdin").write_all(script.as_bytes()).expect("writing the script");
+    child.wait_with_output().expect("waiting for bfir")
+}
+
+#[test]
+fn prints_and_searches_cells() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let program = root.join("programs/hello.bf");
+    let output = inspect(&program.to_string_lossy(), "p 2..7\nx 0..8\ns \"HdW\"\ns 21 0a\ns \"zzz\"\nq\np\n");
+    assert!(output.status.success());
+    assert_eq!(output.stdout, b"Hello World!\n");
+    let answers = String::from_utf8_lossy(&output.stderr);
+    assert!(answers.contains("     2:  72 100  87  33  10\n"), "{}", answers);
+    assert!(answers.contains("     0: 00 00 48 64 57 21 0a 00"), "{}", answers);
+    assert!(answers.contains("|..HdW!..|\n"), "{}", answers);
+    assert!(answers.contains("found at 2\n") && answers.contains("found at 5\n"), "{}", answers);
+    assert!(answers.contains("not found\n"), "{}", answers);
+    // `q` ends the session before the last command.
+    assert_eq!(answers.matches("inspect> ").count(), 6, "{}", answers);
+}
+
+#[test]
+fn follows_a_failed_run() {
+    let program = Path::new(env!("CARGO_TARGET_TMPDIR")).join("inspect_fails.bf");
+    fs::write(&program, "+++>++<<").expect("writing the program");
+    let output = inspect(&program.to_string_lossy(), "p 0..2\nt 3\n");
+    assert!(!output.status.success());
+    let answers = String::from_utf8_lossy(&output.stderr);
+    assert!(answers.contains("     0:   3   2\n"), "{}", answers);
+    assert!(answers.contains("expected a tape from 0 to 0"), "{}", answers);
+}
@@ -0,0 +1,63 @@
+// Runs programs with balanced loops both in closed form and one iteration at a time (which
+// `--profile-output` forces, since it watches every step) and checks the runs cannot be told apart:
+// same output, tapes, instruction count and errors.
+
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+const PROGRAMS: [(&str, &str); 5] = [
+    // A copy to three cells, one of them twice in the same body, and a cell passed over.
+    ("spread", "+++++[->++>>+++<<<+<+>>>>-<<<]>[-<+>]>>[.-]"),
+    // Counting up through the wrap, and adding more than fits in a cell.
+    ("up", "++++++++++[+>+++<]>[->+>+<<]>>."),
+    // A loop that changes its cell by 2 cannot be run in closed form.
+    ("by_two", "++++++++[-->+<]>."),
+    // The pointer would leave the tape in the first iteration.
+    ("edge", ">>+++[-<+>>+<]"),
+    // With -w, the second cell goes past 255.
+    ("wraps", "++++++++[->++++++++++++++++++++++++++++++++++++<]"),
+];
+
+fn bfir(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_bfir")).args(args).output().expect("running bfir")
+}
+
+#[test]
+fn closed_forms_run_like_the_loops() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR"));
+    for (name, code) in PROGRAMS {
+        let program = dir.join(format!("linear_{}.bf", name));
+        fs::write(&program, code).expect("writing the program");
+        let program = program.to_string_lossy();
+        for flags in [&["-c", "3"][..], &["-c", "3", "-w"][..]] {
+            let runs: Vec<(Output, String)> = [false, true]
+                .iter()
+                .map(|&slow| {
+                    let saved = dir.join(format!("linear_{}_{}.json", name, slow));
+                    let saved = saved.to_string_lossy().into_owned();
+                    let profile = dir.join("linear.folded").to_string_lossy().into_owned();
+                    let mut args = flags.to_vec();
+                    args.extend(["--time", "--save-run", &saved, &program]);
+                    if slow {
+                        args.extend(["--profile-output", &profile]);
+                    }
+                    let output = bfir(&args);
+                    (output, fs::read_to_string(&saved).expect("reading the saved run"))
+                })
+                .collect();
+            let ((fast, fast_run), (slow, slow_run)) = (&runs[0], &runs[1]);
+            let context = format!("{} {:?}", name, flags);
+            assert_eq!(fast.stdout, slow.stdout, "{}", context);
+            assert_eq!(fast_run, slow_run, "{}", context);
+            assert_eq!(fast.status.code(), slow.status.code(), "{}", context);
+            // The same error, and the same number of instructions.
+            let report = |output: &Output| {
+                let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+                let time = stderr.lines().find(|line| line.starts_with("[time]")).expect("the --time line").to_string();
+                (stderr.lines().filter(|line| !line.starts_with("[time]")).collect::<Vec<_>>().join("\n"), time.split(", ").nth(1).map(String::from))
+            };
+            assert_eq!(report(fast), report(slow), "{}", context);
+        }
+    }
+}
@@ -0,0 +1,20 @@
+// Maps a cell to a file with `bfir --map-out`, and checks the file gets what was written there.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn mapped_outputs_survive_runtime_errors() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR"));
+    let (program, aux) = (dir.join("mmio.bf"), dir.join("aux.out"));
+    // Writes '5' to cell 4, then moves the pointer off the tape.
+    fs::write(&program, ">>>>>+++++[<++++++++++>-]<+++.<<<<<<").expect("writing the program");
+    let _ = fs::remove_file(&aux);
+    let output = Command::new(env!("CARGO_BIN_EXE_bfir"))
+        .args(["--map-out", &format!("4={}", aux.display()), &program.to_string_lossy()])
+        .output()
+        .expect("running bfir");
+    assert!(!output.status.success());
+    assert_eq!(fs::read(&aux).expect("reading the mapped output"), b"5");
+}
@@ -0,0 +1,31 @@
+// Checks that dead-code elimination never removes a compile error: a value or store it would drop
+// on a name that is not in scope there still fails to compile, with or without `--no-opt`.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+// Compiles `source` with `args` and returns the error it reports.
+fn error(name: &str, source: &str, args: &[&str]) -> String {
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("{}.bfs", name));
+    fs::write(&path, source).expect("writing the program");
+    let output = Command::new(env!("CARGO_BIN_EXE_bfconstructor")).arg(&path).args(args).output().expect("running bfconstructor");
+    assert!(!output.status.success(), "{} compiled:\n{}", name, String::from_utf8_lossy(&output.stdout));
+    String::from_utf8(output.stderr).expect("utf-8 errors")
+}
+
+#[test]
+fn names_out_of_scope_still_fail() {
+    let programs = [
+        ("scoped_get", "scope { let x; push 1; set x; get x; printnum }\nget x; drop\n"),
+        ("scoped_set", "scope { let x }\npush 1; set x\n"),
+        ("global_in_proc", "let x\nproc p() returns 0 { get x; drop }\ncall p\n"),
+        ("allocated", "heap 4\nlet a\nalloc a 2; get a; drop; free a\n"),
+        ("freed", "heap 4\nlet a[2]\nalloc b 2; free b; get b[0]; drop\n"),
+    ];
+    for (name, source) in programs {
+        let optimized = error(name, source, &[]);
+        assert!(optimized.contains("error"), "{}: {}", name, optimized);
+        assert_eq!(optimized, error(name, source, &["--no-opt"]), "{}", name);
+    }
+}
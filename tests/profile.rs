@@ -0,0 +1,48 @@
+// Runs a classic program with `--profile-output` and `--time` and checks what they report.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn profiles_are_folded_stacks_of_loops() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let folded = Path::new(env!("CARGO_TARGET_TMPDIR")).join("hello.folded");
+    let output = Command::new(env!("CARGO_BIN_EXE_bfir"))
+        .current_dir(root)
+        .args(["programs/hello.bf", "--profile-output", &folded.to_string_lossy()])
+        .output()
+        .expect("running bfir");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let folded = fs::read_to_string(folded).expect("reading the profile");
+    let mut depths = Vec::new();
+    for line in folded.lines() {
+        let (stack, steps) = line.rsplit_once(' ').expect("a count after the stack");
+        assert!(steps.parse::<u64>().is_ok_and(|n| n > 0), "{}", line);
+        let mut frames = stack.split(';');
+        assert_eq!(frames.next(), Some("programs/hello.bf"));
+        for frame in frames.clone() {
+            let (line, col) = frame.strip_prefix("loop@").and_then(|at| at.split_once(':')).expect("loop@line:col");
+            assert!(line.parse::<usize>().is_ok() && col.parse::<usize>().is_ok(), "{}", frame);
+        }
+        depths.push(frames.count());
+    }
+    // hello.bf has top-level commands and a loop nested in another.
+    assert!(depths.contains(&0) && depths.contains(&2), "{}", folded);
+}
+
+#[test]
+fn time_prints_one_line_with_the_steps() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let output = Command::new(env!("CARGO_BIN_EXE_bfir"))
+        .current_dir(root)
+        .args(["programs/hello.bf", "--time"])
+        .output()
+        .expect("running bfir");
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let line = stderr.lines().find(|line| line.starts_with("[time] ")).expect("a --time line");
+    let fields: Vec<&str> = line["[time] ".len()..].split(", ").collect();
+    assert!(matches!(fields[..], [time, steps, mips]
+        if time.ends_with('s') && steps.ends_with(" instructions") && mips.ends_with(" MIPS")), "{}", line);
+}
@@ -0,0 +1,29 @@
+// Runs the constructor's snapshot programs on the reference interpreter and the optimized one.
+
+use brainfucktool::reference;
+use std::fs;
+use std::path::Path;
+
+#[test]
+fn snapshots_agree() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots");
+    for entry in fs::read_dir(dir).expect("reading tests/snapshots") {
+        let path = entry.expect("a directory entry").path();
+        if path.extension().is_some_and(|e| e == "bf") {
+            let src = fs::read_to_string(&path).expect("reading a snapshot");
+            if let Err(e) = reference::selftest(&src, b"hello\n") {
+                panic!("{}: {}", path.display(), e);
+            }
+        }
+    }
+}
+
+#[test]
+fn reports_the_first_difference() {
+    let expected = reference::run("+++.>++", b"", 4).expect("running");
+    assert_eq!(expected.output, [3]);
+    assert_eq!((expected.tape.as_slice(), expected.ptr), (&[3, 2, 0, 0][..], 1));
+    let actual = reference::Run { tape: vec![3, 1, 0, 0], ..expected.clone() };
+    assert_eq!(reference::compare(&expected, &actual), Err("cell 1 ends as 1, but as 2 in the reference".to_string()));
+    assert_eq!(reference::run("+>>", b"", 2), Err("pointer moved past the last cell".to_string()));
+}
@@ -0,0 +1,35 @@
+// Drives `bfconstructor --repl` with a scripted session on stdin.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+fn session(script: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_bfconstructor"))
+        .arg("--repl")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("running bfconstructor");
+    child.stdin.take().expect("stdin").write_all(script.as_bytes()).expect("writing the script");
+    let output = child.wait_with_output().expect("waiting for bfconstructor");
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).expect("utf-8 output")
+}
+
+#[test]
+fn runs_each_entry() {
+    let out = session("let x\npush 5\npush 3; add\nset x\nif { get x } then {\n    print \"hi\"\n}\n");
+    assert!(out.contains("/* push 5               */ >+++++ #2\nstack: [5]\nvars: x=0\n"), "{}", out);
+    assert!(out.contains("stack: [8]\n"), "{}", out);
+    assert!(out.contains("stack: []\nvars: x=8\n"), "{}", out);
+    assert!(out.contains("..> "), "{}", out);
+    assert!(out.contains("output: \"hi\"\n"), "{}", out);
+}
+
+#[test]
+fn rejects_entries_that_do_not_compile() {
+    let out = session("push 1\nset y\n:run off\npush 2\n:show\n");
+    assert!(out.contains("error: undefined variable 'y'"), "{}", out);
+    assert!(!out.contains("stack: [1, 2]"), "{}", out);
+    assert!(out.contains("bf> push 1\npush 2\n"), "{}", out);
+}
@@ -0,0 +1,65 @@
+// Drives `bfir --rpc` with a session of requests and checks the responses.
+
+use serde_json::{json, Value};
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+// The responses to `requests`, sent one per line.
+fn session(requests: &[Value]) -> Vec<Value> {
+    let mut bfir = Command::new(env!("CARGO_BIN_EXE_bfir"))
+        .arg("--rpc")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("running bfir");
+    let mut stdin = bfir.stdin.take().expect("bfir's stdin");
+    for request in requests {
+        writeln!(stdin, "{}", request).expect("writing a request");
+    }
+    drop(stdin);
+    let output = bfir.wait_with_output().expect("waiting for bfir");
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).expect("UTF-8 responses");
+    stdout.lines().map(|line| serde_json::from_str(line).expect("a JSON response")).collect()
+}
+
+fn request(id: u64, method: &str, params: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params })
+}
+
+#[test]
+fn steps_to_breakpoints_and_reads_the_tape() {
+    let responses = session(&[
+        request(1, "load", json!({ "source": ",[.,]", "input": "hi", "cells": 4 })),
+        request(2, "setBreakpoint", json!({ "position": 2 })),
+        request(3, "run", Value::Null),
+        request(4, "readTape", json!({ "length": 2 })),
+        request(5, "step", json!({ "count": 2 })),
+        json!({ "jsonrpc": "2.0", "method": "clearBreakpoint", "params": { "position": 2 } }),
+        request(6, "run", Value::Null),
+        request(7, "state", Value::Null),
+    ]);
+    let results: Vec<&Value> = responses.iter().map(|response| &response["result"]).collect();
+    assert_eq!(results[0], &json!({ "ops": 5 }));
+    assert_eq!(results[1], &json!({ "position": 2 }));
+    assert_eq!((&results[2]["stopped"], &results[2]["pc"]), (&json!("breakpoint"), &json!(2)));
+    assert_eq!(results[3], &json!({ "start": 0, "cells": [104, 0], "pointer": 0 }));
+    assert_eq!((&results[4]["stopped"], &results[4]["output"]), (&json!("step"), &json!([104])));
+    assert_eq!((&results[5]["stopped"], &results[5]["output"]), (&json!("end"), &json!([105])));
+    assert_eq!((&results[6]["kind"], &results[6]["tape"]), (&json!("machine"), &json!([0, 0, 0, 0])));
+    assert_eq!(responses.len(), 7, "notifications get no response");
+}
+
+#[test]
+fn reports_errors() {
+    let responses = session(&[
+        request(1, "step", Value::Null),
+        json!({ "jsonrpc": "2.0", "id": 2, "method": "fly" }),
+        request(3, "load", json!({ "source": "[" })),
+        request(4, "load", json!({ "source": "+", "speed": 1 })),
+        request(5, "exit", Value::Null),
+        request(6, "step", Value::Null),
+    ]);
+    let codes: Vec<&Value> = responses.iter().map(|response| &response["error"]["code"]).collect();
+    assert_eq!(codes, [&json!(-32000), &json!(-32601), &json!(-32000), &json!(-32602), &Value::Null]);
+}
@@ -0,0 +1,48 @@
+// Writes and reads back the JSON documents of `schema`.
+
+use brainfucktool::ir::{self, Syntax};
+use brainfucktool::machine::{Machine, MachineState};
+use brainfucktool::parser::Program;
+use brainfucktool::schema::{self, Ir};
+
+#[test]
+fn documents_round_trip() {
+    let ir = Ir { ops: ir::parse("+++[>,.<-]", Syntax::default()).expect("parsing") };
+    let json = schema::to_json(&ir);
+    assert!(json.contains("\"kind\": \"ir\"") && json.contains("\"version\": 1"));
+    assert_eq!(schema::from_json::<Ir>(&json), Ok(ir));
+
+    let mut machine = Machine::new("+++[>,.<-]", b"abc".to_vec(), 4, 8).expect("parsing");
+    for _ in 0..6 {
+        machine.step().expect("stepping");
+    }
+    let state: MachineState = schema::from_json(&schema::to_json(&machine.state())).expect("reading");
+    let mut resumed = Machine::new("+++[>,.<-]", b"abc".to_vec(), 4, 8).expect("parsing");
+    resumed.restore(state).expect("restoring");
+    machine.run(None).expect("running");
+    resumed.run(None).expect("running");
+    assert_eq!(resumed.state(), machine.state());
+    assert_eq!(resumed.output(), b"abc");
+}
+
+#[test]
+fn rejects_other_kinds_and_versions() {
+    let json = schema::to_json(&Ir { ops: Vec::new() });
+    assert_eq!(
+        schema::from_json::<Program>(&json).map(|_| ()),
+        Err("expected a 'program' document, not 'ir'".to_string())
+    );
+    let json = json.replace("\"version\": 1", "\"version\": 2");
+    assert_eq!(schema::from_json::<Ir>(&json), Err("unsupported version 2 of the 'ir' schema".to_string()));
+    // `--emit ast` wrote programs without `kind` and `version` before they were added.
+    assert!(schema::from_json::<Program>(r#"{"body": []}"#).is_ok());
+}
+
+#[test]
+fn restore_checks_the_state_fits() {
+    let mut machine = Machine::new("+>+", Vec::new(), 2, 8).expect("parsing");
+    let state = MachineState { tape: vec![0, 256], pointer: 0, pc: 0, steps: 0, read: 0, output: Vec::new() };
+    assert_eq!(machine.restore(state.clone()), Err("cell 1 does not fit in 8 bits".to_string()));
+    let state = MachineState { tape: vec![0; 3], ..state };
+    assert_eq!(machine.restore(state), Err("the state has 3 cells, but the tape 2".to_string()));
+}
@@ -0,0 +1,100 @@
+// Snapshot tests for the constructor's generated code.
+//
+// Every DSL program in `examples/` and `tests/corpus/` is compiled with the default options and
+// compared with `tests/snapshots/<name>.bf`. Pass `--bless` (or set `BLESS=1`) to write the
+// current output as the new snapshot instead:
+//
+//     cargo test --test snapshots -- --bless
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{exit, Command};
+
+const CORPUS: &[&str] = &["examples", "tests/corpus"];
+const SNAPSHOTS: &str = "tests/snapshots";
+
+// The programs to snapshot, sorted so that reports are stable.
+fn programs(root: &Path) -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    for dir in CORPUS {
+        let entries = fs::read_dir(root.join(dir)).unwrap_or_else(|e| panic!("reading {}: {}", dir, e));
+        for entry in entries {
+            let path = entry.expect("directory entry").path();
+            if path.extension().is_some_and(|ext| ext == "bfs") {
+                found.push(path);
+            }
+        }
+    }
+    found.sort();
+    found
+}
+
+fn compile(root: &Path, program: &Path) -> Result<String, String> {
+    let output = Command::new(env!("CARGO_BIN_EXE_bfconstructor"))
+        .current_dir(root)
+        .arg(program.strip_prefix(root).unwrap_or(program))
+        .output()
+        .map_err(|e| e.to_string())?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).into_owned());
+    }
+    String::from_utf8(output.stdout).map_err(|e| e.to_string())
+}
+
+// Describes the first line where `actual` departs from `expected`.
+fn first_difference(expected: &str, actual: &str) -> String {
+    let (mut expected_lines, mut actual_lines) = (expected.lines(), actual.lines());
+    for line in 1.. {
+        match (expected_lines.next(), actual_lines.next()) {
+            (Some(e), Some(a)) if e == a => {}
+            (e, a) => {
+                return format!(
+                    "  line {}:\n  - {}\n  + {}",
+                    line,
+                    e.unwrap_or("<end of snapshot>"),
+                    a.unwrap_or("<end of output>")
+                )
+            }
+        }
+    }
+    unreachable!()
+}
+
+fn main() {
+    let bless = std::env::args().any(|arg| arg == "--bless") || std::env::var_os("BLESS").is_some();
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let (mut passed, mut failed, mut blessed) = (0, 0, 0);
+    for program in programs(root) {
+        let name = program.file_stem().expect("file name").to_string_lossy().into_owned();
+        let snapshot = root.join(SNAPSHOTS).join(format!("{}.bf", name));
+        let actual = match compile(root, &program) {
+            Ok(code) => code,
+            Err(e) => {
+                println!("FAIL {}: compilation failed\n{}", name, e);
+                failed += 1;
+                continue;
+            }
+        };
+        match fs::read_to_string(&snapshot) {
+            Ok(expected) if expected == actual => passed += 1,
+            _ if bless => {
+                fs::write(&snapshot, &actual).unwrap_or_else(|e| panic!("writing {}: {}", snapshot.display(), e));
+                println!("blessed {}", name);
+                blessed += 1;
+            }
+            Ok(expected) => {
+                println!("FAIL {}: output differs from {}\n{}", name, snapshot.display(), first_difference(&expected, &actual));
+                failed += 1;
+            }
+            Err(_) => {
+                println!("FAIL {}: no snapshot at {}", name, snapshot.display());
+                failed += 1;
+            }
+        }
+    }
+    println!("snapshots: {} passed, {} failed, {} blessed", passed, failed, blessed);
+    if failed > 0 {
+        println!("run `cargo test --test snapshots -- --bless` to accept the new output");
+        exit(1);
+    }
+}
@@ -0,0 +1,3341 @@
+/* target: 8 bit cells with wraparound */
+/* let x                */ > #1
+
+/* push 200             */ >>+++++++[<-------->-]< #2
+/* push 100             */ >>++++++++++[<++++++++++>-]< #3
+/* add                  */ [<+>-]< #2
+/* printnum             */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #1
+/* push 32              */ >>++++[<++++++++>-]< #2
+/* write                */ .[-]< #1
+/* push 7               */ >+++++++ #2
+/* push 9               */ >+++++++++ #3
+/* sub                  */ [<->-]< #2
+/* sprintnum            */ 
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #3
+/*         push 128     */ >>++++++++[<++++++++++++++++>-]< #4
+/*         ge           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]+<[[-]>-<]>[<+>-]< #3
+/*     then             */     [
+/*         print "\x2d" */ >>+++++[<+++++++++>-]<.[-]< #3
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #4
+/*         neg          */ [>-<-]>[<+>-]< #4
+/*         poke 2       */ <<[-]>>[<<+>>-]< #3
+/*     end if           */       [-]]< #2
+/*     printnum         */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #1
+/* end sprintnum         */ #1
+/* push 32              */ >>++++[<++++++++>-]< #2
+/* write                */ .[-]< #1
+/* push 3               */ >+++ #2
+/* neg                  */ [>-<-]>[<+>-]< #2
+/* push 2               */ >++ #3
+/* slt                  */ >>++++++++[<++++++++++++++++>-]<[-<+<+>>]<<[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #2
+/* printnum             */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #1
+/* push 32              */ >>++++[<++++++++>-]< #2
+/* write                */ .[-]< #1
+/* push 6               */ >++++++ #2
+/* push 7               */ >+++++++ #3
+/* mul                  */ <[>>+<<-]>[>[<<+>>>+<-]>[<+>-]<<-]>[-]<< #2
+/* set x                */ <<[-]>>[<<+>>-]< #1
+/* get x                */ <[>>+>+<<<-]>>>[<<<+>>>-]< #2
+/* printnum             */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #1
+/* push 32              */ >>++++[<++++++++>-]< #2
+/* write                */ .[-]< #1
+/* get x                */ <[>>+>+<<<-]>>>[<<<+>>>-]< #2
+/* push 42              */ >>++++++[<+++++++>-]< #3
+/* eq                   */ [<->-]+<[[-]>-<]>[<+>-]< #2
+/* get x                */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #3
+/* push 41              */ >>+++++[<++++++++>-]<+ #4
+/* gt                   */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #3
+/* and                  */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #2
+/* printnum             */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #1
+/* push 10              */ >++++++++++ #2
+/* write                */ .[-]< #1
+/* push16 1000          */ 
+/*     push 3           */ >+++ #2
+/*     push 232         */ >>++++[<------>-]< #3
+/* end push16 1000         */ #3
+/* push16 234           */ 
+/*     push 0           */ > #4
+/*     push 234         */ >>+++[<------->-]<- #5
+/* end push16 234         */ #5
+/* add16                */ 
+/*     push 0           */ > #6
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*     add              */ [<+>-]< #7
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #8
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #9
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #8
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #8
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #9
+/*     add              */ [<+>-]< #8
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #9
+/*     not              */ >+<[[-]>-<]>[<+>-]< #9
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #10
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #9
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #9
+/*     poke 6           */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #7
+/*     poke 1           */ <[-]>[<+>-]< #6
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #7
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*     add              */ [<+>-]< #7
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #8
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #8
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #8
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #9
+/*     add              */ [<+>-]< #8
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #9
+/*     not              */ >+<[[-]>-<]>[<+>-]< #9
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #10
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #9
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #9
+/*     poke 7           */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #7
+/*     poke 1           */ <[-]>[<+>-]< #6
+/*     drop             */ [-]< #5
+/*     drop             */ [-]< #4
+/*     drop             */ [-]< #3
+/* end add16            */ #3
+/* print16              */ 
+/*     push 0           */ > #4
+/*     push 0           */ > #5
+/*     while            */ 
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 39      */ >>+++++[<++++++++>-]<- #8
+/*         push 16      */ >>++++[<++++>-]< #9
+/*         push 0       */ > #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         drop         */ [-]< #6
+/*         push 255     */ >- #7
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #6
+/*     do               */     [[-]
+/*         pick 3       */ <<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 39      */ >>+++++[<++++++++>-]<- #8
+/*         push 16      */ >>++++[<++++>-]< #9
+/*         push 0       */ > #10
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #11
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*         sub          */ [<->-]< #12
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #13
+/*         not          */ >+<[[-]>-<]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #13
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #11
+/*         poke 1       */ <[-]>[<+>-]< #10
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #11
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         sub          */ [<->-]< #12
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #13
+/*         not          */ >+<[[-]>-<]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #13
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #11
+/*         poke 1       */ <[-]>[<+>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #6
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #5
+/*         inc          */ + #5
+/*     again            */
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 39      */ >>+++++[<++++++++>-]<- #8
+/*         push 16      */ >>++++[<++++>-]< #9
+/*         push 0       */ > #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         drop         */ [-]< #6
+/*         push 255     */ >- #7
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #6
+/*     end while        */       ]< #5
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #6
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #7
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #6
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #7
+/*         addc 48      */ >++++++[<++++++++>-]< #7
+/*         write        */ .[-]< #6
+/*         push 1       */ >+ #7
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #6
+/*     end if           */       [-]]< #5
+/*     drop             */ [-]< #4
+/*     push 0           */ > #5
+/*     while            */ 
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 3       */ >+++ #8
+/*         push 232     */ >>++++[<------>-]< #9
+/*         push 0       */ > #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         drop         */ [-]< #6
+/*         push 255     */ >- #7
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #6
+/*     do               */     [[-]
+/*         pick 3       */ <<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 3       */ >+++ #8
+/*         push 232     */ >>++++[<------>-]< #9
+/*         push 0       */ > #10
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #11
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*         sub          */ [<->-]< #12
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #13
+/*         not          */ >+<[[-]>-<]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #13
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #11
+/*         poke 1       */ <[-]>[<+>-]< #10
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #11
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         sub          */ [<->-]< #12
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #13
+/*         not          */ >+<[[-]>-<]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #13
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #11
+/*         poke 1       */ <[-]>[<+>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #6
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #5
+/*         inc          */ + #5
+/*     again            */
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 3       */ >+++ #8
+/*         push 232     */ >>++++[<------>-]< #9
+/*         push 0       */ > #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         drop         */ [-]< #6
+/*         push 255     */ >- #7
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #6
+/*     end while        */       ]< #5
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #6
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #7
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #6
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #7
+/*         addc 48      */ >++++++[<++++++++>-]< #7
+/*         write        */ .[-]< #6
+/*         push 1       */ >+ #7
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #6
+/*     end if           */       [-]]< #5
+/*     drop             */ [-]< #4
+/*     push 0           */ > #5
+/*     while            */ 
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 0       */ > #8
+/*         push 100     */ >>++++++++++[<++++++++++>-]< #9
+/*         push 0       */ > #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         drop         */ [-]< #6
+/*         push 255     */ >- #7
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #6
+/*     do               */     [[-]
+/*         pick 3       */ <<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 0       */ > #8
+/*         push 100     */ >>++++++++++[<++++++++++>-]< #9
+/*         push 0       */ > #10
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #11
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*         sub          */ [<->-]< #12
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #13
+/*         not          */ >+<[[-]>-<]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #13
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #11
+/*         poke 1       */ <[-]>[<+>-]< #10
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #11
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         sub          */ [<->-]< #12
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #13
+/*         not          */ >+<[[-]>-<]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #13
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #11
+/*         poke 1       */ <[-]>[<+>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #6
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #5
+/*         inc          */ + #5
+/*     again            */
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 0       */ > #8
+/*         push 100     */ >>++++++++++[<++++++++++>-]< #9
+/*         push 0       */ > #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         drop         */ [-]< #6
+/*         push 255     */ >- #7
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #6
+/*     end while        */       ]< #5
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #6
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #7
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #6
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #7
+/*         addc 48      */ >++++++[<++++++++>-]< #7
+/*         write        */ .[-]< #6
+/*         push 1       */ >+ #7
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #6
+/*     end if           */       [-]]< #5
+/*     drop             */ [-]< #4
+/*     push 0           */ > #5
+/*     while            */ 
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 0       */ > #8
+/*         push 10      */ >++++++++++ #9
+/*         push 0       */ > #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         drop         */ [-]< #6
+/*         push 255     */ >- #7
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #6
+/*     do               */     [[-]
+/*         pick 3       */ <<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 0       */ > #8
+/*         push 10      */ >++++++++++ #9
+/*         push 0       */ > #10
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #11
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*         sub          */ [<->-]< #12
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #13
+/*         not          */ >+<[[-]>-<]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #13
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #11
+/*         poke 1       */ <[-]>[<+>-]< #10
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #11
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         sub          */ [<->-]< #12
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #13
+/*         not          */ >+<[[-]>-<]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #13
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #11
+/*         poke 1       */ <[-]>[<+>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #6
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #5
+/*         inc          */ + #5
+/*     again            */
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 0       */ > #8
+/*         push 10      */ >++++++++++ #9
+/*         push 0       */ > #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         drop         */ [-]< #6
+/*         push 255     */ >- #7
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #6
+/*     end while        */       ]< #5
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #6
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #7
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #6
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #7
+/*         addc 48      */ >++++++[<++++++++>-]< #7
+/*         write        */ .[-]< #6
+/*         push 1       */ >+ #7
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #6
+/*     end if           */       [-]]< #5
+/*     drop             */ [-]< #4
+/*     push 0           */ > #5
+/*     while            */ 
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 0       */ > #8
+/*         push 1       */ >+ #9
+/*         push 0       */ > #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         drop         */ [-]< #6
+/*         push 255     */ >- #7
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #6
+/*     do               */     [[-]
+/*         pick 3       */ <<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 0       */ > #8
+/*         push 1       */ >+ #9
+/*         push 0       */ > #10
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #11
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*         sub          */ [<->-]< #12
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #13
+/*         not          */ >+<[[-]>-<]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #13
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #11
+/*         poke 1       */ <[-]>[<+>-]< #10
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #11
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         sub          */ [<->-]< #12
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #13
+/*         not          */ >+<[[-]>-<]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #13
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #11
+/*         poke 1       */ <[-]>[<+>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #6
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #5
+/*         inc          */ + #5
+/*     again            */
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 0       */ > #8
+/*         push 1       */ >+ #9
+/*         push 0       */ > #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         drop         */ [-]< #6
+/*         push 255     */ >- #7
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #6
+/*     end while        */       ]< #5
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #6
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #7
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #6
+/*         push 1       */ >+ #7
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #6
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #7
+/*         addc 48      */ >++++++[<++++++++>-]< #7
+/*         write        */ .[-]< #6
+/*         push 1       */ >+ #7
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #6
+/*     end if           */       [-]]< #5
+/*     drop             */ [-]< #4
+/*     drop             */ [-]< #3
+/*     drop             */ [-]< #2
+/*     drop             */ [-]< #1
+/* end print16          */ #1
+/* push 32              */ >>++++[<++++++++>-]< #2
+/* write                */ .[-]< #1
+/* push32 70000         */ 
+/*     push 0           */ > #2
+/*     push 1           */ >+ #3
+/*     push 17          */ >>++++[<++++>-]<+ #4
+/*     push 112         */ >>++++++++[<++++++++++++++>-]< #5
+/* end push32 70000         */ #5
+/* push32 4465          */ 
+/*     push 0           */ > #6
+/*     push 0           */ > #7
+/*     push 17          */ >>++++[<++++>-]<+ #8
+/*     push 113         */ >>++++++++[<++++++++++++++>-]<+ #9
+/* end push32 4465         */ #9
+/* sub32                */ 
+/*     push 0           */ > #10
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     sub              */ [<->-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     not              */ >+<[[-]>-<]>[<+>-]< #13
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*     sub              */ [<->-]< #13
+/*     poke 8           */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #12
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #11
+/*     poke 1           */ <[-]>[<+>-]< #10
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #11
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     pick 7           */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #12
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*     sub              */ [<->-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     not              */ >+<[[-]>-<]>[<+>-]< #13
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*     sub              */ [<->-]< #13
+/*     poke 9           */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #12
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #11
+/*     poke 1           */ <[-]>[<+>-]< #10
+/*     pick 7           */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #11
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     pick 8           */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #12
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*     sub              */ [<->-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     not              */ >+<[[-]>-<]>[<+>-]< #13
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*     sub              */ [<->-]< #13
+/*     poke 10          */ <<<<<<<<<<[-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #12
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #11
+/*     poke 1           */ <[-]>[<+>-]< #10
+/*     pick 8           */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #11
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     pick 9           */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #12
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*     sub              */ [<->-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     not              */ >+<[[-]>-<]>[<+>-]< #13
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*     sub              */ [<->-]< #13
+/*     poke 11          */ <<<<<<<<<<<[-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #12
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #11
+/*     poke 1           */ <[-]>[<+>-]< #10
+/*     drop             */ [-]< #9
+/*     drop             */ [-]< #8
+/*     drop             */ [-]< #7
+/*     drop             */ [-]< #6
+/*     drop             */ [-]< #5
+/* end sub32            */ #5
+/* print32              */ 
+/*     push 0           */ > #6
+/*     push 0           */ > #7
+/*     while            */ 
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 59      */ >>++++++[<++++++++++>-]<- #12
+/*         push 154     */ >>++++++++++[<---------->-]<-- #13
+/*         push 202     */ >>++++++[<--------->-]< #14
+/*         push 0       */ > #15
+/*         push 0       */ > #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 10      */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #19
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #19
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #19
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #19
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     do               */     [[-]
+/*         pick 5       */ <<<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 59      */ >>++++++[<++++++++++>-]<- #12
+/*         push 154     */ >>++++++++++[<---------->-]<-- #13
+/*         push 202     */ >>++++++[<--------->-]< #14
+/*         push 0       */ > #15
+/*         push 0       */ > #16
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 9       */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 10      */ <<<<<<<<<<[-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 11      */ <<<<<<<<<<<[-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         drop         */ [-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #7
+/*         inc          */ + #7
+/*     again            */
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 59      */ >>++++++[<++++++++++>-]<- #12
+/*         push 154     */ >>++++++++++[<---------->-]<-- #13
+/*         push 202     */ >>++++++[<--------->-]< #14
+/*         push 0       */ > #15
+/*         push 0       */ > #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 10      */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #19
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #19
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #19
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #19
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     end while        */       ]< #7
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #8
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #9
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #8
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #9
+/*         addc 48      */ >++++++[<++++++++>-]< #9
+/*         write        */ .[-]< #8
+/*         push 1       */ >+ #9
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #8
+/*     end if           */       [-]]< #7
+/*     drop             */ [-]< #6
+/*     push 0           */ > #7
+/*     while            */ 
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 5       */ >+++++ #12
+/*         push 245     */ >----------- #13
+/*         push 225     */ >>+++++[<------>-]<- #14
+/*         push 0       */ > #15
+/*         push 0       */ > #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 10      */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #19
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #19
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #19
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #19
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     do               */     [[-]
+/*         pick 5       */ <<<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 5       */ >+++++ #12
+/*         push 245     */ >----------- #13
+/*         push 225     */ >>+++++[<------>-]<- #14
+/*         push 0       */ > #15
+/*         push 0       */ > #16
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 9       */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 10      */ <<<<<<<<<<[-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 11      */ <<<<<<<<<<<[-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         drop         */ [-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #7
+/*         inc          */ + #7
+/*     again            */
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 5       */ >+++++ #12
+/*         push 245     */ >----------- #13
+/*         push 225     */ >>+++++[<------>-]<- #14
+/*         push 0       */ > #15
+/*         push 0       */ > #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 10      */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #19
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #19
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #19
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #19
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     end while        */       ]< #7
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #8
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #9
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #8
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #9
+/*         addc 48      */ >++++++[<++++++++>-]< #9
+/*         write        */ .[-]< #8
+/*         push 1       */ >+ #9
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #8
+/*     end if           */       [-]]< #7
+/*     drop             */ [-]< #6
+/*     push 0           */ > #7
+/*     while            */ 
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 0       */ > #12
+/*         push 152     */ >>++++++++[<------------->-]< #13
+/*         push 150     */ >>+++++++[<--------------->-]<- #14
+/*         push 128     */ >>++++++++[<++++++++++++++++>-]< #15
+/*         push 0       */ > #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 10      */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #19
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #19
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #19
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #19
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     do               */     [[-]
+/*         pick 5       */ <<<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 0       */ > #12
+/*         push 152     */ >>++++++++[<------------->-]< #13
+/*         push 150     */ >>+++++++[<--------------->-]<- #14
+/*         push 128     */ >>++++++++[<++++++++++++++++>-]< #15
+/*         push 0       */ > #16
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 9       */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 10      */ <<<<<<<<<<[-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 11      */ <<<<<<<<<<<[-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         drop         */ [-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #7
+/*         inc          */ + #7
+/*     again            */
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 0       */ > #12
+/*         push 152     */ >>++++++++[<------------->-]< #13
+/*         push 150     */ >>+++++++[<--------------->-]<- #14
+/*         push 128     */ >>++++++++[<++++++++++++++++>-]< #15
+/*         push 0       */ > #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 10      */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #19
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #19
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #19
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #19
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     end while        */       ]< #7
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #8
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #9
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #8
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #9
+/*         addc 48      */ >++++++[<++++++++>-]< #9
+/*         write        */ .[-]< #8
+/*         push 1       */ >+ #9
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #8
+/*     end if           */       [-]]< #7
+/*     drop             */ [-]< #6
+/*     push 0           */ > #7
+/*     while            */ 
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 0       */ > #12
+/*         push 15      */ >+++++++++++++++ #13
+/*         push 66      */ >>++++++[<+++++++++++>-]< #14
+/*         push 64      */ >>++++++++[<++++++++>-]< #15
+/*         push 0       */ > #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 10      */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #19
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #19
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #19
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #19
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     do               */     [[-]
+/*         pick 5       */ <<<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 0       */ > #12
+/*         push 15      */ >+++++++++++++++ #13
+/*         push 66      */ >>++++++[<+++++++++++>-]< #14
+/*         push 64      */ >>++++++++[<++++++++>-]< #15
+/*         push 0       */ > #16
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 9       */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 10      */ <<<<<<<<<<[-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 11      */ <<<<<<<<<<<[-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         drop         */ [-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #7
+/*         inc          */ + #7
+/*     again            */
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 0       */ > #12
+/*         push 15      */ >+++++++++++++++ #13
+/*         push 66      */ >>++++++[<+++++++++++>-]< #14
+/*         push 64      */ >>++++++++[<++++++++>-]< #15
+/*         push 0       */ > #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 10      */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #19
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #19
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #19
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #19
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     end while        */       ]< #7
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #8
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #9
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #8
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #9
+/*         addc 48      */ >++++++[<++++++++>-]< #9
+/*         write        */ .[-]< #8
+/*         push 1       */ >+ #9
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #8
+/*     end if           */       [-]]< #7
+/*     drop             */ [-]< #6
+/*     push 0           */ > #7
+/*     while            */ 
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 0       */ > #12
+/*         push 1       */ >+ #13
+/*         push 134     */ >>+++++++++++[<----------->-]<- #14
+/*         push 160     */ >>++++++++[<------------>-]< #15
+/*         push 0       */ > #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 10      */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #19
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #19
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #19
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #19
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     do               */     [[-]
+/*         pick 5       */ <<<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 0       */ > #12
+/*         push 1       */ >+ #13
+/*         push 134     */ >>+++++++++++[<----------->-]<- #14
+/*         push 160     */ >>++++++++[<------------>-]< #15
+/*         push 0       */ > #16
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 9       */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 10      */ <<<<<<<<<<[-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 11      */ <<<<<<<<<<<[-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         drop         */ [-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #7
+/*         inc          */ + #7
+/*     again            */
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 0       */ > #12
+/*         push 1       */ >+ #13
+/*         push 134     */ >>+++++++++++[<----------->-]<- #14
+/*         push 160     */ >>++++++++[<------------>-]< #15
+/*         push 0       */ > #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 10      */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #19
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #19
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #19
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #19
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     end while        */       ]< #7
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #8
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #9
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #8
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #9
+/*         addc 48      */ >++++++[<++++++++>-]< #9
+/*         write        */ .[-]< #8
+/*         push 1       */ >+ #9
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #8
+/*     end if           */       [-]]< #7
+/*     drop             */ [-]< #6
+/*     push 0           */ > #7
+/*     while            */ 
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 0       */ > #12
+/*         push 0       */ > #13
+/*         push 39      */ >>+++++[<++++++++>-]<- #14
+/*         push 16      */ >>++++[<++++>-]< #15
+/*         push 0       */ > #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 10      */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #19
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #19
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #19
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #19
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     do               */     [[-]
+/*         pick 5       */ <<<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 0       */ > #12
+/*         push 0       */ > #13
+/*         push 39      */ >>+++++[<++++++++>-]<- #14
+/*         push 16      */ >>++++[<++++>-]< #15
+/*         push 0       */ > #16
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 9       */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 10      */ <<<<<<<<<<[-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 11      */ <<<<<<<<<<<[-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         drop         */ [-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #7
+/*         inc          */ + #7
+/*     again            */
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 0       */ > #12
+/*         push 0       */ > #13
+/*         push 39      */ >>+++++[<++++++++>-]<- #14
+/*         push 16      */ >>++++[<++++>-]< #15
+/*         push 0       */ > #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 10      */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #19
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #19
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #19
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #19
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     end while        */       ]< #7
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #8
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #9
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #8
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #9
+/*         addc 48      */ >++++++[<++++++++>-]< #9
+/*         write        */ .[-]< #8
+/*         push 1       */ >+ #9
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #8
+/*     end if           */       [-]]< #7
+/*     drop             */ [-]< #6
+/*     push 0           */ > #7
+/*     while            */ 
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 0       */ > #12
+/*         push 0       */ > #13
+/*         push 3       */ >+++ #14
+/*         push 232     */ >>++++[<------>-]< #15
+/*         push 0       */ > #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 10      */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #19
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #19
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #19
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #19
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     do               */     [[-]
+/*         pick 5       */ <<<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 0       */ > #12
+/*         push 0       */ > #13
+/*         push 3       */ >+++ #14
+/*         push 232     */ >>++++[<------>-]< #15
+/*         push 0       */ > #16
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 9       */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 10      */ <<<<<<<<<<[-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 11      */ <<<<<<<<<<<[-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         drop         */ [-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #7
+/*         inc          */ + #7
+/*     again            */
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 0       */ > #12
+/*         push 0       */ > #13
+/*         push 3       */ >+++ #14
+/*         push 232     */ >>++++[<------>-]< #15
+/*         push 0       */ > #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 10      */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #19
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #19
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #19
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #19
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     end while        */       ]< #7
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #8
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #9
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #8
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #9
+/*         addc 48      */ >++++++[<++++++++>-]< #9
+/*         write        */ .[-]< #8
+/*         push 1       */ >+ #9
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #8
+/*     end if           */       [-]]< #7
+/*     drop             */ [-]< #6
+/*     push 0           */ > #7
+/*     while            */ 
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 0       */ > #12
+/*         push 0       */ > #13
+/*         push 0       */ > #14
+/*         push 100     */ >>++++++++++[<++++++++++>-]< #15
+/*         push 0       */ > #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 10      */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #19
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #19
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #19
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #19
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     do               */     [[-]
+/*         pick 5       */ <<<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 0       */ > #12
+/*         push 0       */ > #13
+/*         push 0       */ > #14
+/*         push 100     */ >>++++++++++[<++++++++++>-]< #15
+/*         push 0       */ > #16
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 9       */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 10      */ <<<<<<<<<<[-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 11      */ <<<<<<<<<<<[-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         drop         */ [-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #7
+/*         inc          */ + #7
+/*     again            */
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 0       */ > #12
+/*         push 0       */ > #13
+/*         push 0       */ > #14
+/*         push 100     */ >>++++++++++[<++++++++++>-]< #15
+/*         push 0       */ > #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 10      */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #19
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #19
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #19
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #19
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     end while        */       ]< #7
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #8
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #9
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #8
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #9
+/*         addc 48      */ >++++++[<++++++++>-]< #9
+/*         write        */ .[-]< #8
+/*         push 1       */ >+ #9
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #8
+/*     end if           */       [-]]< #7
+/*     drop             */ [-]< #6
+/*     push 0           */ > #7
+/*     while            */ 
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 0       */ > #12
+/*         push 0       */ > #13
+/*         push 0       */ > #14
+/*         push 10      */ >++++++++++ #15
+/*         push 0       */ > #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 10      */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #19
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #19
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #19
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #19
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     do               */     [[-]
+/*         pick 5       */ <<<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 0       */ > #12
+/*         push 0       */ > #13
+/*         push 0       */ > #14
+/*         push 10      */ >++++++++++ #15
+/*         push 0       */ > #16
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 9       */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 10      */ <<<<<<<<<<[-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 11      */ <<<<<<<<<<<[-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         drop         */ [-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #7
+/*         inc          */ + #7
+/*     again            */
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 0       */ > #12
+/*         push 0       */ > #13
+/*         push 0       */ > #14
+/*         push 10      */ >++++++++++ #15
+/*         push 0       */ > #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 10      */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #19
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #19
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #19
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #19
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     end while        */       ]< #7
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #8
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #9
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #8
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #9
+/*         addc 48      */ >++++++[<++++++++>-]< #9
+/*         write        */ .[-]< #8
+/*         push 1       */ >+ #9
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #8
+/*     end if           */       [-]]< #7
+/*     drop             */ [-]< #6
+/*     push 0           */ > #7
+/*     while            */ 
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 0       */ > #12
+/*         push 0       */ > #13
+/*         push 0       */ > #14
+/*         push 1       */ >+ #15
+/*         push 0       */ > #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 10      */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #19
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #19
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #19
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #19
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     do               */     [[-]
+/*         pick 5       */ <<<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 0       */ > #12
+/*         push 0       */ > #13
+/*         push 0       */ > #14
+/*         push 1       */ >+ #15
+/*         push 0       */ > #16
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 9       */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 10      */ <<<<<<<<<<[-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*         pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*         sub          */ [<->-]< #18
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #19
+/*         not          */ >+<[[-]>-<]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #19
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*         sub          */ [<->-]< #19
+/*         poke 11      */ <<<<<<<<<<<[-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #17
+/*         poke 1       */ <[-]>[<+>-]< #16
+/*         drop         */ [-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #7
+/*         inc          */ + #7
+/*     again            */
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         push 0       */ > #12
+/*         push 0       */ > #13
+/*         push 0       */ > #14
+/*         push 1       */ >+ #15
+/*         push 0       */ > #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #18
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 10      */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #19
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #18
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #19
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #18
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #19
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #17
+/*             not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #18
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #19
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #20
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #19
+/*             sub          */ [<->-]< #18
+/*             poke 2       */ <<[-]>>[<<+>>-]< #17
+/*         end if       */           [-]]< #16
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #15
+/*         drop         */ [-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     end while        */       ]< #7
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #8
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #9
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #8
+/*         push 1       */ >+ #9
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #8
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #9
+/*         addc 48      */ >++++++[<++++++++>-]< #9
+/*         write        */ .[-]< #8
+/*         push 1       */ >+ #9
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #8
+/*     end if           */       [-]]< #7
+/*     drop             */ [-]< #6
+/*     drop             */ [-]< #5
+/*     drop             */ [-]< #4
+/*     drop             */ [-]< #3
+/*     drop             */ [-]< #2
+/*     drop             */ [-]< #1
+/* end print32          */ #1
+/* push 32              */ >>++++[<++++++++>-]< #2
+/* write                */ .[-]< #1
+/* push16 5             */ 
+/*     push 0           */ > #2
+/*     push 5           */ >+++++ #3
+/* end push16 5         */ #3
+/* push16 300           */ 
+/*     push 1           */ >+ #4
+/*     push 44          */ >>++++[<+++++++++++>-]< #5
+/* end push16 300         */ #5
+/* cmp16                */ 
+/*     push 0           */ > #6
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #7
+/*         not          */ >+<[[-]>-<]>[<+>-]< #7
+/*     then             */     [
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #8
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #9
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #9
+/*         sub          */ [<->-]< #8
+/*         poke 2       */ <<[-]>>[<<+>>-]< #7
+/*     end if           */       [-]]< #6
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #7
+/*         not          */ >+<[[-]>-<]>[<+>-]< #7
+/*     then             */     [
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #9
+/*         gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #8
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #9
+/*         sub          */ [<->-]< #8
+/*         poke 2       */ <<[-]>>[<<+>>-]< #7
+/*     end if           */       [-]]< #6
+/*     poke 4           */ <<<<[-]>>>>[<<<<+>>>>-]< #5
+/*     drop             */ [-]< #4
+/*     drop             */ [-]< #3
+/*     drop             */ [-]< #2
+/* end cmp16            */ #2
+/* printnum             */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #1
+/* push 10              */ >++++++++++ #2
+/* write                */ .[-]< #1
+
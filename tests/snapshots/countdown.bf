@@ -0,0 +1,19 @@
+/* target: 8 bit cells with wraparound */
+/* let i                */ > #1
+
+/* push 5               */ >+++++ #2
+/* set i                */ <<[-]>>[<<+>>-]< #1
+/* while                */ 
+/*     get i            */ <[>>+>+<<<-]>>>[<<<+>>>-]< #2
+/* do                   */ [[-]
+/*     get i            */ <<[>>+>+<<<-]>>>[<<<+>>>-]< #2
+/*     push 48          */ >>++++++[<++++++++>-]< #3
+/*     add              */ [<+>-]< #2
+/*     write            */ .[-]< #1
+/*     get i            */ <[>>+>+<<<-]>>>[<<<+>>>-]< #2
+/*     dec              */ - #2
+/*     set i            */ <<[-]>>[<<+>>-]< #1
+/* again                */
+/*     get i            */ <[>>+>+<<<-]>>>[<<<+>>>-]< #2
+/* end while            */   ]< #1
+
@@ -0,0 +1,27 @@
+/* target: 8 bit cells with wraparound */
+/* let a                */ > #1
+/* let b                */ > #2
+
+/* if                   */ 
+/*     push 1           */ >+ #3
+/* then                 */ [
+/*     stat             */ 
+/*     end stat         */ #3
+/* end if               */   [-]]< #2
+/* if                   */ 
+/*     push 0           */ > #3
+/* then                 */ [
+/*     stat             */ 
+/*     end stat         */ #3
+/* end if               */   [-]]< #2
+/* stat                 */ 
+/*     push 5           */ >+++++ #3
+/*     push 2           */ >++ #4
+/*     add              */ [<+>-]< #3
+/*     push 3           */ >+++ #4
+/*     sub              */ [<->-]< #3
+/*     push 10          */ >++++++++++ #4
+/*     mul              */ <[>>+<<-]>[>[<<+>>>+<-]>[<+>-]<<-]>[-]<< #3
+/*     write            */ .[-]< #2
+/* end stat             */ #2
+
@@ -0,0 +1,5817 @@
+/* target: 8 bit cells with wraparound */
+
+/* push16 832           */ 
+/*     push 3           */ >+++ #1
+/*     push 64          */ >>++++++++[<++++++++>-]< #2
+/* end push16 832         */ #2
+/* push16 384           */ 
+/*     push 1           */ >+ #3
+/*     push 128         */ >>++++++++[<++++++++++++++++>-]< #4
+/* end push16 384         */ #4
+/* mulfx                */ 
+/*     push 0           */ > #5
+/*     push 0           */ > #6
+/*     push 0           */ > #7
+/*     push 0           */ > #8
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*     while            */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #10
+/*     do               */     [[-]
+/*         pick 1       */ <<[>>+>+<<<-]>>>[<<<+>>>-]< #10
+/*         pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #11
+/*         add          */ [<+>-]< #10
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #11
+/*         pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #12
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         add          */ [<+>-]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         poke 2       */ <<[-]>>[<<+>>-]< #9
+/*         dec          */ - #9
+/*     again            */
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #10
+/*     end while        */       ]< #9
+/*     drop             */ [-]< #8
+/*     drop             */ [-]< #7
+/*     poke 1           */ <[-]>[<+>-]< #6
+/*     push 0           */ > #7
+/*     push 0           */ > #8
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*     while            */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #10
+/*     do               */     [[-]
+/*         pick 1       */ <<[>>+>+<<<-]>>>[<<<+>>>-]< #10
+/*         pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #11
+/*         add          */ [<+>-]< #10
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #11
+/*         pick 10      */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #12
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         add          */ [<+>-]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         poke 2       */ <<[-]>>[<<+>>-]< #9
+/*         dec          */ - #9
+/*     again            */
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #10
+/*     end while        */       ]< #9
+/*     drop             */ [-]< #8
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #9
+/*     add              */ [<+>-]< #8
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #9
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #10
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #9
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #9
+/*     poke 3           */ <<<[-]>>>[<<<+>>>-]< #8
+/*     add              */ [<+>-]< #7
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*     add              */ [<+>-]< #7
+/*     poke 2           */ <<[-]>>[<<+>>-]< #6
+/*     push 0           */ > #7
+/*     push 0           */ > #8
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*     while            */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #10
+/*     do               */     [[-]
+/*         pick 1       */ <<[>>+>+<<<-]>>>[<<<+>>>-]< #10
+/*         pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #11
+/*         add          */ [<+>-]< #10
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #11
+/*         pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #12
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         add          */ [<+>-]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         poke 2       */ <<[-]>>[<<+>>-]< #9
+/*         dec          */ - #9
+/*     again            */
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #10
+/*     end while        */       ]< #9
+/*     drop             */ [-]< #8
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #9
+/*     add              */ [<+>-]< #8
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #9
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #10
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #9
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #9
+/*     poke 3           */ <<<[-]>>>[<<<+>>>-]< #8
+/*     add              */ [<+>-]< #7
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*     add              */ [<+>-]< #7
+/*     poke 2           */ <<[-]>>[<<+>>-]< #6
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #7
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*     mul              */ <[>>+<<-]>[>[<<+>>>+<-]>[<+>-]<<-]>[-]<< #7
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*     add              */ [<+>-]< #7
+/*     poke 2           */ <<[-]>>[<<+>>-]< #6
+/*     pick 1           */ <[>>+>+<<<-]>>>[<<<+>>>-]< #7
+/*     poke 6           */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #6
+/*     pick 0           */ [>+>+<<-]>>[<<+>>-]< #7
+/*     poke 5           */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #6
+/*     drop             */ [-]< #5
+/*     drop             */ [-]< #4
+/*     drop             */ [-]< #3
+/*     drop             */ [-]< #2
+/* end mulfx            */ #2
+/* printfx 3            */ 
+/*     push 0           */ > #3
+/*     push 0           */ > #4
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #5
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #9
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #10
+/*     pick 7           */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #11
+/*     pick 7           */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #12
+/*     pick 7           */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #13
+/*     pick 7           */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #14
+/*     push 0           */ > #15
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #17
+/*     add              */ [<+>-]< #16
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #17
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*     add              */ [<+>-]< #17
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #18
+/*     not              */ >+<[[-]>-<]>[<+>-]< #18
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #18
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #18
+/*     poke 8           */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #16
+/*     poke 1           */ <[-]>[<+>-]< #15
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*     add              */ [<+>-]< #16
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #17
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*     add              */ [<+>-]< #17
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #18
+/*     not              */ >+<[[-]>-<]>[<+>-]< #18
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #18
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #18
+/*     poke 9           */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #16
+/*     poke 1           */ <[-]>[<+>-]< #15
+/*     pick 7           */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*     add              */ [<+>-]< #16
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #17
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*     add              */ [<+>-]< #17
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #18
+/*     not              */ >+<[[-]>-<]>[<+>-]< #18
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #18
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #18
+/*     poke 10          */ <<<<<<<<<<[-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #16
+/*     poke 1           */ <[-]>[<+>-]< #15
+/*     pick 8           */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #16
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*     add              */ [<+>-]< #16
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #17
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*     add              */ [<+>-]< #17
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #18
+/*     not              */ >+<[[-]>-<]>[<+>-]< #18
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #18
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #18
+/*     poke 11          */ <<<<<<<<<<<[-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #17
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #16
+/*     poke 1           */ <[-]>[<+>-]< #15
+/*     drop             */ [-]< #14
+/*     drop             */ [-]< #13
+/*     drop             */ [-]< #12
+/*     drop             */ [-]< #11
+/*     drop             */ [-]< #10
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #11
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*     push 0           */ > #15
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #17
+/*     add              */ [<+>-]< #16
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #17
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*     add              */ [<+>-]< #17
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #18
+/*     not              */ >+<[[-]>-<]>[<+>-]< #18
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #18
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #18
+/*     poke 8           */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #16
+/*     poke 1           */ <[-]>[<+>-]< #15
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*     add              */ [<+>-]< #16
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #17
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*     add              */ [<+>-]< #17
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #18
+/*     not              */ >+<[[-]>-<]>[<+>-]< #18
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #18
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #18
+/*     poke 9           */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #16
+/*     poke 1           */ <[-]>[<+>-]< #15
+/*     pick 7           */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*     add              */ [<+>-]< #16
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #17
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*     add              */ [<+>-]< #17
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #18
+/*     not              */ >+<[[-]>-<]>[<+>-]< #18
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #18
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #18
+/*     poke 10          */ <<<<<<<<<<[-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #16
+/*     poke 1           */ <[-]>[<+>-]< #15
+/*     pick 8           */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #16
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*     add              */ [<+>-]< #16
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #17
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*     add              */ [<+>-]< #17
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #18
+/*     not              */ >+<[[-]>-<]>[<+>-]< #18
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #18
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #18
+/*     poke 11          */ <<<<<<<<<<<[-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #17
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #16
+/*     poke 1           */ <[-]>[<+>-]< #15
+/*     drop             */ [-]< #14
+/*     drop             */ [-]< #13
+/*     drop             */ [-]< #12
+/*     drop             */ [-]< #11
+/*     drop             */ [-]< #10
+/*     push 0           */ > #11
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #13
+/*     add              */ [<+>-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     not              */ >+<[[-]>-<]>[<+>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     poke 8           */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*     poke 1           */ <[-]>[<+>-]< #11
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     add              */ [<+>-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     not              */ >+<[[-]>-<]>[<+>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     poke 9           */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #13
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*     poke 1           */ <[-]>[<+>-]< #11
+/*     pick 7           */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #12
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*     add              */ [<+>-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     not              */ >+<[[-]>-<]>[<+>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     poke 10          */ <<<<<<<<<<[-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #13
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*     poke 1           */ <[-]>[<+>-]< #11
+/*     pick 8           */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #12
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*     add              */ [<+>-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     not              */ >+<[[-]>-<]>[<+>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     poke 11          */ <<<<<<<<<<<[-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #13
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*     poke 1           */ <[-]>[<+>-]< #11
+/*     drop             */ [-]< #10
+/*     drop             */ [-]< #9
+/*     drop             */ [-]< #8
+/*     drop             */ [-]< #7
+/*     drop             */ [-]< #6
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #9
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #10
+/*     push 0           */ > #11
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #13
+/*     add              */ [<+>-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     not              */ >+<[[-]>-<]>[<+>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     poke 8           */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*     poke 1           */ <[-]>[<+>-]< #11
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     add              */ [<+>-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     not              */ >+<[[-]>-<]>[<+>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     poke 9           */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #13
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*     poke 1           */ <[-]>[<+>-]< #11
+/*     pick 7           */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #12
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*     add              */ [<+>-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     not              */ >+<[[-]>-<]>[<+>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     poke 10          */ <<<<<<<<<<[-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #13
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*     poke 1           */ <[-]>[<+>-]< #11
+/*     pick 8           */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #12
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*     add              */ [<+>-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     not              */ >+<[[-]>-<]>[<+>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     poke 11          */ <<<<<<<<<<<[-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #13
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*     poke 1           */ <[-]>[<+>-]< #11
+/*     drop             */ [-]< #10
+/*     drop             */ [-]< #9
+/*     drop             */ [-]< #8
+/*     drop             */ [-]< #7
+/*     drop             */ [-]< #6
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #9
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #10
+/*     pick 7           */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #11
+/*     pick 7           */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #12
+/*     pick 7           */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #13
+/*     pick 7           */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #14
+/*     push 0           */ > #15
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #17
+/*     add              */ [<+>-]< #16
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #17
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*     add              */ [<+>-]< #17
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #18
+/*     not              */ >+<[[-]>-<]>[<+>-]< #18
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #18
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #18
+/*     poke 8           */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #16
+/*     poke 1           */ <[-]>[<+>-]< #15
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*     add              */ [<+>-]< #16
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #17
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*     add              */ [<+>-]< #17
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #18
+/*     not              */ >+<[[-]>-<]>[<+>-]< #18
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #18
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #18
+/*     poke 9           */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #16
+/*     poke 1           */ <[-]>[<+>-]< #15
+/*     pick 7           */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*     add              */ [<+>-]< #16
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #17
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*     add              */ [<+>-]< #17
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #18
+/*     not              */ >+<[[-]>-<]>[<+>-]< #18
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #18
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #18
+/*     poke 10          */ <<<<<<<<<<[-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #16
+/*     poke 1           */ <[-]>[<+>-]< #15
+/*     pick 8           */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #16
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*     add              */ [<+>-]< #16
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #17
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*     add              */ [<+>-]< #17
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #18
+/*     not              */ >+<[[-]>-<]>[<+>-]< #18
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #18
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #18
+/*     poke 11          */ <<<<<<<<<<<[-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #17
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #16
+/*     poke 1           */ <[-]>[<+>-]< #15
+/*     drop             */ [-]< #14
+/*     drop             */ [-]< #13
+/*     drop             */ [-]< #12
+/*     drop             */ [-]< #11
+/*     drop             */ [-]< #10
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #11
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*     push 0           */ > #15
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #17
+/*     add              */ [<+>-]< #16
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #17
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*     add              */ [<+>-]< #17
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #18
+/*     not              */ >+<[[-]>-<]>[<+>-]< #18
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #18
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #18
+/*     poke 8           */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #16
+/*     poke 1           */ <[-]>[<+>-]< #15
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*     add              */ [<+>-]< #16
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #17
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*     add              */ [<+>-]< #17
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #18
+/*     not              */ >+<[[-]>-<]>[<+>-]< #18
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #18
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #18
+/*     poke 9           */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #16
+/*     poke 1           */ <[-]>[<+>-]< #15
+/*     pick 7           */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*     add              */ [<+>-]< #16
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #17
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*     add              */ [<+>-]< #17
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #18
+/*     not              */ >+<[[-]>-<]>[<+>-]< #18
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #18
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #18
+/*     poke 10          */ <<<<<<<<<<[-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #16
+/*     poke 1           */ <[-]>[<+>-]< #15
+/*     pick 8           */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #16
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*     add              */ [<+>-]< #16
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #17
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*     add              */ [<+>-]< #17
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #18
+/*     not              */ >+<[[-]>-<]>[<+>-]< #18
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #18
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #18
+/*     poke 11          */ <<<<<<<<<<<[-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #17
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #16
+/*     poke 1           */ <[-]>[<+>-]< #15
+/*     drop             */ [-]< #14
+/*     drop             */ [-]< #13
+/*     drop             */ [-]< #12
+/*     drop             */ [-]< #11
+/*     drop             */ [-]< #10
+/*     push 0           */ > #11
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #13
+/*     add              */ [<+>-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     not              */ >+<[[-]>-<]>[<+>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     poke 8           */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*     poke 1           */ <[-]>[<+>-]< #11
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     add              */ [<+>-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     not              */ >+<[[-]>-<]>[<+>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     poke 9           */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #13
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*     poke 1           */ <[-]>[<+>-]< #11
+/*     pick 7           */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #12
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*     add              */ [<+>-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     not              */ >+<[[-]>-<]>[<+>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     poke 10          */ <<<<<<<<<<[-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #13
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*     poke 1           */ <[-]>[<+>-]< #11
+/*     pick 8           */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #12
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*     add              */ [<+>-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     not              */ >+<[[-]>-<]>[<+>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     poke 11          */ <<<<<<<<<<<[-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #13
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*     poke 1           */ <[-]>[<+>-]< #11
+/*     drop             */ [-]< #10
+/*     drop             */ [-]< #9
+/*     drop             */ [-]< #8
+/*     drop             */ [-]< #7
+/*     drop             */ [-]< #6
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #9
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #10
+/*     push 0           */ > #11
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #13
+/*     add              */ [<+>-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     not              */ >+<[[-]>-<]>[<+>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     poke 8           */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*     poke 1           */ <[-]>[<+>-]< #11
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     add              */ [<+>-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     not              */ >+<[[-]>-<]>[<+>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     poke 9           */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #13
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*     poke 1           */ <[-]>[<+>-]< #11
+/*     pick 7           */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #12
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*     add              */ [<+>-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     not              */ >+<[[-]>-<]>[<+>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     poke 10          */ <<<<<<<<<<[-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #13
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*     poke 1           */ <[-]>[<+>-]< #11
+/*     pick 8           */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #12
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*     add              */ [<+>-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     not              */ >+<[[-]>-<]>[<+>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     poke 11          */ <<<<<<<<<<<[-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #13
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*     poke 1           */ <[-]>[<+>-]< #11
+/*     drop             */ [-]< #10
+/*     drop             */ [-]< #9
+/*     drop             */ [-]< #8
+/*     drop             */ [-]< #7
+/*     drop             */ [-]< #6
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #9
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #10
+/*     pick 7           */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #11
+/*     pick 7           */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #12
+/*     pick 7           */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #13
+/*     pick 7           */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #14
+/*     push 0           */ > #15
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #17
+/*     add              */ [<+>-]< #16
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #17
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*     add              */ [<+>-]< #17
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #18
+/*     not              */ >+<[[-]>-<]>[<+>-]< #18
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #18
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #18
+/*     poke 8           */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #16
+/*     poke 1           */ <[-]>[<+>-]< #15
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*     add              */ [<+>-]< #16
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #17
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*     add              */ [<+>-]< #17
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #18
+/*     not              */ >+<[[-]>-<]>[<+>-]< #18
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #18
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #18
+/*     poke 9           */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #16
+/*     poke 1           */ <[-]>[<+>-]< #15
+/*     pick 7           */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*     add              */ [<+>-]< #16
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #17
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*     add              */ [<+>-]< #17
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #18
+/*     not              */ >+<[[-]>-<]>[<+>-]< #18
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #18
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #18
+/*     poke 10          */ <<<<<<<<<<[-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #16
+/*     poke 1           */ <[-]>[<+>-]< #15
+/*     pick 8           */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #16
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*     add              */ [<+>-]< #16
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #17
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*     add              */ [<+>-]< #17
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #18
+/*     not              */ >+<[[-]>-<]>[<+>-]< #18
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #18
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #18
+/*     poke 11          */ <<<<<<<<<<<[-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #17
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #16
+/*     poke 1           */ <[-]>[<+>-]< #15
+/*     drop             */ [-]< #14
+/*     drop             */ [-]< #13
+/*     drop             */ [-]< #12
+/*     drop             */ [-]< #11
+/*     drop             */ [-]< #10
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #11
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*     push 0           */ > #15
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #17
+/*     add              */ [<+>-]< #16
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #17
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*     add              */ [<+>-]< #17
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #18
+/*     not              */ >+<[[-]>-<]>[<+>-]< #18
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #18
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #18
+/*     poke 8           */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #16
+/*     poke 1           */ <[-]>[<+>-]< #15
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*     add              */ [<+>-]< #16
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #17
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*     add              */ [<+>-]< #17
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #18
+/*     not              */ >+<[[-]>-<]>[<+>-]< #18
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #18
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #18
+/*     poke 9           */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #16
+/*     poke 1           */ <[-]>[<+>-]< #15
+/*     pick 7           */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*     add              */ [<+>-]< #16
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #17
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*     add              */ [<+>-]< #17
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #18
+/*     not              */ >+<[[-]>-<]>[<+>-]< #18
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #18
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #18
+/*     poke 10          */ <<<<<<<<<<[-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #16
+/*     poke 1           */ <[-]>[<+>-]< #15
+/*     pick 8           */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #16
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*     add              */ [<+>-]< #16
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #17
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #18
+/*     add              */ [<+>-]< #17
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #18
+/*     not              */ >+<[[-]>-<]>[<+>-]< #18
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #19
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #18
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #18
+/*     poke 11          */ <<<<<<<<<<<[-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #17
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #16
+/*     poke 1           */ <[-]>[<+>-]< #15
+/*     drop             */ [-]< #14
+/*     drop             */ [-]< #13
+/*     drop             */ [-]< #12
+/*     drop             */ [-]< #11
+/*     drop             */ [-]< #10
+/*     push 0           */ > #11
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #13
+/*     add              */ [<+>-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     not              */ >+<[[-]>-<]>[<+>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     poke 8           */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*     poke 1           */ <[-]>[<+>-]< #11
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     add              */ [<+>-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     not              */ >+<[[-]>-<]>[<+>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     poke 9           */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #13
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*     poke 1           */ <[-]>[<+>-]< #11
+/*     pick 7           */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #12
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*     add              */ [<+>-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     not              */ >+<[[-]>-<]>[<+>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     poke 10          */ <<<<<<<<<<[-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #13
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*     poke 1           */ <[-]>[<+>-]< #11
+/*     pick 8           */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #12
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*     add              */ [<+>-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     not              */ >+<[[-]>-<]>[<+>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     poke 11          */ <<<<<<<<<<<[-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #13
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*     poke 1           */ <[-]>[<+>-]< #11
+/*     drop             */ [-]< #10
+/*     drop             */ [-]< #9
+/*     drop             */ [-]< #8
+/*     drop             */ [-]< #7
+/*     drop             */ [-]< #6
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #9
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #10
+/*     push 0           */ > #11
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #13
+/*     add              */ [<+>-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     not              */ >+<[[-]>-<]>[<+>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     poke 8           */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*     poke 1           */ <[-]>[<+>-]< #11
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     add              */ [<+>-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     not              */ >+<[[-]>-<]>[<+>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     poke 9           */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #13
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*     poke 1           */ <[-]>[<+>-]< #11
+/*     pick 7           */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #12
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*     add              */ [<+>-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     not              */ >+<[[-]>-<]>[<+>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     poke 10          */ <<<<<<<<<<[-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #13
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*     poke 1           */ <[-]>[<+>-]< #11
+/*     pick 8           */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #12
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*     add              */ [<+>-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     not              */ >+<[[-]>-<]>[<+>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     poke 11          */ <<<<<<<<<<<[-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #13
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*     poke 1           */ <[-]>[<+>-]< #11
+/*     drop             */ [-]< #10
+/*     drop             */ [-]< #9
+/*     drop             */ [-]< #8
+/*     drop             */ [-]< #7
+/*     drop             */ [-]< #6
+/*     push 0           */ > #7
+/*     push 0           */ > #8
+/*     push 0           */ > #9
+/*     push 128         */ >>++++++++[<++++++++++++++++>-]< #10
+/*     push 0           */ > #11
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #13
+/*     add              */ [<+>-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     not              */ >+<[[-]>-<]>[<+>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     poke 8           */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*     poke 1           */ <[-]>[<+>-]< #11
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     add              */ [<+>-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     not              */ >+<[[-]>-<]>[<+>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     poke 9           */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #13
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*     poke 1           */ <[-]>[<+>-]< #11
+/*     pick 7           */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #12
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*     add              */ [<+>-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     not              */ >+<[[-]>-<]>[<+>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     poke 10          */ <<<<<<<<<<[-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #13
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*     poke 1           */ <[-]>[<+>-]< #11
+/*     pick 8           */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #12
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*     add              */ [<+>-]< #12
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #13
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     not              */ >+<[[-]>-<]>[<+>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     poke 11          */ <<<<<<<<<<<[-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #13
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*     poke 1           */ <[-]>[<+>-]< #11
+/*     drop             */ [-]< #10
+/*     drop             */ [-]< #9
+/*     drop             */ [-]< #8
+/*     drop             */ [-]< #7
+/*     drop             */ [-]< #6
+/*     drop             */ [-]< #5
+/*     push 0           */ > #6
+/*     push 0           */ > #7
+/*     while            */ 
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         push 152     */ >>++++++++[<------------->-]< #11
+/*         push 150     */ >>+++++++[<--------------->-]<- #12
+/*         push 128     */ >>++++++++[<++++++++++++++++>-]< #13
+/*         push 0       */ > #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     do               */     [[-]
+/*         pick 4       */ <<<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         push 152     */ >>++++++++[<------------->-]< #11
+/*         push 150     */ >>+++++++[<--------------->-]<- #12
+/*         push 128     */ >>++++++++[<++++++++++++++++>-]< #13
+/*         push 0       */ > #14
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #16
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #15
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*         sub          */ [<->-]< #16
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #17
+/*         not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #17
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         sub          */ [<->-]< #17
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #15
+/*         poke 1       */ <[-]>[<+>-]< #14
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #16
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #15
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*         sub          */ [<->-]< #16
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #17
+/*         not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #17
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         sub          */ [<->-]< #17
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #15
+/*         poke 1       */ <[-]>[<+>-]< #14
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #15
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #16
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #15
+/*         pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*         sub          */ [<->-]< #16
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #17
+/*         not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #17
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         sub          */ [<->-]< #17
+/*         poke 9       */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #15
+/*         poke 1       */ <[-]>[<+>-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         poke 5       */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #9
+/*         poke 5       */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #8
+/*         poke 5       */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #7
+/*         inc          */ + #7
+/*     again            */
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         push 152     */ >>++++++++[<------------->-]< #11
+/*         push 150     */ >>+++++++[<--------------->-]<- #12
+/*         push 128     */ >>++++++++[<++++++++++++++++>-]< #13
+/*         push 0       */ > #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     end while        */       ]< #7
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #8
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #9
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #8
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #9
+/*         addc 48      */ >++++++[<++++++++>-]< #9
+/*         write        */ .[-]< #8
+/*         push 1       */ >+ #9
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #8
+/*     end if           */       [-]]< #7
+/*     drop             */ [-]< #6
+/*     push 0           */ > #7
+/*     while            */ 
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         push 15      */ >+++++++++++++++ #11
+/*         push 66      */ >>++++++[<+++++++++++>-]< #12
+/*         push 64      */ >>++++++++[<++++++++>-]< #13
+/*         push 0       */ > #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     do               */     [[-]
+/*         pick 4       */ <<<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         push 15      */ >+++++++++++++++ #11
+/*         push 66      */ >>++++++[<+++++++++++>-]< #12
+/*         push 64      */ >>++++++++[<++++++++>-]< #13
+/*         push 0       */ > #14
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #16
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #15
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*         sub          */ [<->-]< #16
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #17
+/*         not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #17
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         sub          */ [<->-]< #17
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #15
+/*         poke 1       */ <[-]>[<+>-]< #14
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #16
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #15
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*         sub          */ [<->-]< #16
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #17
+/*         not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #17
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         sub          */ [<->-]< #17
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #15
+/*         poke 1       */ <[-]>[<+>-]< #14
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #15
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #16
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #15
+/*         pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*         sub          */ [<->-]< #16
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #17
+/*         not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #17
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         sub          */ [<->-]< #17
+/*         poke 9       */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #15
+/*         poke 1       */ <[-]>[<+>-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         poke 5       */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #9
+/*         poke 5       */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #8
+/*         poke 5       */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #7
+/*         inc          */ + #7
+/*     again            */
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         push 15      */ >+++++++++++++++ #11
+/*         push 66      */ >>++++++[<+++++++++++>-]< #12
+/*         push 64      */ >>++++++++[<++++++++>-]< #13
+/*         push 0       */ > #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     end while        */       ]< #7
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #8
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #9
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #8
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #9
+/*         addc 48      */ >++++++[<++++++++>-]< #9
+/*         write        */ .[-]< #8
+/*         push 1       */ >+ #9
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #8
+/*     end if           */       [-]]< #7
+/*     drop             */ [-]< #6
+/*     push 0           */ > #7
+/*     while            */ 
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         push 1       */ >+ #11
+/*         push 134     */ >>+++++++++++[<----------->-]<- #12
+/*         push 160     */ >>++++++++[<------------>-]< #13
+/*         push 0       */ > #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     do               */     [[-]
+/*         pick 4       */ <<<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         push 1       */ >+ #11
+/*         push 134     */ >>+++++++++++[<----------->-]<- #12
+/*         push 160     */ >>++++++++[<------------>-]< #13
+/*         push 0       */ > #14
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #16
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #15
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*         sub          */ [<->-]< #16
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #17
+/*         not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #17
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         sub          */ [<->-]< #17
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #15
+/*         poke 1       */ <[-]>[<+>-]< #14
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #16
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #15
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*         sub          */ [<->-]< #16
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #17
+/*         not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #17
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         sub          */ [<->-]< #17
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #15
+/*         poke 1       */ <[-]>[<+>-]< #14
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #15
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #16
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #15
+/*         pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*         sub          */ [<->-]< #16
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #17
+/*         not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #17
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         sub          */ [<->-]< #17
+/*         poke 9       */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #15
+/*         poke 1       */ <[-]>[<+>-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         poke 5       */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #9
+/*         poke 5       */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #8
+/*         poke 5       */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #7
+/*         inc          */ + #7
+/*     again            */
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         push 1       */ >+ #11
+/*         push 134     */ >>+++++++++++[<----------->-]<- #12
+/*         push 160     */ >>++++++++[<------------>-]< #13
+/*         push 0       */ > #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     end while        */       ]< #7
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #8
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #9
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #8
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #9
+/*         addc 48      */ >++++++[<++++++++>-]< #9
+/*         write        */ .[-]< #8
+/*         push 1       */ >+ #9
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #8
+/*     end if           */       [-]]< #7
+/*     drop             */ [-]< #6
+/*     push 0           */ > #7
+/*     while            */ 
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         push 0       */ > #11
+/*         push 39      */ >>+++++[<++++++++>-]<- #12
+/*         push 16      */ >>++++[<++++>-]< #13
+/*         push 0       */ > #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     do               */     [[-]
+/*         pick 4       */ <<<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         push 0       */ > #11
+/*         push 39      */ >>+++++[<++++++++>-]<- #12
+/*         push 16      */ >>++++[<++++>-]< #13
+/*         push 0       */ > #14
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #16
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #15
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*         sub          */ [<->-]< #16
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #17
+/*         not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #17
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         sub          */ [<->-]< #17
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #15
+/*         poke 1       */ <[-]>[<+>-]< #14
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #16
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #15
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*         sub          */ [<->-]< #16
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #17
+/*         not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #17
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         sub          */ [<->-]< #17
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #15
+/*         poke 1       */ <[-]>[<+>-]< #14
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #15
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #16
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #15
+/*         pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*         sub          */ [<->-]< #16
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #17
+/*         not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #17
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         sub          */ [<->-]< #17
+/*         poke 9       */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #15
+/*         poke 1       */ <[-]>[<+>-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         poke 5       */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #9
+/*         poke 5       */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #8
+/*         poke 5       */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #7
+/*         inc          */ + #7
+/*     again            */
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         push 0       */ > #11
+/*         push 39      */ >>+++++[<++++++++>-]<- #12
+/*         push 16      */ >>++++[<++++>-]< #13
+/*         push 0       */ > #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     end while        */       ]< #7
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #8
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #9
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #8
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #9
+/*         addc 48      */ >++++++[<++++++++>-]< #9
+/*         write        */ .[-]< #8
+/*         push 1       */ >+ #9
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #8
+/*     end if           */       [-]]< #7
+/*     drop             */ [-]< #6
+/*     push 0           */ > #7
+/*     while            */ 
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         push 0       */ > #11
+/*         push 3       */ >+++ #12
+/*         push 232     */ >>++++[<------>-]< #13
+/*         push 0       */ > #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     do               */     [[-]
+/*         pick 4       */ <<<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         push 0       */ > #11
+/*         push 3       */ >+++ #12
+/*         push 232     */ >>++++[<------>-]< #13
+/*         push 0       */ > #14
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #16
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #15
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*         sub          */ [<->-]< #16
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #17
+/*         not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #17
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         sub          */ [<->-]< #17
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #15
+/*         poke 1       */ <[-]>[<+>-]< #14
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #16
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #15
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*         sub          */ [<->-]< #16
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #17
+/*         not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #17
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         sub          */ [<->-]< #17
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #15
+/*         poke 1       */ <[-]>[<+>-]< #14
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #15
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #16
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #15
+/*         pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*         sub          */ [<->-]< #16
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #17
+/*         not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #17
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         sub          */ [<->-]< #17
+/*         poke 9       */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #15
+/*         poke 1       */ <[-]>[<+>-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         poke 5       */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #9
+/*         poke 5       */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #8
+/*         poke 5       */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #7
+/*         inc          */ + #7
+/*     again            */
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         push 0       */ > #11
+/*         push 3       */ >+++ #12
+/*         push 232     */ >>++++[<------>-]< #13
+/*         push 0       */ > #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     end while        */       ]< #7
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #8
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #9
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #8
+/*         push 1       */ >+ #9
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #8
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #9
+/*         addc 48      */ >++++++[<++++++++>-]< #9
+/*         write        */ .[-]< #8
+/*         push 1       */ >+ #9
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #8
+/*     end if           */       [-]]< #7
+/*     drop             */ [-]< #6
+/*     push 0           */ > #7
+/*     while            */ 
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         push 0       */ > #11
+/*         push 0       */ > #12
+/*         push 100     */ >>++++++++++[<++++++++++>-]< #13
+/*         push 0       */ > #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     do               */     [[-]
+/*         pick 4       */ <<<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         push 0       */ > #11
+/*         push 0       */ > #12
+/*         push 100     */ >>++++++++++[<++++++++++>-]< #13
+/*         push 0       */ > #14
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #16
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #15
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*         sub          */ [<->-]< #16
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #17
+/*         not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #17
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         sub          */ [<->-]< #17
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #15
+/*         poke 1       */ <[-]>[<+>-]< #14
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #16
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #15
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*         sub          */ [<->-]< #16
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #17
+/*         not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #17
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         sub          */ [<->-]< #17
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #15
+/*         poke 1       */ <[-]>[<+>-]< #14
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #15
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #16
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #15
+/*         pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*         sub          */ [<->-]< #16
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #17
+/*         not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #17
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         sub          */ [<->-]< #17
+/*         poke 9       */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #15
+/*         poke 1       */ <[-]>[<+>-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         poke 5       */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #9
+/*         poke 5       */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #8
+/*         poke 5       */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #7
+/*         inc          */ + #7
+/*     again            */
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         push 0       */ > #11
+/*         push 0       */ > #12
+/*         push 100     */ >>++++++++++[<++++++++++>-]< #13
+/*         push 0       */ > #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     end while        */       ]< #7
+/*     print "\x2e"     */ >>+++++[<+++++++++>-]<+.[-]< #7
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #8
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #9
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #8
+/*         push 1       */ >+ #9
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #8
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #9
+/*         addc 48      */ >++++++[<++++++++>-]< #9
+/*         write        */ .[-]< #8
+/*         push 1       */ >+ #9
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #8
+/*     end if           */       [-]]< #7
+/*     drop             */ [-]< #6
+/*     push 0           */ > #7
+/*     while            */ 
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         push 0       */ > #11
+/*         push 0       */ > #12
+/*         push 10      */ >++++++++++ #13
+/*         push 0       */ > #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     do               */     [[-]
+/*         pick 4       */ <<<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         push 0       */ > #11
+/*         push 0       */ > #12
+/*         push 10      */ >++++++++++ #13
+/*         push 0       */ > #14
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #16
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #15
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*         sub          */ [<->-]< #16
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #17
+/*         not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #17
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         sub          */ [<->-]< #17
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #15
+/*         poke 1       */ <[-]>[<+>-]< #14
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #16
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #15
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*         sub          */ [<->-]< #16
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #17
+/*         not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #17
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         sub          */ [<->-]< #17
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #15
+/*         poke 1       */ <[-]>[<+>-]< #14
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #15
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #16
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #15
+/*         pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*         sub          */ [<->-]< #16
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #17
+/*         not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #17
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         sub          */ [<->-]< #17
+/*         poke 9       */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #15
+/*         poke 1       */ <[-]>[<+>-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         poke 5       */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #9
+/*         poke 5       */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #8
+/*         poke 5       */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #7
+/*         inc          */ + #7
+/*     again            */
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         push 0       */ > #11
+/*         push 0       */ > #12
+/*         push 10      */ >++++++++++ #13
+/*         push 0       */ > #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     end while        */       ]< #7
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #8
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #9
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #8
+/*         push 1       */ >+ #9
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #8
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #9
+/*         addc 48      */ >++++++[<++++++++>-]< #9
+/*         write        */ .[-]< #8
+/*         push 1       */ >+ #9
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #8
+/*     end if           */       [-]]< #7
+/*     drop             */ [-]< #6
+/*     push 0           */ > #7
+/*     while            */ 
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         push 0       */ > #11
+/*         push 0       */ > #12
+/*         push 1       */ >+ #13
+/*         push 0       */ > #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     do               */     [[-]
+/*         pick 4       */ <<<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         push 0       */ > #11
+/*         push 0       */ > #12
+/*         push 1       */ >+ #13
+/*         push 0       */ > #14
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #16
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #15
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*         sub          */ [<->-]< #16
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #17
+/*         not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #17
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         sub          */ [<->-]< #17
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #15
+/*         poke 1       */ <[-]>[<+>-]< #14
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #16
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #15
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*         sub          */ [<->-]< #16
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #17
+/*         not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #17
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         sub          */ [<->-]< #17
+/*         poke 8       */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #15
+/*         poke 1       */ <[-]>[<+>-]< #14
+/*         pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #15
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #16
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #15
+/*         pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*         sub          */ [<->-]< #16
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #17
+/*         not          */ >+<[[-]>-<]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #17
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #17
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #18
+/*         sub          */ [<->-]< #17
+/*         poke 9       */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #15
+/*         poke 1       */ <[-]>[<+>-]< #14
+/*         drop         */ [-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         poke 5       */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #9
+/*         poke 5       */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #8
+/*         poke 5       */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #7
+/*         inc          */ + #7
+/*     again            */
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*         push 0       */ > #11
+/*         push 0       */ > #12
+/*         push 1       */ >+ #13
+/*         push 0       */ > #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 7       */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #15
+/*             not          */ >+<[[-]>-<]>[<+>-]< #15
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #17
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #16
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #18
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #17
+/*             sub          */ [<->-]< #16
+/*             poke 2       */ <<[-]>>[<<+>>-]< #15
+/*         end if       */           [-]]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         drop         */ [-]< #12
+/*         drop         */ [-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         push 255     */ >- #9
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #8
+/*     end while        */       ]< #7
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #8
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #9
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #8
+/*         push 1       */ >+ #9
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #8
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #9
+/*         addc 48      */ >++++++[<++++++++>-]< #9
+/*         write        */ .[-]< #8
+/*         push 1       */ >+ #9
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #8
+/*     end if           */       [-]]< #7
+/*     drop             */ [-]< #6
+/*     drop             */ [-]< #5
+/*     drop             */ [-]< #4
+/*     drop             */ [-]< #3
+/*     drop             */ [-]< #2
+/*     drop             */ [-]< #1
+/*     drop             */ [-]< #0
+/* end printfx 3         */ #0
+/* push 32              */ >>++++[<++++++++>-]< #1
+/* write                */ .[-]< #0
+/* push16 25792         */ 
+/*     push 100         */ >>++++++++++[<++++++++++>-]< #1
+/*     push 192         */ >>++++++++[<-------->-]< #2
+/* end push16 25792         */ #2
+/* push16 544           */ 
+/*     push 2           */ >++ #3
+/*     push 32          */ >>++++[<++++++++>-]< #4
+/* end push16 544         */ #4
+/* sub16                */ 
+/*     push 0           */ > #5
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #7
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #6
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #7
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*     sub              */ [<->-]< #7
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #8
+/*     not              */ >+<[[-]>-<]>[<+>-]< #8
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #9
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #8
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #8
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #9
+/*     sub              */ [<->-]< #8
+/*     poke 6           */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #7
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #6
+/*     poke 1           */ <[-]>[<+>-]< #5
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #6
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #6
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #7
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*     sub              */ [<->-]< #7
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #8
+/*     not              */ >+<[[-]>-<]>[<+>-]< #8
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #9
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #8
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #8
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #9
+/*     sub              */ [<->-]< #8
+/*     poke 7           */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #7
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #6
+/*     poke 1           */ <[-]>[<+>-]< #5
+/*     drop             */ [-]< #4
+/*     drop             */ [-]< #3
+/*     drop             */ [-]< #2
+/* end sub16            */ #2
+/* push16 128           */ 
+/*     push 0           */ > #3
+/*     push 128         */ >>++++++++[<++++++++++++++++>-]< #4
+/* end push16 128         */ #4
+/* add16                */ 
+/*     push 0           */ > #5
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #7
+/*     add              */ [<+>-]< #6
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #7
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #7
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #7
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*     add              */ [<+>-]< #7
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #8
+/*     not              */ >+<[[-]>-<]>[<+>-]< #8
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #9
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #8
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #8
+/*     poke 6           */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #7
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #6
+/*     poke 1           */ <[-]>[<+>-]< #5
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #6
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*     add              */ [<+>-]< #6
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #7
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #7
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #7
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*     add              */ [<+>-]< #7
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #8
+/*     not              */ >+<[[-]>-<]>[<+>-]< #8
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #9
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #8
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #8
+/*     poke 7           */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #7
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #6
+/*     poke 1           */ <[-]>[<+>-]< #5
+/*     drop             */ [-]< #4
+/*     drop             */ [-]< #3
+/*     drop             */ [-]< #2
+/* end add16            */ #2
+/* printfx 2            */ 
+/*     push 0           */ > #3
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #4
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #5
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #6
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #7
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*     push 0           */ > #12
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #15
+/*     add              */ [<+>-]< #14
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #15
+/*     not              */ >+<[[-]>-<]>[<+>-]< #15
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #16
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #15
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #15
+/*     poke 7           */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #13
+/*     poke 1           */ <[-]>[<+>-]< #12
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #15
+/*     add              */ [<+>-]< #14
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #15
+/*     not              */ >+<[[-]>-<]>[<+>-]< #15
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #16
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #15
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #15
+/*     poke 8           */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #13
+/*     poke 1           */ <[-]>[<+>-]< #12
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #15
+/*     add              */ [<+>-]< #14
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #15
+/*     not              */ >+<[[-]>-<]>[<+>-]< #15
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #16
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #15
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #15
+/*     poke 9           */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #14
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #13
+/*     poke 1           */ <[-]>[<+>-]< #12
+/*     drop             */ [-]< #11
+/*     drop             */ [-]< #10
+/*     drop             */ [-]< #9
+/*     drop             */ [-]< #8
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #9
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #10
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #11
+/*     push 0           */ > #12
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #15
+/*     add              */ [<+>-]< #14
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #15
+/*     not              */ >+<[[-]>-<]>[<+>-]< #15
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #16
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #15
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #15
+/*     poke 7           */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #13
+/*     poke 1           */ <[-]>[<+>-]< #12
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #15
+/*     add              */ [<+>-]< #14
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #15
+/*     not              */ >+<[[-]>-<]>[<+>-]< #15
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #16
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #15
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #15
+/*     poke 8           */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #13
+/*     poke 1           */ <[-]>[<+>-]< #12
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #15
+/*     add              */ [<+>-]< #14
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #15
+/*     not              */ >+<[[-]>-<]>[<+>-]< #15
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #16
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #15
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #15
+/*     poke 9           */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #14
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #13
+/*     poke 1           */ <[-]>[<+>-]< #12
+/*     drop             */ [-]< #11
+/*     drop             */ [-]< #10
+/*     drop             */ [-]< #9
+/*     drop             */ [-]< #8
+/*     push 0           */ > #9
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #11
+/*     add              */ [<+>-]< #10
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #11
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #11
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*     add              */ [<+>-]< #11
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #12
+/*     not              */ >+<[[-]>-<]>[<+>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #12
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #12
+/*     poke 7           */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #10
+/*     poke 1           */ <[-]>[<+>-]< #9
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #11
+/*     add              */ [<+>-]< #10
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #11
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #11
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*     add              */ [<+>-]< #11
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #12
+/*     not              */ >+<[[-]>-<]>[<+>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #12
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #12
+/*     poke 8           */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #11
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #10
+/*     poke 1           */ <[-]>[<+>-]< #9
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #10
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #11
+/*     add              */ [<+>-]< #10
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #11
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #11
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*     add              */ [<+>-]< #11
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #12
+/*     not              */ >+<[[-]>-<]>[<+>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #12
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #12
+/*     poke 9           */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #11
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #10
+/*     poke 1           */ <[-]>[<+>-]< #9
+/*     drop             */ [-]< #8
+/*     drop             */ [-]< #7
+/*     drop             */ [-]< #6
+/*     drop             */ [-]< #5
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #6
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #7
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*     push 0           */ > #9
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #11
+/*     add              */ [<+>-]< #10
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #11
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #11
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*     add              */ [<+>-]< #11
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #12
+/*     not              */ >+<[[-]>-<]>[<+>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #12
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #12
+/*     poke 7           */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #10
+/*     poke 1           */ <[-]>[<+>-]< #9
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #11
+/*     add              */ [<+>-]< #10
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #11
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #11
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*     add              */ [<+>-]< #11
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #12
+/*     not              */ >+<[[-]>-<]>[<+>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #12
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #12
+/*     poke 8           */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #11
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #10
+/*     poke 1           */ <[-]>[<+>-]< #9
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #10
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #11
+/*     add              */ [<+>-]< #10
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #11
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #11
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*     add              */ [<+>-]< #11
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #12
+/*     not              */ >+<[[-]>-<]>[<+>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #12
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #12
+/*     poke 9           */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #11
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #10
+/*     poke 1           */ <[-]>[<+>-]< #9
+/*     drop             */ [-]< #8
+/*     drop             */ [-]< #7
+/*     drop             */ [-]< #6
+/*     drop             */ [-]< #5
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #6
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #7
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*     push 0           */ > #12
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #15
+/*     add              */ [<+>-]< #14
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #15
+/*     not              */ >+<[[-]>-<]>[<+>-]< #15
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #16
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #15
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #15
+/*     poke 7           */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #13
+/*     poke 1           */ <[-]>[<+>-]< #12
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #15
+/*     add              */ [<+>-]< #14
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #15
+/*     not              */ >+<[[-]>-<]>[<+>-]< #15
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #16
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #15
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #15
+/*     poke 8           */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #13
+/*     poke 1           */ <[-]>[<+>-]< #12
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #15
+/*     add              */ [<+>-]< #14
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #15
+/*     not              */ >+<[[-]>-<]>[<+>-]< #15
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #16
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #15
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #15
+/*     poke 9           */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #14
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #13
+/*     poke 1           */ <[-]>[<+>-]< #12
+/*     drop             */ [-]< #11
+/*     drop             */ [-]< #10
+/*     drop             */ [-]< #9
+/*     drop             */ [-]< #8
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #9
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #10
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #11
+/*     push 0           */ > #12
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #15
+/*     add              */ [<+>-]< #14
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #15
+/*     not              */ >+<[[-]>-<]>[<+>-]< #15
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #16
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #15
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #15
+/*     poke 7           */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #13
+/*     poke 1           */ <[-]>[<+>-]< #12
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #15
+/*     add              */ [<+>-]< #14
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #15
+/*     not              */ >+<[[-]>-<]>[<+>-]< #15
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #16
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #15
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #15
+/*     poke 8           */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #13
+/*     poke 1           */ <[-]>[<+>-]< #12
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #15
+/*     add              */ [<+>-]< #14
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #15
+/*     not              */ >+<[[-]>-<]>[<+>-]< #15
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #16
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #15
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #15
+/*     poke 9           */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #14
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #13
+/*     poke 1           */ <[-]>[<+>-]< #12
+/*     drop             */ [-]< #11
+/*     drop             */ [-]< #10
+/*     drop             */ [-]< #9
+/*     drop             */ [-]< #8
+/*     push 0           */ > #9
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #11
+/*     add              */ [<+>-]< #10
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #11
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #11
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*     add              */ [<+>-]< #11
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #12
+/*     not              */ >+<[[-]>-<]>[<+>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #12
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #12
+/*     poke 7           */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #10
+/*     poke 1           */ <[-]>[<+>-]< #9
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #11
+/*     add              */ [<+>-]< #10
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #11
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #11
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*     add              */ [<+>-]< #11
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #12
+/*     not              */ >+<[[-]>-<]>[<+>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #12
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #12
+/*     poke 8           */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #11
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #10
+/*     poke 1           */ <[-]>[<+>-]< #9
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #10
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #11
+/*     add              */ [<+>-]< #10
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #11
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #11
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*     add              */ [<+>-]< #11
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #12
+/*     not              */ >+<[[-]>-<]>[<+>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #12
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #12
+/*     poke 9           */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #11
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #10
+/*     poke 1           */ <[-]>[<+>-]< #9
+/*     drop             */ [-]< #8
+/*     drop             */ [-]< #7
+/*     drop             */ [-]< #6
+/*     drop             */ [-]< #5
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #6
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #7
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*     push 0           */ > #9
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #11
+/*     add              */ [<+>-]< #10
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #11
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #11
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*     add              */ [<+>-]< #11
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #12
+/*     not              */ >+<[[-]>-<]>[<+>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #12
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #12
+/*     poke 7           */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #10
+/*     poke 1           */ <[-]>[<+>-]< #9
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #11
+/*     add              */ [<+>-]< #10
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #11
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #11
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*     add              */ [<+>-]< #11
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #12
+/*     not              */ >+<[[-]>-<]>[<+>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #12
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #12
+/*     poke 8           */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #11
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #10
+/*     poke 1           */ <[-]>[<+>-]< #9
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #10
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #11
+/*     add              */ [<+>-]< #10
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #11
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #11
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*     add              */ [<+>-]< #11
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #12
+/*     not              */ >+<[[-]>-<]>[<+>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #12
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #12
+/*     poke 9           */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #11
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #10
+/*     poke 1           */ <[-]>[<+>-]< #9
+/*     drop             */ [-]< #8
+/*     drop             */ [-]< #7
+/*     drop             */ [-]< #6
+/*     drop             */ [-]< #5
+/*     push 0           */ > #6
+/*     push 0           */ > #7
+/*     push 128         */ >>++++++++[<++++++++++++++++>-]< #8
+/*     push 0           */ > #9
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #11
+/*     add              */ [<+>-]< #10
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #11
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #11
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*     add              */ [<+>-]< #11
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #12
+/*     not              */ >+<[[-]>-<]>[<+>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #12
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #12
+/*     poke 7           */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #10
+/*     poke 1           */ <[-]>[<+>-]< #9
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #11
+/*     add              */ [<+>-]< #10
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #11
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #11
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*     add              */ [<+>-]< #11
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #12
+/*     not              */ >+<[[-]>-<]>[<+>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #12
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #12
+/*     poke 8           */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #11
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #10
+/*     poke 1           */ <[-]>[<+>-]< #9
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #10
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #11
+/*     add              */ [<+>-]< #10
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #11
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #11
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*     add              */ [<+>-]< #11
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #12
+/*     not              */ >+<[[-]>-<]>[<+>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #12
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #12
+/*     poke 9           */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #11
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #10
+/*     poke 1           */ <[-]>[<+>-]< #9
+/*     drop             */ [-]< #8
+/*     drop             */ [-]< #7
+/*     drop             */ [-]< #6
+/*     drop             */ [-]< #5
+/*     drop             */ [-]< #4
+/*     push 0           */ > #5
+/*     push 0           */ > #6
+/*     while            */ 
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 39      */ >>+++++[<++++++++>-]<- #9
+/*         push 16      */ >>++++[<++++>-]< #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     do               */     [[-]
+/*         pick 3       */ <<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 39      */ >>+++++[<++++++++>-]<- #9
+/*         push 16      */ >>++++[<++++>-]< #10
+/*         push 0       */ > #11
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #7
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #6
+/*         inc          */ + #6
+/*     again            */
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 39      */ >>+++++[<++++++++>-]<- #9
+/*         push 16      */ >>++++[<++++>-]< #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     end while        */       ]< #6
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #7
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #7
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #8
+/*         addc 48      */ >++++++[<++++++++>-]< #8
+/*         write        */ .[-]< #7
+/*         push 1       */ >+ #8
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #7
+/*     end if           */       [-]]< #6
+/*     drop             */ [-]< #5
+/*     push 0           */ > #6
+/*     while            */ 
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 3       */ >+++ #9
+/*         push 232     */ >>++++[<------>-]< #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     do               */     [[-]
+/*         pick 3       */ <<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 3       */ >+++ #9
+/*         push 232     */ >>++++[<------>-]< #10
+/*         push 0       */ > #11
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #7
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #6
+/*         inc          */ + #6
+/*     again            */
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 3       */ >+++ #9
+/*         push 232     */ >>++++[<------>-]< #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     end while        */       ]< #6
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #7
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #7
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #8
+/*         addc 48      */ >++++++[<++++++++>-]< #8
+/*         write        */ .[-]< #7
+/*         push 1       */ >+ #8
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #7
+/*     end if           */       [-]]< #6
+/*     drop             */ [-]< #5
+/*     push 0           */ > #6
+/*     while            */ 
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 0       */ > #9
+/*         push 100     */ >>++++++++++[<++++++++++>-]< #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     do               */     [[-]
+/*         pick 3       */ <<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 0       */ > #9
+/*         push 100     */ >>++++++++++[<++++++++++>-]< #10
+/*         push 0       */ > #11
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #7
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #6
+/*         inc          */ + #6
+/*     again            */
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 0       */ > #9
+/*         push 100     */ >>++++++++++[<++++++++++>-]< #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     end while        */       ]< #6
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #7
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #7
+/*         push 1       */ >+ #8
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #7
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #8
+/*         addc 48      */ >++++++[<++++++++>-]< #8
+/*         write        */ .[-]< #7
+/*         push 1       */ >+ #8
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #7
+/*     end if           */       [-]]< #6
+/*     drop             */ [-]< #5
+/*     push 0           */ > #6
+/*     while            */ 
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 0       */ > #9
+/*         push 10      */ >++++++++++ #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     do               */     [[-]
+/*         pick 3       */ <<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 0       */ > #9
+/*         push 10      */ >++++++++++ #10
+/*         push 0       */ > #11
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #7
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #6
+/*         inc          */ + #6
+/*     again            */
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 0       */ > #9
+/*         push 10      */ >++++++++++ #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     end while        */       ]< #6
+/*     print "\x2e"     */ >>+++++[<+++++++++>-]<+.[-]< #6
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #7
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #7
+/*         push 1       */ >+ #8
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #7
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #8
+/*         addc 48      */ >++++++[<++++++++>-]< #8
+/*         write        */ .[-]< #7
+/*         push 1       */ >+ #8
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #7
+/*     end if           */       [-]]< #6
+/*     drop             */ [-]< #5
+/*     push 0           */ > #6
+/*     while            */ 
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 0       */ > #9
+/*         push 1       */ >+ #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     do               */     [[-]
+/*         pick 3       */ <<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 0       */ > #9
+/*         push 1       */ >+ #10
+/*         push 0       */ > #11
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #7
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #6
+/*         inc          */ + #6
+/*     again            */
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 0       */ > #9
+/*         push 1       */ >+ #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     end while        */       ]< #6
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #7
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #7
+/*         push 1       */ >+ #8
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #7
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #8
+/*         addc 48      */ >++++++[<++++++++>-]< #8
+/*         write        */ .[-]< #7
+/*         push 1       */ >+ #8
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #7
+/*     end if           */       [-]]< #6
+/*     drop             */ [-]< #5
+/*     drop             */ [-]< #4
+/*     drop             */ [-]< #3
+/*     drop             */ [-]< #2
+/*     drop             */ [-]< #1
+/*     drop             */ [-]< #0
+/* end printfx 2         */ #0
+/* push 32              */ >>++++[<++++++++>-]< #1
+/* write                */ .[-]< #0
+/* push16 179           */ 
+/*     push 0           */ > #1
+/*     push 179         */ >>+++++++[<----------->-]< #2
+/* end push16 179         */ #2
+/* printfx 1            */ 
+/*     push 0           */ > #3
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #4
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #5
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #6
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #7
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*     push 0           */ > #12
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #15
+/*     add              */ [<+>-]< #14
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #15
+/*     not              */ >+<[[-]>-<]>[<+>-]< #15
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #16
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #15
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #15
+/*     poke 7           */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #13
+/*     poke 1           */ <[-]>[<+>-]< #12
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #15
+/*     add              */ [<+>-]< #14
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #15
+/*     not              */ >+<[[-]>-<]>[<+>-]< #15
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #16
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #15
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #15
+/*     poke 8           */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #13
+/*     poke 1           */ <[-]>[<+>-]< #12
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #15
+/*     add              */ [<+>-]< #14
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #15
+/*     not              */ >+<[[-]>-<]>[<+>-]< #15
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #16
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #15
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #15
+/*     poke 9           */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #14
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #13
+/*     poke 1           */ <[-]>[<+>-]< #12
+/*     drop             */ [-]< #11
+/*     drop             */ [-]< #10
+/*     drop             */ [-]< #9
+/*     drop             */ [-]< #8
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #9
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #10
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #11
+/*     push 0           */ > #12
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #15
+/*     add              */ [<+>-]< #14
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #15
+/*     not              */ >+<[[-]>-<]>[<+>-]< #15
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #16
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #15
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #15
+/*     poke 7           */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #13
+/*     poke 1           */ <[-]>[<+>-]< #12
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #15
+/*     add              */ [<+>-]< #14
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #15
+/*     not              */ >+<[[-]>-<]>[<+>-]< #15
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #16
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #15
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #15
+/*     poke 8           */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #13
+/*     poke 1           */ <[-]>[<+>-]< #12
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*     add              */ [<+>-]< #13
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #14
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #15
+/*     add              */ [<+>-]< #14
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #15
+/*     not              */ >+<[[-]>-<]>[<+>-]< #15
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #16
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #15
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #15
+/*     poke 9           */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #14
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #13
+/*     poke 1           */ <[-]>[<+>-]< #12
+/*     drop             */ [-]< #11
+/*     drop             */ [-]< #10
+/*     drop             */ [-]< #9
+/*     drop             */ [-]< #8
+/*     push 0           */ > #9
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #11
+/*     add              */ [<+>-]< #10
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #11
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #11
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*     add              */ [<+>-]< #11
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #12
+/*     not              */ >+<[[-]>-<]>[<+>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #12
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #12
+/*     poke 7           */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #10
+/*     poke 1           */ <[-]>[<+>-]< #9
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #11
+/*     add              */ [<+>-]< #10
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #11
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #11
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*     add              */ [<+>-]< #11
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #12
+/*     not              */ >+<[[-]>-<]>[<+>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #12
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #12
+/*     poke 8           */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #11
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #10
+/*     poke 1           */ <[-]>[<+>-]< #9
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #10
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #11
+/*     add              */ [<+>-]< #10
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #11
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #11
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*     add              */ [<+>-]< #11
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #12
+/*     not              */ >+<[[-]>-<]>[<+>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #12
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #12
+/*     poke 9           */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #11
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #10
+/*     poke 1           */ <[-]>[<+>-]< #9
+/*     drop             */ [-]< #8
+/*     drop             */ [-]< #7
+/*     drop             */ [-]< #6
+/*     drop             */ [-]< #5
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #6
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #7
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*     push 0           */ > #9
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #11
+/*     add              */ [<+>-]< #10
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #11
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #11
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*     add              */ [<+>-]< #11
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #12
+/*     not              */ >+<[[-]>-<]>[<+>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #12
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #12
+/*     poke 7           */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #10
+/*     poke 1           */ <[-]>[<+>-]< #9
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #11
+/*     add              */ [<+>-]< #10
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #11
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #11
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*     add              */ [<+>-]< #11
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #12
+/*     not              */ >+<[[-]>-<]>[<+>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #12
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #12
+/*     poke 8           */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #11
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #10
+/*     poke 1           */ <[-]>[<+>-]< #9
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #10
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #11
+/*     add              */ [<+>-]< #10
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #11
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #11
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*     add              */ [<+>-]< #11
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #12
+/*     not              */ >+<[[-]>-<]>[<+>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #12
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #12
+/*     poke 9           */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #11
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #10
+/*     poke 1           */ <[-]>[<+>-]< #9
+/*     drop             */ [-]< #8
+/*     drop             */ [-]< #7
+/*     drop             */ [-]< #6
+/*     drop             */ [-]< #5
+/*     push 0           */ > #6
+/*     push 0           */ > #7
+/*     push 128         */ >>++++++++[<++++++++++++++++>-]< #8
+/*     push 0           */ > #9
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #11
+/*     add              */ [<+>-]< #10
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #11
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #11
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*     add              */ [<+>-]< #11
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #12
+/*     not              */ >+<[[-]>-<]>[<+>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #12
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #12
+/*     poke 7           */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #10
+/*     poke 1           */ <[-]>[<+>-]< #9
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #11
+/*     add              */ [<+>-]< #10
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #11
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #11
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*     add              */ [<+>-]< #11
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #12
+/*     not              */ >+<[[-]>-<]>[<+>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #12
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #12
+/*     poke 8           */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #11
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #10
+/*     poke 1           */ <[-]>[<+>-]< #9
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #10
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #11
+/*     add              */ [<+>-]< #10
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #11
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #11
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*     add              */ [<+>-]< #11
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #12
+/*     not              */ >+<[[-]>-<]>[<+>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #12
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #12
+/*     poke 9           */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #11
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #10
+/*     poke 1           */ <[-]>[<+>-]< #9
+/*     drop             */ [-]< #8
+/*     drop             */ [-]< #7
+/*     drop             */ [-]< #6
+/*     drop             */ [-]< #5
+/*     drop             */ [-]< #4
+/*     push 0           */ > #5
+/*     push 0           */ > #6
+/*     while            */ 
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 39      */ >>+++++[<++++++++>-]<- #9
+/*         push 16      */ >>++++[<++++>-]< #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     do               */     [[-]
+/*         pick 3       */ <<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 39      */ >>+++++[<++++++++>-]<- #9
+/*         push 16      */ >>++++[<++++>-]< #10
+/*         push 0       */ > #11
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #7
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #6
+/*         inc          */ + #6
+/*     again            */
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 39      */ >>+++++[<++++++++>-]<- #9
+/*         push 16      */ >>++++[<++++>-]< #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     end while        */       ]< #6
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #7
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #7
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #8
+/*         addc 48      */ >++++++[<++++++++>-]< #8
+/*         write        */ .[-]< #7
+/*         push 1       */ >+ #8
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #7
+/*     end if           */       [-]]< #6
+/*     drop             */ [-]< #5
+/*     push 0           */ > #6
+/*     while            */ 
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 3       */ >+++ #9
+/*         push 232     */ >>++++[<------>-]< #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     do               */     [[-]
+/*         pick 3       */ <<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 3       */ >+++ #9
+/*         push 232     */ >>++++[<------>-]< #10
+/*         push 0       */ > #11
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #7
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #6
+/*         inc          */ + #6
+/*     again            */
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 3       */ >+++ #9
+/*         push 232     */ >>++++[<------>-]< #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     end while        */       ]< #6
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #7
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #7
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #8
+/*         addc 48      */ >++++++[<++++++++>-]< #8
+/*         write        */ .[-]< #7
+/*         push 1       */ >+ #8
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #7
+/*     end if           */       [-]]< #6
+/*     drop             */ [-]< #5
+/*     push 0           */ > #6
+/*     while            */ 
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 0       */ > #9
+/*         push 100     */ >>++++++++++[<++++++++++>-]< #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     do               */     [[-]
+/*         pick 3       */ <<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 0       */ > #9
+/*         push 100     */ >>++++++++++[<++++++++++>-]< #10
+/*         push 0       */ > #11
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #7
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #6
+/*         inc          */ + #6
+/*     again            */
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 0       */ > #9
+/*         push 100     */ >>++++++++++[<++++++++++>-]< #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     end while        */       ]< #6
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #7
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #7
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #8
+/*         addc 48      */ >++++++[<++++++++>-]< #8
+/*         write        */ .[-]< #7
+/*         push 1       */ >+ #8
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #7
+/*     end if           */       [-]]< #6
+/*     drop             */ [-]< #5
+/*     push 0           */ > #6
+/*     while            */ 
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 0       */ > #9
+/*         push 10      */ >++++++++++ #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     do               */     [[-]
+/*         pick 3       */ <<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 0       */ > #9
+/*         push 10      */ >++++++++++ #10
+/*         push 0       */ > #11
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #7
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #6
+/*         inc          */ + #6
+/*     again            */
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 0       */ > #9
+/*         push 10      */ >++++++++++ #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     end while        */       ]< #6
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #7
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #7
+/*         push 1       */ >+ #8
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #7
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #8
+/*         addc 48      */ >++++++[<++++++++>-]< #8
+/*         write        */ .[-]< #7
+/*         push 1       */ >+ #8
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #7
+/*     end if           */       [-]]< #6
+/*     drop             */ [-]< #5
+/*     push 0           */ > #6
+/*     while            */ 
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 0       */ > #9
+/*         push 1       */ >+ #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     do               */     [[-]
+/*         pick 3       */ <<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 0       */ > #9
+/*         push 1       */ >+ #10
+/*         push 0       */ > #11
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #7
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #6
+/*         inc          */ + #6
+/*     again            */
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 0       */ > #9
+/*         push 1       */ >+ #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     end while        */       ]< #6
+/*     print "\x2e"     */ >>+++++[<+++++++++>-]<+.[-]< #6
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #7
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #7
+/*         push 1       */ >+ #8
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #7
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #8
+/*         addc 48      */ >++++++[<++++++++>-]< #8
+/*         write        */ .[-]< #7
+/*         push 1       */ >+ #8
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #7
+/*     end if           */       [-]]< #6
+/*     drop             */ [-]< #5
+/*     drop             */ [-]< #4
+/*     drop             */ [-]< #3
+/*     drop             */ [-]< #2
+/*     drop             */ [-]< #1
+/*     drop             */ [-]< #0
+/* end printfx 1         */ #0
+/* push 32              */ >>++++[<++++++++>-]< #1
+/* write                */ .[-]< #0
+/* push16 512           */ 
+/*     push 2           */ >++ #1
+/*     push 0           */ > #2
+/* end push16 512         */ #2
+/* push16 269           */ 
+/*     push 1           */ >+ #3
+/*     push 13          */ >+++++++++++++ #4
+/* end push16 269         */ #4
+/* mulfx                */ 
+/*     push 0           */ > #5
+/*     push 0           */ > #6
+/*     push 0           */ > #7
+/*     push 0           */ > #8
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*     while            */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #10
+/*     do               */     [[-]
+/*         pick 1       */ <<[>>+>+<<<-]>>>[<<<+>>>-]< #10
+/*         pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #11
+/*         add          */ [<+>-]< #10
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #11
+/*         pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #12
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         add          */ [<+>-]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         poke 2       */ <<[-]>>[<<+>>-]< #9
+/*         dec          */ - #9
+/*     again            */
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #10
+/*     end while        */       ]< #9
+/*     drop             */ [-]< #8
+/*     drop             */ [-]< #7
+/*     poke 1           */ <[-]>[<+>-]< #6
+/*     push 0           */ > #7
+/*     push 0           */ > #8
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*     while            */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #10
+/*     do               */     [[-]
+/*         pick 1       */ <<[>>+>+<<<-]>>>[<<<+>>>-]< #10
+/*         pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #11
+/*         add          */ [<+>-]< #10
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #11
+/*         pick 10      */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #12
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         add          */ [<+>-]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         poke 2       */ <<[-]>>[<<+>>-]< #9
+/*         dec          */ - #9
+/*     again            */
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #10
+/*     end while        */       ]< #9
+/*     drop             */ [-]< #8
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #9
+/*     add              */ [<+>-]< #8
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #9
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #10
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #9
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #9
+/*     poke 3           */ <<<[-]>>>[<<<+>>>-]< #8
+/*     add              */ [<+>-]< #7
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*     add              */ [<+>-]< #7
+/*     poke 2           */ <<[-]>>[<<+>>-]< #6
+/*     push 0           */ > #7
+/*     push 0           */ > #8
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #9
+/*     while            */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #10
+/*     do               */     [[-]
+/*         pick 1       */ <<[>>+>+<<<-]>>>[<<<+>>>-]< #10
+/*         pick 8       */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #11
+/*         add          */ [<+>-]< #10
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #11
+/*         pick 9       */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #12
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         add          */ [<+>-]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         poke 2       */ <<[-]>>[<<+>>-]< #9
+/*         dec          */ - #9
+/*     again            */
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #10
+/*     end while        */       ]< #9
+/*     drop             */ [-]< #8
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #9
+/*     add              */ [<+>-]< #8
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #9
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #10
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #9
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #9
+/*     poke 3           */ <<<[-]>>>[<<<+>>>-]< #8
+/*     add              */ [<+>-]< #7
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*     add              */ [<+>-]< #7
+/*     poke 2           */ <<[-]>>[<<+>>-]< #6
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #7
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*     mul              */ <[>>+<<-]>[>[<<+>>>+<-]>[<+>-]<<-]>[-]<< #7
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*     add              */ [<+>-]< #7
+/*     poke 2           */ <<[-]>>[<<+>>-]< #6
+/*     pick 1           */ <[>>+>+<<<-]>>>[<<<+>>>-]< #7
+/*     poke 6           */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #6
+/*     pick 0           */ [>+>+<<-]>>[<<+>>-]< #7
+/*     poke 5           */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #6
+/*     drop             */ [-]< #5
+/*     drop             */ [-]< #4
+/*     drop             */ [-]< #3
+/*     drop             */ [-]< #2
+/* end mulfx            */ #2
+/* printfx 0            */ 
+/*     push 0           */ > #3
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #4
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #5
+/*     push 0           */ > #6
+/*     push 0           */ > #7
+/*     push 128         */ >>++++++++[<++++++++++++++++>-]< #8
+/*     push 0           */ > #9
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #10
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #11
+/*     add              */ [<+>-]< #10
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #11
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #11
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*     add              */ [<+>-]< #11
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #12
+/*     not              */ >+<[[-]>-<]>[<+>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #12
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #12
+/*     poke 7           */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #10
+/*     poke 1           */ <[-]>[<+>-]< #9
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #10
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #11
+/*     add              */ [<+>-]< #10
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #11
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #11
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*     add              */ [<+>-]< #11
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #12
+/*     not              */ >+<[[-]>-<]>[<+>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #12
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #12
+/*     poke 8           */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #11
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #10
+/*     poke 1           */ <[-]>[<+>-]< #9
+/*     pick 6           */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #10
+/*     pick 4           */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #11
+/*     add              */ [<+>-]< #10
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #11
+/*     pick 5           */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #11
+/*     pick 2           */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*     add              */ [<+>-]< #11
+/*     copy             */ [>+>+<<-]>>[<<+>>-]< #12
+/*     not              */ >+<[[-]>-<]>[<+>-]< #12
+/*     pick 3           */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #12
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #12
+/*     poke 9           */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #11
+/*     or               */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #10
+/*     poke 1           */ <[-]>[<+>-]< #9
+/*     drop             */ [-]< #8
+/*     drop             */ [-]< #7
+/*     drop             */ [-]< #6
+/*     drop             */ [-]< #5
+/*     drop             */ [-]< #4
+/*     push 0           */ > #5
+/*     push 0           */ > #6
+/*     while            */ 
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 39      */ >>+++++[<++++++++>-]<- #9
+/*         push 16      */ >>++++[<++++>-]< #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     do               */     [[-]
+/*         pick 3       */ <<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 39      */ >>+++++[<++++++++>-]<- #9
+/*         push 16      */ >>++++[<++++>-]< #10
+/*         push 0       */ > #11
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #7
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #6
+/*         inc          */ + #6
+/*     again            */
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 39      */ >>+++++[<++++++++>-]<- #9
+/*         push 16      */ >>++++[<++++>-]< #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     end while        */       ]< #6
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #7
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #7
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #8
+/*         addc 48      */ >++++++[<++++++++>-]< #8
+/*         write        */ .[-]< #7
+/*         push 1       */ >+ #8
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #7
+/*     end if           */       [-]]< #6
+/*     drop             */ [-]< #5
+/*     push 0           */ > #6
+/*     while            */ 
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 3       */ >+++ #9
+/*         push 232     */ >>++++[<------>-]< #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     do               */     [[-]
+/*         pick 3       */ <<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 3       */ >+++ #9
+/*         push 232     */ >>++++[<------>-]< #10
+/*         push 0       */ > #11
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #7
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #6
+/*         inc          */ + #6
+/*     again            */
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 3       */ >+++ #9
+/*         push 232     */ >>++++[<------>-]< #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     end while        */       ]< #6
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #7
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #7
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #8
+/*         addc 48      */ >++++++[<++++++++>-]< #8
+/*         write        */ .[-]< #7
+/*         push 1       */ >+ #8
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #7
+/*     end if           */       [-]]< #6
+/*     drop             */ [-]< #5
+/*     push 0           */ > #6
+/*     while            */ 
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 0       */ > #9
+/*         push 100     */ >>++++++++++[<++++++++++>-]< #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     do               */     [[-]
+/*         pick 3       */ <<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 0       */ > #9
+/*         push 100     */ >>++++++++++[<++++++++++>-]< #10
+/*         push 0       */ > #11
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #7
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #6
+/*         inc          */ + #6
+/*     again            */
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 0       */ > #9
+/*         push 100     */ >>++++++++++[<++++++++++>-]< #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     end while        */       ]< #6
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #7
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #7
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #8
+/*         addc 48      */ >++++++[<++++++++>-]< #8
+/*         write        */ .[-]< #7
+/*         push 1       */ >+ #8
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #7
+/*     end if           */       [-]]< #6
+/*     drop             */ [-]< #5
+/*     push 0           */ > #6
+/*     while            */ 
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 0       */ > #9
+/*         push 10      */ >++++++++++ #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     do               */     [[-]
+/*         pick 3       */ <<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 0       */ > #9
+/*         push 10      */ >++++++++++ #10
+/*         push 0       */ > #11
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #7
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #6
+/*         inc          */ + #6
+/*     again            */
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 0       */ > #9
+/*         push 10      */ >++++++++++ #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     end while        */       ]< #6
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #7
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #7
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #8
+/*         addc 48      */ >++++++[<++++++++>-]< #8
+/*         write        */ .[-]< #7
+/*         push 1       */ >+ #8
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #7
+/*     end if           */       [-]]< #6
+/*     drop             */ [-]< #5
+/*     push 0           */ > #6
+/*     while            */ 
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 0       */ > #9
+/*         push 1       */ >+ #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     do               */     [[-]
+/*         pick 3       */ <<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 0       */ > #9
+/*         push 1       */ >+ #10
+/*         push 0       */ > #11
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #12
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #14
+/*         not          */ >+<[[-]>-<]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #14
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #14
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #15
+/*         sub          */ [<->-]< #14
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #12
+/*         poke 1       */ <[-]>[<+>-]< #11
+/*         drop         */ [-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #7
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #6
+/*         inc          */ + #6
+/*     again            */
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         push 0       */ > #9
+/*         push 1       */ >+ #10
+/*         push 0       */ > #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #14
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #12
+/*             not          */ >+<[[-]>-<]>[<+>-]< #12
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #15
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #14
+/*             sub          */ [<->-]< #13
+/*             poke 2       */ <<[-]>>[<<+>>-]< #12
+/*         end if       */           [-]]< #11
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         push 255     */ >- #8
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #7
+/*     end while        */       ]< #6
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #7
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #7
+/*         push 1       */ >+ #8
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #7
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #8
+/*         addc 48      */ >++++++[<++++++++>-]< #8
+/*         write        */ .[-]< #7
+/*         push 1       */ >+ #8
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #7
+/*     end if           */       [-]]< #6
+/*     drop             */ [-]< #5
+/*     drop             */ [-]< #4
+/*     drop             */ [-]< #3
+/*     drop             */ [-]< #2
+/*     drop             */ [-]< #1
+/*     drop             */ [-]< #0
+/* end printfx 0         */ #0
+/* push 10              */ >++++++++++ #1
+/* write                */ .[-]< #0
+
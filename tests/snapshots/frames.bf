@@ -0,0 +1,96 @@
+/* target: 8 bit cells with wraparound */
+/* let g                */ > #1
+
+/* dispatch             */ >>+[
+/*     block 1          */ [>+>+<<-]>>[<<+>>-]<->+<[[-]>-<]>[<+>-]<[<<+>>-]<<[[-]>[-]<
+/*         push 5       */ +++++ #2
+/*         set g        */ <<[-]>>[<<+>>-]< #1
+/*         push 7       */ >+++++++ #2
+/*     call square      */       >++>>+++<]
+/*     block 2          */ >[>+>+<<-]>>[<<+>>-]<-->+<[[-]>-<]>[<+>-]<[<<+>>-]<<[[-]>[-]
+/*         printnum     */ ++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #1
+/*         push 10      */ >++++++++++ #2
+/*         write        */ .[-]< #1
+/*         push 3       */ >+++ #2
+/*         push 4       */ >++++ #3
+/*     call sumsq       */       >++++>>+++++<]
+/*     block 4          */ >[>+>+<<-]>>[<<+>>-]<---->+<[[-]>-<]>[<+>-]<[<<+>>-]<<[[-]>[-]
+/*         printnum     */ ++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #1
+/*         push 10      */ >++++++++++ #2
+/*         write        */ .[-]< #1
+/*     call hello       */       >++++++>>+++++++<]
+/*     block 6          */ >[>+>+<<-]>>[<<+>>-]<------>+<[[-]>-<]>[<+>-]<[<<+>>-]<<[[-]>[-]<
+/*     call hello       */       ++++++++>>+++++++<]
+/*     block 8          */ >[>+>+<<-]>>[<<+>>-]<-------->+<[[-]>-<]>[<+>-]<[<<+>>-]<<[[-]>[-]<
+/*         get g        */ <<[>>+>+<<<-]>>>[<<<+>>>-]< #2
+/*         call twice   */ 
+/*             get x        */ [>+>+<<-]>>[<<+>>-]< #3
+/*             push 2       */ >++ #4
+/*             mul          */ <[>>+<<-]>[>[<<+>>>+<-]>[<+>-]<<-]>[-]<< #3
+/*         return       */           <[-]>[-<+>]< #2
+/*         printnum     */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #1
+/*         push 10      */ >++++++++++ #2
+/*         write        */ .[-]< #1
+/*         push 100     */ >>++++++++++[<++++++++++>-]< #2
+/*         push 7       */ >+++++++ #3
+/*     call divmod      */       >+++++++++>>++++++++++<]
+/*     block 9          */ >[>+>+<<-]>>[<<+>>-]<--------->+<[[-]>-<]>[<+>-]<[<<+>>-]<<[[-]>[-]
+/*         printnum     */ ++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #2
+/*         push 32      */ >>++++[<++++++++>-]< #3
+/*         write        */ .[-]< #2
+/*         printnum     */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #1
+/*         push 10      */ >++++++++++ #2
+/*         write        */ .[-]< #1
+/*         push 1       */ >+ #2
+/*         push 2       */ >++ #3
+/*         push 3       */ >+++ #4
+/*     call square      */       >+++++++++++>>+++<]
+/*     block 11         */ >[>+>+<<-]>>[<<+>>-]<----------->+<[[-]>-<]>[<+>-]<[<<+>>-]<<[[-]>[-]
+/*         printnum     */ ++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #3
+/*         printnum     */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #2
+/*         printnum     */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #1
+/*         push 10      */ >++++++++++ #2
+/*         write        */ .[-]< #1
+/*         get g        */ <[>>+>+<<<-]>>>[<<<+>>>-]< #2
+/*         printnum     */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #1
+/*         push 10      */ >++++++++++ #2
+/*         write        */ .[-]< #1
+/*     halt             */       >]
+/*     block 10         */ >[>+>+<<-]>>[<<+>>-]<---------->+<[[-]>-<]>[<+>-]<[<<+>>-]<<[[-]>[-]<
+/*         push 0       */  #3
+/*         while        */ 
+/*             get n        */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #4
+/*             get d        */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #5
+/*             ge           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]+<[[-]>-<]>[<+>-]< #4
+/*         do           */         [[-]
+/*             get n        */ <<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #4
+/*             get d        */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #5
+/*             sub          */ [<->-]< #4
+/*             set n        */ <<<<[-]>>>>[<<<<+>>>>-]< #3
+/*             inc          */ + #3
+/*         again        */
+/*             get n        */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #4
+/*             get d        */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #5
+/*             ge           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]+<[[-]>-<]>[<+>-]< #4
+/*         end while    */           ]< #3
+/*         get n        */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #4
+/*     return           */       <<<<[-]>[-]>[->>>>+<<<<]>[-<<<+>>>]>[-<<<+>>>]>>[-<<<+>>>]<<<<]
+/*     block 7          */ >[>+>+<<-]>>[<<+>>-]<------->+<[[-]>-<]>[<+>-]<[<<+>>-]<<[[-]>[-]
+/*         print "hi "  */ ++++++++[<+++++++++++++>-]<.+.[-]>++++[<++++++++>-]<.[-]< #0
+/*     return           */       [->>+<<]>>[-<+>]<<]
+/*     block 5          */ >[>+>+<<-]>>[<<+>>-]<----->+<[[-]>-<]>[<+>-]<[<<+>>-]<<[[-]>[-]<
+/*         get a        */ <<<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #3
+/*     call square      */       >++++++++++++>>+++<]
+/*     block 12         */ >[>+>+<<-]>>[<<+>>-]<------------>+<[[-]>-<]>[<+>-]<[<<+>>-]<<[[-]>[-]<
+/*         get b        */ <<<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #4
+/*     call square      */       >+++++++++++++>>+++<]
+/*     block 13         */ >[>+>+<<-]>>[<<+>>-]<------------->+<[[-]>-<]>[<+>-]<[<<+>>-]<<[[-]>[-]<<
+/*         add          */ [<+>-]< #3
+/*     return           */       <<<[-]>[-]>[->>>+<<<]>[-<<<+>>>]>>[-<<<+>>>]<<<<]
+/*     block 3          */ >[>+>+<<-]>>[<<+>>-]<--->+<[[-]>-<]>[<+>-]<[<<+>>-]<<[[-]>[-]<
+/*         get x        */ <<[>>+>+<<<-]>>>[<<<+>>>-]< #2
+/*         get x        */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #3
+/*         mul          */ <[>>+<<-]>[>[<<+>>>+<-]>[<+>-]<<-]>[-]<< #2
+/*     return           */       <<[-]>[->>>+<<<]>[-<<+>>]>>[-<<+>>]<<<]
+/* end dispatch         */   >]<< #1
+
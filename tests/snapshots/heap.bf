@@ -0,0 +1,355 @@
+/* target: 8 bit cells with wraparound */
+/* let heap\x5b12\x5d         */ >>>>>>>>>>>> #12
+/* let p                */ > #13
+
+/* alloc a 2            */  #13
+/* alloc b 2            */  #13
+/* alloc c 2            */  #13
+/* push 10              */ >++++++++++ #14
+/* set a\x5b0\x5d         */ <<<<<<<<<<<<<<[-]>>>>>>>>>>>>>>[<<<<<<<<<<<<<<+>>>>>>>>>>>>>>-]< #13
+/* push 2               */ >++ #14
+/* set a\x5b1\x5d         */ <<<<<<<<<<<<<[-]>>>>>>>>>>>>>[<<<<<<<<<<<<<+>>>>>>>>>>>>>-]< #13
+/* push 20              */ >>++++[<+++++>-]< #14
+/* set b\x5b0\x5d         */ <<<<<<<<<<<<[-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #13
+/* push 4               */ >++++ #14
+/* set b\x5b1\x5d         */ <<<<<<<<<<<[-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #13
+/* push 30              */ >>+++++[<++++++>-]< #14
+/* set c\x5b0\x5d         */ <<<<<<<<<<[-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #13
+/* push 255             */ >- #14
+/* set c\x5b1\x5d         */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #13
+/* push 0               */ > #14
+/* set p                */ <<[-]>>[<<+>>-]< #13
+/* while                */ 
+/*     get p            */ <[>>+>+<<<-]>>>[<<<+>>>-]< #14
+/*     push 255         */ >- #15
+/*     neq              */ [<->-]<[[-]>+<]>[<+>-]< #14
+/* do                   */ [[-]
+/*     get p            */ <<[>>+>+<<<-]>>>[<<<+>>>-]< #14
+/*     lookup heap      */ 
+/*         push 0       */ > #15
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #15
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*             not          */ >+<[[-]>-<]>[<+>-]< #16
+/*         then         */         [
+/*             get heap\x5b0\x5d */ <<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>-]< #17
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #16
+/*         end if       */           [-]]< #15
+/*         dec          */ - #15
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*             not          */ >+<[[-]>-<]>[<+>-]< #16
+/*         then         */         [
+/*             get heap\x5b1\x5d */ <<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>-]< #17
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #16
+/*         end if       */           [-]]< #15
+/*         dec          */ - #15
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*             not          */ >+<[[-]>-<]>[<+>-]< #16
+/*         then         */         [
+/*             get heap\x5b2\x5d */ <<<<<<<<<<<<<<[>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>-]< #17
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #16
+/*         end if       */           [-]]< #15
+/*         dec          */ - #15
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*             not          */ >+<[[-]>-<]>[<+>-]< #16
+/*         then         */         [
+/*             get heap\x5b3\x5d */ <<<<<<<<<<<<<[>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>-]< #17
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #16
+/*         end if       */           [-]]< #15
+/*         dec          */ - #15
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*             not          */ >+<[[-]>-<]>[<+>-]< #16
+/*         then         */         [
+/*             get heap\x5b4\x5d */ <<<<<<<<<<<<[>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>[<<<<<<<<<<<<<<+>>>>>>>>>>>>>>-]< #17
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #16
+/*         end if       */           [-]]< #15
+/*         dec          */ - #15
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*             not          */ >+<[[-]>-<]>[<+>-]< #16
+/*         then         */         [
+/*             get heap\x5b5\x5d */ <<<<<<<<<<<[>>>>>>>>>>>>+>+<<<<<<<<<<<<<-]>>>>>>>>>>>>>[<<<<<<<<<<<<<+>>>>>>>>>>>>>-]< #17
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #16
+/*         end if       */           [-]]< #15
+/*         dec          */ - #15
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*             not          */ >+<[[-]>-<]>[<+>-]< #16
+/*         then         */         [
+/*             get heap\x5b6\x5d */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #17
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #16
+/*         end if       */           [-]]< #15
+/*         dec          */ - #15
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*             not          */ >+<[[-]>-<]>[<+>-]< #16
+/*         then         */         [
+/*             get heap\x5b7\x5d */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #17
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #16
+/*         end if       */           [-]]< #15
+/*         dec          */ - #15
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*             not          */ >+<[[-]>-<]>[<+>-]< #16
+/*         then         */         [
+/*             get heap\x5b8\x5d */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #16
+/*         end if       */           [-]]< #15
+/*         dec          */ - #15
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*             not          */ >+<[[-]>-<]>[<+>-]< #16
+/*         then         */         [
+/*             get heap\x5b9\x5d */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #16
+/*         end if       */           [-]]< #15
+/*         dec          */ - #15
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*             not          */ >+<[[-]>-<]>[<+>-]< #16
+/*         then         */         [
+/*             get heap\x5b10\x5d */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #16
+/*         end if       */           [-]]< #15
+/*         dec          */ - #15
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*             not          */ >+<[[-]>-<]>[<+>-]< #16
+/*         then         */         [
+/*             get heap\x5b11\x5d */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #16
+/*         end if       */           [-]]< #15
+/*         dec          */ - #15
+/*         drop         */ [-]< #14
+/*     end lookup heap     */ #14
+/*     printnum         */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #13
+/*     print " "        */ >>++++[<++++++++>-]<.[-]< #13
+/*     get p            */ <[>>+>+<<<-]>>>[<<<+>>>-]< #14
+/*     inc              */ + #14
+/*     lookup heap      */ 
+/*         push 0       */ > #15
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #15
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*             not          */ >+<[[-]>-<]>[<+>-]< #16
+/*         then         */         [
+/*             get heap\x5b0\x5d */ <<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>-]< #17
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #16
+/*         end if       */           [-]]< #15
+/*         dec          */ - #15
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*             not          */ >+<[[-]>-<]>[<+>-]< #16
+/*         then         */         [
+/*             get heap\x5b1\x5d */ <<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>-]< #17
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #16
+/*         end if       */           [-]]< #15
+/*         dec          */ - #15
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*             not          */ >+<[[-]>-<]>[<+>-]< #16
+/*         then         */         [
+/*             get heap\x5b2\x5d */ <<<<<<<<<<<<<<[>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>-]< #17
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #16
+/*         end if       */           [-]]< #15
+/*         dec          */ - #15
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*             not          */ >+<[[-]>-<]>[<+>-]< #16
+/*         then         */         [
+/*             get heap\x5b3\x5d */ <<<<<<<<<<<<<[>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>-]< #17
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #16
+/*         end if       */           [-]]< #15
+/*         dec          */ - #15
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*             not          */ >+<[[-]>-<]>[<+>-]< #16
+/*         then         */         [
+/*             get heap\x5b4\x5d */ <<<<<<<<<<<<[>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>[<<<<<<<<<<<<<<+>>>>>>>>>>>>>>-]< #17
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #16
+/*         end if       */           [-]]< #15
+/*         dec          */ - #15
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*             not          */ >+<[[-]>-<]>[<+>-]< #16
+/*         then         */         [
+/*             get heap\x5b5\x5d */ <<<<<<<<<<<[>>>>>>>>>>>>+>+<<<<<<<<<<<<<-]>>>>>>>>>>>>>[<<<<<<<<<<<<<+>>>>>>>>>>>>>-]< #17
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #16
+/*         end if       */           [-]]< #15
+/*         dec          */ - #15
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*             not          */ >+<[[-]>-<]>[<+>-]< #16
+/*         then         */         [
+/*             get heap\x5b6\x5d */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #17
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #16
+/*         end if       */           [-]]< #15
+/*         dec          */ - #15
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*             not          */ >+<[[-]>-<]>[<+>-]< #16
+/*         then         */         [
+/*             get heap\x5b7\x5d */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #17
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #16
+/*         end if       */           [-]]< #15
+/*         dec          */ - #15
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*             not          */ >+<[[-]>-<]>[<+>-]< #16
+/*         then         */         [
+/*             get heap\x5b8\x5d */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #17
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #16
+/*         end if       */           [-]]< #15
+/*         dec          */ - #15
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*             not          */ >+<[[-]>-<]>[<+>-]< #16
+/*         then         */         [
+/*             get heap\x5b9\x5d */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #17
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #16
+/*         end if       */           [-]]< #15
+/*         dec          */ - #15
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*             not          */ >+<[[-]>-<]>[<+>-]< #16
+/*         then         */         [
+/*             get heap\x5b10\x5d */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #17
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #16
+/*         end if       */           [-]]< #15
+/*         dec          */ - #15
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*             not          */ >+<[[-]>-<]>[<+>-]< #16
+/*         then         */         [
+/*             get heap\x5b11\x5d */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #17
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #16
+/*         end if       */           [-]]< #15
+/*         dec          */ - #15
+/*         drop         */ [-]< #14
+/*     end lookup heap     */ #14
+/*     set p            */ <<[-]>>[<<+>>-]< #13
+/* again                */
+/*     get p            */ <[>>+>+<<<-]>>>[<<<+>>>-]< #14
+/*     push 255         */ >- #15
+/*     neq              */ [<->-]<[[-]>+<]>[<+>-]< #14
+/* end while            */   ]< #13
+/* push 2               */ >++ #14
+/* push 99              */ >>+++++++++[<+++++++++++>-]< #15
+/* store heap           */ 
+/*     swap             */ <[>>+<<-]>[<+>-]>[<+>-]< #15
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*         not          */ >+<[[-]>-<]>[<+>-]< #16
+/*     then             */     [
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #17
+/*         set heap\x5b0\x5d */ <<<<<<<<<<<<<<<<<[-]>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>-]< #16
+/*     end if           */       [-]]< #15
+/*     dec              */ - #15
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*         not          */ >+<[[-]>-<]>[<+>-]< #16
+/*     then             */     [
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #17
+/*         set heap\x5b1\x5d */ <<<<<<<<<<<<<<<<[-]>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>-]< #16
+/*     end if           */       [-]]< #15
+/*     dec              */ - #15
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*         not          */ >+<[[-]>-<]>[<+>-]< #16
+/*     then             */     [
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #17
+/*         set heap\x5b2\x5d */ <<<<<<<<<<<<<<<[-]>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>-]< #16
+/*     end if           */       [-]]< #15
+/*     dec              */ - #15
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*         not          */ >+<[[-]>-<]>[<+>-]< #16
+/*     then             */     [
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #17
+/*         set heap\x5b3\x5d */ <<<<<<<<<<<<<<[-]>>>>>>>>>>>>>>[<<<<<<<<<<<<<<+>>>>>>>>>>>>>>-]< #16
+/*     end if           */       [-]]< #15
+/*     dec              */ - #15
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*         not          */ >+<[[-]>-<]>[<+>-]< #16
+/*     then             */     [
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #17
+/*         set heap\x5b4\x5d */ <<<<<<<<<<<<<[-]>>>>>>>>>>>>>[<<<<<<<<<<<<<+>>>>>>>>>>>>>-]< #16
+/*     end if           */       [-]]< #15
+/*     dec              */ - #15
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*         not          */ >+<[[-]>-<]>[<+>-]< #16
+/*     then             */     [
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #17
+/*         set heap\x5b5\x5d */ <<<<<<<<<<<<[-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #16
+/*     end if           */       [-]]< #15
+/*     dec              */ - #15
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*         not          */ >+<[[-]>-<]>[<+>-]< #16
+/*     then             */     [
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #17
+/*         set heap\x5b6\x5d */ <<<<<<<<<<<[-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #16
+/*     end if           */       [-]]< #15
+/*     dec              */ - #15
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*         not          */ >+<[[-]>-<]>[<+>-]< #16
+/*     then             */     [
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #17
+/*         set heap\x5b7\x5d */ <<<<<<<<<<[-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #16
+/*     end if           */       [-]]< #15
+/*     dec              */ - #15
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*         not          */ >+<[[-]>-<]>[<+>-]< #16
+/*     then             */     [
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #17
+/*         set heap\x5b8\x5d */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #16
+/*     end if           */       [-]]< #15
+/*     dec              */ - #15
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*         not          */ >+<[[-]>-<]>[<+>-]< #16
+/*     then             */     [
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #17
+/*         set heap\x5b9\x5d */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #16
+/*     end if           */       [-]]< #15
+/*     dec              */ - #15
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*         not          */ >+<[[-]>-<]>[<+>-]< #16
+/*     then             */     [
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #17
+/*         set heap\x5b10\x5d */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #16
+/*     end if           */       [-]]< #15
+/*     dec              */ - #15
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #16
+/*         not          */ >+<[[-]>-<]>[<+>-]< #16
+/*     then             */     [
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #17
+/*         set heap\x5b11\x5d */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #16
+/*     end if           */       [-]]< #15
+/*     dec              */ - #15
+/*     drop             */ [-]< #14
+/*     drop             */ [-]< #13
+/* end store heap         */ #13
+/* get b\x5b0\x5d         */ <<<<<<<<<<<[>>>>>>>>>>>>+>+<<<<<<<<<<<<<-]>>>>>>>>>>>>>[<<<<<<<<<<<<<+>>>>>>>>>>>>>-]< #14
+/* printnum             */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #13
+/* print " "            */ >>++++[<++++++++>-]<.[-]< #13
+/* stat                 */ 
+/*     alloc d 3        */ <<<<<<<<<<<[-]>[-]>[-]>>>>>>>>> #13
+/*     push 2           */ >++ #14
+/*     printnum         */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #13
+/*     get d\x5b0\x5d     */ <<<<<<<<<<<[>>>>>>>>>>>>+>+<<<<<<<<<<<<<-]>>>>>>>>>>>>>[<<<<<<<<<<<<<+>>>>>>>>>>>>>-]< #14
+/*     printnum         */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #13
+/* end stat             */ #13
+/* push 10              */ >++++++++++ #14
+/* write                */ .[-]< #13
+
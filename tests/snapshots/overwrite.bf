@@ -0,0 +1,19 @@
+/* target: 8 bit cells with wraparound */
+/* let x                */ > #1
+/* let y                */ > #2
+
+/* push 7               */ >+++++++ #3
+/* set x                */ <<<[-]>>>[<<<+>>>-]< #2
+/* push 0               */ > #3
+/* printnum             */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #2
+/* get x                */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #3
+/* printnum             */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #2
+/* push 0               */ > #3
+/* printnum             */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #2
+/* get x                */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #3
+/* push 4               */ >++++ #4
+/* add                  */ [<+>-]< #3
+/* printnum             */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #2
+/* push 10              */ >++++++++++ #3
+/* write                */ .[-]< #2
+
@@ -0,0 +1,43 @@
+/* target: 8 bit cells with wraparound */
+
+/* dispatch             */ >>+[
+/*     block 1          */ [>+>+<<-]>>[<<+>>-]<->+<[[-]>-<]>[<+>-]<[<<+>>-]<<[[-]>[-]<
+/*         push 7       */ +++++++ #1
+/*     call square      */       >++>>+++<]
+/*     block 2          */ >[>+>+<<-]>>[<<+>>-]<-->+<[[-]>-<]>[<+>-]<[<<+>>-]<<[[-]>[-]
+/*         printnum     */ ++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #0
+/*         push 32      */ >>++++[<++++++++>-]< #1
+/*         write        */ .[-]< #0
+/*         push 3       */ >+++ #1
+/*         push 4       */ >++++ #2
+/*     call sumsq       */       >++++>>+++++<]
+/*     block 4          */ >[>+>+<<-]>>[<<+>>-]<---->+<[[-]>-<]>[<+>-]<[<<+>>-]<<[[-]>[-]
+/*         printnum     */ ++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #0
+/*         push 32      */ >>++++[<++++++++>-]< #1
+/*         write        */ .[-]< #0
+/*         push 5       */ >+++++ #1
+/*         call twice   */ 
+/*             get x        */ [>+>+<<-]>>[<<+>>-]< #2
+/*             push 2       */ >++ #3
+/*             mul          */ <[>>+<<-]>[>[<<+>>>+<-]>[<+>-]<<-]>[-]<< #2
+/*         return       */           <[-]>[-<+>]< #1
+/*         printnum     */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #0
+/*         push 10      */ >++++++++++ #1
+/*         write        */ .[-]< #0
+/*     halt             */       >]
+/*     block 5          */ >[>+>+<<-]>>[<<+>>-]<----->+<[[-]>-<]>[<+>-]<[<<+>>-]<<[[-]>[-]<
+/*         get a        */ <<<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #3
+/*     call square      */       >++++++>>+++<]
+/*     block 6          */ >[>+>+<<-]>>[<<+>>-]<------>+<[[-]>-<]>[<+>-]<[<<+>>-]<<[[-]>[-]<
+/*         get b        */ <<<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #4
+/*     call square      */       >+++++++>>+++<]
+/*     block 7          */ >[>+>+<<-]>>[<<+>>-]<------->+<[[-]>-<]>[<+>-]<[<<+>>-]<<[[-]>[-]<<
+/*         add          */ [<+>-]< #3
+/*     return           */       <<<[-]>[-]>[->>>+<<<]>[-<<<+>>>]>>[-<<<+>>>]<<<<]
+/*     block 3          */ >[>+>+<<-]>>[<<+>>-]<--->+<[[-]>-<]>[<+>-]<[<<+>>-]<<[[-]>[-]<
+/*         get x        */ <<[>>+>+<<<-]>>>[<<<+>>>-]< #2
+/*         get x        */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #3
+/*         mul          */ <[>>+<<-]>[>[<<+>>>+<-]>[<+>-]<<-]>[-]<< #2
+/*     return           */       <<[-]>[->>>+<<<]>[-<<+>>]>>[-<<+>>]<<<]
+/* end dispatch         */   >]<< #0
+
@@ -0,0 +1,107 @@
+/* target: 8 bit cells with wraparound */
+/* let line\x5b4\x5d         */ >>>> #4
+
+/* readline line         */ 
+/*     push 0           */ > #5
+/*     push 1           */ >+ #6
+/*     if               */ 
+/*         get readline:running */ [>+>+<<-]>>[<<+>>-]< #7
+/*     then             */     [
+/*         read         */ >, #8
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #9
+/*         push 10      */ >++++++++++ #10
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #9
+/*         over         */ <[>>+>+<<<-]>>>[<<<+>>>-]< #10
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #9
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #10
+/*         set readline:running */ <<<<[-]>>>>[<<<<+>>>>-]< #9
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #10
+/*         get readline:count */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         add          */ [<+>-]< #10
+/*         set readline:count */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #9
+/*         mul          */ <[>>+<<-]>[>[<<+>>>+<-]>[<+>-]<<-]>[-]<< #8
+/*         set line\x5b0\x5d */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #7
+/*     end if           */       [-]]< #6
+/*     if               */ 
+/*         get readline:running */ [>+>+<<-]>>[<<+>>-]< #7
+/*     then             */     [
+/*         read         */ >, #8
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #9
+/*         push 10      */ >++++++++++ #10
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #9
+/*         over         */ <[>>+>+<<<-]>>>[<<<+>>>-]< #10
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #9
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #10
+/*         set readline:running */ <<<<[-]>>>>[<<<<+>>>>-]< #9
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #10
+/*         get readline:count */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         add          */ [<+>-]< #10
+/*         set readline:count */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #9
+/*         mul          */ <[>>+<<-]>[>[<<+>>>+<-]>[<+>-]<<-]>[-]<< #8
+/*         set line\x5b1\x5d */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #7
+/*     end if           */       [-]]< #6
+/*     if               */ 
+/*         get readline:running */ [>+>+<<-]>>[<<+>>-]< #7
+/*     then             */     [
+/*         read         */ >, #8
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #9
+/*         push 10      */ >++++++++++ #10
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #9
+/*         over         */ <[>>+>+<<<-]>>>[<<<+>>>-]< #10
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #9
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #10
+/*         set readline:running */ <<<<[-]>>>>[<<<<+>>>>-]< #9
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #10
+/*         get readline:count */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         add          */ [<+>-]< #10
+/*         set readline:count */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #9
+/*         mul          */ <[>>+<<-]>[>[<<+>>>+<-]>[<+>-]<<-]>[-]<< #8
+/*         set line\x5b2\x5d */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #7
+/*     end if           */       [-]]< #6
+/*     if               */ 
+/*         get readline:running */ [>+>+<<-]>>[<<+>>-]< #7
+/*     then             */     [
+/*         read         */ >, #8
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #9
+/*         push 10      */ >++++++++++ #10
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #9
+/*         over         */ <[>>+>+<<<-]>>>[<<<+>>>-]< #10
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #9
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #10
+/*         set readline:running */ <<<<[-]>>>>[<<<<+>>>>-]< #9
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #10
+/*         get readline:count */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*         add          */ [<+>-]< #10
+/*         set readline:count */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #9
+/*         mul          */ <[>>+<<-]>[>[<<+>>>+<-]>[<+>-]<<-]>[-]<< #8
+/*         set line\x5b3\x5d */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #7
+/*     end if           */       [-]]< #6
+/*     drop             */ [-]< #5
+/* end readline         */ #5
+/* drop                 */ [-]< #4
+/* if                   */ 
+/*     get line\x5b3\x5d     */ <[>>+>+<<<-]>>>[<<<+>>>-]< #5
+/* then                 */ [
+/*     get line\x5b3\x5d     */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #6
+/*     write            */ .[-]< #5
+/* end if               */   [-]]< #4
+/* if                   */ 
+/*     get line\x5b2\x5d     */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #5
+/* then                 */ [
+/*     get line\x5b2\x5d     */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*     write            */ .[-]< #5
+/* end if               */   [-]]< #4
+/* if                   */ 
+/*     get line\x5b1\x5d     */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #5
+/* then                 */ [
+/*     get line\x5b1\x5d     */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #6
+/*     write            */ .[-]< #5
+/* end if               */   [-]]< #4
+/* if                   */ 
+/*     get line\x5b0\x5d     */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #5
+/* then                 */ [
+/*     get line\x5b0\x5d     */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #6
+/*     write            */ .[-]< #5
+/* end if               */   [-]]< #4
+/* print "\n"           */ >++++++++++.[-]< #4
+
@@ -0,0 +1,79 @@
+/* target: 8 bit cells with wraparound */
+/* let a                */ > #1
+/* let buf\x5b2\x5d         */ >> #3
+
+/* dispatch             */ >>+[
+/*     block 1          */ [>+>+<<-]>>[<<+>>-]<->+<[[-]>-<]>[<+>-]<[<<+>>-]<<[[-]>[-]<
+/*         push 1       */ + #4
+/*         set a        */ <<<<[-]>>>>[<<<<+>>>>-]< #3
+/*         push 120     */ >>++++++++++[<++++++++++++>-]< #4
+/*         set buf\x5b0\x5d */ <<<[-]>>>[<<<+>>>-]< #3
+/*         scope        */ 
+/*             let a        */ > #4
+/*             let t        */ > #5
+/*             push 2       */ >++ #6
+/*             set a        */ <<[-]>>[<<+>>-]< #5
+/*             get a        */ <[>>+>+<<<-]>>>[<<<+>>>-]< #6
+/*             printnum     */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #5
+/*             scope        */ 
+/*                 let a\x5b2\x5d */ >> #7
+/*                 push 9       */ >+++++++++ #8
+/*                 set a\x5b1\x5d */ <[-]>[<+>-]< #7
+/*                 get a\x5b1\x5d */ [>+>+<<-]>>[<<+>>-]< #8
+/*                 printnum     */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #7
+/*                 get buf\x5b0\x5d */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #8
+/*                 write        */ .[-]< #7
+/*                 free         */ [-]<[-]< #5
+/*             end scope    */ #5
+/*             get a        */ <[>>+>+<<<-]>>>[<<<+>>>-]< #6
+/*             printnum     */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #5
+/*             free         */ [-]<[-]< #3
+/*         end scope    */ #3
+/*         get a        */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #4
+/*         printnum     */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #3
+/*         push 10      */ >++++++++++ #4
+/*         write        */ .[-]< #3
+/*         push 0       */ > #4
+/*         while        */ >+[>>>+>+<<<<-]>>>>[<<<<+>>>>-]<[[-]
+/*             push 1       */ >+ #9
+/*         test         */           <<[-]>>[<<+>>-]<]<[[-]<+
+/*             scope        */ 
+/*                 let i        */ > #7
+/*                 push 5       */ >+++++ #8
+/*                 set i        */ <[-]>[<+>-]< #7
+/*                 get i        */ [>+>+<<-]>>[<<+>>-]< #8
+/*                 printnum     */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #7
+/*                 break        */ <[-]<[-]>> #7
+/*                 if running   */ <[>>+>+<<<-]>>>[<<<+>>>-]<[
+/*                     push 7       */ >+++++++ #9
+/*                     printnum     */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #8
+/*                 end if       */                   [-]]< #7
+/*                 free         */ [-]< #6
+/*             end scope    */ #6
+/*         again        */           [-]<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]<[[-]
+/*             push 1       */ >+ #9
+/*         test         */           <<[-]>>[<<+>>-]<]<]
+/*         end while    */           <<[-]< #4
+/*         drop         */ [-]< #3
+/*         push 4       */ >++++ #4
+/*     call f           */       >++>>+++<]
+/*     block 2          */ >[>+>+<<-]>>[<<+>>-]<-->+<[[-]>-<]>[<+>-]<[<<+>>-]<<[[-]>[-]
+/*         printnum     */ ++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #3
+/*         push 10      */ >++++++++++ #4
+/*         write        */ .[-]< #3
+/*     halt             */       >]
+/*     block 3          */ >[>+>+<<-]>>[<<+>>-]<--->+<[[-]>-<]>[<+>-]<[<<+>>-]<<[[-]>[-]<
+/*         scope        */ 
+/*             let y        */  #2
+/*             get x        */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #3
+/*             push 3       */ >+++ #4
+/*             mul          */ <[>>+<<-]>[>[<<+>>>+<-]>[<+>-]<<-]>[-]<< #3
+/*             set y        */ <[-]>[<+>-]< #2
+/*             get y        */ [>+>+<<-]>>[<<+>>-]< #3
+/*             set x        */ <<<[-]>>>[<<<+>>>-]< #2
+/*             free         */ [-]< #1
+/*         end scope    */ #1
+/*         get x        */ <[>>+>+<<<-]>>>[<<<+>>>-]< #2
+/*     return           */       <<[-]>[->>>+<<<]>[-<<+>>]>>[-<<+>>]<<<]
+/* end dispatch         */   >]<< #3
+
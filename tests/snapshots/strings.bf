@@ -0,0 +1,596 @@
+/* target: 8 bit cells with wraparound */
+/* let msg\x5b6\x5d         */ >++++++++[<+++++++++>-]>++++++++++[<++++++++++>-]<+>>+++++++++[<++++++++++++>-]>+++++++++[<++++++++++++>-]>++++++++++[<+++++++++++>-]<+>> #6
+/* let c\x5b4\x5d         */ +++>>++++++++[<++++++++++++>-]<+>>+++++++[<++++++++++++++>-]>+++++++++[<+++++++++++>-] #10
+/* let line\x5b8\x5d         */ >>>>>>>> #18
+
+/* print msg            */ 
+/*     push 1           */ >+ #19
+/*     get msg\x5b0\x5d     */ <<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>-]< #20
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #20
+/*     then             */     [
+/*         get msg\x5b0\x5d */ <<<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>-]< #21
+/*         write        */ .[-]< #20
+/*     end if           */       [-]]< #19
+/*     get msg\x5b1\x5d     */ <<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>-]< #20
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #20
+/*     then             */     [
+/*         get msg\x5b1\x5d */ <<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>-]< #21
+/*         write        */ .[-]< #20
+/*     end if           */       [-]]< #19
+/*     get msg\x5b2\x5d     */ <<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>-]< #20
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #20
+/*     then             */     [
+/*         get msg\x5b2\x5d */ <<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>-]< #21
+/*         write        */ .[-]< #20
+/*     end if           */       [-]]< #19
+/*     get msg\x5b3\x5d     */ <<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>-]< #20
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #20
+/*     then             */     [
+/*         get msg\x5b3\x5d */ <<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>-]< #21
+/*         write        */ .[-]< #20
+/*     end if           */       [-]]< #19
+/*     get msg\x5b4\x5d     */ <<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>-]< #20
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #20
+/*     then             */     [
+/*         get msg\x5b4\x5d */ <<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>-]< #21
+/*         write        */ .[-]< #20
+/*     end if           */       [-]]< #19
+/*     get msg\x5b5\x5d     */ <<<<<<<<<<<<<<[>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>-]< #20
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #20
+/*     then             */     [
+/*         get msg\x5b5\x5d */ <<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>-]< #21
+/*         write        */ .[-]< #20
+/*     end if           */       [-]]< #19
+/*     drop             */ [-]< #18
+/* end print msg         */ #18
+/* push 10              */ >++++++++++ #19
+/* write                */ .[-]< #18
+/* print c              */ 
+/*     if               */ 
+/*         get c\x5b0\x5d */ <<<<<<<<<<<<[>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>[<<<<<<<<<<<<<<+>>>>>>>>>>>>>>-]< #19
+/*         push 1       */ >+ #20
+/*         ge           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]+<[[-]>-<]>[<+>-]< #19
+/*     then             */     [
+/*         get c\x5b1\x5d */ <<<<<<<<<<<<[>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>[<<<<<<<<<<<<<<+>>>>>>>>>>>>>>-]< #20
+/*         write        */ .[-]< #19
+/*     end if           */       [-]]< #18
+/*     if               */ 
+/*         get c\x5b0\x5d */ <<<<<<<<<<<<[>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>[<<<<<<<<<<<<<<+>>>>>>>>>>>>>>-]< #19
+/*         push 2       */ >++ #20
+/*         ge           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]+<[[-]>-<]>[<+>-]< #19
+/*     then             */     [
+/*         get c\x5b2\x5d */ <<<<<<<<<<<[>>>>>>>>>>>>+>+<<<<<<<<<<<<<-]>>>>>>>>>>>>>[<<<<<<<<<<<<<+>>>>>>>>>>>>>-]< #20
+/*         write        */ .[-]< #19
+/*     end if           */       [-]]< #18
+/*     if               */ 
+/*         get c\x5b0\x5d */ <<<<<<<<<<<<[>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>[<<<<<<<<<<<<<<+>>>>>>>>>>>>>>-]< #19
+/*         push 3       */ >+++ #20
+/*         ge           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]+<[[-]>-<]>[<+>-]< #19
+/*     then             */     [
+/*         get c\x5b3\x5d */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #20
+/*         write        */ .[-]< #19
+/*     end if           */       [-]]< #18
+/* end print c          */ #18
+/* push 10              */ >++++++++++ #19
+/* write                */ .[-]< #18
+/* push 74              */ >>++++++++[<+++++++++>-]<++ #19
+/* set msg\x5b0\x5d         */ <<<<<<<<<<<<<<<<<<<[-]>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>-]< #18
+/* print msg            */ 
+/*     push 1           */ >+ #19
+/*     get msg\x5b0\x5d     */ <<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>-]< #20
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #20
+/*     then             */     [
+/*         get msg\x5b0\x5d */ <<<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>-]< #21
+/*         write        */ .[-]< #20
+/*     end if           */       [-]]< #19
+/*     get msg\x5b1\x5d     */ <<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>-]< #20
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #20
+/*     then             */     [
+/*         get msg\x5b1\x5d */ <<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>-]< #21
+/*         write        */ .[-]< #20
+/*     end if           */       [-]]< #19
+/*     get msg\x5b2\x5d     */ <<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>-]< #20
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #20
+/*     then             */     [
+/*         get msg\x5b2\x5d */ <<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>-]< #21
+/*         write        */ .[-]< #20
+/*     end if           */       [-]]< #19
+/*     get msg\x5b3\x5d     */ <<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>-]< #20
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #20
+/*     then             */     [
+/*         get msg\x5b3\x5d */ <<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>-]< #21
+/*         write        */ .[-]< #20
+/*     end if           */       [-]]< #19
+/*     get msg\x5b4\x5d     */ <<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>-]< #20
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #20
+/*     then             */     [
+/*         get msg\x5b4\x5d */ <<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>-]< #21
+/*         write        */ .[-]< #20
+/*     end if           */       [-]]< #19
+/*     get msg\x5b5\x5d     */ <<<<<<<<<<<<<<[>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>-]< #20
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #20
+/*     then             */     [
+/*         get msg\x5b5\x5d */ <<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>-]< #21
+/*         write        */ .[-]< #20
+/*     end if           */       [-]]< #19
+/*     drop             */ [-]< #18
+/* end print msg         */ #18
+/* push 10              */ >++++++++++ #19
+/* write                */ .[-]< #18
+/* push 0               */ > #19
+/* set msg\x5b2\x5d         */ <<<<<<<<<<<<<<<<<[-]>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>-]< #18
+/* print msg            */ 
+/*     push 1           */ >+ #19
+/*     get msg\x5b0\x5d     */ <<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>-]< #20
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #20
+/*     then             */     [
+/*         get msg\x5b0\x5d */ <<<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>-]< #21
+/*         write        */ .[-]< #20
+/*     end if           */       [-]]< #19
+/*     get msg\x5b1\x5d     */ <<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>-]< #20
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #20
+/*     then             */     [
+/*         get msg\x5b1\x5d */ <<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>-]< #21
+/*         write        */ .[-]< #20
+/*     end if           */       [-]]< #19
+/*     get msg\x5b2\x5d     */ <<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>-]< #20
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #20
+/*     then             */     [
+/*         get msg\x5b2\x5d */ <<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>-]< #21
+/*         write        */ .[-]< #20
+/*     end if           */       [-]]< #19
+/*     get msg\x5b3\x5d     */ <<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>-]< #20
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #20
+/*     then             */     [
+/*         get msg\x5b3\x5d */ <<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>-]< #21
+/*         write        */ .[-]< #20
+/*     end if           */       [-]]< #19
+/*     get msg\x5b4\x5d     */ <<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>-]< #20
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #20
+/*     then             */     [
+/*         get msg\x5b4\x5d */ <<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>-]< #21
+/*         write        */ .[-]< #20
+/*     end if           */       [-]]< #19
+/*     get msg\x5b5\x5d     */ <<<<<<<<<<<<<<[>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>-]< #20
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #20
+/*     then             */     [
+/*         get msg\x5b5\x5d */ <<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>-]< #21
+/*         write        */ .[-]< #20
+/*     end if           */       [-]]< #19
+/*     drop             */ [-]< #18
+/* end print msg         */ #18
+/* push 10              */ >++++++++++ #19
+/* write                */ .[-]< #18
+/* push 2               */ >++ #19
+/* set c\x5b0\x5d         */ <<<<<<<<<<<<<[-]>>>>>>>>>>>>>[<<<<<<<<<<<<<+>>>>>>>>>>>>>-]< #18
+/* print c              */ 
+/*     if               */ 
+/*         get c\x5b0\x5d */ <<<<<<<<<<<<[>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>[<<<<<<<<<<<<<<+>>>>>>>>>>>>>>-]< #19
+/*         push 1       */ >+ #20
+/*         ge           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]+<[[-]>-<]>[<+>-]< #19
+/*     then             */     [
+/*         get c\x5b1\x5d */ <<<<<<<<<<<<[>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>[<<<<<<<<<<<<<<+>>>>>>>>>>>>>>-]< #20
+/*         write        */ .[-]< #19
+/*     end if           */       [-]]< #18
+/*     if               */ 
+/*         get c\x5b0\x5d */ <<<<<<<<<<<<[>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>[<<<<<<<<<<<<<<+>>>>>>>>>>>>>>-]< #19
+/*         push 2       */ >++ #20
+/*         ge           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]+<[[-]>-<]>[<+>-]< #19
+/*     then             */     [
+/*         get c\x5b2\x5d */ <<<<<<<<<<<[>>>>>>>>>>>>+>+<<<<<<<<<<<<<-]>>>>>>>>>>>>>[<<<<<<<<<<<<<+>>>>>>>>>>>>>-]< #20
+/*         write        */ .[-]< #19
+/*     end if           */       [-]]< #18
+/*     if               */ 
+/*         get c\x5b0\x5d */ <<<<<<<<<<<<[>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>[<<<<<<<<<<<<<<+>>>>>>>>>>>>>>-]< #19
+/*         push 3       */ >+++ #20
+/*         ge           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]+<[[-]>-<]>[<+>-]< #19
+/*     then             */     [
+/*         get c\x5b3\x5d */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #20
+/*         write        */ .[-]< #19
+/*     end if           */       [-]]< #18
+/* end print c          */ #18
+/* push 10              */ >++++++++++ #19
+/* write                */ .[-]< #18
+/* scope                */ 
+/*     let s\x5b10\x5d     */ >>+++++++[<+++++++++++++++>-]>++++++++++[<+++++++++++>-]>++++[<++++++++>-]>+++++++++[<+++++++++++++>-]<-->>+++++++++[<+++++++++++>-]>++++++++++[<+++++++++++>-]<+>>++++++++[<++++++++++++++>-]>++++++++++[<++++++++++>-]<+>>++++[<++++++++>-] #28
+/*     print s          */ 
+/*         push 1       */ >+ #29
+/*         get s\x5b0\x5d */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #30
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #29
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #30
+/*         then         */         [
+/*             get s\x5b0\x5d */ <<<<<<<<<<<[>>>>>>>>>>>>+>+<<<<<<<<<<<<<-]>>>>>>>>>>>>>[<<<<<<<<<<<<<+>>>>>>>>>>>>>-]< #31
+/*             write        */ .[-]< #30
+/*         end if       */           [-]]< #29
+/*         get s\x5b1\x5d */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #30
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #29
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #30
+/*         then         */         [
+/*             get s\x5b1\x5d */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #31
+/*             write        */ .[-]< #30
+/*         end if       */           [-]]< #29
+/*         get s\x5b2\x5d */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #30
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #29
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #30
+/*         then         */         [
+/*             get s\x5b2\x5d */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #31
+/*             write        */ .[-]< #30
+/*         end if       */           [-]]< #29
+/*         get s\x5b3\x5d */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #30
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #29
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #30
+/*         then         */         [
+/*             get s\x5b3\x5d */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #31
+/*             write        */ .[-]< #30
+/*         end if       */           [-]]< #29
+/*         get s\x5b4\x5d */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #30
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #29
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #30
+/*         then         */         [
+/*             get s\x5b4\x5d */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #31
+/*             write        */ .[-]< #30
+/*         end if       */           [-]]< #29
+/*         get s\x5b5\x5d */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #30
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #29
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #30
+/*         then         */         [
+/*             get s\x5b5\x5d */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #31
+/*             write        */ .[-]< #30
+/*         end if       */           [-]]< #29
+/*         get s\x5b6\x5d */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #30
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #29
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #30
+/*         then         */         [
+/*             get s\x5b6\x5d */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #31
+/*             write        */ .[-]< #30
+/*         end if       */           [-]]< #29
+/*         get s\x5b7\x5d */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #30
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #29
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #30
+/*         then         */         [
+/*             get s\x5b7\x5d */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #31
+/*             write        */ .[-]< #30
+/*         end if       */           [-]]< #29
+/*         get s\x5b8\x5d */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #30
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #29
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #30
+/*         then         */         [
+/*             get s\x5b8\x5d */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #31
+/*             write        */ .[-]< #30
+/*         end if       */           [-]]< #29
+/*         get s\x5b9\x5d */ <[>>+>+<<<-]>>>[<<<+>>>-]< #30
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #29
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #30
+/*         then         */         [
+/*             get s\x5b9\x5d */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #31
+/*             write        */ .[-]< #30
+/*         end if       */           [-]]< #29
+/*         drop         */ [-]< #28
+/*     end print s      */ #28
+/*     print msg        */ 
+/*         push 1       */ >+ #29
+/*         get msg\x5b0\x5d */ <<<<<<<<<<<<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>-]< #30
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #29
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #30
+/*         then         */         [
+/*             get msg\x5b0\x5d */ <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>-]< #31
+/*             write        */ .[-]< #30
+/*         end if       */           [-]]< #29
+/*         get msg\x5b1\x5d */ <<<<<<<<<<<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>-]< #30
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #29
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #30
+/*         then         */         [
+/*             get msg\x5b1\x5d */ <<<<<<<<<<<<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>-]< #31
+/*             write        */ .[-]< #30
+/*         end if       */           [-]]< #29
+/*         get msg\x5b2\x5d */ <<<<<<<<<<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>-]< #30
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #29
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #30
+/*         then         */         [
+/*             get msg\x5b2\x5d */ <<<<<<<<<<<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>-]< #31
+/*             write        */ .[-]< #30
+/*         end if       */           [-]]< #29
+/*         get msg\x5b3\x5d */ <<<<<<<<<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>-]< #30
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #29
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #30
+/*         then         */         [
+/*             get msg\x5b3\x5d */ <<<<<<<<<<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>-]< #31
+/*             write        */ .[-]< #30
+/*         end if       */           [-]]< #29
+/*         get msg\x5b4\x5d */ <<<<<<<<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>-]< #30
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #29
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #30
+/*         then         */         [
+/*             get msg\x5b4\x5d */ <<<<<<<<<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>-]< #31
+/*             write        */ .[-]< #30
+/*         end if       */           [-]]< #29
+/*         get msg\x5b5\x5d */ <<<<<<<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>-]< #30
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #29
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #30
+/*         then         */         [
+/*             get msg\x5b5\x5d */ <<<<<<<<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>>>>>>>>>>>>>>[<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>-]< #31
+/*             write        */ .[-]< #30
+/*         end if       */           [-]]< #29
+/*         drop         */ [-]< #28
+/*     end print msg     */ #28
+/*     push 10          */ >++++++++++ #29
+/*     write            */ .[-]< #28
+/*     free             */ [-]<[-]<[-]<[-]<[-]<[-]<[-]<[-]<[-]<[-]< #18
+/* end scope            */ #18
+/* readline line         */ 
+/*     push 0           */ > #19
+/*     push 1           */ >+ #20
+/*     if               */ 
+/*         get readline:running */ [>+>+<<-]>>[<<+>>-]< #21
+/*     then             */     [
+/*         read         */ >, #22
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #23
+/*         push 10      */ >++++++++++ #24
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #23
+/*         over         */ <[>>+>+<<<-]>>>[<<<+>>>-]< #24
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #23
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #24
+/*         set readline:running */ <<<<[-]>>>>[<<<<+>>>>-]< #23
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #24
+/*         get readline:count */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #25
+/*         add          */ [<+>-]< #24
+/*         set readline:count */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #23
+/*         mul          */ <[>>+<<-]>[>[<<+>>>+<-]>[<+>-]<<-]>[-]<< #22
+/*         set line\x5b0\x5d */ <<<<<<<<<<<<[-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #21
+/*     end if           */       [-]]< #20
+/*     if               */ 
+/*         get readline:running */ [>+>+<<-]>>[<<+>>-]< #21
+/*     then             */     [
+/*         read         */ >, #22
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #23
+/*         push 10      */ >++++++++++ #24
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #23
+/*         over         */ <[>>+>+<<<-]>>>[<<<+>>>-]< #24
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #23
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #24
+/*         set readline:running */ <<<<[-]>>>>[<<<<+>>>>-]< #23
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #24
+/*         get readline:count */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #25
+/*         add          */ [<+>-]< #24
+/*         set readline:count */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #23
+/*         mul          */ <[>>+<<-]>[>[<<+>>>+<-]>[<+>-]<<-]>[-]<< #22
+/*         set line\x5b1\x5d */ <<<<<<<<<<<[-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #21
+/*     end if           */       [-]]< #20
+/*     if               */ 
+/*         get readline:running */ [>+>+<<-]>>[<<+>>-]< #21
+/*     then             */     [
+/*         read         */ >, #22
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #23
+/*         push 10      */ >++++++++++ #24
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #23
+/*         over         */ <[>>+>+<<<-]>>>[<<<+>>>-]< #24
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #23
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #24
+/*         set readline:running */ <<<<[-]>>>>[<<<<+>>>>-]< #23
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #24
+/*         get readline:count */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #25
+/*         add          */ [<+>-]< #24
+/*         set readline:count */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #23
+/*         mul          */ <[>>+<<-]>[>[<<+>>>+<-]>[<+>-]<<-]>[-]<< #22
+/*         set line\x5b2\x5d */ <<<<<<<<<<[-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #21
+/*     end if           */       [-]]< #20
+/*     if               */ 
+/*         get readline:running */ [>+>+<<-]>>[<<+>>-]< #21
+/*     then             */     [
+/*         read         */ >, #22
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #23
+/*         push 10      */ >++++++++++ #24
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #23
+/*         over         */ <[>>+>+<<<-]>>>[<<<+>>>-]< #24
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #23
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #24
+/*         set readline:running */ <<<<[-]>>>>[<<<<+>>>>-]< #23
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #24
+/*         get readline:count */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #25
+/*         add          */ [<+>-]< #24
+/*         set readline:count */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #23
+/*         mul          */ <[>>+<<-]>[>[<<+>>>+<-]>[<+>-]<<-]>[-]<< #22
+/*         set line\x5b3\x5d */ <<<<<<<<<[-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #21
+/*     end if           */       [-]]< #20
+/*     if               */ 
+/*         get readline:running */ [>+>+<<-]>>[<<+>>-]< #21
+/*     then             */     [
+/*         read         */ >, #22
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #23
+/*         push 10      */ >++++++++++ #24
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #23
+/*         over         */ <[>>+>+<<<-]>>>[<<<+>>>-]< #24
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #23
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #24
+/*         set readline:running */ <<<<[-]>>>>[<<<<+>>>>-]< #23
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #24
+/*         get readline:count */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #25
+/*         add          */ [<+>-]< #24
+/*         set readline:count */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #23
+/*         mul          */ <[>>+<<-]>[>[<<+>>>+<-]>[<+>-]<<-]>[-]<< #22
+/*         set line\x5b4\x5d */ <<<<<<<<[-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #21
+/*     end if           */       [-]]< #20
+/*     if               */ 
+/*         get readline:running */ [>+>+<<-]>>[<<+>>-]< #21
+/*     then             */     [
+/*         read         */ >, #22
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #23
+/*         push 10      */ >++++++++++ #24
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #23
+/*         over         */ <[>>+>+<<<-]>>>[<<<+>>>-]< #24
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #23
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #24
+/*         set readline:running */ <<<<[-]>>>>[<<<<+>>>>-]< #23
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #24
+/*         get readline:count */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #25
+/*         add          */ [<+>-]< #24
+/*         set readline:count */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #23
+/*         mul          */ <[>>+<<-]>[>[<<+>>>+<-]>[<+>-]<<-]>[-]<< #22
+/*         set line\x5b5\x5d */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #21
+/*     end if           */       [-]]< #20
+/*     if               */ 
+/*         get readline:running */ [>+>+<<-]>>[<<+>>-]< #21
+/*     then             */     [
+/*         read         */ >, #22
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #23
+/*         push 10      */ >++++++++++ #24
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #23
+/*         over         */ <[>>+>+<<<-]>>>[<<<+>>>-]< #24
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #23
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #24
+/*         set readline:running */ <<<<[-]>>>>[<<<<+>>>>-]< #23
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #24
+/*         get readline:count */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #25
+/*         add          */ [<+>-]< #24
+/*         set readline:count */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #23
+/*         mul          */ <[>>+<<-]>[>[<<+>>>+<-]>[<+>-]<<-]>[-]<< #22
+/*         set line\x5b6\x5d */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #21
+/*     end if           */       [-]]< #20
+/*     if               */ 
+/*         get readline:running */ [>+>+<<-]>>[<<+>>-]< #21
+/*     then             */     [
+/*         read         */ >, #22
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #23
+/*         push 10      */ >++++++++++ #24
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #23
+/*         over         */ <[>>+>+<<<-]>>>[<<<+>>>-]< #24
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #23
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #24
+/*         set readline:running */ <<<<[-]>>>>[<<<<+>>>>-]< #23
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #24
+/*         get readline:count */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #25
+/*         add          */ [<+>-]< #24
+/*         set readline:count */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #23
+/*         mul          */ <[>>+<<-]>[>[<<+>>>+<-]>[<+>-]<<-]>[-]<< #22
+/*         set line\x5b7\x5d */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #21
+/*     end if           */       [-]]< #20
+/*     drop             */ [-]< #19
+/* end readline         */ #19
+/* drop                 */ [-]< #18
+/* print line           */ 
+/*     push 1           */ >+ #19
+/*     get line\x5b0\x5d     */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #20
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #20
+/*     then             */     [
+/*         get line\x5b0\x5d */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #21
+/*         write        */ .[-]< #20
+/*     end if           */       [-]]< #19
+/*     get line\x5b1\x5d     */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #20
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #20
+/*     then             */     [
+/*         get line\x5b1\x5d */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #21
+/*         write        */ .[-]< #20
+/*     end if           */       [-]]< #19
+/*     get line\x5b2\x5d     */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #20
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #20
+/*     then             */     [
+/*         get line\x5b2\x5d */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #21
+/*         write        */ .[-]< #20
+/*     end if           */       [-]]< #19
+/*     get line\x5b3\x5d     */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #20
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #20
+/*     then             */     [
+/*         get line\x5b3\x5d */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #21
+/*         write        */ .[-]< #20
+/*     end if           */       [-]]< #19
+/*     get line\x5b4\x5d     */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #20
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #20
+/*     then             */     [
+/*         get line\x5b4\x5d */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #21
+/*         write        */ .[-]< #20
+/*     end if           */       [-]]< #19
+/*     get line\x5b5\x5d     */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #20
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #20
+/*     then             */     [
+/*         get line\x5b5\x5d */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #21
+/*         write        */ .[-]< #20
+/*     end if           */       [-]]< #19
+/*     get line\x5b6\x5d     */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #20
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #20
+/*     then             */     [
+/*         get line\x5b6\x5d */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #21
+/*         write        */ .[-]< #20
+/*     end if           */       [-]]< #19
+/*     get line\x5b7\x5d     */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #20
+/*     and              */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #19
+/*     if               */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #20
+/*     then             */     [
+/*         get line\x5b7\x5d */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #21
+/*         write        */ .[-]< #20
+/*     end if           */       [-]]< #19
+/*     drop             */ [-]< #18
+/* end print line         */ #18
+/* push 10              */ >++++++++++ #19
+/* write                */ .[-]< #18
+/* get c\x5b0\x5d         */ <<<<<<<<<<<<[>>>>>>>>>>>>>+>+<<<<<<<<<<<<<<-]>>>>>>>>>>>>>>[<<<<<<<<<<<<<<+>>>>>>>>>>>>>>-]< #19
+/* printnum             */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #18
+/* push 10              */ >++++++++++ #19
+/* write                */ .[-]< #18
+
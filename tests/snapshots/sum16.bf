@@ -0,0 +1,743 @@
+/* target: 8 bit cells with wraparound */
+/* let i                */ > #1
+
+/* push16 0             */ 
+/*     push 0           */ > #2
+/*     push 0           */ > #3
+/* end push16 0         */ #3
+/* while                */ 
+/*     get i            */ > #4
+/*     push 255         */ >- #5
+/*     neq              */ [<->-]<[[-]>+<]>[<+>-]< #4
+/* do                   */ [[-]
+/*     get i            */ <<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #4
+/*     inc              */ + #4
+/*     set i            */ <<<<[-]>>>>[<<<<+>>>>-]< #3
+/*     push 0           */ > #4
+/*     get i            */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #5
+/*     add16            */ 
+/*         push 0       */ > #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #8
+/*         add          */ [<+>-]< #7
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #8
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #9
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #8
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #8
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #9
+/*         add          */ [<+>-]< #8
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #9
+/*         not          */ >+<[[-]>-<]>[<+>-]< #9
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #10
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #9
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #9
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #8
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #7
+/*         poke 1       */ <[-]>[<+>-]< #6
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #7
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #8
+/*         add          */ [<+>-]< #7
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #8
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #9
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #8
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #8
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #9
+/*         add          */ [<+>-]< #8
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #9
+/*         not          */ >+<[[-]>-<]>[<+>-]< #9
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #10
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #9
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #9
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #8
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #7
+/*         poke 1       */ <[-]>[<+>-]< #6
+/*         drop         */ [-]< #5
+/*         drop         */ [-]< #4
+/*         drop         */ [-]< #3
+/*     end add16        */ #3
+/* again                */
+/*     get i            */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #4
+/*     push 255         */ >- #5
+/*     neq              */ [<->-]<[[-]>+<]>[<+>-]< #4
+/* end while            */   ]< #3
+/* print16              */ 
+/*     push 0           */ > #4
+/*     push 0           */ > #5
+/*     while            */ 
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 39      */ >>+++++[<++++++++>-]<- #8
+/*         push 16      */ >>++++[<++++>-]< #9
+/*         push 0       */ > #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         drop         */ [-]< #6
+/*         push 255     */ >- #7
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #6
+/*     do               */     [[-]
+/*         pick 3       */ <<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 39      */ >>+++++[<++++++++>-]<- #8
+/*         push 16      */ >>++++[<++++>-]< #9
+/*         push 0       */ > #10
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #11
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*         sub          */ [<->-]< #12
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #13
+/*         not          */ >+<[[-]>-<]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #13
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #11
+/*         poke 1       */ <[-]>[<+>-]< #10
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #11
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         sub          */ [<->-]< #12
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #13
+/*         not          */ >+<[[-]>-<]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #13
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #11
+/*         poke 1       */ <[-]>[<+>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #6
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #5
+/*         inc          */ + #5
+/*     again            */
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 39      */ >>+++++[<++++++++>-]<- #8
+/*         push 16      */ >>++++[<++++>-]< #9
+/*         push 0       */ > #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         drop         */ [-]< #6
+/*         push 255     */ >- #7
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #6
+/*     end while        */       ]< #5
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #6
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #7
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #6
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #7
+/*         addc 48      */ >++++++[<++++++++>-]< #7
+/*         write        */ .[-]< #6
+/*         push 1       */ >+ #7
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #6
+/*     end if           */       [-]]< #5
+/*     drop             */ [-]< #4
+/*     push 0           */ > #5
+/*     while            */ 
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 3       */ >+++ #8
+/*         push 232     */ >>++++[<------>-]< #9
+/*         push 0       */ > #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         drop         */ [-]< #6
+/*         push 255     */ >- #7
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #6
+/*     do               */     [[-]
+/*         pick 3       */ <<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 3       */ >+++ #8
+/*         push 232     */ >>++++[<------>-]< #9
+/*         push 0       */ > #10
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #11
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*         sub          */ [<->-]< #12
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #13
+/*         not          */ >+<[[-]>-<]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #13
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #11
+/*         poke 1       */ <[-]>[<+>-]< #10
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #11
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         sub          */ [<->-]< #12
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #13
+/*         not          */ >+<[[-]>-<]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #13
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #11
+/*         poke 1       */ <[-]>[<+>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #6
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #5
+/*         inc          */ + #5
+/*     again            */
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 3       */ >+++ #8
+/*         push 232     */ >>++++[<------>-]< #9
+/*         push 0       */ > #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         drop         */ [-]< #6
+/*         push 255     */ >- #7
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #6
+/*     end while        */       ]< #5
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #6
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #7
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #6
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #7
+/*         addc 48      */ >++++++[<++++++++>-]< #7
+/*         write        */ .[-]< #6
+/*         push 1       */ >+ #7
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #6
+/*     end if           */       [-]]< #5
+/*     drop             */ [-]< #4
+/*     push 0           */ > #5
+/*     while            */ 
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 0       */ > #8
+/*         push 100     */ >>++++++++++[<++++++++++>-]< #9
+/*         push 0       */ > #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         drop         */ [-]< #6
+/*         push 255     */ >- #7
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #6
+/*     do               */     [[-]
+/*         pick 3       */ <<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 0       */ > #8
+/*         push 100     */ >>++++++++++[<++++++++++>-]< #9
+/*         push 0       */ > #10
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #11
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*         sub          */ [<->-]< #12
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #13
+/*         not          */ >+<[[-]>-<]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #13
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #11
+/*         poke 1       */ <[-]>[<+>-]< #10
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #11
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         sub          */ [<->-]< #12
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #13
+/*         not          */ >+<[[-]>-<]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #13
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #11
+/*         poke 1       */ <[-]>[<+>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #6
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #5
+/*         inc          */ + #5
+/*     again            */
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 0       */ > #8
+/*         push 100     */ >>++++++++++[<++++++++++>-]< #9
+/*         push 0       */ > #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         drop         */ [-]< #6
+/*         push 255     */ >- #7
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #6
+/*     end while        */       ]< #5
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #6
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #7
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #6
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #7
+/*         addc 48      */ >++++++[<++++++++>-]< #7
+/*         write        */ .[-]< #6
+/*         push 1       */ >+ #7
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #6
+/*     end if           */       [-]]< #5
+/*     drop             */ [-]< #4
+/*     push 0           */ > #5
+/*     while            */ 
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 0       */ > #8
+/*         push 10      */ >++++++++++ #9
+/*         push 0       */ > #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         drop         */ [-]< #6
+/*         push 255     */ >- #7
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #6
+/*     do               */     [[-]
+/*         pick 3       */ <<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 0       */ > #8
+/*         push 10      */ >++++++++++ #9
+/*         push 0       */ > #10
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #11
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*         sub          */ [<->-]< #12
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #13
+/*         not          */ >+<[[-]>-<]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #13
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #11
+/*         poke 1       */ <[-]>[<+>-]< #10
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #11
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         sub          */ [<->-]< #12
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #13
+/*         not          */ >+<[[-]>-<]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #13
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #11
+/*         poke 1       */ <[-]>[<+>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #6
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #5
+/*         inc          */ + #5
+/*     again            */
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 0       */ > #8
+/*         push 10      */ >++++++++++ #9
+/*         push 0       */ > #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         drop         */ [-]< #6
+/*         push 255     */ >- #7
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #6
+/*     end while        */       ]< #5
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #6
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #7
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #6
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #7
+/*         addc 48      */ >++++++[<++++++++>-]< #7
+/*         write        */ .[-]< #6
+/*         push 1       */ >+ #7
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #6
+/*     end if           */       [-]]< #5
+/*     drop             */ [-]< #4
+/*     push 0           */ > #5
+/*     while            */ 
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 0       */ > #8
+/*         push 1       */ >+ #9
+/*         push 0       */ > #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         drop         */ [-]< #6
+/*         push 255     */ >- #7
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #6
+/*     do               */     [[-]
+/*         pick 3       */ <<<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 0       */ > #8
+/*         push 1       */ >+ #9
+/*         push 0       */ > #10
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #11
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #12
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*         sub          */ [<->-]< #12
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #13
+/*         not          */ >+<[[-]>-<]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #13
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         poke 6       */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #11
+/*         poke 1       */ <[-]>[<+>-]< #10
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #11
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #12
+/*         lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #11
+/*         pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*         pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*         sub          */ [<->-]< #12
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #13
+/*         not          */ >+<[[-]>-<]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #13
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #13
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*         sub          */ [<->-]< #13
+/*         poke 7       */ <<<<<<<[-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #11
+/*         poke 1       */ <[-]>[<+>-]< #10
+/*         drop         */ [-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #6
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #5
+/*         inc          */ + #5
+/*     again            */
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #6
+/*         pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #7
+/*         push 0       */ > #8
+/*         push 1       */ >+ #9
+/*         push 0       */ > #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #12
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 6       */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #13
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         if           */ 
+/*             pick 0       */ [>+>+<<-]>>[<<+>>-]< #11
+/*             not          */ >+<[[-]>-<]>[<+>-]< #11
+/*         then         */         [
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #12
+/*             pick 3       */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #13
+/*             gt           */ [-<[>>+>+<<<-]>>>[<<<+>>>-]<[[-]<<->>]<]<[[-]>+<]>[<+>-]< #12
+/*             pick 5       */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #13
+/*             pick 4       */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             lt           */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #13
+/*             sub          */ [<->-]< #12
+/*             poke 2       */ <<[-]>>[<<+>>-]< #11
+/*         end if       */           [-]]< #10
+/*         poke 4       */ <<<<[-]>>>>[<<<<+>>>>-]< #9
+/*         drop         */ [-]< #8
+/*         drop         */ [-]< #7
+/*         drop         */ [-]< #6
+/*         push 255     */ >- #7
+/*         neq          */ [<->-]<[[-]>+<]>[<+>-]< #6
+/*     end while        */       ]< #5
+/*     if               */ 
+/*         pick 0       */ [>+>+<<-]>>[<<+>>-]< #6
+/*         pick 2       */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #7
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #6
+/*         push 1       */ >+ #7
+/*         or           */ [[-]<[-]+>]<[[-]>+<]>[<+>-]< #6
+/*     then             */     [
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #7
+/*         addc 48      */ >++++++[<++++++++>-]< #7
+/*         write        */ .[-]< #6
+/*         push 1       */ >+ #7
+/*         poke 3       */ <<<[-]>>>[<<<+>>>-]< #6
+/*     end if           */       [-]]< #5
+/*     drop             */ [-]< #4
+/*     drop             */ [-]< #3
+/*     drop             */ [-]< #2
+/*     drop             */ [-]< #1
+/* end print16          */ #1
+/* push 10              */ >++++++++++ #2
+/* write                */ .[-]< #1
+
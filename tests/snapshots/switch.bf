@@ -0,0 +1,96 @@
+/* target: 8 bit cells with wraparound */
+/* let i                */ > #1
+
+/* while                */ >+[>>>+>+<<<<-]>>>>[<<<<+>>>>-]<[[-]
+/*     push 1           */ >+ #6
+/* test                 */   <<[-]>>[<<+>>-]<]<[[-]<+
+/*     switch           */ 
+/*         get i        */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #4
+/*         push 1       */ >+ #5
+/*         case 0       */ 
+/*             pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #6
+/*             push 0       */ > #7
+/*             eq           */ [<->-]+<[[-]>-<]>[<+>-]< #6
+/*             pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #7
+/*             and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #6
+/*         then         */         [<[-]>
+/*             print "zero " */ >>+++++++++++[<+++++++++++>-]<+.>+++[<------->-]<.+++++++++++++.---.[-]>++++[<++++++++>-]<.[-]< #6
+/*         end case 0   */           [-]]< #5
+/*         case 2       */ 
+/*             pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #6
+/*             push 2       */ >++ #7
+/*             eq           */ [<->-]+<[[-]>-<]>[<+>-]< #6
+/*             pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #7
+/*             and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #6
+/*         then         */         [<[-]>
+/*             print "two " */ >>+++++++++[<+++++++++++++>-]<-.+++.--------.[-]>++++[<++++++++>-]<.[-]< #6
+/*         end case 2   */           [-]]< #5
+/*         case 5       */ 
+/*             pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #6
+/*             push 5       */ >+++++ #7
+/*             eq           */ [<->-]+<[[-]>-<]>[<+>-]< #6
+/*             pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #7
+/*             and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #6
+/*         then         */         [<[-]>
+/*             print "five " */ >>++++++++++[<++++++++++>-]<++.+++.+++++++++++++.>++++[<---->-]<-.[-]>++++[<++++++++>-]<.[-]< #6
+/*         end case 5   */           [-]]< #5
+/*         case 7       */ 
+/*             pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #6
+/*             push 7       */ >+++++++ #7
+/*             eq           */ [<->-]+<[[-]>-<]>[<+>-]< #6
+/*             pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #7
+/*             and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #6
+/*         then         */         [<[-]>
+/*             print "stop" */ >>+++++++++[<+++++++++++++>-]<--.+.-----.+.[-]< #6
+/*             break        */ <<<[-]<[-]>>>> #6
+/*         end case 7   */           [-]]< #5
+/*         default      */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #6
+/*         then         */         [<[-]
+/*             get i        */ <<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #7
+/*             printnum     */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #6
+/*             print " "    */ >>++++[<++++++++>-]<.[-]< #6
+/*         end default  */           [-]]< #5
+/*         drop         */ [-]< #4
+/*         drop         */ [-]< #3
+/*     end switch       */ #3
+/*     if running       */ [>+>+<<-]>>[<<+>>-]<[
+/*         get i        */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #5
+/*         inc          */ + #5
+/*         set i        */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #4
+/*     end if           */       [-]]< #3
+/* again                */   [-]<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]<[[-]
+/*     push 1           */ >+ #6
+/* test                 */   <<[-]>>[<<+>>-]<]<]
+/* end while            */   <<[-]< #1
+/* push 10              */ >++++++++++ #2
+/* write                */ .[-]< #1
+/* switch               */ 
+/*     push 3           */ >+++ #2
+/*     push 1           */ >+ #3
+/*     case 1           */ 
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #4
+/*         push 1       */ >+ #5
+/*         eq           */ [<->-]+<[[-]>-<]>[<+>-]< #4
+/*         pick 1       */ <[>>+>+<<<-]>>>[<<<+>>>-]< #5
+/*         and          */ [[-]>+<]<[[-]>>[<+>-]<<]>>[-]<[<+>-]< #4
+/*     then             */     [<[-]>
+/*         print "no"   */ >>++++++++++[<+++++++++++>-]<.+.[-]< #4
+/*     end case 1       */       [-]]< #3
+/*     drop             */ [-]< #2
+/*     drop             */ [-]< #1
+/* end switch           */ #1
+/* switch               */ 
+/*     push 255         */ >- #2
+/*     push 1           */ >+ #3
+/*     default          */ 
+/*         copy         */ [>+>+<<-]>>[<<+>>-]< #4
+/*     then             */     [<[-]>
+/*         print "d"    */ >>++++++++++[<++++++++++>-]<.[-]< #4
+/*     end default      */       [-]]< #3
+/*     drop             */ [-]< #2
+/*     drop             */ [-]< #1
+/* end switch           */ #1
+/* push 10              */ >++++++++++ #2
+/* write                */ .[-]< #1
+
@@ -0,0 +1,112 @@
+/* target: 8 bit cells with wraparound */
+/* let squares\x5b6\x5d         */ >+>++++>+++++++++>>++++[<++++>-]>++++++++[<++++++++>-]<+> #6
+/* let i                */ > #7
+
+/* while                */ 
+/*     get i            */ <[>>+>+<<<-]>>>[<<<+>>>-]< #8
+/*     push 7           */ >+++++++ #9
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #8
+/* do                   */ [[-]
+/*     get i            */ <<[>>+>+<<<-]>>>[<<<+>>>-]< #8
+/*     lookup squares     */ 
+/*         push 0       */ > #9
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #9
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #10
+/*             not          */ >+<[[-]>-<]>[<+>-]< #10
+/*         then         */         [
+/*             get squares\x5b0\x5d */ <<<<<<<<<<[>>>>>>>>>>>+>+<<<<<<<<<<<<-]>>>>>>>>>>>>[<<<<<<<<<<<<+>>>>>>>>>>>>-]< #11
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #10
+/*         end if       */           [-]]< #9
+/*         dec          */ - #9
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #10
+/*             not          */ >+<[[-]>-<]>[<+>-]< #10
+/*         then         */         [
+/*             get squares\x5b1\x5d */ <<<<<<<<<[>>>>>>>>>>+>+<<<<<<<<<<<-]>>>>>>>>>>>[<<<<<<<<<<<+>>>>>>>>>>>-]< #11
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #10
+/*         end if       */           [-]]< #9
+/*         dec          */ - #9
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #10
+/*             not          */ >+<[[-]>-<]>[<+>-]< #10
+/*         then         */         [
+/*             get squares\x5b2\x5d */ <<<<<<<<[>>>>>>>>>+>+<<<<<<<<<<-]>>>>>>>>>>[<<<<<<<<<<+>>>>>>>>>>-]< #11
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #10
+/*         end if       */           [-]]< #9
+/*         dec          */ - #9
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #10
+/*             not          */ >+<[[-]>-<]>[<+>-]< #10
+/*         then         */         [
+/*             get squares\x5b3\x5d */ <<<<<<<[>>>>>>>>+>+<<<<<<<<<-]>>>>>>>>>[<<<<<<<<<+>>>>>>>>>-]< #11
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #10
+/*         end if       */           [-]]< #9
+/*         dec          */ - #9
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #10
+/*             not          */ >+<[[-]>-<]>[<+>-]< #10
+/*         then         */         [
+/*             get squares\x5b4\x5d */ <<<<<<[>>>>>>>+>+<<<<<<<<-]>>>>>>>>[<<<<<<<<+>>>>>>>>-]< #11
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #10
+/*         end if       */           [-]]< #9
+/*         dec          */ - #9
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #10
+/*             not          */ >+<[[-]>-<]>[<+>-]< #10
+/*         then         */         [
+/*             get squares\x5b5\x5d */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #11
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #10
+/*         end if       */           [-]]< #9
+/*         dec          */ - #9
+/*         drop         */ [-]< #8
+/*     end lookup squares     */ #8
+/*     printnum         */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #7
+/*     print " "        */ >>++++[<++++++++>-]<.[-]< #7
+/*     get i            */ <[>>+>+<<<-]>>>[<<<+>>>-]< #8
+/*     inc              */ + #8
+/*     set i            */ <<[-]>>[<<+>>-]< #7
+/* again                */
+/*     get i            */ <[>>+>+<<<-]>>>[<<<+>>>-]< #8
+/*     push 7           */ >+++++++ #9
+/*     lt               */ <[->[>+>+<<-]>>[<<+>>-]<[[-]<->]<<]>[[-]<+>]< #8
+/* end while            */   ]< #7
+/* scope                */ 
+/*     let digits\x5b3\x5d     */ >>++++++[<++++++++>-]>+++++++[<+++++++>-]>+++++[<++++++++++>-]< #10
+/*     let t (reused)     */ <<<<[-]>>>> #10
+/*     push 2           */ >++ #11
+/*     lookup digits     */ 
+/*         push 0       */ > #12
+/*         swap         */ <[>>+<<-]>[<+>-]>[<+>-]< #12
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #13
+/*             not          */ >+<[[-]>-<]>[<+>-]< #13
+/*         then         */         [
+/*             get digits\x5b0\x5d */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #14
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #13
+/*         end if       */           [-]]< #12
+/*         dec          */ - #12
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #13
+/*             not          */ >+<[[-]>-<]>[<+>-]< #13
+/*         then         */         [
+/*             get digits\x5b1\x5d */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #14
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #13
+/*         end if       */           [-]]< #12
+/*         dec          */ - #12
+/*         if           */ 
+/*             copy         */ [>+>+<<-]>>[<<+>>-]< #13
+/*             not          */ >+<[[-]>-<]>[<+>-]< #13
+/*         then         */         [
+/*             get digits\x5b2\x5d */ <<<[>>>>+>+<<<<<-]>>>>>[<<<<<+>>>>>-]< #14
+/*             poke 3       */ <<<[-]>>>[<<<+>>>-]< #13
+/*         end if       */           [-]]< #12
+/*         dec          */ - #12
+/*         drop         */ [-]< #11
+/*     end lookup digits     */ #11
+/*     write            */ .[-]< #10
+/*     free             */ [-]<[-]<[-]< #7
+/* end scope            */ #7
+/* push 10              */ >++++++++++ #8
+/* write                */ .[-]< #7
+
@@ -0,0 +1,44 @@
+/* target: 8 bit cells with wraparound */
+/* let total            */ > #1
+/* let count            */ > #2
+/* let buf\x5b3\x5d         */ >>> #5
+
+/* push 3               */ >+++ #6
+/* set count            */ <<<<<[-]>>>>>[<<<<<+>>>>>-]< #5
+/* get count            */ <<<<[>>>>>+>+<<<<<<-]>>>>>>[<<<<<<+>>>>>>-]< #6
+/* push 4               */ >++++ #7
+/* add                  */ [<+>-]< #6
+/* set total            */ <<<<<<[-]>>>>>>[<<<<<<+>>>>>>-]< #5
+/* get total            */ <<<<<[>>>>>>+>+<<<<<<<-]>>>>>>>[<<<<<<<+>>>>>>>-]< #6
+/* printnum             */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #5
+/* push 10              */ >++++++++++ #6
+/* write                */ .[-]< #5
+/* scope                */ 
+/*     let i (reused)     */ <[-]> #5
+/*     let j (reused)     */ <<[-]>> #5
+/*     let pair (reused)     */ <<<<[-]>[-]>>> #5
+/*     push 7           */ >+++++++ #6
+/*     set i            */ <<[-]>>[<<+>>-]< #5
+/*     get i            */ <[>>+>+<<<-]>>>[<<<+>>>-]< #6
+/*     push 2           */ >++ #7
+/*     mul              */ <[>>+<<-]>[>[<<+>>>+<-]>[<+>-]<<-]>[-]<< #6
+/*     set j            */ <<<[-]>>>[<<<+>>>-]< #5
+/*     get j            */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #6
+/*     printnum         */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #5
+/*     push 10          */ >++++++++++ #6
+/*     write            */ .[-]< #5
+/*     scope            */ 
+/*         let k (reused) */ <[-]> #5
+/*         get j        */ <<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]< #6
+/*         push 1       */ >+ #7
+/*         add          */ [<+>-]< #6
+/*         set k        */ <<[-]>>[<<+>>-]< #5
+/*         get k        */ <[>>+>+<<<-]>>>[<<<+>>>-]< #6
+/*         printnum     */ >>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>>>++++++++++<<[->+>-[>+>>]>[+[-<+>]>+>>]<<<<<<]>[-]>[-]>>[>++++++[<++++++++>-]<.[-]<<<[-]+>>>]<[>>+<<-<<[-]+>>]<<[->>>>>++++++[<++++++++>-]<.[-]<<<<]<++++++[<++++++++>-]<.[-]<<<< #5
+/*         push 10      */ >++++++++++ #6
+/*         write        */ .[-]< #5
+/*         free         */  #5
+/*     end scope        */ #5
+/*     free             */  #5
+/* end scope            */ #5
+
@@ -0,0 +1,104 @@
+// Runs the stack commands built with `Program` on plain Brainfuck and on `--target extended1`, and
+// checks the values they leave on the tape and that the pointer ends on the new top.
+
+use brainfucktool::ir::{self, Syntax};
+use brainfucktool::{minify, verify, CallConv, CompileError, Config, Let, Program, Statements, Target};
+
+// The cells from the first pushed one up to the pointer, for every target, which must agree.
+fn stack(program: Program) -> Vec<u64> {
+    let mut stacks = Vec::new();
+    for extended in [false, true] {
+        let target = Target { extended, ..Target::default() };
+        let code = program.compile_with(Config { target, mark_spans: false }).expect("compiling");
+        let insts = ir::parse(&code, Syntax { extended, ..Syntax::default() }).expect("parsing");
+        let run = verify::execute(&insts, b"", target).expect("running");
+        assert!(run.tape[run.ptr + 1..].iter().all(|&cell| cell == 0), "scratch cells above the top are cleared");
+        stacks.push(run.tape[1..=run.ptr].to_vec());
+    }
+    assert_eq!(stacks[0], stacks[1], "plain and extended1 code agree");
+    stacks.remove(0)
+}
+
+#[test]
+fn dup_copies_the_top() {
+    assert_eq!(stack(Program::new().push(7).push(42).copy()), [7, 42, 42]);
+}
+
+#[test]
+fn swap_exchanges_the_top_two() {
+    assert_eq!(stack(Program::new().push(7).push(42).swap()), [42, 7]);
+    assert_eq!(stack(Program::new().push(1).push(0).push(255).swap()), [1, 255, 0]);
+}
+
+#[test]
+fn drop_pops_the_top() {
+    assert_eq!(stack(Program::new().push(7).push(42).drop()), [7]);
+    assert_eq!(stack(Program::new().push(7).push(42).drop().drop()), Vec::<u64>::new());
+}
+
+#[test]
+fn over_copies_the_second() {
+    assert_eq!(stack(Program::new().push(7).push(42).over()), [7, 42, 7]);
+    assert_eq!(stack(Program::new().push(0).push(200).over().over()), [0, 200, 0, 200]);
+}
+
+#[test]
+fn popping_below_the_stack_underflows() {
+    let underflow = |program: Program| program.compile().expect_err("popping a variable's cell");
+    let popped = CompileError::StackUnderflow { cmd: "drop".to_string() };
+    assert_eq!(underflow(Program::new().let_var("x").push(1).drop().drop()), popped);
+    let local = Let { name: "y".to_string(), len: None, init: vec![], counted: false };
+    assert_eq!(underflow(Program::new().push(1).scope(vec![local], |block| block.drop())), popped);
+    let program = Program::new().procedure(CallConv::Inline, "eat", &["a"], 0, |block| block.drop().push(0).drop());
+    assert_eq!(underflow(program.push(1).call("eat")), popped);
+    let called = CompileError::StackUnderflow { cmd: "call eat".to_string() };
+    assert_eq!(underflow(Program::new().let_var("x").procedure(CallConv::Inline, "eat", &["a"], 0, |block| block).call("eat")), called);
+}
+
+#[test]
+fn reading_below_the_stack_underflows() {
+    let underflow = |program: Program, cmd: &str| {
+        assert_eq!(program.compile(), Err(CompileError::StackUnderflow { cmd: cmd.to_string() }));
+    };
+    underflow(Program::new().push(1).add(), "add");
+    underflow(Program::new().push(1).eq(), "eq");
+    underflow(Program::new().push(1).swap(), "swap");
+    underflow(Program::new().let_var("x").push(3).set("x").push(1).add().print_num(), "add");
+    underflow(Program::new().let_var("x").stat(|block| block.push(2).sub().push(0)), "sub");
+    underflow(Program::new().copy(), "copy");
+}
+
+// The Brainfuck a command adds after `push 1; push 2; push 3`, for plain and extended1 targets.
+fn code(op: impl Fn(Program) -> Program) -> [String; 2] {
+    [false, true].map(|extended| {
+        let config = Config { target: Target { extended, ..Target::default() }, mark_spans: false };
+        let base = Program::new().push(1).push(2).push(3);
+        let before = minify(&base.clone().compile_with(config).expect("compiling"));
+        let after = minify(&op(base).compile_with(config).expect("compiling"));
+        after.strip_prefix(&before).expect("same code before the command").to_string()
+    })
+}
+
+#[test]
+fn stack_commands_generate_their_code() {
+    // Copies use one temporary cell above the new top; swap uses one above the top.
+    assert_eq!(code(|p| p.copy()), ["[>+>+<<-]>>[<<+>>-]<", "$>!"]);
+    assert_eq!(code(|p| p.swap()), ["<[>>+<<-]>[<+>-]>[<+>-]<", "$[-]<[>+<-]!>"]);
+    assert_eq!(code(|p| p.drop()), ["[-]<", "[-]<"]);
+    assert_eq!(code(|p| p.over()), ["><<[>>+>+<<<-]>>>[<<<+>>>-]<", "<$>>!"]);
+    assert_eq!(code(|p| p.pick(2)), ["><<<[>>>+>+<<<<-]>>>>[<<<<+>>>>-]<", "<<$>>>!"]);
+}
+
+#[test]
+fn shuffling_below_the_stack_underflows() {
+    let underflow = |program: Program, cmd: &str| {
+        assert_eq!(program.compile(), Err(CompileError::StackUnderflow { cmd: cmd.to_string() }));
+    };
+    underflow(Program::new().swap(), "swap");
+    underflow(Program::new().over(), "over");
+    underflow(Program::new().push(7).over(), "over");
+    underflow(Program::new().push(7).pick(1), "pick 1");
+    underflow(Program::new().push(7).pick(3), "pick 3");
+    underflow(Program::new().let_var("x").push(5).set("x").push(1).swap().print_num(), "swap");
+    assert_eq!(stack(Program::new().push(7).push(8).pick(1)), [7, 8, 7]);
+}
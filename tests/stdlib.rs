@@ -0,0 +1,68 @@
+// Interpreter-backed tests for the standard library.
+//
+// Each routine has a program in `tests/stdlib/` that calls it, and a TOML file of input/output
+// cases next to it. The program is compiled with `--verify`, which runs the generated code on
+// every case, once for plain Brainfuck and once for `--target extended1`.
+
+use std::path::Path;
+use std::process::Command;
+
+fn verify(routine: &str) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    for target in ["brainfuck", "extended1"] {
+        let output = Command::new(env!("CARGO_BIN_EXE_bfconstructor"))
+            .current_dir(root)
+            .arg(format!("tests/stdlib/{}.bfs", routine))
+            .arg("--verify")
+            .arg(format!("tests/stdlib/{}.toml", routine))
+            .args(["--target", target])
+            .output()
+            .expect("running bfconstructor");
+        assert!(output.status.success(), "{} ({}):\n{}", routine, target, String::from_utf8_lossy(&output.stderr));
+    }
+}
+
+#[test]
+fn memset() {
+    verify("memset");
+}
+
+#[test]
+fn memcpy() {
+    verify("memcpy");
+}
+
+#[test]
+fn strcmp() {
+    verify("strcmp");
+}
+
+#[test]
+fn printhex() {
+    verify("printhex");
+}
+
+#[test]
+fn printbin() {
+    verify("printbin");
+}
+
+#[test]
+fn printdec3() {
+    verify("printdec3");
+}
+
+#[test]
+fn band() {
+    verify("band");
+}
+
+#[test]
+fn bor() {
+    verify("bor");
+}
+
+#[test]
+fn bxor() {
+    verify("bxor");
+}
@@ -0,0 +1,83 @@
+// Compiles programs for targets other than the default wrapping 8-bit cells and runs them:
+// `--no-wrap` code under `bfir -w`, which stops on any cell going past 0 or 255, and code for
+// 16-bit cells with `--verify`, whose cells follow the target.
+
+use brainfucktool::{Config, Program, Statements, Target};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+// Compiles `program` for `target` and runs it with `bfir -w`, returning the bytes it printed.
+fn run_without_wrap(name: &str, program: Program, target: Target) -> Vec<u8> {
+    let code = program.compile_with(Config { target, mark_spans: false }).expect("compiling");
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR"));
+    let (path, printed) = (dir.join(format!("{}.bf", name)), dir.join(format!("{}.out", name)));
+    fs::write(&path, code).expect("writing the program");
+    let output = Command::new(env!("CARGO_BIN_EXE_bfir"))
+        .args(["-w", "--tee-output", &printed.to_string_lossy(), &path.to_string_lossy()])
+        .output()
+        .expect("running bfir");
+    assert!(output.status.success(), "{}:\n{}", name, String::from_utf8_lossy(&output.stderr));
+    fs::read(&printed).expect("reading the output")
+}
+
+#[test]
+fn constants_never_overshoot_without_wrap() {
+    let target = Target { wrap: false, ..Target::default() };
+    let (mut added, mut subtracted) = (Program::new(), Program::new());
+    let (mut sums, mut differences) = (Vec::new(), Vec::new());
+    for k in 0..=255usize {
+        for n in 0..=255 - k {
+            added = added.push(k).addc(n).write();
+            sums.push((k + n) as u8);
+        }
+        for n in 0..=k {
+            subtracted = subtracted.push(k).subc(n).write();
+            differences.push((k - n) as u8);
+        }
+    }
+    assert_eq!(run_without_wrap("addc", added, target), sums);
+    assert_eq!(run_without_wrap("subc", subtracted, target), differences);
+}
+
+// Compiles a program of `tests/behaviour/` with `args` and checks it against its cases with
+// `--verify`, whose cells follow the target.
+fn verify(case: &str, args: &[&str]) {
+    let output = Command::new(env!("CARGO_BIN_EXE_bfconstructor"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .arg(format!("tests/behaviour/{}.bfs", case))
+        .arg("--verify")
+        .arg(format!("tests/behaviour/{}.toml", case))
+        .args(args)
+        .output()
+        .expect("running bfconstructor");
+    assert!(output.status.success(), "{} ({}):\n{}", case, args.join(" "), String::from_utf8_lossy(&output.stderr));
+}
+
+#[test]
+fn programs_run_on_every_target() {
+    for case in ["logic", "compare", "temps", "hygiene", "tables", "heap"] {
+        verify(case, &["--no-wrap"]);
+        verify(case, &["--cell-bits", "16"]);
+        verify(case, &["--cell-bits", "16", "--no-wrap"]);
+    }
+}
+
+#[test]
+fn no_wrap_code_runs_under_bfir_w() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let output = Command::new(env!("CARGO_BIN_EXE_bfconstructor"))
+        .current_dir(root)
+        .args(["tests/behaviour/logic.bfs", "--no-wrap"])
+        .output()
+        .expect("running bfconstructor");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let program = Path::new(env!("CARGO_TARGET_TMPDIR")).join("logic.bf");
+    fs::write(&program, output.stdout).expect("writing the program");
+    let bfir = |args: &[&str]| Command::new(env!("CARGO_BIN_EXE_bfir")).current_dir(root).args(args).arg(&program).output().expect("running bfir");
+    let output = bfir(&["-w", "--input-string", "2\\n255\\n"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(output.stdout, b"1 1 0\n");
+    let output = bfir(&["-w", "--test", "tests/behaviour/logic.toml"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+}
@@ -0,0 +1,94 @@
+// Checks how the terminal's color support is worked out from its type and its compiled terminfo
+// entry, with entries built here in both number formats.
+
+use brainfucktool::term::{from_term, max_colors, HighlightMode};
+
+// A compiled terminfo entry: the header, the names, `booleans` true booleans, the padding to an
+// even offset and `numbers`, 16-bit if `wide` is false. There are no strings.
+fn entry(wide: bool, names: &str, booleans: usize, numbers: &[i32]) -> Vec<u8> {
+    let magic: i16 = if wide { 0o1036 } else { 0o432 };
+    let names = format!("{}\0", names);
+    let mut entry = Vec::new();
+    for word in [magic, names.len() as i16, booleans as i16, numbers.len() as i16, 0, 0] {
+        entry.extend(word.to_le_bytes());
+    }
+    entry.extend(names.as_bytes());
+    entry.extend(std::iter::repeat_n(1, booleans));
+    if entry.len() % 2 == 1 {
+        entry.push(0);
+    }
+    for &number in numbers {
+        match wide {
+            true => entry.extend(number.to_le_bytes()),
+            false => entry.extend((number as i16).to_le_bytes()),
+        }
+    }
+    entry
+}
+
+// Numbers up to and including `colors`, the 14th, with `max_colors` at index 13.
+fn numbers(colors: i32) -> Vec<i32> {
+    let mut numbers = vec![80, 8, 24, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1];
+    numbers.push(colors);
+    numbers.push(64);
+    numbers
+}
+
+#[test]
+fn terminal_types_name_their_palette() {
+    assert_eq!(from_term("dumb", Some(256)), HighlightMode::None);
+    assert_eq!(from_term("", None), HighlightMode::None);
+    assert_eq!(from_term("xterm-256color", Some(8)), HighlightMode::Color256);
+    assert_eq!(from_term("xterm-256color", None), HighlightMode::Color256);
+    assert_eq!(from_term("foot-direct", None), HighlightMode::TrueColor);
+    assert_eq!(from_term("kitty-direct", Some(256)), HighlightMode::TrueColor);
+}
+
+#[test]
+fn other_terminals_use_their_colors() {
+    // Without an entry, a terminal is assumed to have the 16 colors.
+    assert_eq!(from_term("xterm", None), HighlightMode::Color16);
+    assert_eq!(from_term("xterm", Some(8)), HighlightMode::Color16);
+    assert_eq!(from_term("xterm-mono", Some(0)), HighlightMode::None);
+    assert_eq!(from_term("vt100", Some(2)), HighlightMode::None);
+    assert_eq!(from_term("screen", Some(256)), HighlightMode::Color256);
+    assert_eq!(from_term("xterm-24bit", Some(1 << 24)), HighlightMode::TrueColor);
+}
+
+#[test]
+fn colors_are_read_from_16_bit_entries() {
+    // 8 bytes of names and 2 booleans end on an even offset, so there is no padding.
+    assert_eq!(max_colors(&entry(false, "xterm|x", 2, &numbers(8))), Some(8));
+    assert_eq!(max_colors(&entry(false, "xterm|x", 2, &numbers(256))), Some(256));
+    // An absent capability, and an entry with fewer numbers than `colors`, mean no colors.
+    assert_eq!(max_colors(&entry(false, "xterm|x", 2, &numbers(-1))), Some(0));
+    assert_eq!(max_colors(&entry(false, "vt100|v", 2, &numbers(8)[..13])), Some(0));
+}
+
+#[test]
+fn colors_are_read_from_32_bit_entries() {
+    assert_eq!(max_colors(&entry(true, "xterm-direct|d", 0, &numbers(1 << 24))), Some(1 << 24));
+    assert_eq!(max_colors(&entry(true, "xterm-direct|d", 0, &numbers(256))), Some(256));
+}
+
+#[test]
+fn booleans_are_padded_to_an_even_offset() {
+    // 7 bytes of names and 2 booleans end on an odd offset.
+    let padded = entry(false, "linux|", 2, &numbers(8));
+    assert_eq!(padded[12 + 7 + 2], 0);
+    assert_eq!(max_colors(&padded), Some(8));
+    assert_eq!(max_colors(&entry(true, "ansi|a", 0, &numbers(16))), Some(16));
+}
+
+#[test]
+fn truncated_and_foreign_entries_have_no_colors() {
+    let whole = entry(false, "xterm|x", 2, &numbers(8));
+    let colors = 12 + 8 + 2 + 13 * 2;
+    assert_eq!(max_colors(&whole[..colors + 1]), None);
+    assert_eq!(max_colors(&whole[..colors + 2]), Some(8));
+    assert_eq!(max_colors(&whole[..5]), None);
+    assert_eq!(max_colors(&[]), None);
+    let mut foreign = whole.clone();
+    foreign[0] = 0;
+    assert_eq!(max_colors(&foreign), None);
+}
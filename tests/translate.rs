@@ -0,0 +1,85 @@
+// Translates the classic programs in `programs/` with `bfir --to` and runs the translations that
+// bfir can read back, which must print what the originals do.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+fn bfir(root: &Path, args: &[&str], input: &Path) -> Result<Vec<u8>, String> {
+    let output = Command::new(env!("CARGO_BIN_EXE_bfir"))
+        .current_dir(root)
+        .args(args)
+        .stdin(fs::File::open(input).expect("opening the input"))
+        .output()
+        .expect("running bfir");
+    match output.status.success() {
+        true => Ok(output.stdout),
+        false => Err(String::from_utf8_lossy(&output.stderr).into_owned()),
+    }
+}
+
+const COW: &str = r#"
+right = "moo"
+left = "mOo"
+inc = "MoO"
+dec = "MOo"
+output = "OOM"
+input = "oom"
+open = "MOO"
+close = "moo!"
+"#;
+
+#[test]
+fn translations_run_like_the_original() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR"));
+    let mapping = dir.join("cow.toml");
+    fs::write(&mapping, COW).expect("writing the mapping");
+    let mapping = format!("map:{}", mapping.display());
+    for name in ["hello", "rot13", "squares"] {
+        let source = format!("programs/{}.bf", name);
+        let input = root.join("programs").join(format!("{}.in", name));
+        let input = if input.exists() { input } else { Path::new("/dev/null").to_path_buf() };
+        let expected = bfir(root, &[&source], &input).unwrap();
+        for (to, ext) in [("ook", "ook"), ("bf", "bf"), (mapping.as_str(), "cow")] {
+            let translated = dir.join(format!("{}.{}", name, ext));
+            fs::write(&translated, bfir(root, &["--to", to, &source], &input).unwrap()).expect("writing the translation");
+            let from = if ext == "cow" { mapping.as_str() } else { ext };
+            let back = bfir(root, &["--from", from, "--to", "bf", &translated.to_string_lossy()], &input).unwrap();
+            let back_path = dir.join(format!("{}.{}.bf", name, ext));
+            fs::write(&back_path, back).expect("writing the translation back");
+            let actual = bfir(root, &[&back_path.to_string_lossy()], &input).unwrap();
+            assert!(actual == expected, "{} through {}:\n{}", name, to, String::from_utf8_lossy(&actual));
+        }
+    }
+}
+
+#[test]
+fn formats_that_cannot_hold_a_program_are_errors() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let program = Path::new(env!("CARGO_TARGET_TMPDIR")).join("store.bf");
+    fs::write(&program, "+$>!.").expect("writing the program");
+    let program = program.to_string_lossy();
+    let null = Path::new("/dev/null");
+    let error = bfir(root, &["--dialect", "extended1", "--to", "ook", &program], null).unwrap_err();
+    assert!(error.contains("ook has no `store` command"), "{}", error);
+    assert!(bfir(root, &["--dialect", "extended1", "--to", "extended1", &program], null).is_ok());
+    let error = bfir(root, &["--from", "c", "--to", "bf", &program], null).unwrap_err();
+    assert!(error.contains("can only be written"), "{}", error);
+}
+
+#[test]
+fn wasm_is_a_module() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let module = bfir(root, &["--to", "wasm", "programs/hello.bf"], Path::new("/dev/null")).unwrap();
+    assert!(module.starts_with(b"\0asm\x01\0\0\0"));
+}
+
+#[test]
+fn every_format_is_listed() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let listed = String::from_utf8(bfir(root, &["--list-targets"], Path::new("/dev/null")).unwrap()).expect("utf-8 list");
+    for format in ["bf", "ook", "extended1", "pbrain", "map:FILE", "c", "rust", "js", "wasm", "ir", "ir-json"] {
+        assert!(listed.lines().any(|line| line.starts_with(&format!("{} ", format))), "{} is not listed", format);
+    }
+}
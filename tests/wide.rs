@@ -0,0 +1,59 @@
+// Runs multi-cell arithmetic built with `Program` on plain Brainfuck and on `--target extended1`,
+// and checks the cells it leaves on the stack, most significant first, and what it prints.
+
+use brainfucktool::ir::{self, Syntax};
+use brainfucktool::{verify, Config, Program, Statements, Target};
+
+// The stack from the first pushed cell up to the pointer, and the output, for every target,
+// which must agree.
+fn run(program: Program) -> (Vec<u64>, String) {
+    let mut runs = Vec::new();
+    for extended in [false, true] {
+        let target = Target { extended, ..Target::default() };
+        let code = program.compile_with(Config { target, mark_spans: false }).expect("compiling");
+        let insts = ir::parse(&code, Syntax { extended, ..Syntax::default() }).expect("parsing");
+        let run = verify::execute(&insts, b"", target).expect("running");
+        runs.push((run.tape[1..=run.ptr].to_vec(), String::from_utf8_lossy(&run.output).into_owned()));
+    }
+    assert_eq!(runs[0], runs[1], "plain and extended1 code agree");
+    runs.remove(0)
+}
+
+fn stack(program: Program) -> Vec<u64> {
+    run(program).0
+}
+
+#[test]
+fn addition_carries() {
+    assert_eq!(stack(Program::new().push16(0x00FF).push16(1).add16()), [0x01, 0x00]);
+    assert_eq!(stack(Program::new().push16(0xFFFF).push16(1).add16()), [0x00, 0x00]);
+    assert_eq!(stack(Program::new().push32(0xFFFF).push32(1).add32()), [0x00, 0x01, 0x00, 0x00]);
+    assert_eq!(stack(Program::new().push32(0x00FF_FFFF).push32(1).add32()), [0x01, 0x00, 0x00, 0x00]);
+}
+
+#[test]
+fn subtraction_borrows() {
+    assert_eq!(stack(Program::new().push16(0x0100).push16(1).sub16()), [0x00, 0xFF]);
+    assert_eq!(stack(Program::new().push16(0).push16(1).sub16()), [0xFF, 0xFF]);
+    assert_eq!(stack(Program::new().push32(0x0001_0000).push32(1).sub32()), [0x00, 0x00, 0xFF, 0xFF]);
+    assert_eq!(stack(Program::new().push32(0).push32(1).sub32()), [0xFF, 0xFF, 0xFF, 0xFF]);
+}
+
+#[test]
+fn comparisons_push_a_sign() {
+    // 255 is -1 as a signed byte: a < b.
+    assert_eq!(stack(Program::new().push16(0x00FF).push16(0x0100).cmp16()), [255]);
+    assert_eq!(stack(Program::new().push16(0x0100).push16(0x00FF).cmp16()), [1]);
+    assert_eq!(stack(Program::new().push16(0x1234).push16(0x1234).cmp16()), [0]);
+    assert_eq!(stack(Program::new().push16(0xFF00).push16(0x00FF).cmp16()), [1]);
+    assert_eq!(stack(Program::new().push32(0x0000_FFFF).push32(0x0001_0000).cmp32()), [255]);
+    assert_eq!(stack(Program::new().push32(0x0001_0000).push32(0x0000_FFFF).cmp32()), [1]);
+    assert_eq!(stack(Program::new().push32(0xFFFF_FFFF).push32(0xFFFF_FFFF).cmp32()), [0]);
+}
+
+#[test]
+fn printing_in_decimal() {
+    assert_eq!(run(Program::new().push16(0x00FF).push16(1).add16().print16()), (vec![], "256".to_string()));
+    assert_eq!(run(Program::new().push16(0).push16(1).sub16().print16()), (vec![], "65535".to_string()));
+    assert_eq!(run(Program::new().push32(0xFFFF).push32(1).add32().print32()), (vec![], "65536".to_string()));
+}
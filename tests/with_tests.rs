@@ -0,0 +1,39 @@
+// Compiles `examples/reverse.bfs` with `--with-tests` and runs the compiled program against the
+// manifest it wrote with `bfir --test`.
+
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+fn run(exe: &str, root: &Path, args: &[&str]) -> Output {
+    Command::new(exe).current_dir(root).args(args).output().expect("running the binary")
+}
+
+#[test]
+fn compiled_programs_pass_their_examples() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR"));
+    let (program, manifest) = (dir.join("reverse.bf"), dir.join("reverse.toml"));
+    let manifest = manifest.to_string_lossy();
+    let output = run(env!("CARGO_BIN_EXE_bfconstructor"), root, &["examples/reverse.bfs", "--with-tests", &manifest]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    fs::write(&program, output.stdout).expect("writing the program");
+    let written = fs::read_to_string(manifest.as_ref()).expect("reading the manifest");
+    assert!(written.contains("input = \"abcd\\n\"\noutput = \"dcba\\n\""), "{}", written);
+
+    let output = run(env!("CARGO_BIN_EXE_bfir"), root, &["--test", &manifest, &program.to_string_lossy()]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    // Another program fails the same examples.
+    let output = run(env!("CARGO_BIN_EXE_bfir"), root, &["--test", &manifest, "programs/hello.bf"]);
+    assert!(!output.status.success());
+}
+
+#[test]
+fn programs_without_examples_are_rejected() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let manifest = Path::new(env!("CARGO_TARGET_TMPDIR")).join("countdown.toml");
+    let output = run(env!("CARGO_BIN_EXE_bfconstructor"), root, &["examples/countdown.bfs", "--with-tests", &manifest.to_string_lossy()]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("example"));
+}